@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(surface) = pepl_ui::fuzz_parse(data) {
+        // A parsed surface must survive its own serialization.
+        let json = surface.to_json();
+        let reparsed = pepl_ui::fuzz_parse(json.as_bytes()).expect("re-serialized surface parses");
+        assert_eq!(reparsed.to_json(), json);
+    }
+});
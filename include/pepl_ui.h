@@ -0,0 +1,37 @@
+/*
+ * C ABI for pepl-ui (build with `--features ffi`).
+ *
+ * All strings are NUL-terminated UTF-8. Every non-NULL `char *` returned here
+ * is owned by the caller and must be released with pepl_ui_string_free().
+ */
+
+#ifndef PEPL_UI_H
+#define PEPL_UI_H
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Validate a surface JSON payload. Returns a JSON array of error strings. */
+char *pepl_ui_validate(const char *json);
+
+/* Compare two surface payloads. Returns a JSON array of differences,
+ * or NULL if either payload is not a valid surface. */
+char *pepl_ui_diff(const char *old_json, const char *new_json);
+
+/* Component registry as JSON. */
+char *pepl_ui_registry_json(void);
+
+/* Whether `name` is a registered component type. */
+bool pepl_ui_is_component(const char *name);
+
+/* Release a string returned by any pepl_ui_* function. NULL is a no-op. */
+void pepl_ui_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PEPL_UI_H */
@@ -9,10 +9,35 @@
 //! Components auto-generate sensible defaults:
 //! - Button label → accessible label, role "button"
 //! - TextInput label/placeholder → accessible label, role "textfield"
-//! - Text value → accessible label, role "text"
-//! - ProgressBar → "{value}% complete", role "progressbar"
-//! - Modal title → accessible label, role "dialog"
-//! - Toast message → accessible label, role "alert"
+//! - Stepper label → accessible label, role "slider", value "{value} of {max}"
+//! - Rating → label "Rating", role "slider" (or "text" when read-only), value "{value} of {max} stars"
+//! - FilePicker label → accessible label, role "button"
+//! - Text value → accessible label, role "text" (copyable → "Copy" action hint)
+//! - RichText plain text (spans joined, markdown markup removed) → accessible label, role "text"
+//! - ProgressBar → "{value}% complete" ("Loading" if indeterminate), role "progressbar"
+//! - Image alt → accessible label, role "image"
+//! - Icon label → accessible label, role "image" (unlabelled icons are
+//!   decorative and hidden from assistive technology)
+//! - Video label → accessible label, role "group"
+//! - AudioPlayer title → accessible label, role "group", value "playing"/"paused"
+//! - Canvas label → accessible label, role "image" (unlabelled canvases are
+//!   decorative and hidden from assistive technology)
+//! - Chart title → accessible label, role "image", value summarizing the data
+//! - Spinner → label "Loading", role "progressbar"
+//! - Skeleton → label "Loading", hidden from assistive technology
+//! - Tabs → "Tabs", role "group", value "{label}, tab {n} of {count}"
+//! - Carousel → "Carousel", role "group", value "page {n} of {count}"
+//! - Divider, Spacer → decorative, role "none"
+//! - Disclosure title → accessible label, role "group", value "expanded"/"collapsed"
+//! - AppBar title → accessible label, role "group"
+//! - Table → "Table", role "table", value "{rows} rows, {columns} columns"
+//! - Modal title → accessible label, role "dialog" (fullscreen modals trap focus)
+//! - BottomSheet title → accessible label, role "dialog"
+//! - Drawer title → accessible label, role "dialog" (modal, like a fullscreen Modal)
+//! - Toast message → accessible label, role "alert" (action label → hint)
+//! - Snackbar message → accessible label, role "alert", polite (action label → hint)
+//! - Tooltip text → accessible label, role "none" (the text is also the
+//!   wrapped node's hint)
 //!
 //! Developers can override defaults via the `accessible` prop:
 //! ```pepl
@@ -48,6 +73,7 @@ pub enum SemanticRole {
     Checkbox,
     Slider,
     List,
+    Table,
     Dialog,
     Alert,
     Group,
@@ -69,6 +95,7 @@ impl SemanticRole {
             Self::Checkbox => "checkbox",
             Self::Slider => "slider",
             Self::List => "list",
+            Self::Table => "table",
             Self::Dialog => "dialog",
             Self::Alert => "alert",
             Self::Group => "group",
@@ -90,6 +117,7 @@ impl SemanticRole {
             "checkbox" => Some(Self::Checkbox),
             "slider" => Some(Self::Slider),
             "list" => Some(Self::List),
+            "table" => Some(Self::Table),
             "dialog" => Some(Self::Dialog),
             "alert" => Some(Self::Alert),
             "group" => Some(Self::Group),
@@ -112,6 +140,7 @@ impl SemanticRole {
             "checkbox",
             "slider",
             "list",
+            "table",
             "dialog",
             "alert",
             "group",
@@ -181,6 +210,14 @@ pub struct AccessibilityInfo {
 
     /// Live region behavior for dynamic updates (optional).
     pub live_region: Option<LiveRegion>,
+
+    /// Whether the element is modal — assistive focus is trapped inside it
+    /// until dismissed (optional).
+    pub modal: Option<bool>,
+
+    /// Whether assistive technology skips the element (optional — e.g.
+    /// decorative loading placeholders).
+    pub hidden: Option<bool>,
 }
 
 impl AccessibilityInfo {
@@ -192,6 +229,8 @@ impl AccessibilityInfo {
             role: None,
             value: None,
             live_region: None,
+            modal: None,
+            hidden: None,
         }
     }
 
@@ -219,6 +258,18 @@ impl AccessibilityInfo {
         self
     }
 
+    /// Mark the element as modal (traps assistive focus).
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = Some(modal);
+        self
+    }
+
+    /// Hide the element from assistive technology.
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+
     /// Convert to a `PropValue::Record` for insertion into `SurfaceNode.props`.
     pub fn to_prop_value(&self) -> PropValue {
         let mut fields = BTreeMap::new();
@@ -241,6 +292,12 @@ impl AccessibilityInfo {
                 PropValue::String(live_region.as_str().to_string()),
             );
         }
+        if let Some(modal) = self.modal {
+            fields.insert("modal".to_string(), PropValue::Bool(modal));
+        }
+        if let Some(hidden) = self.hidden {
+            fields.insert("hidden".to_string(), PropValue::Bool(hidden));
+        }
         PropValue::Record(fields)
     }
 }
@@ -253,26 +310,57 @@ impl AccessibilityInfo {
 /// |-------------|----------------|
 /// | Button      | button         |
 /// | TextInput   | textfield      |
+/// | Stepper     | slider         |
+/// | Rating      | slider         |
+/// | FilePicker  | button         |
 /// | Text        | text           |
+/// | RichText    | text           |
 /// | ProgressBar | progressbar    |
+/// | Spinner     | progressbar    |
+/// | Image       | image          |
+/// | Icon        | image          |
+/// | Skeleton    | none           |
 /// | Column      | group          |
 /// | Row         | group          |
 /// | Scroll      | region         |
+/// | Tabs        | group          |
+/// | Carousel    | group          |
+/// | Disclosure  | group          |
+/// | Overlay     | group          |
+/// | AppBar      | group          |
+/// | Divider     | none           |
+/// | Spacer      | none           |
 /// | ScrollList  | list           |
+/// | Table       | table          |
 /// | Modal       | dialog         |
+/// | BottomSheet | dialog         |
+/// | Drawer      | dialog         |
 /// | Toast       | alert          |
+/// | Snackbar    | alert          |
+/// | Tooltip     | none           |
+/// | Form        | group          |
+/// | Video       | group          |
+/// | AudioPlayer | group          |
+/// | Canvas      | image          |
+/// | Chart       | image          |
 pub fn default_role(component_type: &str) -> SemanticRole {
     match component_type {
-        "Button" => SemanticRole::Button,
+        "Button" | "FilePicker" => SemanticRole::Button,
         "TextInput" => SemanticRole::TextField,
-        "Text" => SemanticRole::Text,
-        "ProgressBar" => SemanticRole::ProgressBar,
+        "Stepper" | "Rating" => SemanticRole::Slider,
+        "Text" | "RichText" => SemanticRole::Text,
+        "ProgressBar" | "Spinner" => SemanticRole::ProgressBar,
+        "Image" | "Icon" | "Canvas" | "Chart" => SemanticRole::Image,
         "Column" => SemanticRole::Group,
         "Row" => SemanticRole::Group,
         "Scroll" => SemanticRole::Region,
+        "Tabs" | "Carousel" | "Disclosure" | "Overlay" | "AppBar" => SemanticRole::Group,
+        "Divider" | "Spacer" | "Skeleton" | "Tooltip" => SemanticRole::None,
         "ScrollList" => SemanticRole::List,
-        "Modal" => SemanticRole::Dialog,
-        "Toast" => SemanticRole::Alert,
+        "Table" => SemanticRole::Table,
+        "Modal" | "BottomSheet" | "Drawer" => SemanticRole::Dialog,
+        "Toast" | "Snackbar" => SemanticRole::Alert,
+        "Form" | "Video" | "AudioPlayer" => SemanticRole::Group,
         _ => SemanticRole::None,
     }
 }
@@ -284,11 +372,31 @@ pub fn default_role(component_type: &str) -> SemanticRole {
 /// Auto-labeling rules:
 /// - Button: `label` prop → accessible label
 /// - TextInput: `label` prop, else `placeholder`, else "Text input"
-/// - Text: `value` prop (truncated to 100 chars)
-/// - ProgressBar: "{value}% complete"
+/// - Stepper: `label` prop, else "Stepper"
+/// - Rating: "Rating" (read-only ratings get role "text")
+/// - FilePicker: `label` prop, else "Choose file"
+/// - Text: `value` prop (truncated to 100 chars); copyable Text gets the hint
+///   "Action available: Copy"
+/// - RichText: the plain text of `spans` or `markdown` (truncated to 100 chars)
+/// - ProgressBar: "{value}% complete", or "Loading" if indeterminate
+/// - Image: `alt` prop, else "Image"
+/// - Icon: `label` prop, else the icon name (hidden from assistive technology)
+/// - Video: `label` prop, else "Video"
+/// - AudioPlayer: `title` prop, else "Audio player"
+/// - Canvas: `label` prop, else "Drawing" (hidden from assistive technology)
+/// - Chart: `title` prop, else "Chart"
+/// - Spinner: "Loading"
+/// - Skeleton: "Loading" (hidden from assistive technology)
 /// - Modal: `title` prop, else "Dialog"
+/// - BottomSheet: `title` prop, else "Sheet"
+/// - Drawer: `title` prop, else "Menu"
 /// - Toast: `message` prop
-/// - Column, Row, Scroll, ScrollList: component type name (generic)
+/// - Snackbar: `message` prop
+/// - Tooltip: `text` prop
+/// - Disclosure: `title` prop, else "Disclosure"
+/// - AppBar: `title` prop
+/// - Column, Row, Scroll, Tabs, Carousel, Overlay, Form: component type name (generic)
+/// - ScrollList: "List"
 pub fn auto_accessible(
     component_type: &str,
     props: &BTreeMap<String, PropValue>,
@@ -298,17 +406,123 @@ pub fn auto_accessible(
 
     let mut info = AccessibilityInfo::new(label).role(role);
 
-    // Add value for ProgressBar
-    if component_type == "ProgressBar" {
+    // Add value for ProgressBar (indeterminate bars have no meaningful value)
+    if component_type == "ProgressBar" && !is_indeterminate(props) {
         if let Some(PropValue::Number(v)) = props.get("value") {
             let pct = (v * 100.0).round() as i64;
             info = info.value(format!("{pct}%"));
         }
     }
 
+    // Announce the selected tab, e.g. "Done, tab 2 of 2"
+    if component_type == "Tabs" {
+        if let (Some(PropValue::List(tabs)), Some(PropValue::Number(selected))) =
+            (props.get("tabs"), props.get("selected"))
+        {
+            let index = *selected as usize;
+            if let Some(PropValue::String(label)) = tabs.get(index) {
+                info = info.value(format!("{label}, tab {} of {}", index + 1, tabs.len()));
+            }
+        }
+    }
+
+    // Announce the Stepper position, e.g. "3 of 10"
+    if component_type == "Stepper" {
+        if let Some(PropValue::Number(value)) = props.get("value") {
+            info = info.value(match props.get("max") {
+                Some(PropValue::Number(max)) => format!("{value} of {max}"),
+                _ => value.to_string(),
+            });
+        }
+    }
+
+    // Announce the Rating, e.g. "3 of 5 stars"; read-only ratings are plain text
+    if component_type == "Rating" {
+        if let (Some(PropValue::Number(value)), Some(PropValue::Number(max))) =
+            (props.get("value"), props.get("max"))
+        {
+            info = info.value(format!("{value} of {max} stars"));
+        }
+        if !props.contains_key("on_change") {
+            info = info.role(SemanticRole::Text);
+        }
+    }
+
+    // Announce whether a Disclosure is open
+    if component_type == "Disclosure" {
+        if let Some(PropValue::Bool(expanded)) = props.get("expanded") {
+            info = info.value(if *expanded { "expanded" } else { "collapsed" });
+        }
+    }
+
+    // Announce whether an AudioPlayer is playing
+    if component_type == "AudioPlayer" {
+        if let Some(PropValue::Bool(playing)) = props.get("playing") {
+            info = info.value(if *playing { "playing" } else { "paused" });
+        }
+    }
+
+    // Announce the table shape, e.g. "12 rows, 3 columns"
+    if component_type == "Table" {
+        if let (Some(PropValue::List(rows)), Some(PropValue::List(columns))) =
+            (props.get("rows"), props.get("columns"))
+        {
+            info = info.value(format!("{} rows, {} columns", rows.len(), columns.len()));
+        }
+    }
+
+    // Summarize the plotted data, e.g. "Steps: 3 values from 4200 to 8100, last 6500"
+    if component_type == "Chart" {
+        if let Some(summary) = chart_summary(props) {
+            info = info.value(summary);
+        }
+    }
+
+    // Skeletons are decorative placeholders
+    if component_type == "Skeleton" {
+        info = info.hidden(true);
+    }
+
+    // Unlabelled canvases and icons are decorative
+    if matches!(component_type, "Canvas" | "Icon") && !props.contains_key("label") {
+        info = info.role(SemanticRole::None).hidden(true);
+    }
+
+    // Announce the copy action on copyable Text
+    if component_type == "Text" && props.get("copyable") == Some(&PropValue::Bool(true)) {
+        info = info.hint("Action available: Copy");
+    }
+
+    // Fullscreen Modals trap focus
+    if component_type == "Modal"
+        && extract_string_prop(props, "presentation").as_deref() == Some("fullscreen")
+    {
+        info = info.modal(true);
+    }
+
+    // Drawers cover the screen they navigate away from
+    if component_type == "Drawer" {
+        info = info.modal(true);
+    }
+
     // Add live_region for Toast (assertive — interrupts to announce)
     if component_type == "Toast" {
         info = info.live_region(LiveRegion::Assertive);
+
+        // Announce an attached action (e.g. "Undo") so it is discoverable
+        if let Some(PropValue::Record(action)) = props.get("action") {
+            if let Some(label) = extract_string_prop(action, "label") {
+                info = info.hint(format!("Action available: {label}"));
+            }
+        }
+    }
+
+    // Snackbars wait for the current announcement to finish
+    if component_type == "Snackbar" {
+        info = info.live_region(LiveRegion::Polite);
+        if let Some(label) = extract_string_prop(props, "action_label") {
+            info = info.hint(format!("Action available: {label}"));
+        }
     }
 
     info
@@ -323,18 +537,40 @@ fn auto_label(component_type: &str, props: &BTreeMap<String, PropValue>) -> Stri
             .or_else(|| extract_string_prop(props, "placeholder"))
             .unwrap_or_else(|| "Text input".to_string()),
 
+        "Stepper" => extract_string_prop(props, "label").unwrap_or_else(|| "Stepper".to_string()),
+        "Rating" => "Rating".to_string(),
+        "FilePicker" => {
+            extract_string_prop(props, "label").unwrap_or_else(|| "Choose file".to_string())
+        }
+
         "Text" => {
             let value = extract_string_prop(props, "value").unwrap_or_else(|| "Text".to_string());
-            // Truncate long text for accessibility labels
-            if value.len() > 100 {
-                format!("{}…", &value[..100])
+            truncate_label(value)
+        }
+
+        "RichText" => {
+            let text = match (props.get("spans"), props.get("markdown")) {
+                (Some(PropValue::List(spans)), _) => spans
+                    .iter()
+                    .filter_map(|span| match span {
+                        PropValue::Record(fields) => extract_string_prop(fields, "text"),
+                        _ => None,
+                    })
+                    .collect(),
+                (_, Some(PropValue::String(markdown))) => markdown_plain_text(markdown),
+                _ => String::new(),
+            };
+            if text.trim().is_empty() {
+                "Text".to_string()
             } else {
-                value
+                truncate_label(text)
             }
         }
 
         "ProgressBar" => {
-            if let Some(PropValue::Number(v)) = props.get("value") {
+            if is_indeterminate(props) {
+                "Loading".to_string()
+            } else if let Some(PropValue::Number(v)) = props.get("value") {
                 let pct = (v * 100.0).round() as i64;
                 format!("{pct}% complete")
             } else {
@@ -342,12 +578,33 @@ fn auto_label(component_type: &str, props: &BTreeMap<String, PropValue>) -> Stri
             }
         }
 
+        "Spinner" | "Skeleton" => "Loading".to_string(),
+        "Image" => extract_string_prop(props, "alt").unwrap_or_else(|| "Image".to_string()),
+        "Video" => extract_string_prop(props, "label").unwrap_or_else(|| "Video".to_string()),
+        "Canvas" => extract_string_prop(props, "label").unwrap_or_else(|| "Drawing".to_string()),
+        "Icon" => extract_string_prop(props, "label")
+            .or_else(|| extract_string_prop(props, "name"))
+            .unwrap_or_else(|| "Icon".to_string()),
+        "Chart" => extract_string_prop(props, "title").unwrap_or_else(|| "Chart".to_string()),
+        "AudioPlayer" => {
+            extract_string_prop(props, "title").unwrap_or_else(|| "Audio player".to_string())
+        }
+
         "Modal" => extract_string_prop(props, "title").unwrap_or_else(|| "Dialog".to_string()),
+        "BottomSheet" => extract_string_prop(props, "title").unwrap_or_else(|| "Sheet".to_string()),
+        "Drawer" => extract_string_prop(props, "title").unwrap_or_else(|| "Menu".to_string()),
 
-        "Toast" => {
+        "Toast" | "Snackbar" => {
             extract_string_prop(props, "message").unwrap_or_else(|| "Notification".to_string())
         }
 
+        "AppBar" => extract_string_prop(props, "title").unwrap_or_else(|| "AppBar".to_string()),
+        "Disclosure" => {
+            extract_string_prop(props, "title").unwrap_or_else(|| "Disclosure".to_string())
+        }
+
+        "Tooltip" => extract_string_prop(props, "text").unwrap_or_else(|| "Tooltip".to_string()),
+
         "ScrollList" => "List".to_string(),
 
         // Layout containers: generic labels
@@ -355,6 +612,122 @@ fn auto_label(component_type: &str, props: &BTreeMap<String, PropValue>) -> Stri
     }
 }
 
+/// Truncate long text for accessibility labels.
+fn truncate_label(text: String) -> String {
+    if text.chars().count() > 100 {
+        format!("{}…", text.chars().take(100).collect::<String>())
+    } else {
+        text
+    }
+}
+
+/// Strip inline markdown: emphasis and code markers are dropped, and
+/// `[text](url)` links keep only their text.
+fn markdown_plain_text(markdown: &str) -> String {
+    let mut text = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '\\' => {
+                if let Some(escaped) = rest.chars().next() {
+                    text.push(escaped);
+                    rest = &rest[escaped.len_utf8()..];
+                }
+            }
+            '*' | '_' | '`' | '~' => {}
+            '[' => match rest
+                .find("](")
+                .and_then(|close| Some((close, close + rest[close..].find(')')?)))
+            {
+                Some((close, end)) => {
+                    text.push_str(&markdown_plain_text(&rest[..close]));
+                    rest = &rest[end + 1..];
+                }
+                None => text.push(c),
+            },
+            _ => text.push(c),
+        }
+    }
+    text
+}
+
+/// Textual summary of a Chart's data: slice percentages for a pie, else the
+/// range and last value of each series.
+fn chart_summary(props: &BTreeMap<String, PropValue>) -> Option<String> {
+    let PropValue::List(series) = props.get("series")? else {
+        return None;
+    };
+    let series: Vec<(String, Vec<f64>)> = series
+        .iter()
+        .filter_map(|entry| {
+            let PropValue::Record(fields) = entry else {
+                return None;
+            };
+            let Some(PropValue::List(values)) = fields.get("values") else {
+                return None;
+            };
+            let values = values
+                .iter()
+                .filter_map(|v| match v {
+                    PropValue::Number(n) => Some(*n),
+                    _ => None,
+                })
+                .collect();
+            Some((
+                extract_string_prop(fields, "name").unwrap_or_default(),
+                values,
+            ))
+        })
+        .collect();
+
+    if extract_string_prop(props, "kind").as_deref() == Some("pie") {
+        let (_, values) = series.first()?;
+        let total: f64 = values.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let labels = match props.get("labels") {
+            Some(PropValue::List(labels)) => labels.as_slice(),
+            _ => &[],
+        };
+        let slices: Vec<String> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let label = match labels.get(i) {
+                    Some(PropValue::String(label)) => label.clone(),
+                    _ => format!("slice {}", i + 1),
+                };
+                format!("{label} {}%", (v / total * 100.0).round())
+            })
+            .collect();
+        return Some(slices.join(", "));
+    }
+
+    let summaries: Vec<String> = series
+        .iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(name, values)| {
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let last = values[values.len() - 1];
+            let range = format!("{} values from {min} to {max}, last {last}", values.len());
+            if name.is_empty() {
+                range
+            } else {
+                format!("{name}: {range}")
+            }
+        })
+        .collect();
+    (!summaries.is_empty()).then(|| summaries.join("; "))
+}
+
+/// Whether a ProgressBar is in indeterminate mode.
+fn is_indeterminate(props: &BTreeMap<String, PropValue>) -> bool {
+    matches!(props.get("indeterminate"), Some(PropValue::Bool(true)))
+}
+
 /// Extract a string prop value.
 fn extract_string_prop(props: &BTreeMap<String, PropValue>, key: &str) -> Option<String> {
     match props.get(key) {
@@ -373,6 +746,7 @@ fn extract_string_prop(props: &BTreeMap<String, PropValue>, key: &str) -> Option
 /// - `role`: string enum (optional) — one of the valid semantic roles
 /// - `value`: string (optional)
 /// - `live_region`: string enum (optional) — "polite" or "assertive"
+/// - `modal`: bool (optional)
 pub fn validate_accessible_prop(component_name: &str, prop: &PropValue) -> Vec<String> {
     let mut errors = Vec::new();
 
@@ -389,7 +763,7 @@ pub fn validate_accessible_prop(component_name: &str, prop: &PropValue) -> Vec<S
 
     // Required: label (string)
     match fields.get("label") {
-        Some(PropValue::String(_)) => {}
+        Some(v) if v.is_text() => {}
         Some(other) => errors.push(format!(
             "{component_name}.accessible.label: expected string, got {}",
             other.type_name()
@@ -401,7 +775,7 @@ pub fn validate_accessible_prop(component_name: &str, prop: &PropValue) -> Vec<S
 
     // Optional: hint (string)
     if let Some(val) = fields.get("hint") {
-        if !matches!(val, PropValue::String(_)) {
+        if !val.is_text() {
             errors.push(format!(
                 "{component_name}.accessible.hint: expected string, got {}",
                 val.type_name()
@@ -426,7 +800,7 @@ pub fn validate_accessible_prop(component_name: &str, prop: &PropValue) -> Vec<S
 
     // Optional: value (string)
     if let Some(val) = fields.get("value") {
-        if !matches!(val, PropValue::String(_)) {
+        if !val.is_text() {
             errors.push(format!(
                 "{component_name}.accessible.value: expected string, got {}",
                 val.type_name()
@@ -448,11 +822,23 @@ pub fn validate_accessible_prop(component_name: &str, prop: &PropValue) -> Vec<S
         }
     }
 
+    // Optional: modal, hidden (bool)
+    for key in ["modal", "hidden"] {
+        if let Some(val) = fields.get(key) {
+            if !matches!(val, PropValue::Bool(_)) {
+                errors.push(format!(
+                    "{component_name}.accessible.{key}: expected bool, got {}",
+                    val.type_name()
+                ));
+            }
+        }
+    }
+
     // Unknown fields
     for key in fields.keys() {
         if !matches!(
             key.as_str(),
-            "label" | "hint" | "role" | "value" | "live_region"
+            "label" | "hint" | "role" | "value" | "live_region" | "modal" | "hidden"
         ) {
             errors.push(format!(
                 "{component_name}.accessible: unknown field '{key}'"
@@ -471,6 +857,79 @@ pub fn ensure_accessible(node: &mut crate::surface::SurfaceNode) {
     if node.props.contains_key("accessible") {
         return;
     }
-    let info = auto_accessible(&node.component_type, &node.props);
-    node.set_prop("accessible", info.to_prop_value());
+    let mut info = auto_accessible(&node.component_type, &node.props);
+    // The Carousel page count comes from its children, not its props. An
+    // index that does not name a child is not announced.
+    if node.component_type == "Carousel" {
+        if let Some(PropValue::Number(index)) = node.props.get("index") {
+            let pages = node.children.len();
+            if index.fract() == 0.0 && *index >= 0.0 && *index < pages as f64 {
+                info = info.value(format!("page {} of {pages}", *index as usize + 1));
+            }
+        }
+    }
+    let mut accessible = info.to_prop_value();
+    // A localized source string stays localized in the label.
+    if let (Some(source @ PropValue::I18n { .. }), PropValue::Record(fields)) =
+        (label_source(node), &mut accessible)
+    {
+        fields.insert("label".to_string(), source.clone());
+    }
+    node.set_prop("accessible", accessible);
+}
+
+/// The prop the auto-generated label is taken from, if any.
+fn label_source(node: &crate::surface::SurfaceNode) -> Option<&PropValue> {
+    let props = &node.props;
+    match node.component_type.as_str() {
+        "Button" | "FilePicker" => props.get("label"),
+        "TextInput" => props.get("label").or_else(|| props.get("placeholder")),
+        "Stepper" => props.get("label"),
+        "Text" => props.get("value"),
+        "Image" => props.get("alt"),
+        "Video" | "Canvas" | "Icon" => props.get("label"),
+        "AudioPlayer" => props.get("title"),
+        "Modal" | "BottomSheet" | "Drawer" => props.get("title"),
+        "Toast" | "Snackbar" => props.get("message"),
+        "Tooltip" => props.get("text"),
+        "Disclosure" | "AppBar" | "Chart" => props.get("title"),
+        _ => None,
+    }
+}
+
+/// Audit every node of `surface`, layers included, for accessibility
+/// problems.
+///
+/// Reports nodes without an `accessible` prop, malformed `accessible`
+/// records (see [`validate_accessible_prop`]), and empty labels. Each message
+/// is prefixed with the node path. Empty means no findings.
+pub fn audit(surface: &crate::surface::Surface) -> Vec<String> {
+    let mut findings = Vec::new();
+    surface.walk(|node, path| audit_node(node, path, &mut findings));
+    findings
+}
+
+fn audit_node(
+    node: &crate::surface::SurfaceNode,
+    path: &crate::path::NodePath,
+    findings: &mut Vec<String>,
+) {
+    let name = &node.component_type;
+    match node.props.get("accessible") {
+        None => findings.push(format!("{path}: {name} has no accessible prop")),
+        Some(prop) => {
+            for error in validate_accessible_prop(name, prop) {
+                findings.push(format!("{path}: {error}"));
+            }
+            if let PropValue::Record(fields) = prop {
+                if let Some(PropValue::String(label)) = fields.get("label") {
+                    if label.trim().is_empty() {
+                        findings.push(format!(
+                            "{path}: {name}.accessible.label: must not be empty"
+                        ));
+                    }
+                }
+            }
+        }
+    }
 }
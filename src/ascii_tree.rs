@@ -0,0 +1,73 @@
+//! ASCII dump of a surface tree for debugging.
+//!
+//! ```text
+//! Column spacing=8.0
+//! ├── Text value="Title"
+//! └── Column
+//!     └── Button #save label="Save" on_tap={"__action":"save"}
+//! ```
+//!
+//! Each line shows the component type, `#test_id` and `$key` if set, and the props as
+//! compact JSON. The generated `accessible` prop is omitted to keep lines
+//! short; use [`accessibility::audit`](crate::accessibility::audit) for that.
+//! Each [layer](crate::layers) follows the content tree under a `[name]`
+//! header line.
+
+use std::fmt::Write;
+
+use crate::surface::{Surface, SurfaceNode};
+
+impl Surface {
+    /// Render the tree and its layers as indented ASCII, one node per line.
+    pub fn ascii_tree(&self) -> String {
+        let mut out = self.root.ascii_tree();
+        for layer in &self.layers {
+            let _ = writeln!(out, "[{}]", layer.name);
+            write_node(&layer.root, "", "", &mut out);
+        }
+        out
+    }
+}
+
+impl SurfaceNode {
+    /// Render the subtree at this node as indented ASCII, one node per line.
+    pub fn ascii_tree(&self) -> String {
+        let mut out = String::new();
+        write_node(self, "", "", &mut out);
+        out
+    }
+}
+
+fn write_node(node: &SurfaceNode, lead: &str, indent: &str, out: &mut String) {
+    out.push_str(lead);
+    out.push_str(&node.component_type);
+    if let Some(test_id) = &node.test_id {
+        let _ = write!(out, " #{test_id}");
+    }
+    if let Some(key) = &node.key {
+        let _ = write!(out, " ${key}");
+    }
+    for (key, value) in &node.props {
+        if key == "accessible" {
+            continue;
+        }
+        let json = serde_json::to_string(value).unwrap_or_else(|_| "?".to_string());
+        let _ = write!(out, " {key}={json}");
+    }
+    out.push('\n');
+
+    let last = node.children.len().saturating_sub(1);
+    for (i, child) in node.children.iter().enumerate() {
+        let (branch, next) = if i == last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        write_node(
+            child,
+            &format!("{indent}{branch}"),
+            &format!("{indent}{next}"),
+            out,
+        );
+    }
+}
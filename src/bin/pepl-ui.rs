@@ -0,0 +1,68 @@
+//! `pepl-ui` — inspect and check surface JSON payloads from the command line.
+//!
+//! ```text
+//! pepl-ui validate <file.json>     validation errors, one per line
+//! pepl-ui tree <file.json>         ASCII dump of the node tree
+//! pepl-ui diff <a.json> <b.json>   path-annotated differences
+//! pepl-ui audit <file.json>        accessibility findings
+//! ```
+//!
+//! Exit status: 0 when clean, 1 when errors/differences/findings are
+//! reported, 2 on usage, I/O, or parse errors.
+
+use std::process::ExitCode;
+
+use pepl_ui::complexity::{LimitError, Limits};
+use pepl_ui::{accessibility, Surface};
+
+const USAGE: &str = "\
+usage: pepl-ui <command> <file.json> [file.json]
+
+commands:
+  validate <file>    report validation errors
+  tree <file>        print the node tree
+  diff <a> <b>       report differences between two surfaces
+  audit <file>       report accessibility findings";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match run(&args) {
+        Ok(lines) if lines.is_empty() => ExitCode::SUCCESS,
+        Ok(lines) => {
+            for line in lines {
+                println!("{line}");
+            }
+            ExitCode::from(1)
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Run a command. `Ok` holds the findings to report (empty means clean).
+fn run(args: &[&str]) -> Result<Vec<String>, String> {
+    match args {
+        ["validate", path] => Ok(load(path)?.validate()),
+        ["tree", path] => {
+            print!("{}", load(path)?.ascii_tree());
+            Ok(Vec::new())
+        }
+        ["diff", a, b] => {
+            let report = load(a)?.diff_report(&load(b)?);
+            Ok(report.lines().map(str::to_string).collect())
+        }
+        ["audit", path] => Ok(accessibility::audit(&load(path)?)),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn load(path: &str) -> Result<Surface, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("error: {path}: {e}"))?;
+    Surface::from_json_limited(&json, &Limits::default()).map_err(|e| match e {
+        LimitError::Parse(message) => format!("error: {path}: invalid surface JSON: {message}"),
+        other => format!("error: {path}: {other}"),
+    })
+}
@@ -0,0 +1,491 @@
+//! Native data-model code generation for host decoders.
+//!
+//! [`swift_types`] emits `Codable` Swift types and [`kotlin_types`] emits
+//! kotlinx-serialization Kotlin classes for [`Surface`](crate::Surface),
+//! [`SurfaceNode`](crate::SurfaceNode), [`Route`](crate::Route), and
+//! [`PropValue`](crate::PropValue). Both follow the JSON wire format exactly,
+//! including the untagged `PropValue` encoding, so regenerating after a
+//! schema change keeps native decoders in sync mechanically.
+//!
+//! `PropValue` objects are decoded in the same order as serde's untagged
+//! representation: `Color` (`r`, `g`, `b`, `a`), then `ActionRef`
+//! (`__action`), then `Lambda` (`__lambda`), then `ColorToken`
+//! (`__color_token`), then `ThemeToken` (`__theme_token`), then `I18n`
+//! (`__i18n`), then `Timestamp`
+//! (`__timestamp`), then `Bytes` (`__bytes`), then `Dimension` (`__dim`),
+//! then `Record`. Timestamps are
+//! carried as their ISO-8601 text; parsing into a native date type is left to
+//! the host. Bytes are base64 on the wire and raw data in the native types.
+
+/// Swift source for the surface data model (`Codable`, `Equatable`).
+pub fn swift_types() -> String {
+    format!(
+        "// Generated by pepl-ui {} (codegen::swift_types). Do not edit.\n{SWIFT}",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// Kotlin source for the surface data model, in package `package`
+/// (kotlinx-serialization, JSON only).
+pub fn kotlin_types(package: &str) -> String {
+    format!(
+        "// Generated by pepl-ui {} (codegen::kotlin_types). Do not edit.\n\npackage {package}\n{KOTLIN}",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+const SWIFT: &str = r#"
+import Foundation
+
+public struct Surface: Codable, Equatable {
+    public var root: SurfaceNode
+    public var layers: [Layer]
+    public var shortcuts: [String: PropValue]
+    public var route: Route?
+    public var respectSafeArea: Bool
+
+    enum CodingKeys: String, CodingKey {
+        case root, layers, shortcuts, route
+        case respectSafeArea = "respect_safe_area"
+    }
+
+    public init(root: SurfaceNode, layers: [Layer] = [], shortcuts: [String: PropValue] = [:], route: Route? = nil, respectSafeArea: Bool = false) {
+        self.root = root
+        self.layers = layers
+        self.shortcuts = shortcuts
+        self.route = route
+        self.respectSafeArea = respectSafeArea
+    }
+
+    public init(from decoder: Decoder) throws {
+        let c = try decoder.container(keyedBy: CodingKeys.self)
+        root = try c.decode(SurfaceNode.self, forKey: .root)
+        layers = try c.decodeIfPresent([Layer].self, forKey: .layers) ?? []
+        shortcuts = try c.decodeIfPresent([String: PropValue].self, forKey: .shortcuts) ?? [:]
+        route = try c.decodeIfPresent(Route.self, forKey: .route)
+        respectSafeArea = try c.decodeIfPresent(Bool.self, forKey: .respectSafeArea) ?? false
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var c = encoder.container(keyedBy: CodingKeys.self)
+        try c.encode(root, forKey: .root)
+        if !layers.isEmpty { try c.encode(layers, forKey: .layers) }
+        if !shortcuts.isEmpty { try c.encode(shortcuts, forKey: .shortcuts) }
+        try c.encodeIfPresent(route, forKey: .route)
+        if respectSafeArea { try c.encode(respectSafeArea, forKey: .respectSafeArea) }
+    }
+}
+
+public struct Layer: Codable, Equatable {
+    public var name: String
+    public var root: SurfaceNode
+
+    enum CodingKeys: String, CodingKey {
+        case name, root
+    }
+
+    public init(name: String, root: SurfaceNode) {
+        self.name = name
+        self.root = root
+    }
+}
+
+public struct SurfaceNode: Codable, Equatable {
+    public var type: String
+    public var testId: String?
+    public var key: String?
+    public var props: [String: PropValue]
+    public var children: [SurfaceNode]
+
+    enum CodingKeys: String, CodingKey {
+        case type
+        case testId = "test_id"
+        case key, props, children
+    }
+
+    public init(type: String, testId: String? = nil, key: String? = nil, props: [String: PropValue] = [:], children: [SurfaceNode] = []) {
+        self.type = type
+        self.testId = testId
+        self.key = key
+        self.props = props
+        self.children = children
+    }
+}
+
+public struct Route: Codable, Equatable {
+    public var path: String
+    public var params: [String: String]
+
+    enum CodingKeys: String, CodingKey {
+        case path, params
+    }
+
+    public init(path: String, params: [String: String] = [:]) {
+        self.path = path
+        self.params = params
+    }
+
+    public init(from decoder: Decoder) throws {
+        let c = try decoder.container(keyedBy: CodingKeys.self)
+        path = try c.decode(String.self, forKey: .path)
+        params = try c.decodeIfPresent([String: String].self, forKey: .params) ?? [:]
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var c = encoder.container(keyedBy: CodingKeys.self)
+        try c.encode(path, forKey: .path)
+        if !params.isEmpty { try c.encode(params, forKey: .params) }
+    }
+}
+
+public enum Dimension: Equatable {
+    case px(Double)
+    case auto
+    case fill
+    case percent(Double)
+
+    /// Parses the textual `__dim` form: "auto", "fill", or "<n>%".
+    public init?(wire text: String) {
+        switch text {
+        case "auto": self = .auto
+        case "fill": self = .fill
+        default:
+            guard text.hasSuffix("%"), let n = Double(text.dropLast()) else { return nil }
+            self = .percent(n)
+        }
+    }
+
+    /// The textual `__dim` form; pixels are encoded as plain numbers instead.
+    public var wire: String {
+        switch self {
+        case .px(let n), .percent(let n):
+            let text = n == n.rounded() && abs(n) < 1e15 ? String(Int64(n)) : String(n)
+            if case .percent = self { return text + "%" }
+            return text
+        case .auto: return "auto"
+        case .fill: return "fill"
+        }
+    }
+}
+
+public indirect enum PropValue: Codable, Equatable {
+    case string(String)
+    case number(Double)
+    case bool(Bool)
+    case null
+    case color(r: Double, g: Double, b: Double, a: Double)
+    case action(String, args: [PropValue]?)
+    case lambda(UInt32)
+    case colorToken(String)
+    case themeToken(String)
+    case i18n(String, args: [String: PropValue])
+    case timestamp(String)
+    case bytes(Data)
+    case dimension(Dimension)
+    case list([PropValue])
+    case record([String: PropValue])
+
+    private enum ReferenceKeys: String, CodingKey {
+        case r, g, b, a
+        case action = "__action"
+        case args = "__args"
+        case lambda = "__lambda"
+        case colorToken = "__color_token"
+        case themeToken = "__theme_token"
+        case i18n = "__i18n"
+        case timestamp = "__timestamp"
+        case bytes = "__bytes"
+        case dim = "__dim"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let single = try decoder.singleValueContainer()
+        if single.decodeNil() { self = .null; return }
+        if let v = try? single.decode(String.self) { self = .string(v); return }
+        if let v = try? single.decode(Bool.self) { self = .bool(v); return }
+        if let v = try? single.decode(Double.self) { self = .number(v); return }
+        if let v = try? single.decode([PropValue].self) { self = .list(v); return }
+
+        let keyed = try decoder.container(keyedBy: ReferenceKeys.self)
+        if let r = try? keyed.decode(Double.self, forKey: .r),
+           let g = try? keyed.decode(Double.self, forKey: .g),
+           let b = try? keyed.decode(Double.self, forKey: .b),
+           let a = try? keyed.decode(Double.self, forKey: .a) {
+            self = .color(r: r, g: g, b: b, a: a)
+            return
+        }
+        if let name = try? keyed.decode(String.self, forKey: .action) {
+            self = .action(name, args: try keyed.decodeIfPresent([PropValue].self, forKey: .args))
+            return
+        }
+        if let id = try? keyed.decode(UInt32.self, forKey: .lambda) {
+            self = .lambda(id)
+            return
+        }
+        if let token = try? keyed.decode(String.self, forKey: .colorToken) {
+            self = .colorToken(token)
+            return
+        }
+        if let token = try? keyed.decode(String.self, forKey: .themeToken) {
+            self = .themeToken(token)
+            return
+        }
+        if let key = try? keyed.decode(String.self, forKey: .i18n) {
+            self = .i18n(key, args: try keyed.decodeIfPresent([String: PropValue].self, forKey: .args) ?? [:])
+            return
+        }
+        if let text = try? keyed.decode(String.self, forKey: .timestamp) {
+            self = .timestamp(text)
+            return
+        }
+        if let encoded = try? keyed.decode(String.self, forKey: .bytes),
+           let data = Data(base64Encoded: encoded) {
+            self = .bytes(data)
+            return
+        }
+        if let px = try? keyed.decode(Double.self, forKey: .dim) {
+            self = .dimension(.px(px))
+            return
+        }
+        if let text = try? keyed.decode(String.self, forKey: .dim), let dim = Dimension(wire: text) {
+            self = .dimension(dim)
+            return
+        }
+        self = .record(try single.decode([String: PropValue].self))
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        switch self {
+        case .string(let v):
+            var c = encoder.singleValueContainer()
+            try c.encode(v)
+        case .number(let v):
+            var c = encoder.singleValueContainer()
+            try c.encode(v)
+        case .bool(let v):
+            var c = encoder.singleValueContainer()
+            try c.encode(v)
+        case .null:
+            var c = encoder.singleValueContainer()
+            try c.encodeNil()
+        case let .color(r, g, b, a):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(r, forKey: .r)
+            try c.encode(g, forKey: .g)
+            try c.encode(b, forKey: .b)
+            try c.encode(a, forKey: .a)
+        case let .action(name, args):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(name, forKey: .action)
+            try c.encodeIfPresent(args, forKey: .args)
+        case .lambda(let id):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(id, forKey: .lambda)
+        case .colorToken(let token):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(token, forKey: .colorToken)
+        case .themeToken(let token):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(token, forKey: .themeToken)
+        case let .i18n(key, args):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(key, forKey: .i18n)
+            if !args.isEmpty { try c.encode(args, forKey: .args) }
+        case .timestamp(let text):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(text, forKey: .timestamp)
+        case .bytes(let data):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            try c.encode(data.base64EncodedString(), forKey: .bytes)
+        case .dimension(let dim):
+            var c = encoder.container(keyedBy: ReferenceKeys.self)
+            if case let .px(px) = dim {
+                try c.encode(px, forKey: .dim)
+            } else {
+                try c.encode(dim.wire, forKey: .dim)
+            }
+        case .list(let v):
+            var c = encoder.singleValueContainer()
+            try c.encode(v)
+        case .record(let v):
+            var c = encoder.singleValueContainer()
+            try c.encode(v)
+        }
+    }
+}
+"#;
+
+const KOTLIN: &str = r#"
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Surface(
+    val root: SurfaceNode,
+    val layers: List<Layer> = emptyList(),
+    val shortcuts: Map<String, PropValue> = emptyMap(),
+    val route: Route? = null,
+    @SerialName("respect_safe_area") val respectSafeArea: Boolean = false,
+)
+
+@Serializable
+data class Layer(
+    val name: String,
+    val root: SurfaceNode,
+)
+
+@Serializable
+data class SurfaceNode(
+    val type: String,
+    @SerialName("test_id") val testId: String? = null,
+    val key: String? = null,
+    val props: Map<String, PropValue>,
+    val children: List<SurfaceNode>,
+)
+
+@Serializable
+data class Route(
+    val path: String,
+    val params: Map<String, String> = emptyMap(),
+)
+
+@Serializable(with = PropValueSerializer::class)
+sealed class PropValue {
+    data class Str(val value: String) : PropValue()
+    data class Number(val value: Double) : PropValue()
+    data class Bool(val value: Boolean) : PropValue()
+    object Nil : PropValue()
+    data class Color(val r: Double, val g: Double, val b: Double, val a: Double) : PropValue()
+    data class ActionRef(val action: String, val args: List<PropValue>? = null) : PropValue()
+    data class Lambda(val lambdaId: Long) : PropValue()
+    data class ColorToken(val token: String) : PropValue()
+    data class ThemeToken(val token: String) : PropValue()
+    data class I18n(val key: String, val args: Map<String, PropValue> = emptyMap()) : PropValue()
+    data class Timestamp(val text: String) : PropValue()
+    data class DimensionValue(val dim: Dimension) : PropValue()
+    class Bytes(val data: ByteArray) : PropValue() {
+        override fun equals(other: Any?) = other is Bytes && data.contentEquals(other.data)
+        override fun hashCode() = data.contentHashCode()
+    }
+    data class ListValue(val items: List<PropValue>) : PropValue()
+    data class Record(val fields: Map<String, PropValue>) : PropValue()
+}
+
+sealed class Dimension {
+    data class Px(val value: Double) : Dimension()
+    object Auto : Dimension()
+    object Fill : Dimension()
+    data class Percent(val value: Double) : Dimension()
+}
+
+object PropValueSerializer : KSerializer<PropValue> {
+    override val descriptor: SerialDescriptor = JsonElement.serializer().descriptor
+
+    override fun deserialize(decoder: Decoder): PropValue {
+        val input = decoder as? JsonDecoder ?: error("PropValue can only be decoded from JSON")
+        return fromJson(input.decodeJsonElement())
+    }
+
+    override fun serialize(encoder: Encoder, value: PropValue) {
+        val output = encoder as? JsonEncoder ?: error("PropValue can only be encoded to JSON")
+        output.encodeJsonElement(toJson(value))
+    }
+
+    private fun number(element: JsonElement?): Double? =
+        (element as? JsonPrimitive)?.takeIf { !it.isString }?.doubleOrNull
+
+    private fun dimension(wire: JsonPrimitive): Dimension? = when {
+        !wire.isString -> wire.doubleOrNull?.let { Dimension.Px(it) }
+        wire.content == "auto" -> Dimension.Auto
+        wire.content == "fill" -> Dimension.Fill
+        wire.content.endsWith("%") -> wire.content.dropLast(1).toDoubleOrNull()?.let { Dimension.Percent(it) }
+        else -> null
+    }
+
+    private fun format(n: Double): String =
+        if (n == Math.rint(n) && Math.abs(n) < 1e15) n.toLong().toString() else n.toString()
+
+    fun fromJson(element: JsonElement): PropValue = when (element) {
+        is JsonNull -> PropValue.Nil
+        is JsonPrimitive -> when {
+            element.isString -> PropValue.Str(element.content)
+            element.booleanOrNull != null -> PropValue.Bool(element.boolean)
+            else -> PropValue.Number(element.double)
+        }
+        is JsonArray -> PropValue.ListValue(element.map(::fromJson))
+        is JsonObject -> {
+            val r = number(element["r"])
+            val g = number(element["g"])
+            val b = number(element["b"])
+            val a = number(element["a"])
+            val action = (element["__action"] as? JsonPrimitive)?.takeIf { it.isString }?.content
+            val lambda = (element["__lambda"] as? JsonPrimitive)
+                ?.takeIf { !it.isString }?.longOrNull?.takeIf { it in 0..4294967295L }
+            val colorToken = (element["__color_token"] as? JsonPrimitive)?.takeIf { it.isString }?.content
+            val themeToken = (element["__theme_token"] as? JsonPrimitive)?.takeIf { it.isString }?.content
+            val i18n = (element["__i18n"] as? JsonPrimitive)?.takeIf { it.isString }?.content
+            val timestamp = (element["__timestamp"] as? JsonPrimitive)?.takeIf { it.isString }?.content
+            val bytes = (element["__bytes"] as? JsonPrimitive)?.takeIf { it.isString }?.content
+            val dim = (element["__dim"] as? JsonPrimitive)?.let(::dimension)
+            when {
+                r != null && g != null && b != null && a != null -> PropValue.Color(r, g, b, a)
+                action != null -> PropValue.ActionRef(action, (element["__args"] as? JsonArray)?.map(::fromJson))
+                lambda != null -> PropValue.Lambda(lambda)
+                colorToken != null -> PropValue.ColorToken(colorToken)
+                themeToken != null -> PropValue.ThemeToken(themeToken)
+                i18n != null -> PropValue.I18n(
+                    i18n,
+                    (element["__args"] as? JsonObject)?.mapValues { fromJson(it.value) } ?: emptyMap(),
+                )
+                timestamp != null -> PropValue.Timestamp(timestamp)
+                bytes != null -> PropValue.Bytes(java.util.Base64.getDecoder().decode(bytes))
+                dim != null -> PropValue.DimensionValue(dim)
+                else -> PropValue.Record(element.mapValues { fromJson(it.value) })
+            }
+        }
+    }
+
+    fun toJson(value: PropValue): JsonElement = when (value) {
+        is PropValue.Str -> JsonPrimitive(value.value)
+        is PropValue.Number -> JsonPrimitive(value.value)
+        is PropValue.Bool -> JsonPrimitive(value.value)
+        PropValue.Nil -> JsonNull
+        is PropValue.Color -> buildJsonObject {
+            put("r", value.r)
+            put("g", value.g)
+            put("b", value.b)
+            put("a", value.a)
+        }
+        is PropValue.ActionRef -> buildJsonObject {
+            put("__action", value.action)
+            value.args?.let { args -> put("__args", JsonArray(args.map(::toJson))) }
+        }
+        is PropValue.Lambda -> buildJsonObject { put("__lambda", value.lambdaId) }
+        is PropValue.ColorToken -> buildJsonObject { put("__color_token", value.token) }
+        is PropValue.ThemeToken -> buildJsonObject { put("__theme_token", value.token) }
+        is PropValue.I18n -> buildJsonObject {
+            put("__i18n", value.key)
+            if (value.args.isNotEmpty()) put("__args", JsonObject(value.args.mapValues { toJson(it.value) }))
+        }
+        is PropValue.Timestamp -> buildJsonObject { put("__timestamp", value.text) }
+        is PropValue.Bytes -> buildJsonObject {
+            put("__bytes", java.util.Base64.getEncoder().encodeToString(value.data))
+        }
+        is PropValue.DimensionValue -> buildJsonObject {
+            when (val dim = value.dim) {
+                is Dimension.Px -> put("__dim", dim.value)
+                Dimension.Auto -> put("__dim", "auto")
+                Dimension.Fill -> put("__dim", "fill")
+                is Dimension.Percent -> put("__dim", format(dim.value) + "%")
+            }
+        }
+        is PropValue.ListValue -> JsonArray(value.items.map(::toJson))
+        is PropValue.Record -> JsonObject(value.fields.mapValues { toJson(it.value) })
+    }
+}
+"#;
@@ -0,0 +1,444 @@
+//! Surface complexity metrics and limits.
+//!
+//! The integration tests hold every component to a 16ms frame budget.
+//! [`ComplexityReport`] turns that philosophy into a runtime guard: hosts
+//! measure an incoming [`Surface`] and reject it with
+//! [`ComplexityReport::check_against`] before rendering.
+//!
+//! ```
+//! use pepl_ui::{complexity::Limits, Surface, TextBuilder};
+//!
+//! let surface = Surface::new(TextBuilder::new("Hi").build());
+//! let report = surface.complexity();
+//! assert_eq!(report.node_count, 1);
+//! assert!(report.check_against(&Limits::default()).is_empty());
+//! ```
+//!
+//! [`Surface::stats`] adds a per-component breakdown, for render-budget
+//! tests and devtools that need to know *what* the nodes are.
+//!
+//! # Untrusted input
+//!
+//! Hosts running untrusted PEPL programs parse payloads with
+//! [`Surface::from_json_limited`], which refuses oversized JSON before
+//! parsing and checks every limit — including single-string and single-list
+//! sizes — before returning the surface. [`Surface::check_limits`] runs the
+//! same checks on a tree built in process and stops at the first violation.
+//!
+//! The limits are the same [`Limits`] used by [`ComplexityReport`], also
+//! exported as [`SurfaceLimits`]. They are enforced at the parsing entry
+//! point rather than inside `Deserialize`: the byte cap bounds the work the
+//! parser can do, and the remaining limits are checked on the parsed tree
+//! before it is returned. Every entry point that takes untrusted JSON — the
+//! C ABI, the wasm and Python bindings, the `pepl-ui` tool, and
+//! [`fuzz_parse`](crate::fuzz_parse) — parses with `from_json_limited` and
+//! the default limits.
+//! String limits cover every string in the payload — prop values and names,
+//! component types, test ids, keys, action names, tokens, layer names,
+//! shortcuts, and route fields.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+/// Size and shape metrics of a [`Surface`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComplexityReport {
+    /// Total number of nodes, including the root.
+    pub node_count: usize,
+    /// Depth of the deepest node; a root without children has depth 0
+    /// (same convention as [`NodePath::depth`](crate::NodePath::depth)).
+    pub max_depth: usize,
+    /// Total number of props across all nodes.
+    pub prop_count: usize,
+    /// Total number of prop values, counting every list item and record
+    /// field inside nested values.
+    pub prop_volume: usize,
+    /// Size of the compact JSON serialization in bytes.
+    pub estimated_bytes: usize,
+}
+
+/// Upper bounds for [`ComplexityReport::check_against`].
+///
+/// The defaults are generous; hosts targeting low-end devices should tighten
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum total node count.
+    pub max_nodes: usize,
+    /// Maximum tree depth.
+    pub max_depth: usize,
+    /// Maximum total prop volume.
+    pub max_prop_volume: usize,
+    /// Maximum serialized size in bytes.
+    pub max_bytes: usize,
+    /// Maximum length of any string value, in bytes.
+    pub max_string_len: usize,
+    /// Maximum number of items in any list value.
+    pub max_list_len: usize,
+}
+
+/// The limits [`Surface::from_json_limited`] enforces on untrusted payloads;
+/// the same type as [`Limits`].
+pub type SurfaceLimits = Limits;
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_nodes: 10_000,
+            max_depth: 64,
+            max_prop_volume: 100_000,
+            max_bytes: 1024 * 1024,
+            max_string_len: 64 * 1024,
+            max_list_len: 10_000,
+        }
+    }
+}
+
+impl ComplexityReport {
+    /// Measure `surface`.
+    pub fn measure(surface: &Surface) -> Self {
+        let mut report = ComplexityReport::default();
+        for (_, root) in surface.layer_roots() {
+            measure_node(root, 0, &mut report);
+        }
+
+        let mut counter = ByteCounter(0);
+        // Writing to a counter cannot fail, and Surface always serializes.
+        let _ = serde_json::to_writer(&mut counter, surface);
+        report.estimated_bytes = counter.0;
+        report
+    }
+
+    /// Compare against `limits`. Empty means within limits.
+    pub fn check_against(&self, limits: &Limits) -> Vec<String> {
+        let checks = [
+            ("node count", self.node_count, limits.max_nodes),
+            ("depth", self.max_depth, limits.max_depth),
+            ("prop volume", self.prop_volume, limits.max_prop_volume),
+            ("serialized size", self.estimated_bytes, limits.max_bytes),
+        ];
+        checks
+            .into_iter()
+            .filter(|(_, value, limit)| value > limit)
+            .map(|(what, value, limit)| format!("Surface: {what} {value} exceeds limit {limit}"))
+            .collect()
+    }
+}
+
+/// The first limit a surface exceeds, from [`Surface::check_limits`] or
+/// [`Surface::from_json_limited`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimitError {
+    /// A whole-surface measure (`"node count"`, `"depth"`, `"prop volume"`,
+    /// `"serialized size"`) is over its limit.
+    Surface {
+        what: &'static str,
+        value: usize,
+        limit: usize,
+    },
+    /// A value in prop `key` of the node at `path` is too large (`what` is
+    /// `"string length"` or `"list length"`).
+    Prop {
+        path: NodePath,
+        key: String,
+        what: &'static str,
+        value: usize,
+        limit: usize,
+    },
+    /// A string that is not inside a prop value is too large: a component
+    /// type, test id, key, or prop name of a node, a layer name, a shortcut,
+    /// or a route field. `field` names it, e.g. `"root/1: key"` or
+    /// `"Surface.route.path"`.
+    Field {
+        field: String,
+        what: &'static str,
+        value: usize,
+        limit: usize,
+    },
+    /// The payload is within the size limit but is not a valid surface.
+    Parse(String),
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitError::Surface { what, value, limit } => {
+                write!(f, "Surface: {what} {value} exceeds limit {limit}")
+            }
+            LimitError::Prop {
+                path,
+                key,
+                what,
+                value,
+                limit,
+            } => write!(f, "{path}: {key}: {what} {value} exceeds limit {limit}"),
+            LimitError::Field {
+                field,
+                what,
+                value,
+                limit,
+            } => write!(f, "{field}: {what} {value} exceeds limit {limit}"),
+            LimitError::Parse(message) => write!(f, "Surface: invalid JSON: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LimitError {}
+
+/// A [`ComplexityReport`] plus the number of nodes of each component type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SurfaceStats {
+    /// Size and shape metrics.
+    pub complexity: ComplexityReport,
+    /// Node count per component type, e.g. `{"Button": 2, "Column": 1}`.
+    pub component_counts: BTreeMap<String, usize>,
+}
+
+impl SurfaceStats {
+    /// Number of nodes of `component_type` (0 if there are none).
+    pub fn count(&self, component_type: &str) -> usize {
+        self.component_counts
+            .get(component_type)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl Surface {
+    /// Measure this surface. See [`ComplexityReport`].
+    pub fn complexity(&self) -> ComplexityReport {
+        ComplexityReport::measure(self)
+    }
+
+    /// Check this surface against every limit, stopping at the first
+    /// violation.
+    pub fn check_limits(&self, limits: &Limits) -> Result<(), LimitError> {
+        let mut result = Ok(());
+        let mut nodes = 0;
+        self.walk(|node, path| {
+            if result.is_err() {
+                return;
+            }
+            nodes += 1;
+            result = check_node(node, path, nodes, limits);
+        });
+        result?;
+        check_surface_strings(self, limits)?;
+
+        let report = self.complexity();
+        let checks = [
+            ("prop volume", report.prop_volume, limits.max_prop_volume),
+            ("serialized size", report.estimated_bytes, limits.max_bytes),
+        ];
+        match checks.into_iter().find(|(_, value, limit)| value > limit) {
+            Some((what, value, limit)) => Err(LimitError::Surface { what, value, limit }),
+            None => Ok(()),
+        }
+    }
+
+    /// Parse a JSON payload, enforcing `limits`. Payloads longer than
+    /// `limits.max_bytes` are rejected without being parsed.
+    pub fn from_json_limited(json: &str, limits: &Limits) -> Result<Surface, LimitError> {
+        if json.len() > limits.max_bytes {
+            return Err(LimitError::Surface {
+                what: "serialized size",
+                value: json.len(),
+                limit: limits.max_bytes,
+            });
+        }
+        let surface: Surface =
+            serde_json::from_str(json).map_err(|e| LimitError::Parse(e.to_string()))?;
+        surface.check_limits(limits)?;
+        Ok(surface)
+    }
+
+    /// Measure this surface and count its nodes by component type.
+    pub fn stats(&self) -> SurfaceStats {
+        let mut component_counts = BTreeMap::new();
+        self.walk(|node, _| {
+            *component_counts
+                .entry(node.component_type.clone())
+                .or_default() += 1;
+        });
+        SurfaceStats {
+            complexity: self.complexity(),
+            component_counts,
+        }
+    }
+}
+
+/// Parse an untrusted payload with the default [`Limits`], for the FFI, wasm,
+/// and Python entry points. Malformed JSON reads `invalid surface JSON: ...`;
+/// a limit violation reads as its [`LimitError`].
+#[cfg(any(feature = "ffi", feature = "wasm", feature = "python"))]
+pub(crate) fn parse_untrusted(json: &str) -> Result<Surface, String> {
+    Surface::from_json_limited(json, &Limits::default()).map_err(|e| match e {
+        LimitError::Parse(message) => format!("invalid surface JSON: {message}"),
+        other => other.to_string(),
+    })
+}
+
+/// Check one node, the `nodes`-th visited, against the per-node limits.
+fn check_node(
+    node: &SurfaceNode,
+    path: &NodePath,
+    nodes: usize,
+    limits: &Limits,
+) -> Result<(), LimitError> {
+    let checks = [
+        ("node count", nodes, limits.max_nodes),
+        ("depth", path.depth(), limits.max_depth),
+    ];
+    if let Some((what, value, limit)) = checks.into_iter().find(|(_, value, limit)| value > limit) {
+        return Err(LimitError::Surface { what, value, limit });
+    }
+    let fields = [
+        ("component_type", Some(&node.component_type)),
+        ("test_id", node.test_id.as_ref()),
+        ("key", node.key.as_ref()),
+    ];
+    for (field, s) in fields {
+        if let Some((what, value, limit)) = s.and_then(|s| long_string(s, limits)) {
+            return Err(LimitError::Field {
+                field: format!("{path}: {field}"),
+                what,
+                value,
+                limit,
+            });
+        }
+    }
+    for (key, value) in &node.props {
+        if let Some((what, value, limit)) = long_string(key, limits) {
+            return Err(LimitError::Field {
+                field: format!("{path}: prop name"),
+                what,
+                value,
+                limit,
+            });
+        }
+        if let Some((what, value, limit)) = oversized(value, limits) {
+            return Err(LimitError::Prop {
+                path: path.clone(),
+                key: key.clone(),
+                what,
+                value,
+                limit,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Check the strings and values outside the node tree: layer names,
+/// shortcuts, and the route.
+fn check_surface_strings(surface: &Surface, limits: &Limits) -> Result<(), LimitError> {
+    let field_error = |field: String, (what, value, limit)| LimitError::Field {
+        field,
+        what,
+        value,
+        limit,
+    };
+    for layer in &surface.layers {
+        if let Some(found) = long_string(&layer.name, limits) {
+            return Err(field_error("Surface.layers".to_string(), found));
+        }
+    }
+    for (combo, action) in &surface.shortcuts {
+        if let Some(found) = long_string(combo, limits) {
+            return Err(field_error("Surface.shortcuts".to_string(), found));
+        }
+        if let Some(found) = oversized(action, limits) {
+            return Err(field_error(format!("Surface.shortcuts['{combo}']"), found));
+        }
+    }
+    if let Some(route) = &surface.route {
+        if let Some(found) = long_string(&route.path, limits) {
+            return Err(field_error("Surface.route.path".to_string(), found));
+        }
+        for (name, value) in &route.params {
+            if let Some(found) = long_string(name, limits).or_else(|| long_string(value, limits)) {
+                return Err(field_error("Surface.route.params".to_string(), found));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `s` as `(what, length, limit)` if it is over the string limit.
+fn long_string(s: &str, limits: &Limits) -> Option<(&'static str, usize, usize)> {
+    (s.len() > limits.max_string_len).then_some(("string length", s.len(), limits.max_string_len))
+}
+
+/// The first string or list inside `value` over its limit, as
+/// `(what, length, limit)`. Action names, tokens, i18n keys, and record
+/// field names count as strings.
+fn oversized(value: &PropValue, limits: &Limits) -> Option<(&'static str, usize, usize)> {
+    match value {
+        PropValue::String(s)
+        | PropValue::ColorToken { token: s }
+        | PropValue::ThemeToken { token: s } => long_string(s, limits),
+        PropValue::List(items)
+        | PropValue::ActionRef {
+            args: Some(items), ..
+        } if items.len() > limits.max_list_len => {
+            Some(("list length", items.len(), limits.max_list_len))
+        }
+        PropValue::List(items) => items.iter().find_map(|item| oversized(item, limits)),
+        PropValue::ActionRef { action, args } => long_string(action, limits).or_else(|| {
+            args.iter()
+                .flatten()
+                .find_map(|item| oversized(item, limits))
+        }),
+        PropValue::Record(fields) => fields.iter().find_map(|(name, field)| {
+            long_string(name, limits).or_else(|| oversized(field, limits))
+        }),
+        PropValue::I18n { key, args } => long_string(key, limits).or_else(|| {
+            args.iter().find_map(|(name, arg)| {
+                long_string(name, limits).or_else(|| oversized(arg, limits))
+            })
+        }),
+        _ => None,
+    }
+}
+
+fn measure_node(node: &SurfaceNode, depth: usize, report: &mut ComplexityReport) {
+    report.node_count += 1;
+    report.max_depth = report.max_depth.max(depth);
+    report.prop_count += node.props.len();
+    report.prop_volume += node.props.values().map(value_volume).sum::<usize>();
+    for child in &node.children {
+        measure_node(child, depth + 1, report);
+    }
+}
+
+fn value_volume(value: &PropValue) -> usize {
+    1 + match value {
+        PropValue::List(items) => items.iter().map(value_volume).sum(),
+        PropValue::Record(fields) | PropValue::I18n { args: fields, .. } => {
+            fields.values().map(value_volume).sum()
+        }
+        PropValue::ActionRef {
+            args: Some(args), ..
+        } => args.iter().map(value_volume).sum(),
+        _ => 0,
+    }
+}
+
+/// An `io::Write` sink that only counts bytes.
+struct ByteCounter(usize);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,873 @@
+//! Props accepted on every component.
+//!
+//! Component validators call [`validate_common_props`] once per node and skip
+//! keys for which [`is_common_prop`] returns `true` in their unknown-prop checks.
+//! Every builder implements [`CommonProps`], the shared setter mixin.
+//!
+//! | Prop | Type | Purpose |
+//! |------|------|---------|
+//! | `focus_group` | string | Directional-navigation group for keyboard/TV hosts |
+//! | `initial_focus` | bool | Preferred node to focus when the surface appears |
+//! | `focus_trap` | bool | Keep focus inside this subtree (Modal, BottomSheet, and Drawer default to `true`) |
+//! | `on_appear` | action | Fired when the node becomes visible (e.g. a screen opens, a row scrolls in) |
+//! | `on_disappear` | action | Fired when the node stops being visible |
+//! | `animate` | record | Animations for changes to animatable props |
+//! | `anchor` | string | Target key for an enclosing Scroll's `scroll_to` |
+//! | `draggable` | bool | The node can be picked up and dragged |
+//! | `drag_payload` | record | Data carried by a drag from this node |
+//! | `drop_target` | bool | The node accepts drops |
+//! | `on_drop` | action | Fired on a drop onto this node |
+//! | `width` | dimension | Preferred width (number of px, `Px`, `Percent`, `Fill`, `Auto`) |
+//! | `height` | dimension | Preferred height (number of px, `Px`, `Percent`, `Fill`, `Auto`) |
+//! | `opacity` | number | 0 (transparent) to 1 (opaque); the node keeps its layout space |
+//! | `visible` | bool | `false` hides the node and its subtree but keeps it in the tree |
+//! | `flex` | number | Share of the parent Row's or Column's remaining space |
+//! | `align_self` | string | Cross-axis alignment overriding the parent Row's or Column's `align` |
+//! | `pinned` | bool | Sticks to the top of the enclosing Scroll or ScrollList while content scrolls |
+//! | `position` | record | Absolute offsets `{ top?, bottom?, start?, end? }` inside an Overlay or Modal |
+//!
+//! # Test IDs
+//!
+//! `test_id` is a node-level field ([`SurfaceNode::test_id`]) rather than a
+//! prop, so automation tools find it in the same place on every node. It must
+//! be non-empty and use only ASCII letters, digits, `_`, `-`, `.`, and `:`.
+//! Validators report a `test_id` found inside `props` as misplaced.
+//!
+//! # Keys
+//!
+//! `key` ([`SurfaceNode::key`]) is also a node-level field. It gives a node a
+//! stable identity across re-renders, so the host keeps its focus, scroll
+//! position, and running animations when siblings are inserted, removed, or
+//! reordered — the child index alone can't tell a moved node from a new one.
+//! Keys must be non-empty and unique among siblings
+//! ([`Surface::validate`](crate::Surface::validate) reports duplicates).
+//! Don't confuse it with ScrollList's `key` prop, the lambda that keys its
+//! items.
+//!
+//! # Animations
+//!
+//! `animate` maps an animatable prop name to an [`AnimationSpec`] record.
+//! When the evaluator produces a new value for that prop, the host animates
+//! from the old value instead of jumping:
+//!
+//! ```json
+//! "animate": {
+//!   "value": { "duration": 300, "easing": "ease_out" },
+//!   "opacity": { "duration": 200, "delay": 50,
+//!                "spring": { "stiffness": 170, "damping": 26, "mass": 1 } }
+//! }
+//! ```
+//!
+//! Animatable props are `opacity`, `width`, and `height` on every component,
+//! plus `value` on ProgressBar (see [`is_animatable`]).
+//!
+//! # Sizing
+//!
+//! `width` and `height` are [`Dimension`]s; builders set them with
+//! [`CommonProps::width`] and [`CommonProps::height`], and plain numbers on
+//! the wire are read as pixels. Components that give these props their own
+//! meaning validate them themselves (see [`validates_own_prop`]) — e.g.
+//! Canvas requires positive numbers, its drawing coordinate space.
+//!
+//! # Visibility
+//!
+//! `opacity` fades a node and `visible: false` hides it, without removing it
+//! from the tree: its layout space, node path, and diff identity stay put,
+//! so toggling them produces a single prop change. Hidden subtrees are skipped
+//! for focus. Modal and BottomSheet already use `visible` (required) to
+//! present themselves, with the same meaning.
+//!
+//! # Flex
+//!
+//! A direct child of a Row or Column with `flex` takes a share of the space
+//! left after its siblings without `flex` are laid out, proportional to its
+//! `flex` among the flexible siblings (three buttons with `flex: 1` get equal
+//! widths). `flex` must be a positive number, and
+//! [`Surface::validate`](crate::Surface::validate) reports `flex` on a node
+//! whose parent is not a Row or Column.
+//!
+//! `align_self` positions one child of a Row or Column on the cross axis
+//! (`"start"`, `"center"`, `"end"`, or `"stretch"`), overriding the parent's
+//! `align` for that child only. Like `flex`, it is reported when its parent is
+//! not a Row or Column.
+//!
+//! # Pinned headers
+//!
+//! A node with `pinned: true` inside a Scroll sticks to the top of the
+//! viewport once scrolled to, until the next pinned node pushes it away
+//! (section headers). Items rendered by a ScrollList's `render` lambda may
+//! set it too. [`Surface::validate`](crate::Surface::validate) reports
+//! `pinned` on a node with no Scroll or ScrollList ancestor.
+//!
+//! # Absolute positioning
+//!
+//! A direct child of an Overlay or Modal may set `position` to pin itself at
+//! fixed offsets from the container's edges instead of flowing with its
+//! siblings: badges, floating action buttons, close buttons. Inside an
+//! Overlay, `position` takes precedence over the child's layer `align` and
+//! `offset`. [`Surface::validate`](crate::Surface::validate) reports
+//! `position` on a node whose parent is not an Overlay or Modal.
+//!
+//! # Drag and drop
+//!
+//! A node with `draggable: true` can be dragged onto any node with
+//! `drop_target: true`. When dispatching the target's `on_drop` action, the
+//! host appends the source's `drag_payload` (or an empty record) as the last
+//! argument. `drag_payload` requires `draggable: true`, and `drop_target: true`
+//! requires an `on_drop` handler (and vice versa).
+
+use crate::prop_value::PropValue;
+use crate::surface::SurfaceNode;
+use crate::types::{AlignSelf, AnimationSpec, CornerRadii, Dimension, Easing, Gradient, Position};
+use std::collections::BTreeMap;
+
+/// Shared state behind [`CommonProps`]; every builder stores one.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommonFields {
+    test_id: Option<String>,
+    key: Option<String>,
+    width: Option<Dimension>,
+    height: Option<Dimension>,
+    opacity: Option<f64>,
+    visible: Option<bool>,
+    align_self: Option<AlignSelf>,
+    pinned: Option<bool>,
+    position: Option<Position>,
+}
+
+impl CommonFields {
+    /// Apply the collected fields to a freshly built node.
+    pub(crate) fn apply(self, node: &mut SurfaceNode) {
+        if let Some(test_id) = self.test_id {
+            node.test_id = Some(test_id);
+        }
+        if let Some(key) = self.key {
+            node.key = Some(key);
+        }
+        if let Some(width) = self.width {
+            node.set_prop("width", PropValue::dimension(width));
+        }
+        if let Some(height) = self.height {
+            node.set_prop("height", PropValue::dimension(height));
+        }
+        if let Some(opacity) = self.opacity {
+            node.set_prop("opacity", PropValue::Number(opacity));
+        }
+        if let Some(visible) = self.visible {
+            node.set_prop("visible", PropValue::Bool(visible));
+        }
+        if let Some(pinned) = self.pinned {
+            node.set_prop("pinned", PropValue::Bool(pinned));
+        }
+        if let Some(position) = &self.position {
+            node.set_prop("position", position_to_prop(position));
+        }
+        if let Some(align_self) = self.align_self {
+            node.set_prop(
+                "align_self",
+                PropValue::String(align_self.as_str().to_string()),
+            );
+        }
+    }
+}
+
+/// Setters shared by every component builder.
+///
+/// Import the trait to use them:
+///
+/// ```
+/// use pepl_ui::{CommonProps, TextBuilder};
+///
+/// let node = TextBuilder::new("Total").test_id("cart.total").build();
+/// assert_eq!(node.test_id.as_deref(), Some("cart.total"));
+/// ```
+pub trait CommonProps: Sized {
+    /// Access the builder's [`CommonFields`].
+    fn common_mut(&mut self) -> &mut CommonFields;
+
+    /// Set the automation test ID (node-level field).
+    fn test_id(mut self, test_id: impl Into<String>) -> Self {
+        self.common_mut().test_id = Some(test_id.into());
+        self
+    }
+
+    /// Set the stable identity key (node-level field), unique among
+    /// siblings.
+    fn key(mut self, key: impl Into<String>) -> Self {
+        self.common_mut().key = Some(key.into());
+        self
+    }
+
+    /// Set the preferred width.
+    fn width(mut self, width: Dimension) -> Self {
+        self.common_mut().width = Some(width);
+        self
+    }
+
+    /// Set the preferred height.
+    fn height(mut self, height: Dimension) -> Self {
+        self.common_mut().height = Some(height);
+        self
+    }
+
+    /// Set the opacity, clamped to 0 (transparent) through 1 (opaque).
+    fn opacity(mut self, opacity: f64) -> Self {
+        self.common_mut().opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Show or hide the node without removing it from the tree.
+    fn visible(mut self, visible: bool) -> Self {
+        self.common_mut().visible = Some(visible);
+        self
+    }
+
+    /// Override the parent Row's or Column's cross-axis `align` for this node.
+    fn align_self(mut self, align: AlignSelf) -> Self {
+        self.common_mut().align_self = Some(align);
+        self
+    }
+
+    /// Stick to the top of the enclosing Scroll or ScrollList while content
+    /// scrolls past.
+    fn pinned(mut self, pinned: bool) -> Self {
+        self.common_mut().pinned = Some(pinned);
+        self
+    }
+
+    /// Place this node at fixed offsets inside its Overlay or Modal parent.
+    fn position(mut self, position: Position) -> Self {
+        self.common_mut().position = Some(position);
+        self
+    }
+}
+
+/// Returns `true` if `key` is a prop accepted on every component.
+///
+/// `test_id` and `key` are included so validators recognize them and report
+/// them as misplaced rather than unknown.
+pub fn is_common_prop(key: &str) -> bool {
+    matches!(
+        key,
+        "test_id"
+            | "key"
+            | "focus_group"
+            | "initial_focus"
+            | "focus_trap"
+            | "on_appear"
+            | "on_disappear"
+            | "animate"
+            | "anchor"
+            | "draggable"
+            | "drag_payload"
+            | "drop_target"
+            | "on_drop"
+            | "width"
+            | "height"
+            | "opacity"
+            | "visible"
+            | "flex"
+            | "align_self"
+            | "pinned"
+            | "position"
+    )
+}
+
+/// Returns `true` if `component`'s own validator checks the common prop
+/// `key`, so [`validate_common_props`] leaves it alone.
+pub fn validates_own_prop(component: &str, key: &str) -> bool {
+    matches!(
+        (component, key),
+        ("Canvas" | "Image" | "Skeleton", "width" | "height")
+            | ("Modal", "width" | "visible")
+            | ("BottomSheet", "visible")
+            | ("ProgressBar", "height")
+    )
+}
+
+/// Validate the common props present on `node`.
+///
+/// Returns a list of validation errors. Empty means valid.
+pub fn validate_common_props(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+    let component = node.component_type.as_str();
+
+    // Optional: test_id (node-level field, never a prop)
+    if let Some(test_id) = &node.test_id {
+        if test_id.is_empty() {
+            errors.push(format!("{component}.test_id: must not be empty"));
+        } else if let Some(c) = test_id
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':')))
+        {
+            errors.push(format!(
+                "{component}.test_id: invalid character {c:?} in {test_id:?}"
+            ));
+        }
+    }
+    if node.props.contains_key("test_id") {
+        errors.push(format!(
+            "{component}.test_id: must be set on the node, not in props"
+        ));
+    }
+
+    // Optional: key (node-level field, never a prop)
+    if node.key.as_deref().is_some_and(|key| key.trim().is_empty()) {
+        errors.push(format!("{component}.key: must not be empty"));
+    }
+    // ScrollList's `key` prop is its item-key lambda, not a node key
+    if node.props.contains_key("key") && component != "ScrollList" {
+        errors.push(format!(
+            "{component}.key: must be set on the node, not in props"
+        ));
+    }
+
+    // Optional: focus_group (non-empty string)
+    match node.props.get("focus_group") {
+        Some(PropValue::String(s)) if s.is_empty() => {
+            errors.push(format!("{component}.focus_group: must not be empty"));
+        }
+        Some(PropValue::String(_)) | None => {}
+        Some(other) => errors.push(format!(
+            "{component}.focus_group: expected string, got {}",
+            other.type_name()
+        )),
+    }
+
+    // Optional: anchor (non-empty string)
+    match node.props.get("anchor") {
+        Some(PropValue::String(s)) if s.is_empty() => {
+            errors.push(format!("{component}.anchor: must not be empty"));
+        }
+        Some(PropValue::String(_)) | None => {}
+        Some(other) => errors.push(format!(
+            "{component}.anchor: expected string, got {}",
+            other.type_name()
+        )),
+    }
+
+    // Optional: initial_focus, focus_trap, draggable, drop_target (bool)
+    for key in ["initial_focus", "focus_trap", "draggable", "drop_target"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::Bool(_)) {
+                errors.push(format!(
+                    "{component}.{key}: expected bool, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+
+    // Optional: on_appear, on_disappear (action)
+    for key in ["on_appear", "on_disappear"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::ActionRef { .. }) {
+                errors.push(format!(
+                    "{component}.{key}: expected action, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+
+    // Optional: drag_payload (record, requires draggable: true)
+    if let Some(prop) = node.props.get("drag_payload") {
+        if !matches!(prop, PropValue::Record(_)) {
+            errors.push(format!(
+                "{component}.drag_payload: expected record, got {}",
+                prop.type_name()
+            ));
+        }
+        if node.props.get("draggable") != Some(&PropValue::Bool(true)) {
+            errors.push(format!(
+                "{component}.drag_payload: requires draggable: true"
+            ));
+        }
+    }
+
+    // Optional: on_drop (action) — paired with drop_target: true
+    if let Some(prop) = node.props.get("on_drop") {
+        if !matches!(prop, PropValue::ActionRef { .. }) {
+            errors.push(format!(
+                "{component}.on_drop: expected action, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+    let drop_target = node.props.get("drop_target") == Some(&PropValue::Bool(true));
+    match (drop_target, node.props.contains_key("on_drop")) {
+        (true, false) => errors.push(format!(
+            "{component}.drop_target: requires an on_drop action"
+        )),
+        (false, true) => errors.push(format!("{component}.on_drop: requires drop_target: true")),
+        _ => {}
+    }
+
+    // Optional: width, height (number or dimension)
+    for key in ["width", "height"] {
+        if let Some(prop) = node.props.get(key) {
+            if !validates_own_prop(component, key) {
+                errors.extend(validate_dimension_prop(component, key, prop));
+            }
+        }
+    }
+
+    // Optional: opacity (number within 0-1)
+    match node.props.get("opacity") {
+        Some(PropValue::Number(n)) if (0.0..=1.0).contains(n) => {}
+        Some(PropValue::Number(n)) => {
+            errors.push(format!("{component}.opacity: must be within 0-1, got {n}"))
+        }
+        Some(other) => errors.push(format!(
+            "{component}.opacity: expected number, got {}",
+            other.type_name()
+        )),
+        None => {}
+    }
+
+    // Optional: visible (bool)
+    if let Some(prop) = node.props.get("visible") {
+        if !validates_own_prop(component, "visible") && !matches!(prop, PropValue::Bool(_)) {
+            errors.push(format!(
+                "{component}.visible: expected bool, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: pinned (bool)
+    if let Some(prop) = node.props.get("pinned") {
+        if !matches!(prop, PropValue::Bool(_)) {
+            errors.push(format!(
+                "{component}.pinned: expected bool, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: position (record of edge offsets)
+    if let Some(prop) = node.props.get("position") {
+        errors.extend(validate_position_prop(component, prop));
+    }
+
+    // Optional: flex (positive weight)
+    match node.props.get("flex") {
+        Some(PropValue::Number(n)) if *n > 0.0 && n.is_finite() => {}
+        Some(PropValue::Number(n)) => errors.push(format!(
+            "{component}.flex: must be a positive number, got {n}"
+        )),
+        Some(other) => errors.push(format!(
+            "{component}.flex: expected number, got {}",
+            other.type_name()
+        )),
+        None => {}
+    }
+
+    // Optional: align_self (cross-axis alignment)
+    if let Some(prop) = node.props.get("align_self") {
+        match prop {
+            PropValue::String(s) if AlignSelf::ALL.iter().any(|a| a.as_str() == s) => {}
+            _ => errors.push(format!(
+                "{component}.align_self: expected one of [start, center, end, stretch], got {prop:?}"
+            )),
+        }
+    }
+
+    // Optional: animate (record of prop → animation spec)
+    if let Some(prop) = node.props.get("animate") {
+        errors.extend(validate_animate_prop(component, prop));
+    }
+
+    errors
+}
+
+/// Validate a size prop (`width`, `height`, `spacing`, ...).
+///
+/// Accepts a number (shorthand for [`Dimension::Px`](crate::Dimension::Px)),
+/// a [`PropValue::Dimension`], or a `spacing.*` theme token. Numbers and
+/// dimensions must be in range (see
+/// [`Dimension::check_range`](crate::Dimension::check_range)).
+pub fn validate_dimension_prop(
+    component_name: &str,
+    prop_name: &str,
+    prop: &PropValue,
+) -> Vec<String> {
+    let range = match prop {
+        PropValue::Number(n) => Dimension::Px(*n).check_range(),
+        PropValue::Dimension { dim } => dim.check_range(),
+        _ if prop.is_theme_token("spacing") => Ok(()),
+        other => {
+            return vec![format!(
+                "{component_name}.{prop_name}: expected number or dimension, got {}",
+                other.type_name()
+            )]
+        }
+    };
+    match range {
+        Ok(()) => Vec::new(),
+        Err(reason) => vec![format!("{component_name}.{prop_name}: {reason}")],
+    }
+}
+
+/// Convert a [`Position`] to a `PropValue::Record` of the set edges.
+fn position_to_prop(position: &Position) -> PropValue {
+    let edges = [
+        ("top", position.top),
+        ("bottom", position.bottom),
+        ("start", position.start),
+        ("end", position.end),
+    ];
+    PropValue::Record(
+        edges
+            .into_iter()
+            .filter_map(|(edge, offset)| Some((edge.to_string(), PropValue::Number(offset?))))
+            .collect(),
+    )
+}
+
+/// Validate a `position` prop: a non-empty record of `top`, `bottom`,
+/// `start`, and `end` numbers.
+fn validate_position_prop(component: &str, prop: &PropValue) -> Vec<String> {
+    let PropValue::Record(fields) = prop else {
+        return vec![format!(
+            "{component}.position: expected record, got {}",
+            prop.type_name()
+        )];
+    };
+    if fields.is_empty() {
+        return vec![format!(
+            "{component}.position: must set at least one of [top, bottom, start, end]"
+        )];
+    }
+    let mut errors = Vec::new();
+    for (edge, value) in fields {
+        if !matches!(edge.as_str(), "top" | "bottom" | "start" | "end") {
+            errors.push(format!("{component}.position: unknown field '{edge}'"));
+        } else if !matches!(value, PropValue::Number(n) if n.is_finite()) {
+            errors.push(format!(
+                "{component}.position.{edge}: expected number, got {}",
+                value.type_name()
+            ));
+        }
+    }
+    errors
+}
+
+/// Convert a [`Gradient`] to its tagged record,
+/// `{ "__gradient": "linear" | "radial", "angle"?, "stops": [...] }`.
+pub fn gradient_to_prop(gradient: &Gradient) -> PropValue {
+    let json = serde_json::to_value(gradient).expect("Gradient serialization should never fail");
+    serde_json::from_value(json).expect("Gradient deserialization should never fail")
+}
+
+/// Whether `prop` is a gradient record (it carries `__gradient`).
+pub fn is_gradient(prop: &PropValue) -> bool {
+    matches!(prop, PropValue::Record(fields) if fields.contains_key("__gradient"))
+}
+
+/// Validate a gradient record (see [`Gradient`]) held by `component.key`.
+///
+/// Stops need a color and an `offset` within 0-1, in non-decreasing order.
+pub fn validate_gradient_prop(component: &str, key: &str, prop: &PropValue) -> Vec<String> {
+    let ctx = format!("{component}.{key}");
+    let PropValue::Record(fields) = prop else {
+        return vec![format!(
+            "{ctx}: expected gradient, got {}",
+            prop.type_name()
+        )];
+    };
+
+    let mut errors = Vec::new();
+    let linear = match fields.get("__gradient") {
+        Some(PropValue::String(kind)) if kind == "linear" => true,
+        Some(PropValue::String(kind)) if kind == "radial" => false,
+        other => {
+            return vec![format!(
+                "{ctx}.__gradient: expected one of [linear, radial], got {other:?}"
+            )]
+        }
+    };
+    if linear {
+        match fields.get("angle") {
+            Some(PropValue::Number(_)) => {}
+            Some(other) => errors.push(format!(
+                "{ctx}.angle: expected number, got {}",
+                other.type_name()
+            )),
+            None => errors.push(format!("{ctx}.angle: required field missing")),
+        }
+    }
+
+    match fields.get("stops") {
+        Some(PropValue::List(stops)) if stops.len() < 2 => errors.push(format!(
+            "{ctx}.stops: expected at least 2 stops, got {}",
+            stops.len()
+        )),
+        Some(PropValue::List(stops)) => {
+            let mut previous = 0.0;
+            for (i, stop) in stops.iter().enumerate() {
+                let PropValue::Record(stop) = stop else {
+                    errors.push(format!(
+                        "{ctx}.stops[{i}]: expected record, got {}",
+                        stop.type_name()
+                    ));
+                    continue;
+                };
+                match stop.get("offset") {
+                    Some(PropValue::Number(n)) if !(0.0..=1.0).contains(n) => {
+                        errors.push(format!("{ctx}.stops[{i}].offset: must be within 0-1, got {n}"))
+                    }
+                    Some(PropValue::Number(n)) if *n < previous => errors.push(format!(
+                        "{ctx}.stops[{i}].offset: must not be less than the previous offset {previous}, got {n}"
+                    )),
+                    Some(PropValue::Number(n)) => previous = *n,
+                    Some(other) => errors.push(format!(
+                        "{ctx}.stops[{i}].offset: expected number, got {}",
+                        other.type_name()
+                    )),
+                    None => errors.push(format!("{ctx}.stops[{i}].offset: required field missing")),
+                }
+                match stop.get("color") {
+                    Some(color) if color.is_color() => {}
+                    Some(other) => errors.push(format!(
+                        "{ctx}.stops[{i}].color: expected color, got {}",
+                        other.type_name()
+                    )),
+                    None => errors.push(format!("{ctx}.stops[{i}].color: required field missing")),
+                }
+                for field in stop.keys() {
+                    if !matches!(field.as_str(), "offset" | "color") {
+                        errors.push(format!("{ctx}.stops[{i}]: unknown field '{field}'"));
+                    }
+                }
+            }
+        }
+        Some(other) => errors.push(format!(
+            "{ctx}.stops: expected list, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("{ctx}.stops: required field missing")),
+    }
+
+    for field in fields.keys() {
+        let known = match field.as_str() {
+            "__gradient" | "stops" => true,
+            "angle" => linear,
+            _ => false,
+        };
+        if !known {
+            errors.push(format!("{ctx}: unknown field '{field}'"));
+        }
+    }
+    errors
+}
+
+/// Convert [`CornerRadii`] to a `PropValue` for the Surface tree.
+///
+/// - `Uniform(n)` → `PropValue::Number(n)` (number literal coercion)
+/// - `Corners { .. }` → `PropValue::Record { top_start, top_end, bottom_start, bottom_end }`
+pub fn corner_radius_to_prop(radii: &CornerRadii) -> PropValue {
+    match *radii {
+        CornerRadii::Uniform(n) => PropValue::Number(n),
+        CornerRadii::Corners {
+            top_start,
+            top_end,
+            bottom_start,
+            bottom_end,
+        } => PropValue::Record(BTreeMap::from([
+            ("top_start".to_string(), PropValue::Number(top_start)),
+            ("top_end".to_string(), PropValue::Number(top_end)),
+            ("bottom_start".to_string(), PropValue::Number(bottom_start)),
+            ("bottom_end".to_string(), PropValue::Number(bottom_end)),
+        ])),
+    }
+}
+
+/// Validate a `corner_radius` prop: a non-negative number, or a record with
+/// a non-negative number for each of `top_start`, `top_end`, `bottom_start`,
+/// and `bottom_end`.
+pub fn validate_corner_radius_prop(component_name: &str, prop: &PropValue) -> Vec<String> {
+    const CORNERS: [&str; 4] = ["top_start", "top_end", "bottom_start", "bottom_end"];
+    let ctx = format!("{component_name}.corner_radius");
+
+    let fields = match prop {
+        PropValue::Number(n) if *n >= 0.0 => return Vec::new(),
+        PropValue::Number(n) => return vec![format!("{ctx}: must be non-negative, got {n}")],
+        _ if prop.is_theme_token("radius") => return Vec::new(),
+        PropValue::Record(fields) => fields,
+        other => {
+            return vec![format!(
+                "{ctx}: expected number or record, got {}",
+                other.type_name()
+            )]
+        }
+    };
+
+    let mut errors = Vec::new();
+    for corner in CORNERS {
+        match fields.get(corner) {
+            Some(PropValue::Number(n)) if *n >= 0.0 => {}
+            Some(PropValue::Number(n)) => {
+                errors.push(format!("{ctx}.{corner}: must be non-negative, got {n}"))
+            }
+            Some(other) => errors.push(format!(
+                "{ctx}.{corner}: expected number, got {}",
+                other.type_name()
+            )),
+            None => errors.push(format!("{ctx}.{corner}: required field missing")),
+        }
+    }
+    for key in fields.keys() {
+        if !CORNERS.contains(&key.as_str()) {
+            errors.push(format!("{ctx}: unknown field '{key}'"));
+        }
+    }
+    errors
+}
+
+/// Returns `true` if `prop` on `component` may appear in an `animate` record.
+pub fn is_animatable(component: &str, prop: &str) -> bool {
+    matches!(prop, "opacity" | "width" | "height")
+        || (component == "ProgressBar" && prop == "value")
+}
+
+/// Convert an [`AnimationSpec`] to a `PropValue::Record` for the Surface tree.
+///
+/// `{ duration, delay?, easing?, spring? }` — unset fields are omitted.
+pub fn animation_to_prop(spec: &AnimationSpec) -> PropValue {
+    let mut fields = BTreeMap::new();
+    fields.insert("duration".to_string(), PropValue::Number(spec.duration));
+    if let Some(delay) = spec.delay {
+        fields.insert("delay".to_string(), PropValue::Number(delay));
+    }
+    if let Some(easing) = spec.easing {
+        fields.insert(
+            "easing".to_string(),
+            PropValue::String(easing.as_str().to_string()),
+        );
+    }
+    if let Some(spring) = spec.spring {
+        let mut spring_fields = BTreeMap::new();
+        spring_fields.insert("stiffness".to_string(), PropValue::Number(spring.stiffness));
+        spring_fields.insert("damping".to_string(), PropValue::Number(spring.damping));
+        spring_fields.insert("mass".to_string(), PropValue::Number(spring.mass));
+        fields.insert("spring".to_string(), PropValue::Record(spring_fields));
+    }
+    PropValue::Record(fields)
+}
+
+/// Build an `animate` prop from `(prop name, spec)` pairs.
+pub fn animate_prop<'a>(specs: impl IntoIterator<Item = (&'a str, AnimationSpec)>) -> PropValue {
+    PropValue::Record(
+        specs
+            .into_iter()
+            .map(|(prop, spec)| (prop.to_string(), animation_to_prop(&spec)))
+            .collect(),
+    )
+}
+
+/// Validate an `animate` prop.
+///
+/// Must be a record whose keys are [animatable](is_animatable) props of
+/// `component_name` and whose values are animation spec records.
+pub fn validate_animate_prop(component_name: &str, prop: &PropValue) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let entries = match prop {
+        PropValue::Record(entries) => entries,
+        other => {
+            errors.push(format!(
+                "{component_name}.animate: expected record, got {}",
+                other.type_name()
+            ));
+            return errors;
+        }
+    };
+
+    for (target, spec) in entries {
+        let ctx = format!("{component_name}.animate.{target}");
+        if !is_animatable(component_name, target) {
+            errors.push(format!("{ctx}: prop is not animatable"));
+        }
+        let fields = match spec {
+            PropValue::Record(fields) => fields,
+            other => {
+                errors.push(format!("{ctx}: expected record, got {}", other.type_name()));
+                continue;
+            }
+        };
+
+        // Required: duration; optional: delay (non-negative numbers)
+        for key in ["duration", "delay"] {
+            match fields.get(key) {
+                Some(PropValue::Number(n)) if *n >= 0.0 => {}
+                Some(PropValue::Number(n)) => {
+                    errors.push(format!("{ctx}.{key}: must be non-negative, got {n}"))
+                }
+                Some(other) => errors.push(format!(
+                    "{ctx}.{key}: expected number, got {}",
+                    other.type_name()
+                )),
+                None if key == "duration" => {
+                    errors.push(format!("{ctx}.duration: required field missing"))
+                }
+                None => {}
+            }
+        }
+
+        // Optional: easing (string enum)
+        if let Some(val) = fields.get("easing") {
+            match val {
+                PropValue::String(s) if Easing::valid_values().contains(&s.as_str()) => {}
+                _ => errors.push(format!(
+                    "{ctx}.easing: expected one of {:?}, got {:?}",
+                    Easing::valid_values(),
+                    val
+                )),
+            }
+        }
+
+        // Optional: spring (record of positive numbers)
+        if let Some(val) = fields.get("spring") {
+            if fields.contains_key("easing") {
+                errors.push(format!("{ctx}: easing and spring are mutually exclusive"));
+            }
+            match val {
+                PropValue::Record(spring) => {
+                    for key in ["stiffness", "damping", "mass"] {
+                        match spring.get(key) {
+                            Some(PropValue::Number(n)) if *n > 0.0 => {}
+                            Some(PropValue::Number(n)) => errors
+                                .push(format!("{ctx}.spring.{key}: must be positive, got {n}")),
+                            Some(other) => errors.push(format!(
+                                "{ctx}.spring.{key}: expected number, got {}",
+                                other.type_name()
+                            )),
+                            None => {
+                                errors.push(format!("{ctx}.spring.{key}: required field missing"))
+                            }
+                        }
+                    }
+                    for key in spring.keys() {
+                        if !matches!(key.as_str(), "stiffness" | "damping" | "mass") {
+                            errors.push(format!("{ctx}.spring: unknown field '{key}'"));
+                        }
+                    }
+                }
+                other => errors.push(format!(
+                    "{ctx}.spring: expected record, got {}",
+                    other.type_name()
+                )),
+            }
+        }
+
+        // Unknown fields
+        for key in fields.keys() {
+            if !matches!(key.as_str(), "duration" | "delay" | "easing" | "spring") {
+                errors.push(format!("{ctx}: unknown field '{key}'"));
+            }
+        }
+    }
+
+    errors
+}
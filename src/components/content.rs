@@ -1,12 +1,17 @@
-//! Content component builders — Text, ProgressBar.
+//! Content component builders — Text, RichText, ProgressBar, Spinner, Image,
+//! Skeleton.
 //!
 //! These are leaf components with no children. They render visible content
 //! for PEPL UI views.
 
+use std::collections::BTreeMap;
+
 use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
+use crate::icons;
 use crate::prop_value::PropValue;
 use crate::surface::SurfaceNode;
-use crate::types::ColorValue;
+use crate::types::{ColorValue, Dimension, Gradient};
 
 // ── Text Size Enum ────────────────────────────────────────────────────────────
 
@@ -21,7 +26,18 @@ pub enum TextSize {
 }
 
 impl TextSize {
-    fn as_str(self) -> &'static str {
+    /// Every preset, smallest first.
+    pub const ALL: [TextSize; 5] = [
+        Self::Small,
+        Self::Body,
+        Self::Title,
+        Self::Heading,
+        Self::Display,
+    ];
+
+    /// The wire name, also the preset's [`Typography`](crate::theme::Typography)
+    /// style name.
+    pub fn as_str(self) -> &'static str {
         match self {
             Self::Small => "small",
             Self::Body => "body",
@@ -63,7 +79,7 @@ pub enum TextAlign {
 }
 
 impl TextAlign {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             Self::Start => "start",
             Self::Center => "center",
@@ -92,6 +108,72 @@ impl TextOverflow {
     }
 }
 
+// ── Progress Shape Enum ───────────────────────────────────────────────────────
+
+/// Shape of a ProgressBar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProgressShape {
+    /// Horizontal bar (default).
+    Linear,
+    /// Ring / spinner-with-progress.
+    Circular,
+}
+
+impl ProgressShape {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Circular => "circular",
+        }
+    }
+}
+
+// ── Image Fit Enum ────────────────────────────────────────────────────────────
+
+/// How an Image is scaled into its frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFit {
+    /// Scale to fit entirely inside the frame, preserving aspect ratio.
+    Contain,
+    /// Scale to cover the frame, preserving aspect ratio (may crop).
+    Cover,
+    /// Stretch to the frame, ignoring aspect ratio.
+    Fill,
+}
+
+impl ImageFit {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Contain => "contain",
+            Self::Cover => "cover",
+            Self::Fill => "fill",
+        }
+    }
+}
+
+// ── Skeleton Shape Enum ───────────────────────────────────────────────────────
+
+/// The placeholder shape a Skeleton draws while content loads.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SkeletonShape {
+    /// One or more text-height bars (see [`SkeletonBuilder::lines`]).
+    Text,
+    /// A rectangle (e.g. an image or card placeholder).
+    Rect,
+    /// A circle (e.g. an avatar placeholder).
+    Circle,
+}
+
+impl SkeletonShape {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Rect => "rect",
+            Self::Circle => "circle",
+        }
+    }
+}
+
 // ── TextBuilder ───────────────────────────────────────────────────────────────
 
 /// Builder for the `Text` component.
@@ -99,6 +181,16 @@ impl TextOverflow {
 /// `Text` is a leaf component (no children) that displays a string value
 /// with optional styling props.
 ///
+/// `selectable: true` lets the user select ranges of the text; `copyable: true`
+/// offers an explicit "Copy" action for the whole value (e.g. confirmation
+/// codes). Copyable text announces the action to assistive technology, and
+/// hosts should announce "Copied" politely once the copy completes.
+///
+/// `style` names a text style from the host's
+/// [`Typography`](crate::theme::Typography) (font size, line height, and
+/// weight together); explicit `size` and `weight` override it. `font` names
+/// a logical font family (see [`FontRegistry`](crate::fonts::FontRegistry)).
+///
 /// # Example
 /// ```
 /// use pepl_ui::TextBuilder;
@@ -112,29 +204,69 @@ impl TextOverflow {
 /// assert_eq!(node.component_type, "Text");
 /// ```
 pub struct TextBuilder {
-    value: String,
+    value: PropValue,
     size: Option<TextSize>,
     weight: Option<TextWeight>,
-    color: Option<ColorValue>,
+    color: Option<PropValue>,
     align: Option<TextAlign>,
     max_lines: Option<f64>,
     overflow: Option<TextOverflow>,
+    selectable: Option<bool>,
+    copyable: Option<bool>,
+    style: Option<String>,
+    font: Option<String>,
+    common: CommonFields,
 }
 
 impl TextBuilder {
     /// Create a new `TextBuilder` with the required `value` prop.
     pub fn new(value: impl Into<String>) -> Self {
+        Self::with_value(PropValue::String(value.into()))
+    }
+
+    /// Create a `TextBuilder` whose `value` is a localized string reference.
+    /// See [`crate::i18n`].
+    pub fn i18n(key: impl Into<String>) -> Self {
+        Self::with_value(PropValue::i18n(key))
+    }
+
+    /// Create a `TextBuilder` whose `value` is a localized string reference
+    /// with interpolation arguments.
+    pub fn i18n_with_args(key: impl Into<String>, args: BTreeMap<String, PropValue>) -> Self {
+        Self::with_value(PropValue::i18n_with_args(key, args))
+    }
+
+    fn with_value(value: PropValue) -> Self {
         Self {
-            value: value.into(),
+            value,
             size: None,
             weight: None,
             color: None,
             align: None,
             max_lines: None,
             overflow: None,
+            selectable: None,
+            copyable: None,
+            style: None,
+            font: None,
+            common: CommonFields::default(),
         }
     }
 
+    /// Use a named [`Typography`](crate::theme::Typography) style, e.g. a
+    /// preset name like `"title"` or an app-defined `"caption"`.
+    pub fn style(mut self, token: impl Into<String>) -> Self {
+        self.style = Some(token.into());
+        self
+    }
+
+    /// Use a logical font family (e.g. `"brand"`, `"mono"`), mapped to a
+    /// platform font by the host's [`FontRegistry`](crate::fonts::FontRegistry).
+    pub fn font(mut self, family: impl Into<String>) -> Self {
+        self.font = Some(family.into());
+        self
+    }
+
     /// Set the text size preset.
     pub fn size(mut self, size: TextSize) -> Self {
         self.size = Some(size);
@@ -149,7 +281,13 @@ impl TextBuilder {
 
     /// Set the text color.
     pub fn color(mut self, color: ColorValue) -> Self {
-        self.color = Some(color);
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the text color to a theme color token (e.g. `"primary"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
         self
     }
 
@@ -171,10 +309,22 @@ impl TextBuilder {
         self
     }
 
+    /// Allow the user to select ranges of the text.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = Some(selectable);
+        self
+    }
+
+    /// Offer a "Copy" action for the whole value.
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = Some(copyable);
+        self
+    }
+
     /// Build the `SurfaceNode`.
     pub fn build(self) -> SurfaceNode {
         let mut node = SurfaceNode::new("Text");
-        node.set_prop("value", PropValue::String(self.value));
+        node.set_prop("value", self.value);
         if let Some(size) = self.size {
             node.set_prop("size", PropValue::String(size.as_str().to_string()));
         }
@@ -182,10 +332,7 @@ impl TextBuilder {
             node.set_prop("weight", PropValue::String(weight.as_str().to_string()));
         }
         if let Some(color) = self.color {
-            node.set_prop(
-                "color",
-                PropValue::color(color.r, color.g, color.b, color.a),
-            );
+            node.set_prop("color", color);
         }
         if let Some(align) = self.align {
             node.set_prop("align", PropValue::String(align.as_str().to_string()));
@@ -196,11 +343,30 @@ impl TextBuilder {
         if let Some(overflow) = self.overflow {
             node.set_prop("overflow", PropValue::String(overflow.as_str().to_string()));
         }
+        if let Some(selectable) = self.selectable {
+            node.set_prop("selectable", PropValue::Bool(selectable));
+        }
+        if let Some(copyable) = self.copyable {
+            node.set_prop("copyable", PropValue::Bool(copyable));
+        }
+        if let Some(style) = self.style {
+            node.set_prop("style", PropValue::String(style));
+        }
+        if let Some(font) = self.font {
+            node.set_prop("font", PropValue::String(font));
+        }
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for TextBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 // ── ProgressBarBuilder ────────────────────────────────────────────────────────
 
 /// Builder for the `ProgressBar` component.
@@ -208,6 +374,12 @@ impl TextBuilder {
 /// `ProgressBar` is a leaf component (no children) that displays a
 /// horizontal progress indicator. The `value` prop is clamped to 0.0–1.0.
 ///
+/// An indeterminate bar (`indeterminate: true`) shows ongoing activity of
+/// unknown length and has no `value` — see [`ProgressBarBuilder::indeterminate`].
+///
+/// With `shape: "circular"` the indicator is drawn as a ring; `size` sets its
+/// diameter and `thickness` its stroke width.
+///
 /// # Example
 /// ```
 /// use pepl_ui::ProgressBarBuilder;
@@ -216,10 +388,15 @@ impl TextBuilder {
 /// assert_eq!(node.component_type, "ProgressBar");
 /// ```
 pub struct ProgressBarBuilder {
-    value: f64,
-    color: Option<ColorValue>,
-    background: Option<ColorValue>,
+    value: Option<f64>,
+    indeterminate: Option<bool>,
+    shape: Option<ProgressShape>,
+    size: Option<f64>,
+    thickness: Option<f64>,
+    color: Option<PropValue>,
+    background: Option<PropValue>,
     height: Option<f64>,
+    common: CommonFields,
 }
 
 impl ProgressBarBuilder {
@@ -228,22 +405,83 @@ impl ProgressBarBuilder {
     /// Values outside 0.0–1.0 are clamped.
     pub fn new(value: f64) -> Self {
         Self {
-            value: value.clamp(0.0, 1.0),
+            value: Some(value.clamp(0.0, 1.0)),
+            indeterminate: None,
+            shape: None,
+            size: None,
+            thickness: None,
+            color: None,
+            background: None,
+            height: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Create an indeterminate `ProgressBarBuilder` (no `value`).
+    pub fn indeterminate() -> Self {
+        Self {
+            value: None,
+            indeterminate: Some(true),
+            shape: None,
+            size: None,
+            thickness: None,
             color: None,
             background: None,
             height: None,
+            common: CommonFields::default(),
         }
     }
 
     /// Set the fill color.
     pub fn color(mut self, color: ColorValue) -> Self {
-        self.color = Some(color);
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the fill color to a theme color token (e.g. `"primary"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Fill the indicator with a gradient instead of a solid color.
+    pub fn color_gradient(mut self, gradient: Gradient) -> Self {
+        self.color = Some(common::gradient_to_prop(&gradient));
+        self
+    }
+
+    /// Set the indicator shape (linear or circular).
+    pub fn shape(mut self, shape: ProgressShape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    /// Set the diameter of a circular indicator in logical pixels.
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the stroke width of a circular indicator in logical pixels.
+    pub fn thickness(mut self, thickness: f64) -> Self {
+        self.thickness = Some(thickness);
         self
     }
 
     /// Set the background (track) color.
     pub fn background(mut self, background: ColorValue) -> Self {
-        self.background = Some(background);
+        self.background = Some(PropValue::color(
+            background.r,
+            background.g,
+            background.b,
+            background.a,
+        ));
+        self
+    }
+
+    /// Set the background (track) color to a theme color token.
+    pub fn background_token(mut self, token: impl Into<String>) -> Self {
+        self.background = Some(PropValue::color_token(token));
         self
     }
 
@@ -256,196 +494,972 @@ impl ProgressBarBuilder {
     /// Build the `SurfaceNode`.
     pub fn build(self) -> SurfaceNode {
         let mut node = SurfaceNode::new("ProgressBar");
-        node.set_prop("value", PropValue::Number(self.value));
+        if let Some(value) = self.value {
+            node.set_prop("value", PropValue::Number(value));
+        }
+        if let Some(indeterminate) = self.indeterminate {
+            node.set_prop("indeterminate", PropValue::Bool(indeterminate));
+        }
+        if let Some(shape) = self.shape {
+            node.set_prop("shape", PropValue::String(shape.as_str().to_string()));
+        }
+        if let Some(size) = self.size {
+            node.set_prop("size", PropValue::Number(size));
+        }
+        if let Some(thickness) = self.thickness {
+            node.set_prop("thickness", PropValue::Number(thickness));
+        }
         if let Some(color) = self.color {
-            node.set_prop(
-                "color",
-                PropValue::color(color.r, color.g, color.b, color.a),
-            );
+            node.set_prop("color", color);
         }
         if let Some(background) = self.background {
-            node.set_prop(
-                "background",
-                PropValue::color(background.r, background.g, background.b, background.a),
-            );
+            node.set_prop("background", background);
         }
         if let Some(height) = self.height {
             node.set_prop("height", PropValue::Number(height));
         }
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
-// ── Validation ────────────────────────────────────────────────────────────────
-
-/// Validates a content component node's props.
-///
-/// Returns a list of human-readable error strings. An empty list means
-/// the node is valid.
-pub fn validate_content_node(node: &SurfaceNode) -> Vec<String> {
-    match node.component_type.as_str() {
-        "Text" => validate_text(node),
-        "ProgressBar" => validate_progress_bar(node),
-        _ => vec![format!(
-            "Unknown content component: {}",
-            node.component_type
-        )],
+impl CommonProps for ProgressBarBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
     }
 }
 
-fn validate_text(node: &SurfaceNode) -> Vec<String> {
-    let mut errors = Vec::new();
+// ── SpinnerBuilder ────────────────────────────────────────────────────────────
 
-    // Required: value must be a string
-    match node.props.get("value") {
-        Some(PropValue::String(_)) => {}
-        Some(other) => errors.push(format!(
-            "Text.value: expected string, got {}",
-            other.type_name()
-        )),
-        None => errors.push("Text.value: required prop missing".to_string()),
-    }
+/// Builder for the `Spinner` component.
+///
+/// `Spinner` is a leaf component (no children) that shows indeterminate
+/// activity as the platform's native activity indicator. Use it for
+/// standalone loading states; use [`ProgressBarBuilder`] when progress can be
+/// measured. Its accessible label is "Loading".
+///
+/// # Example
+/// ```
+/// use pepl_ui::SpinnerBuilder;
+///
+/// let node = SpinnerBuilder::new().size(24.0).color_token("primary").build();
+/// assert_eq!(node.component_type, "Spinner");
+/// ```
+pub struct SpinnerBuilder {
+    size: Option<f64>,
+    color: Option<PropValue>,
+    common: CommonFields,
+}
 
-    // Optional: size must be one of the allowed values
-    if let Some(prop) = node.props.get("size") {
-        match prop {
-            PropValue::String(s)
-                if matches!(
-                    s.as_str(),
-                    "small" | "body" | "title" | "heading" | "display"
-                ) => {}
-            _ => errors.push(format!(
-                "Text.size: expected one of [small, body, title, heading, display], got {:?}",
-                prop
-            )),
+impl SpinnerBuilder {
+    /// Create a new `SpinnerBuilder` (all props optional).
+    pub fn new() -> Self {
+        Self {
+            size: None,
+            color: None,
+            common: CommonFields::default(),
         }
     }
 
-    // Optional: weight
-    if let Some(prop) = node.props.get("weight") {
-        match prop {
-            PropValue::String(s) if matches!(s.as_str(), "normal" | "medium" | "bold") => {}
-            _ => errors.push(format!(
-                "Text.weight: expected one of [normal, medium, bold], got {:?}",
-                prop
-            )),
-        }
+    /// Set the diameter in logical pixels.
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
     }
 
-    // Optional: color
-    if let Some(prop) = node.props.get("color") {
-        if !matches!(prop, PropValue::Color { .. }) {
-            errors.push(format!(
-                "Text.color: expected color, got {}",
-                prop.type_name()
-            ));
-        }
+    /// Set the indicator color.
+    pub fn color(mut self, color: ColorValue) -> Self {
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
     }
 
-    // Optional: align
-    if let Some(prop) = node.props.get("align") {
-        match prop {
-            PropValue::String(s) if matches!(s.as_str(), "start" | "center" | "end") => {}
-            _ => errors.push(format!(
-                "Text.align: expected one of [start, center, end], got {:?}",
-                prop
-            )),
-        }
+    /// Set the indicator color to a theme color token (e.g. `"primary"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
+        self
     }
 
-    // Optional: max_lines
-    if let Some(prop) = node.props.get("max_lines") {
-        if !matches!(prop, PropValue::Number(_)) {
-            errors.push(format!(
-                "Text.max_lines: expected number, got {}",
-                prop.type_name()
-            ));
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Spinner");
+        if let Some(size) = self.size {
+            node.set_prop("size", PropValue::Number(size));
         }
-    }
-
-    // Optional: overflow
-    if let Some(prop) = node.props.get("overflow") {
-        match prop {
-            PropValue::String(s) if matches!(s.as_str(), "clip" | "ellipsis" | "wrap") => {}
-            _ => errors.push(format!(
-                "Text.overflow: expected one of [clip, ellipsis, wrap], got {:?}",
-                prop
-            )),
+        if let Some(color) = self.color {
+            node.set_prop("color", color);
         }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
     }
+}
 
-    // No children allowed
-    if !node.children.is_empty() {
-        errors.push(format!(
-            "Text: does not accept children, but got {}",
-            node.children.len()
-        ));
+impl CommonProps for SpinnerBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
     }
+}
 
-    // Optional: accessible (record)
-    if let Some(prop) = node.props.get("accessible") {
-        errors.extend(accessibility::validate_accessible_prop("Text", prop));
+impl Default for SpinnerBuilder {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    // Check for unknown props
-    for key in node.props.keys() {
-        if !matches!(
-            key.as_str(),
-            "value"
-                | "size"
-                | "weight"
-                | "color"
-                | "align"
-                | "max_lines"
-                | "overflow"
-                | "accessible"
-        ) {
-            errors.push(format!("Text: unknown prop '{key}'"));
+// ── IconBuilder ───────────────────────────────────────────────────────────────
+
+/// Builder for the `Icon` component.
+///
+/// `Icon` is a leaf component (no children) that shows a named glyph from
+/// the host's icon set: a [canonical name](crate::icons::CANONICAL_ICONS)
+/// such as `"search"`, or a host-bundled `asset:<name>`. Unknown names fail
+/// validation with a suggestion. Set `label` for icons that carry meaning on
+/// their own; unlabelled icons are decorative and hidden from assistive
+/// technology.
+///
+/// # Example
+/// ```
+/// use pepl_ui::IconBuilder;
+///
+/// let node = IconBuilder::new("warning")
+///     .size(20.0)
+///     .color_token("error")
+///     .label("Warning")
+///     .build();
+/// assert_eq!(node.component_type, "Icon");
+/// ```
+pub struct IconBuilder {
+    name: String,
+    size: Option<f64>,
+    color: Option<PropValue>,
+    label: Option<String>,
+    common: CommonFields,
+}
+
+impl IconBuilder {
+    /// Create a new `IconBuilder` with the required icon `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            size: None,
+            color: None,
+            label: None,
+            common: CommonFields::default(),
         }
     }
 
-    errors
-}
+    /// Set the glyph size in logical pixels.
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
 
-fn validate_progress_bar(node: &SurfaceNode) -> Vec<String> {
-    let mut errors = Vec::new();
+    /// Set the glyph color.
+    pub fn color(mut self, color: ColorValue) -> Self {
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
 
-    // Required: value must be a number
-    match node.props.get("value") {
-        Some(PropValue::Number(_)) => {}
-        Some(other) => errors.push(format!(
-            "ProgressBar.value: expected number, got {}",
-            other.type_name()
-        )),
-        None => errors.push("ProgressBar.value: required prop missing".to_string()),
+    /// Set the glyph color to a theme color token (e.g. `"primary"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
+        self
     }
 
-    // Optional: color
-    if let Some(prop) = node.props.get("color") {
-        if !matches!(prop, PropValue::Color { .. }) {
-            errors.push(format!(
-                "ProgressBar.color: expected color, got {}",
-                prop.type_name()
-            ));
-        }
+    /// Describe the icon for assistive technology.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Icon");
+        node.set_prop("name", PropValue::String(self.name));
+        if let Some(size) = self.size {
+            node.set_prop("size", PropValue::Number(size));
+        }
+        if let Some(color) = self.color {
+            node.set_prop("color", color);
+        }
+        if let Some(label) = self.label {
+            node.set_prop("label", PropValue::String(label));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for IconBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── ImageBuilder ──────────────────────────────────────────────────────────────
+
+/// Builder for the `Image` component.
+///
+/// `Image` is a leaf component (no children) that displays a bitmap. The
+/// `source` is either a URL (`https://`, `http://`, or a `data:image/` URI)
+/// or a reference to an asset bundled with the host app (`asset:<name>`).
+///
+/// `alt` describes the image for assistive technology and becomes its
+/// accessible label.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{Dimension, ImageBuilder, ImageFit};
+///
+/// let node = ImageBuilder::asset("logo")
+///     .fit(ImageFit::Contain)
+///     .width(Dimension::Px(120.0))
+///     .alt("Company logo")
+///     .build();
+///
+/// assert_eq!(node.component_type, "Image");
+/// ```
+pub struct ImageBuilder {
+    source: String,
+    fit: Option<ImageFit>,
+    width: Option<Dimension>,
+    height: Option<Dimension>,
+    alt: Option<String>,
+    common: CommonFields,
+}
+
+impl ImageBuilder {
+    /// Create a new `ImageBuilder` with the required `source` prop (a URL or
+    /// `asset:<name>` reference).
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            fit: None,
+            width: None,
+            height: None,
+            alt: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Create an `ImageBuilder` for the bundled asset `name`.
+    pub fn asset(name: impl AsRef<str>) -> Self {
+        Self::new(format!("asset:{}", name.as_ref()))
+    }
+
+    /// Set how the image is scaled into its frame.
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = Some(fit);
+        self
+    }
+
+    /// Set the frame width.
+    pub fn width(mut self, width: Dimension) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the frame height.
+    pub fn height(mut self, height: Dimension) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the text alternative for assistive technology.
+    pub fn alt(mut self, alt: impl Into<String>) -> Self {
+        self.alt = Some(alt.into());
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Image");
+        node.set_prop("source", PropValue::String(self.source));
+        if let Some(fit) = self.fit {
+            node.set_prop("fit", PropValue::String(fit.as_str().to_string()));
+        }
+        if let Some(width) = self.width {
+            node.set_prop("width", PropValue::dimension(width));
+        }
+        if let Some(height) = self.height {
+            node.set_prop("height", PropValue::dimension(height));
+        }
+        if let Some(alt) = self.alt {
+            node.set_prop("alt", PropValue::String(alt));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for ImageBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── SkeletonBuilder ───────────────────────────────────────────────────────────
+
+/// Builder for the `Skeleton` component.
+///
+/// `Skeleton` is a leaf component (no children) that draws a shimmering
+/// placeholder in the shape of content that is still loading. It is hidden
+/// from assistive technology by default; announce the loading state once,
+/// e.g. with a Spinner or a live-region Text, rather than per placeholder.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{Dimension, SkeletonBuilder, SkeletonShape};
+///
+/// let avatar = SkeletonBuilder::new(SkeletonShape::Circle)
+///     .width(Dimension::Px(40.0))
+///     .height(Dimension::Px(40.0))
+///     .build();
+/// let body = SkeletonBuilder::new(SkeletonShape::Text).lines(3).build();
+///
+/// assert_eq!(avatar.component_type, "Skeleton");
+/// assert_eq!(body.component_type, "Skeleton");
+/// ```
+pub struct SkeletonBuilder {
+    shape: SkeletonShape,
+    width: Option<Dimension>,
+    height: Option<Dimension>,
+    lines: Option<u32>,
+    common: CommonFields,
+}
+
+impl SkeletonBuilder {
+    /// Create a new `SkeletonBuilder` with the required `shape` prop.
+    pub fn new(shape: SkeletonShape) -> Self {
+        Self {
+            shape,
+            width: None,
+            height: None,
+            lines: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the placeholder width.
+    pub fn width(mut self, width: Dimension) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the placeholder height (per line for `text`).
+    pub fn height(mut self, height: Dimension) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the number of text lines (`text` shape only; hosts default to 1).
+    pub fn lines(mut self, lines: u32) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Skeleton");
+        node.set_prop("shape", PropValue::String(self.shape.as_str().to_string()));
+        if let Some(width) = self.width {
+            node.set_prop("width", PropValue::dimension(width));
+        }
+        if let Some(height) = self.height {
+            node.set_prop("height", PropValue::dimension(height));
+        }
+        if let Some(lines) = self.lines {
+            node.set_prop("lines", PropValue::Number(f64::from(lines)));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for SkeletonBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── TextSpan ──────────────────────────────────────────────────────────────────
+
+/// One styled run of a RichText.
+///
+/// Serialized as a record `{ text, weight?, color?, link? }`. A span with a
+/// `link` is rendered as a link; hosts open the URL when it is activated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub weight: Option<TextWeight>,
+    /// A color value or theme color token.
+    pub color: Option<PropValue>,
+    /// Link target (`https://`, `http://`, or `mailto:`).
+    pub link: Option<String>,
+}
+
+impl TextSpan {
+    /// Create an unstyled span.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            weight: None,
+            color: None,
+            link: None,
+        }
+    }
+
+    /// Set the font weight.
+    pub fn weight(mut self, weight: TextWeight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Set the span color.
+    pub fn color(mut self, color: ColorValue) -> Self {
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the span color to a theme color token (e.g. `"primary"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Make the span a link to `url`.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
+    /// Convert to a `PropValue::Record` for the Surface tree.
+    pub fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        fields.insert("text".to_string(), PropValue::String(self.text.clone()));
+        if let Some(weight) = self.weight {
+            fields.insert(
+                "weight".to_string(),
+                PropValue::String(weight.as_str().to_string()),
+            );
+        }
+        if let Some(color) = &self.color {
+            fields.insert("color".to_string(), color.clone());
+        }
+        if let Some(link) = &self.link {
+            fields.insert("link".to_string(), PropValue::String(link.clone()));
+        }
+        PropValue::Record(fields)
+    }
+}
+
+// ── RichTextBuilder ───────────────────────────────────────────────────────────
+
+/// Builder for the `RichText` component.
+///
+/// `RichText` is a leaf component (no children) that displays inline-styled
+/// text. The content is either a list of [`TextSpan`] records (`spans`) or a
+/// `markdown` string limited to inline markup (emphasis, code, links); exactly
+/// one of the two must be set.
+///
+/// # Example
+/// ```
+/// use pepl_ui::components::content::TextWeight;
+/// use pepl_ui::{RichTextBuilder, TextSpan};
+///
+/// let node = RichTextBuilder::new(vec![
+///     TextSpan::new("Read the "),
+///     TextSpan::new("terms").weight(TextWeight::Bold).link("https://example.com/terms"),
+///     TextSpan::new(" before continuing."),
+/// ])
+/// .build();
+/// let note = RichTextBuilder::markdown("Saved **3** items").build();
+///
+/// assert_eq!(node.component_type, "RichText");
+/// assert_eq!(note.component_type, "RichText");
+/// ```
+pub struct RichTextBuilder {
+    content: (&'static str, PropValue),
+    size: Option<TextSize>,
+    align: Option<TextAlign>,
+    common: CommonFields,
+}
+
+impl RichTextBuilder {
+    /// Create a new `RichTextBuilder` from styled spans.
+    pub fn new(spans: Vec<TextSpan>) -> Self {
+        let spans = spans.iter().map(TextSpan::to_prop_value).collect();
+        Self::with_content("spans", PropValue::List(spans))
+    }
+
+    /// Create a `RichTextBuilder` from an inline markdown string.
+    pub fn markdown(source: impl Into<String>) -> Self {
+        Self::with_content("markdown", PropValue::String(source.into()))
+    }
+
+    fn with_content(key: &'static str, value: PropValue) -> Self {
+        Self {
+            content: (key, value),
+            size: None,
+            align: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the text size preset.
+    pub fn size(mut self, size: TextSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the text alignment.
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("RichText");
+        let (key, value) = self.content;
+        node.set_prop(key, value);
+        if let Some(size) = self.size {
+            node.set_prop("size", PropValue::String(size.as_str().to_string()));
+        }
+        if let Some(align) = self.align {
+            node.set_prop("align", PropValue::String(align.as_str().to_string()));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for RichTextBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+/// Validates a content component node's props.
+///
+/// Returns a list of human-readable error strings. An empty list means
+/// the node is valid.
+pub fn validate_content_node(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Text" => validate_text(node),
+        "ProgressBar" => validate_progress_bar(node),
+        "Image" => validate_image(node),
+        "Skeleton" => validate_skeleton(node),
+        "RichText" => validate_rich_text(node),
+        "Spinner" => validate_spinner(node),
+        "Icon" => validate_icon(node),
+        _ => vec![format!(
+            "Unknown content component: {}",
+            node.component_type
+        )],
+    }
+}
+
+fn validate_text(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: value must be a string
+    match node.props.get("value") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Text.value: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Text.value: required prop missing".to_string()),
+    }
+
+    // Optional: size must be one of the allowed values
+    if let Some(prop) = node.props.get("size") {
+        match prop {
+            PropValue::String(s)
+                if matches!(
+                    s.as_str(),
+                    "small" | "body" | "title" | "heading" | "display"
+                ) => {}
+            // A font size, e.g. a resolved `typography.*` theme token
+            PropValue::Number(n) if *n > 0.0 => {}
+            _ if prop.is_theme_token("typography") => {}
+            _ => errors.push(format!(
+                "Text.size: expected one of [small, body, title, heading, display], a positive number, or a typography token, got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: weight
+    if let Some(prop) = node.props.get("weight") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "normal" | "medium" | "bold") => {}
+            _ => errors.push(format!(
+                "Text.weight: expected one of [normal, medium, bold], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: color
+    if let Some(prop) = node.props.get("color") {
+        if !prop.is_color() {
+            errors.push(format!(
+                "Text.color: expected color, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: align
+    if let Some(prop) = node.props.get("align") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "start" | "center" | "end") => {}
+            _ => errors.push(format!(
+                "Text.align: expected one of [start, center, end], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: max_lines
+    if let Some(prop) = node.props.get("max_lines") {
+        if !matches!(prop, PropValue::Number(_)) {
+            errors.push(format!(
+                "Text.max_lines: expected number, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: overflow
+    if let Some(prop) = node.props.get("overflow") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "clip" | "ellipsis" | "wrap") => {}
+            _ => errors.push(format!(
+                "Text.overflow: expected one of [clip, ellipsis, wrap], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: selectable, copyable (bool)
+    for key in ["selectable", "copyable"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::Bool(_)) {
+                errors.push(format!(
+                    "Text.{key}: expected bool, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+
+    // Optional: style (typography style name), font (logical family name)
+    for key in ["style", "font"] {
+        match node.props.get(key) {
+            Some(PropValue::String(s)) if s.trim().is_empty() => {
+                errors.push(format!("Text.{key}: must not be empty"))
+            }
+            Some(PropValue::String(_)) | None => {}
+            Some(other) => errors.push(format!(
+                "Text.{key}: expected string, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Text: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Text", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "value"
+                    | "size"
+                    | "weight"
+                    | "color"
+                    | "align"
+                    | "max_lines"
+                    | "overflow"
+                    | "selectable"
+                    | "copyable"
+                    | "style"
+                    | "font"
+                    | "accessible"
+            )
+        {
+            errors.push(format!("Text: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_progress_bar(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Optional: indeterminate (bool)
+    let indeterminate = match node.props.get("indeterminate") {
+        Some(PropValue::Bool(b)) => *b,
+        Some(other) => {
+            errors.push(format!(
+                "ProgressBar.indeterminate: expected bool, got {}",
+                other.type_name()
+            ));
+            false
+        }
+        None => false,
+    };
+
+    // Required unless indeterminate: value must be a number
+    match node.props.get("value") {
+        Some(PropValue::Number(_)) => {}
+        Some(other) => errors.push(format!(
+            "ProgressBar.value: expected number, got {}",
+            other.type_name()
+        )),
+        None if indeterminate => {}
+        None => errors.push("ProgressBar.value: required prop missing".to_string()),
+    }
+
+    // Optional: shape (string enum)
+    let circular = match node.props.get("shape") {
+        Some(PropValue::String(s)) if matches!(s.as_str(), "linear" | "circular") => {
+            s == "circular"
+        }
+        Some(prop) => {
+            errors.push(format!(
+                "ProgressBar.shape: expected one of [linear, circular], got {:?}",
+                prop
+            ));
+            false
+        }
+        None => false,
+    };
+
+    // Optional: size, thickness (number, circular only)
+    for key in ["size", "thickness"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::Number(_)) {
+                errors.push(format!(
+                    "ProgressBar.{key}: expected number, got {}",
+                    prop.type_name()
+                ));
+            } else if !circular {
+                errors.push(format!(
+                    "ProgressBar.{key}: only applies when shape is \"circular\""
+                ));
+            }
+        }
+    }
+
+    // Optional: color (solid or gradient)
+    if let Some(prop) = node.props.get("color") {
+        if common::is_gradient(prop) {
+            errors.extend(common::validate_gradient_prop("ProgressBar", "color", prop));
+        } else if !prop.is_color() {
+            errors.push(format!(
+                "ProgressBar.color: expected color, got {}",
+                prop.type_name()
+            ));
+        }
     }
 
     // Optional: background
     if let Some(prop) = node.props.get("background") {
-        if !matches!(prop, PropValue::Color { .. }) {
+        if !prop.is_color() {
+            errors.push(format!(
+                "ProgressBar.background: expected color, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: height (number or dimension)
+    if let Some(prop) = node.props.get("height") {
+        errors.extend(common::validate_dimension_prop(
+            "ProgressBar",
+            "height",
+            prop,
+        ));
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "ProgressBar: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("ProgressBar", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "value"
+                    | "indeterminate"
+                    | "shape"
+                    | "size"
+                    | "thickness"
+                    | "color"
+                    | "background"
+                    | "height"
+                    | "accessible"
+            )
+        {
+            errors.push(format!("ProgressBar: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_image(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: source must be a URL or asset reference
+    match node.props.get("source") {
+        Some(PropValue::String(s)) if is_image_source(s) => {}
+        Some(PropValue::String(s)) => errors.push(format!(
+            "Image.source: expected url (https://, http://, data:image/) or asset:<name>, got {s:?}"
+        )),
+        Some(other) => errors.push(format!(
+            "Image.source: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Image.source: required prop missing".to_string()),
+    }
+
+    // Optional: fit
+    if let Some(prop) = node.props.get("fit") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "contain" | "cover" | "fill") => {}
+            _ => errors.push(format!(
+                "Image.fit: expected one of [contain, cover, fill], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: width, height (number or dimension)
+    for key in ["width", "height"] {
+        if let Some(prop) = node.props.get(key) {
+            errors.extend(common::validate_dimension_prop("Image", key, prop));
+        }
+    }
+
+    // Optional: alt (string)
+    if let Some(prop) = node.props.get("alt") {
+        if !prop.is_text() {
             errors.push(format!(
-                "ProgressBar.background: expected color, got {}",
+                "Image.alt: expected string, got {}",
                 prop.type_name()
             ));
         }
     }
 
-    // Optional: height
-    if let Some(prop) = node.props.get("height") {
-        if !matches!(prop, PropValue::Number(_)) {
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Image: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Image", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "source" | "fit" | "width" | "height" | "alt" | "accessible"
+            )
+        {
+            errors.push(format!("Image: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_icon(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: name (canonical icon or asset:<name>)
+    match node.props.get("name") {
+        Some(prop) => errors.extend(icons::validate_icon_prop("Icon", "name", prop)),
+        None => errors.push("Icon.name: required prop missing".to_string()),
+    }
+
+    // Optional: size (positive number)
+    if let Some(prop) = node.props.get("size") {
+        match prop {
+            PropValue::Number(n) if *n > 0.0 => {}
+            PropValue::Number(n) => {
+                errors.push(format!("Icon.size: must be positive, got {n}"));
+            }
+            other => errors.push(format!(
+                "Icon.size: expected number, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Optional: color
+    if let Some(prop) = node.props.get("color") {
+        if !prop.is_color() {
+            errors.push(format!(
+                "Icon.color: expected color, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: label (string)
+    if let Some(prop) = node.props.get("label") {
+        if !prop.is_text() {
             errors.push(format!(
-                "ProgressBar.height: expected number, got {}",
+                "Icon.label: expected string, got {}",
                 prop.type_name()
             ));
         }
@@ -454,25 +1468,340 @@ fn validate_progress_bar(node: &SurfaceNode) -> Vec<String> {
     // No children allowed
     if !node.children.is_empty() {
         errors.push(format!(
-            "ProgressBar: does not accept children, but got {}",
+            "Icon: does not accept children, but got {}",
             node.children.len()
         ));
     }
 
     // Optional: accessible (record)
     if let Some(prop) = node.props.get("accessible") {
-        errors.extend(accessibility::validate_accessible_prop("ProgressBar", prop));
+        errors.extend(accessibility::validate_accessible_prop("Icon", prop));
     }
 
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
     // Check for unknown props
     for key in node.props.keys() {
-        if !matches!(
-            key.as_str(),
-            "value" | "color" | "background" | "height" | "accessible"
-        ) {
-            errors.push(format!("ProgressBar: unknown prop '{key}'"));
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "name" | "size" | "color" | "label" | "accessible"
+            )
+        {
+            errors.push(format!("Icon: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_spinner(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Optional: size (positive number)
+    if let Some(prop) = node.props.get("size") {
+        match prop {
+            PropValue::Number(n) if *n > 0.0 => {}
+            PropValue::Number(n) => {
+                errors.push(format!("Spinner.size: must be positive, got {n}"));
+            }
+            other => errors.push(format!(
+                "Spinner.size: expected number, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Optional: color
+    if let Some(prop) = node.props.get("color") {
+        if !prop.is_color() {
+            errors.push(format!(
+                "Spinner.color: expected color, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Spinner: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Spinner", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key) && !matches!(key.as_str(), "size" | "color" | "accessible")
+        {
+            errors.push(format!("Spinner: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_skeleton(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: shape
+    let shape = match node.props.get("shape") {
+        Some(PropValue::String(s)) if matches!(s.as_str(), "text" | "rect" | "circle") => {
+            Some(s.as_str())
+        }
+        Some(other) => {
+            errors.push(format!(
+                "Skeleton.shape: expected one of [text, rect, circle], got {:?}",
+                other
+            ));
+            None
+        }
+        None => {
+            errors.push("Skeleton.shape: required prop missing".to_string());
+            None
+        }
+    };
+
+    // Optional: width, height (number or dimension)
+    for key in ["width", "height"] {
+        if let Some(prop) = node.props.get(key) {
+            errors.extend(common::validate_dimension_prop("Skeleton", key, prop));
+        }
+    }
+
+    // Optional: lines (positive integer, text shape only)
+    if let Some(prop) = node.props.get("lines") {
+        match prop {
+            PropValue::Number(n) if *n >= 1.0 && n.fract() == 0.0 => {
+                if shape.is_some_and(|shape| shape != "text") {
+                    errors.push(format!(
+                        "Skeleton.lines: only valid with shape \"text\", got shape {:?}",
+                        shape.unwrap_or_default()
+                    ));
+                }
+            }
+            PropValue::Number(n) => errors.push(format!(
+                "Skeleton.lines: expected positive integer, got {n}"
+            )),
+            other => errors.push(format!(
+                "Skeleton.lines: expected number, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Skeleton: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Skeleton", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "shape" | "width" | "height" | "lines" | "accessible"
+            )
+        {
+            errors.push(format!("Skeleton: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_rich_text(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: exactly one of spans, markdown
+    match (node.props.get("spans"), node.props.get("markdown")) {
+        (Some(_), Some(_)) => {
+            errors.push("RichText: expected one of 'spans' or 'markdown', got both".to_string())
+        }
+        (None, None) => errors.push(
+            "RichText: required prop missing, expected one of 'spans' or 'markdown'".to_string(),
+        ),
+        _ => {}
+    }
+
+    // spans (non-empty list of span records)
+    if let Some(prop) = node.props.get("spans") {
+        match prop {
+            PropValue::List(spans) if spans.is_empty() => {
+                errors.push("RichText.spans: must not be empty".to_string())
+            }
+            PropValue::List(spans) => {
+                for (i, span) in spans.iter().enumerate() {
+                    errors.extend(validate_text_span(i, span));
+                }
+            }
+            other => errors.push(format!(
+                "RichText.spans: expected list, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // markdown (string)
+    if let Some(prop) = node.props.get("markdown") {
+        if !prop.is_text() {
+            errors.push(format!(
+                "RichText.markdown: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: size
+    if let Some(prop) = node.props.get("size") {
+        match prop {
+            PropValue::String(s)
+                if matches!(
+                    s.as_str(),
+                    "small" | "body" | "title" | "heading" | "display"
+                ) => {}
+            _ => errors.push(format!(
+                "RichText.size: expected one of [small, body, title, heading, display], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: align
+    if let Some(prop) = node.props.get("align") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "start" | "center" | "end") => {}
+            _ => errors.push(format!(
+                "RichText.align: expected one of [start, center, end], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "RichText: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("RichText", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "spans" | "markdown" | "size" | "align" | "accessible"
+            )
+        {
+            errors.push(format!("RichText: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Validate one span record of a RichText.
+fn validate_text_span(index: usize, span: &PropValue) -> Vec<String> {
+    let prefix = format!("RichText.spans[{index}]");
+    let PropValue::Record(fields) = span else {
+        return vec![format!(
+            "{prefix}: expected record, got {}",
+            span.type_name()
+        )];
+    };
+    let mut errors = Vec::new();
+
+    match fields.get("text") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "{prefix}.text: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("{prefix}.text: required field missing")),
+    }
+
+    if let Some(prop) = fields.get("weight") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "normal" | "medium" | "bold") => {}
+            _ => errors.push(format!(
+                "{prefix}.weight: expected one of [normal, medium, bold], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    if let Some(prop) = fields.get("color") {
+        if !prop.is_color() {
+            errors.push(format!(
+                "{prefix}.color: expected color, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    match fields.get("link") {
+        Some(PropValue::String(url)) if is_link(url) => {}
+        Some(PropValue::String(url)) => errors.push(format!(
+            "{prefix}.link: expected url (https://, http://, mailto:), got {url:?}"
+        )),
+        Some(other) => errors.push(format!(
+            "{prefix}.link: expected string, got {}",
+            other.type_name()
+        )),
+        None => {}
+    }
+
+    for key in fields.keys() {
+        if !matches!(key.as_str(), "text" | "weight" | "color" | "link") {
+            errors.push(format!("{prefix}: unknown field '{key}'"));
         }
     }
 
     errors
 }
+
+/// Whether `source` is a supported URL scheme or a non-empty asset reference.
+fn is_image_source(source: &str) -> bool {
+    match source.strip_prefix("asset:") {
+        Some(name) => !name.trim().is_empty(),
+        None => ["https://", "http://", "data:image/"]
+            .iter()
+            .any(|scheme| source.len() > scheme.len() && source.starts_with(scheme)),
+    }
+}
+
+/// Whether `url` is a non-empty link with a supported scheme.
+fn is_link(url: &str) -> bool {
+    ["https://", "http://", "mailto:"]
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme))
+}
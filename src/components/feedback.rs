@@ -1,11 +1,17 @@
-//! Feedback component builders — Modal, Toast.
+//! Feedback component builders — Modal, BottomSheet, Drawer, Toast, Snackbar,
+//! Tooltip.
 //!
-//! Modal is a container component (accepts children via second brace block).
-//! Toast is a leaf notification component.
+//! Modal, BottomSheet, and Drawer are container components (accept children
+//! via second brace block).
+//! Toast and Snackbar are leaf notification components. Tooltip wraps exactly
+//! one child.
 
 use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
 use crate::prop_value::PropValue;
 use crate::surface::SurfaceNode;
+use crate::types::{CornerRadii, Easing, TransitionKind, TransitionSpec};
+use std::collections::BTreeMap;
 
 // ── Toast Type Enum ───────────────────────────────────────────────────────────
 
@@ -29,18 +35,216 @@ impl ToastType {
     }
 }
 
+// ── Toast Priority Enum ───────────────────────────────────────────────────────
+
+/// Queue priority for a Toast. Hosts show higher-priority toasts first;
+/// toasts of equal priority keep their Surface order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ToastPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl ToastPriority {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+        }
+    }
+}
+
+// ── Modal Presentation Enum ───────────────────────────────────────────────────
+
+/// How a Modal is presented by the host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalPresentation {
+    /// Centered dialog over dimmed content.
+    Center,
+    /// Covers the whole screen (e.g. full-screen editors).
+    Fullscreen,
+    /// Slides up from the bottom edge.
+    Sheet,
+}
+
+impl ModalPresentation {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Center => "center",
+            Self::Fullscreen => "fullscreen",
+            Self::Sheet => "sheet",
+        }
+    }
+}
+
+// ── Sheet Detent Enum ─────────────────────────────────────────────────────────
+
+/// A height a BottomSheet can rest at. Users drag between the sheet's detents;
+/// the first one is the initial height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SheetDetent {
+    /// Roughly a quarter of the screen (e.g. a short action list).
+    Small,
+    /// Roughly half the screen.
+    Medium,
+    /// Nearly full height, leaving the underlying screen's top edge visible.
+    Large,
+}
+
+impl SheetDetent {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
+// ── Drawer Side Enum ──────────────────────────────────────────────────────────
+
+/// The edge a Drawer slides in from. `Start` is the left edge in
+/// left-to-right locales and the right edge in right-to-left ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawerSide {
+    Start,
+    End,
+}
+
+impl DrawerSide {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::End => "end",
+        }
+    }
+}
+
+// ── Tooltip Placement Enum ────────────────────────────────────────────────────
+
+/// Preferred side of the wrapped node on which a Tooltip appears. Hosts may
+/// flip it when there is not enough room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    Top,
+    Bottom,
+    Start,
+    End,
+}
+
+impl TooltipPlacement {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::Start => "start",
+            Self::End => "end",
+        }
+    }
+}
+
+// ── ModalAction ───────────────────────────────────────────────────────────────
+
+/// A standard dialog button rendered by the host in a Modal's `confirm` or
+/// `cancel` slot.
+///
+/// Serialized as `{ label, action, destructive? }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalAction {
+    /// Button label.
+    pub label: String,
+    /// Action invoked when the button is pressed (ActionRef).
+    pub action: PropValue,
+    /// Whether the action is destructive (hosts render it in a warning style).
+    pub destructive: bool,
+}
+
+impl ModalAction {
+    /// Create a non-destructive dialog action.
+    pub fn new(label: impl Into<String>, action: PropValue) -> Self {
+        Self {
+            label: label.into(),
+            action,
+            destructive: false,
+        }
+    }
+
+    /// Mark the action as destructive.
+    pub fn destructive(mut self) -> Self {
+        self.destructive = true;
+        self
+    }
+
+    /// Convert to a `PropValue::Record` for insertion into `SurfaceNode.props`.
+    pub fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        fields.insert("label".to_string(), PropValue::String(self.label.clone()));
+        fields.insert("action".to_string(), self.action.clone());
+        if self.destructive {
+            fields.insert("destructive".to_string(), PropValue::Bool(true));
+        }
+        PropValue::Record(fields)
+    }
+}
+
+// ── ToastAction ───────────────────────────────────────────────────────────────
+
+/// An action button attached to a Toast (e.g. "Undo").
+///
+/// Serialized as `{ label, action }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToastAction {
+    /// Button label.
+    pub label: String,
+    /// Action invoked when the button is pressed (ActionRef).
+    pub action: PropValue,
+}
+
+impl ToastAction {
+    /// Create a toast action.
+    pub fn new(label: impl Into<String>, action: PropValue) -> Self {
+        Self {
+            label: label.into(),
+            action,
+        }
+    }
+
+    /// Convert to a `PropValue::Record` for insertion into `SurfaceNode.props`.
+    pub fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        fields.insert("label".to_string(), PropValue::String(self.label.clone()));
+        fields.insert("action".to_string(), self.action.clone());
+        PropValue::Record(fields)
+    }
+}
+
 // ── ModalBuilder ──────────────────────────────────────────────────────────────
 
 /// Builder for a Modal component.
 ///
 /// Required: `visible` (Bool), `on_dismiss` (ActionRef).
-/// Optional: `title` (String).
+/// Optional: `title` (String), `presentation` (string enum), `width` (Number),
+/// `max_height` (Number), `corner_radius` ([`CornerRadii`]), `confirm` /
+/// `cancel` ([`ModalAction`] records), `transition` ([`TransitionSpec`]),
+/// `avoid_keyboard` (Bool).
 /// Accepts children (content inside the modal).
 pub struct ModalBuilder {
     visible: bool,
     on_dismiss: PropValue,
     title: Option<String>,
+    presentation: Option<ModalPresentation>,
+    width: Option<f64>,
+    max_height: Option<f64>,
+    corner_radius: Option<CornerRadii>,
+    confirm: Option<ModalAction>,
+    cancel: Option<ModalAction>,
+    transition: Option<TransitionSpec>,
+    focus_trap: Option<bool>,
+    avoid_keyboard: Option<bool>,
     children: Vec<SurfaceNode>,
+    common: CommonFields,
 }
 
 impl ModalBuilder {
@@ -52,7 +256,17 @@ impl ModalBuilder {
             visible,
             on_dismiss,
             title: None,
+            presentation: None,
+            width: None,
+            max_height: None,
+            corner_radius: None,
+            confirm: None,
+            cancel: None,
+            transition: None,
+            focus_trap: None,
+            avoid_keyboard: None,
             children: Vec::new(),
+            common: CommonFields::default(),
         }
     }
 
@@ -61,6 +275,60 @@ impl ModalBuilder {
         self
     }
 
+    /// Set the presentation style (center, fullscreen, sheet).
+    pub fn presentation(mut self, presentation: ModalPresentation) -> Self {
+        self.presentation = Some(presentation);
+        self
+    }
+
+    /// Set the preferred width in logical pixels.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the maximum height in logical pixels.
+    pub fn max_height(mut self, max_height: f64) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Round the dialog's corners.
+    pub fn corner_radius(mut self, corner_radius: CornerRadii) -> Self {
+        self.corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Modals trap focus by default; pass `false` to let focus leave the dialog.
+    pub fn focus_trap(mut self, focus_trap: bool) -> Self {
+        self.focus_trap = Some(focus_trap);
+        self
+    }
+
+    /// Resize or reposition the dialog when the soft keyboard appears.
+    pub fn avoid_keyboard(mut self, avoid_keyboard: bool) -> Self {
+        self.avoid_keyboard = Some(avoid_keyboard);
+        self
+    }
+
+    /// Set the platform-standard confirm button.
+    pub fn confirm(mut self, confirm: ModalAction) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Set the platform-standard cancel button.
+    pub fn cancel(mut self, cancel: ModalAction) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Set the enter/exit transition.
+    pub fn transition(mut self, transition: TransitionSpec) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
     /// Add a child node to the modal's content.
     pub fn child(mut self, child: SurfaceNode) -> Self {
         self.children.push(child);
@@ -74,24 +342,264 @@ impl ModalBuilder {
         if let Some(title) = self.title {
             node.set_prop("title", PropValue::String(title));
         }
+        if let Some(presentation) = self.presentation {
+            node.set_prop(
+                "presentation",
+                PropValue::String(presentation.as_str().to_string()),
+            );
+        }
+        if let Some(width) = self.width {
+            node.set_prop("width", PropValue::Number(width));
+        }
+        if let Some(max_height) = self.max_height {
+            node.set_prop("max_height", PropValue::Number(max_height));
+        }
+        if let Some(corner_radius) = &self.corner_radius {
+            node.set_prop(
+                "corner_radius",
+                common::corner_radius_to_prop(corner_radius),
+            );
+        }
+        if let Some(confirm) = self.confirm {
+            node.set_prop("confirm", confirm.to_prop_value());
+        }
+        if let Some(cancel) = self.cancel {
+            node.set_prop("cancel", cancel.to_prop_value());
+        }
+        if let Some(transition) = self.transition {
+            node.set_prop("transition", transition_to_prop(&transition));
+        }
+        if let Some(focus_trap) = self.focus_trap {
+            node.set_prop("focus_trap", PropValue::Bool(focus_trap));
+        }
+        if let Some(avoid_keyboard) = self.avoid_keyboard {
+            node.set_prop("avoid_keyboard", PropValue::Bool(avoid_keyboard));
+        }
+        for child in self.children {
+            node.add_child(child);
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for ModalBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── BottomSheetBuilder ────────────────────────────────────────────────────────
+
+/// Builder for a BottomSheet component.
+///
+/// Required: `visible` (Bool), `on_dismiss` (ActionRef).
+/// Optional: `title` (String), `detents` (list of [`SheetDetent`] strings,
+/// host default when omitted), `transition` ([`TransitionSpec`]).
+/// Accepts children (content inside the sheet).
+///
+/// Like Modal, a BottomSheet traps focus unless it sets `focus_trap: false`.
+pub struct BottomSheetBuilder {
+    visible: bool,
+    on_dismiss: PropValue,
+    title: Option<String>,
+    detents: Vec<SheetDetent>,
+    transition: Option<TransitionSpec>,
+    children: Vec<SurfaceNode>,
+    common: CommonFields,
+}
+
+impl BottomSheetBuilder {
+    /// Create a new BottomSheetBuilder with required props.
+    ///
+    /// `on_dismiss` must be a `PropValue::ActionRef` — use `PropValue::action()`.
+    pub fn new(visible: bool, on_dismiss: PropValue) -> Self {
+        Self {
+            visible,
+            on_dismiss,
+            title: None,
+            detents: Vec::new(),
+            transition: None,
+            children: Vec::new(),
+            common: CommonFields::default(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Add a resting height. The first detent added is the initial height.
+    pub fn detent(mut self, detent: SheetDetent) -> Self {
+        self.detents.push(detent);
+        self
+    }
+
+    /// Set the enter/exit transition.
+    pub fn transition(mut self, transition: TransitionSpec) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Add a child node to the sheet's content.
+    pub fn child(mut self, child: SurfaceNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("BottomSheet");
+        node.set_prop("visible", PropValue::Bool(self.visible));
+        node.set_prop("on_dismiss", self.on_dismiss);
+        if let Some(title) = self.title {
+            node.set_prop("title", PropValue::String(title));
+        }
+        if !self.detents.is_empty() {
+            node.set_prop(
+                "detents",
+                PropValue::List(
+                    self.detents
+                        .iter()
+                        .map(|detent| PropValue::String(detent.as_str().to_string()))
+                        .collect(),
+                ),
+            );
+        }
+        if let Some(transition) = self.transition {
+            node.set_prop("transition", transition_to_prop(&transition));
+        }
+        for child in self.children {
+            node.add_child(child);
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for BottomSheetBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── DrawerBuilder ─────────────────────────────────────────────────────────────
+
+/// Builder for a Drawer component (navigation drawer / hamburger menu).
+///
+/// Required: `open` (Bool), `on_dismiss` (ActionRef).
+/// Optional: `side` (string enum, `start` when omitted), `title` (String),
+/// `transition` ([`TransitionSpec`]).
+/// Accepts children (the drawer's menu content).
+///
+/// An open Drawer traps focus unless it sets `focus_trap: false`; a closed
+/// one is skipped by focus like a hidden subtree.
+pub struct DrawerBuilder {
+    open: bool,
+    on_dismiss: PropValue,
+    side: Option<DrawerSide>,
+    title: Option<String>,
+    transition: Option<TransitionSpec>,
+    children: Vec<SurfaceNode>,
+    common: CommonFields,
+}
+
+impl DrawerBuilder {
+    /// Create a new DrawerBuilder with required props.
+    ///
+    /// `on_dismiss` must be a `PropValue::ActionRef` — use `PropValue::action()`.
+    pub fn new(open: bool, on_dismiss: PropValue) -> Self {
+        Self {
+            open,
+            on_dismiss,
+            side: None,
+            title: None,
+            transition: None,
+            children: Vec::new(),
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the edge the drawer slides in from.
+    pub fn side(mut self, side: DrawerSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the enter/exit transition.
+    pub fn transition(mut self, transition: TransitionSpec) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Add a child node to the drawer's content.
+    pub fn child(mut self, child: SurfaceNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Drawer");
+        node.set_prop("open", PropValue::Bool(self.open));
+        node.set_prop("on_dismiss", self.on_dismiss);
+        if let Some(side) = self.side {
+            node.set_prop("side", PropValue::String(side.as_str().to_string()));
+        }
+        if let Some(title) = self.title {
+            node.set_prop("title", PropValue::String(title));
+        }
+        if let Some(transition) = self.transition {
+            node.set_prop("transition", transition_to_prop(&transition));
+        }
         for child in self.children {
             node.add_child(child);
         }
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for DrawerBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 // ── ToastBuilder ──────────────────────────────────────────────────────────────
 
 /// Builder for a Toast component.
 ///
 /// Required: `message` (String).
-/// Optional: `duration` (Number), `toast_type` (string enum).
+/// Optional: `duration` (Number), `toast_type` (string enum),
+/// `transition` ([`TransitionSpec`]), `action` ([`ToastAction`] record),
+/// `on_dismiss` (ActionRef), `id` (String), `priority` (string enum),
+/// `replace` (Bool).
+///
+/// # Queueing
+///
+/// Hosts queue toasts by `priority`, then by Surface order. A toast whose `id`
+/// matches one already queued is dropped as a duplicate — unless it sets
+/// `replace: true` (see [`ToastBuilder::replaceable`]), in which case it takes
+/// the queued toast's place.
 pub struct ToastBuilder {
     message: String,
     duration: Option<f64>,
     toast_type: Option<ToastType>,
+    transition: Option<TransitionSpec>,
+    action: Option<ToastAction>,
+    on_dismiss: Option<PropValue>,
+    id: Option<String>,
+    priority: Option<ToastPriority>,
+    replace: Option<bool>,
+    common: CommonFields,
 }
 
 impl ToastBuilder {
@@ -101,6 +609,13 @@ impl ToastBuilder {
             message: message.into(),
             duration: None,
             toast_type: None,
+            transition: None,
+            action: None,
+            on_dismiss: None,
+            id: None,
+            priority: None,
+            replace: None,
+            common: CommonFields::default(),
         }
     }
 
@@ -116,27 +631,347 @@ impl ToastBuilder {
         self
     }
 
-    pub fn build(self) -> SurfaceNode {
-        let mut node = SurfaceNode::new("Toast");
-        node.set_prop("message", PropValue::String(self.message));
-        if let Some(duration) = self.duration {
-            node.set_prop("duration", PropValue::Number(duration));
+    /// Set the enter/exit transition.
+    pub fn transition(mut self, transition: TransitionSpec) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Attach an action button (e.g. "Undo").
+    pub fn action(mut self, action: ToastAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Set the action fired when the toast is dismissed or times out (ActionRef).
+    pub fn on_dismiss(mut self, on_dismiss: PropValue) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    /// Set the queue identity used for de-duplication.
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the queue priority.
+    pub fn priority(mut self, priority: ToastPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Give the toast an identity and let it replace any queued toast with the
+    /// same `id` (e.g. a "Syncing… / Synced" status toast).
+    pub fn replaceable(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self.replace = Some(true);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Toast");
+        node.set_prop("message", PropValue::String(self.message));
+        if let Some(duration) = self.duration {
+            node.set_prop("duration", PropValue::Number(duration));
+        }
+        if let Some(toast_type) = self.toast_type {
+            node.set_prop("type", PropValue::String(toast_type.as_str().to_string()));
+        }
+        if let Some(transition) = self.transition {
+            node.set_prop("transition", transition_to_prop(&transition));
+        }
+        if let Some(action) = self.action {
+            node.set_prop("action", action.to_prop_value());
+        }
+        if let Some(on_dismiss) = self.on_dismiss {
+            node.set_prop("on_dismiss", on_dismiss);
+        }
+        if let Some(id) = self.id {
+            node.set_prop("id", PropValue::String(id));
+        }
+        if let Some(priority) = self.priority {
+            node.set_prop("priority", PropValue::String(priority.as_str().to_string()));
+        }
+        if let Some(replace) = self.replace {
+            node.set_prop("replace", PropValue::Bool(replace));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for ToastBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── SnackbarBuilder ───────────────────────────────────────────────────────────
+
+/// Builder for a Snackbar component.
+///
+/// Required: `message` (String).
+/// Optional: `action_label` (String) with `on_action` (ActionRef), `duration`
+/// (Number), `on_dismiss` (ActionRef).
+///
+/// A Snackbar is a brief message at the bottom edge that can carry a single
+/// action such as "Undo". `action_label` and `on_action` are set together
+/// (see [`SnackbarBuilder::action`]). Unlike a [`Toast`](ToastBuilder), which
+/// interrupts with an assertive announcement, a Snackbar is announced
+/// politely so it never cuts off what the user is listening to.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{PropValue, SnackbarBuilder};
+///
+/// let node = SnackbarBuilder::new("Message archived")
+///     .action("Undo", PropValue::action("unarchive"))
+///     .duration(5000.0)
+///     .build();
+///
+/// assert_eq!(node.component_type, "Snackbar");
+/// ```
+pub struct SnackbarBuilder {
+    message: String,
+    action: Option<(String, PropValue)>,
+    duration: Option<f64>,
+    on_dismiss: Option<PropValue>,
+    common: CommonFields,
+}
+
+impl SnackbarBuilder {
+    /// Create a new SnackbarBuilder with the required message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            action: None,
+            duration: None,
+            on_dismiss: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Attach an action button: its label (e.g. "Undo") and the ActionRef
+    /// fired when it is pressed.
+    pub fn action(mut self, label: impl Into<String>, on_action: PropValue) -> Self {
+        self.action = Some((label.into(), on_action));
+        self
+    }
+
+    /// Set the duration in milliseconds.
+    pub fn duration(mut self, duration: f64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set the action fired when the snackbar is dismissed or times out (ActionRef).
+    pub fn on_dismiss(mut self, on_dismiss: PropValue) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Snackbar");
+        node.set_prop("message", PropValue::String(self.message));
+        if let Some((label, on_action)) = self.action {
+            node.set_prop("action_label", PropValue::String(label));
+            node.set_prop("on_action", on_action);
+        }
+        if let Some(duration) = self.duration {
+            node.set_prop("duration", PropValue::Number(duration));
+        }
+        if let Some(on_dismiss) = self.on_dismiss {
+            node.set_prop("on_dismiss", on_dismiss);
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for SnackbarBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── TooltipBuilder ────────────────────────────────────────────────────────────
+
+/// Builder for a Tooltip component.
+///
+/// Required: `text` (String) and exactly one child, the node the tooltip
+/// describes. Optional: `placement` (string enum).
+///
+/// Hosts show the text on hover (desktop/web) or long press (touch). Screen
+/// readers cannot hover, so [`build`](Self::build) also copies the text into
+/// the wrapped node's `accessible.hint` unless it already has one.
+pub struct TooltipBuilder {
+    text: String,
+    placement: Option<TooltipPlacement>,
+    child: SurfaceNode,
+    common: CommonFields,
+}
+
+impl TooltipBuilder {
+    /// Create a new TooltipBuilder with the required text and wrapped node.
+    pub fn new(text: impl Into<String>, child: SurfaceNode) -> Self {
+        Self {
+            text: text.into(),
+            placement: None,
+            child,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the preferred placement.
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Tooltip");
+        node.set_prop("text", PropValue::String(self.text.clone()));
+        if let Some(placement) = self.placement {
+            node.set_prop(
+                "placement",
+                PropValue::String(placement.as_str().to_string()),
+            );
+        }
+        let mut child = self.child;
+        accessibility::ensure_accessible(&mut child);
+        if let Some(PropValue::Record(fields)) = child.props.get_mut("accessible") {
+            fields
+                .entry("hint".to_string())
+                .or_insert(PropValue::String(self.text));
+        }
+        node.add_child(child);
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for TooltipBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Convert a [`TransitionSpec`] to a `PropValue::Record` for the Surface tree.
+///
+/// `{ type, duration, easing? }` — `easing` is omitted when unset.
+pub fn transition_to_prop(transition: &TransitionSpec) -> PropValue {
+    let mut fields = BTreeMap::new();
+    fields.insert(
+        "type".to_string(),
+        PropValue::String(
+            match transition.kind {
+                TransitionKind::Fade => "fade",
+                TransitionKind::Slide => "slide",
+                TransitionKind::Scale => "scale",
+            }
+            .to_string(),
+        ),
+    );
+    fields.insert(
+        "duration".to_string(),
+        PropValue::Number(transition.duration),
+    );
+    if let Some(easing) = transition.easing {
+        fields.insert(
+            "easing".to_string(),
+            PropValue::String(easing.as_str().to_string()),
+        );
+    }
+    PropValue::Record(fields)
+}
+
+/// Validate a `transition` prop on an overlay component.
+///
+/// Must be a record with `type` (fade|slide|scale), a non-negative
+/// `duration` in milliseconds, and an optional `easing`.
+pub fn validate_transition_prop(component_name: &str, prop: &PropValue) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let fields = match prop {
+        PropValue::Record(fields) => fields,
+        other => {
+            errors.push(format!(
+                "{component_name}.transition: expected record, got {}",
+                other.type_name()
+            ));
+            return errors;
+        }
+    };
+
+    // Required: type (string enum)
+    match fields.get("type") {
+        Some(PropValue::String(s)) if matches!(s.as_str(), "fade" | "slide" | "scale") => {}
+        Some(other) => errors.push(format!(
+            "{component_name}.transition.type: expected one of [fade, slide, scale], got {:?}",
+            other
+        )),
+        None => errors.push(format!(
+            "{component_name}.transition.type: required field missing"
+        )),
+    }
+
+    // Required: duration (non-negative number)
+    match fields.get("duration") {
+        Some(PropValue::Number(n)) if *n >= 0.0 => {}
+        Some(PropValue::Number(n)) => errors.push(format!(
+            "{component_name}.transition.duration: must be non-negative, got {n}"
+        )),
+        Some(other) => errors.push(format!(
+            "{component_name}.transition.duration: expected number, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!(
+            "{component_name}.transition.duration: required field missing"
+        )),
+    }
+
+    // Optional: easing (string enum)
+    if let Some(val) = fields.get("easing") {
+        match val {
+            PropValue::String(s) if Easing::valid_values().contains(&s.as_str()) => {}
+            _ => errors.push(format!(
+                "{component_name}.transition.easing: expected one of {:?}, got {:?}",
+                Easing::valid_values(),
+                val
+            )),
         }
-        if let Some(toast_type) = self.toast_type {
-            node.set_prop("type", PropValue::String(toast_type.as_str().to_string()));
+    }
+
+    // Unknown fields
+    for key in fields.keys() {
+        if !matches!(key.as_str(), "type" | "duration" | "easing") {
+            errors.push(format!(
+                "{component_name}.transition: unknown field '{key}'"
+            ));
         }
-        accessibility::ensure_accessible(&mut node);
-        node
     }
+
+    errors
 }
 
 // ── Validation ────────────────────────────────────────────────────────────────
 
-/// Validate a feedback component node (Modal or Toast).
+/// Validate a feedback component node (Modal, BottomSheet, Drawer, Toast,
+/// Snackbar, or Tooltip).
 pub fn validate_feedback_node(node: &SurfaceNode) -> Vec<String> {
     match node.component_type.as_str() {
         "Modal" => validate_modal(node),
+        "BottomSheet" => validate_bottom_sheet(node),
+        "Drawer" => validate_drawer(node),
         "Toast" => validate_toast(node),
+        "Snackbar" => validate_snackbar(node),
+        "Tooltip" => validate_tooltip(node),
         _ => vec![format!(
             "Unknown feedback component: {}",
             node.component_type
@@ -144,34 +979,89 @@ pub fn validate_feedback_node(node: &SurfaceNode) -> Vec<String> {
     }
 }
 
-fn validate_modal(node: &SurfaceNode) -> Vec<String> {
+/// Validate the props shared by dismissible overlays (Modal, BottomSheet,
+/// Drawer): the required `shown` flag (`visible` or `open`) and `on_dismiss`,
+/// optional `title` and `transition`.
+fn validate_dismissible(component: &str, shown: &str, node: &SurfaceNode) -> Vec<String> {
     let mut errors = Vec::new();
 
-    // Required: visible (bool)
-    match node.props.get("visible") {
+    // Required: visible / open (bool)
+    match node.props.get(shown) {
         Some(PropValue::Bool(_)) => {}
         Some(other) => errors.push(format!(
-            "Modal.visible: expected bool, got {}",
+            "{component}.{shown}: expected bool, got {}",
             other.type_name()
         )),
-        None => errors.push("Modal.visible: required prop missing".to_string()),
+        None => errors.push(format!("{component}.{shown}: required prop missing")),
     }
 
     // Required: on_dismiss (action)
     match node.props.get("on_dismiss") {
         Some(PropValue::ActionRef { .. }) => {}
         Some(other) => errors.push(format!(
-            "Modal.on_dismiss: expected action, got {}",
+            "{component}.on_dismiss: expected action, got {}",
             other.type_name()
         )),
-        None => errors.push("Modal.on_dismiss: required prop missing".to_string()),
+        None => errors.push(format!("{component}.on_dismiss: required prop missing")),
     }
 
     // Optional: title (string)
     if let Some(prop) = node.props.get("title") {
-        if !matches!(prop, PropValue::String(_)) {
+        if !prop.is_text() {
+            errors.push(format!(
+                "{component}.title: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: transition (record)
+    if let Some(prop) = node.props.get("transition") {
+        errors.extend(validate_transition_prop(component, prop));
+    }
+
+    errors
+}
+
+fn validate_modal(node: &SurfaceNode) -> Vec<String> {
+    // visible, on_dismiss, title, transition
+    let mut errors = validate_dismissible("Modal", "visible", node);
+
+    // Optional: presentation (string enum)
+    if let Some(prop) = node.props.get("presentation") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "center" | "fullscreen" | "sheet") => {}
+            _ => errors.push(format!(
+                "Modal.presentation: expected one of [center, fullscreen, sheet], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: width, max_height (number or dimension)
+    for key in ["width", "max_height"] {
+        if let Some(prop) = node.props.get(key) {
+            errors.extend(common::validate_dimension_prop("Modal", key, prop));
+        }
+    }
+
+    // Optional: corner_radius (number or per-corner record)
+    if let Some(prop) = node.props.get("corner_radius") {
+        errors.extend(common::validate_corner_radius_prop("Modal", prop));
+    }
+
+    // Optional: confirm, cancel (action slot records)
+    for key in ["confirm", "cancel"] {
+        if let Some(prop) = node.props.get(key) {
+            errors.extend(validate_modal_action(key, prop));
+        }
+    }
+
+    // Optional: avoid_keyboard (bool)
+    if let Some(prop) = node.props.get("avoid_keyboard") {
+        if !matches!(prop, PropValue::Bool(_)) {
             errors.push(format!(
-                "Modal.title: expected string, got {}",
+                "Modal.avoid_keyboard: expected bool, got {}",
                 prop.type_name()
             ));
         }
@@ -184,12 +1074,28 @@ fn validate_modal(node: &SurfaceNode) -> Vec<String> {
         errors.extend(accessibility::validate_accessible_prop("Modal", prop));
     }
 
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
     // Unknown props
     for key in node.props.keys() {
-        if !matches!(
-            key.as_str(),
-            "visible" | "on_dismiss" | "title" | "accessible"
-        ) {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "visible"
+                    | "on_dismiss"
+                    | "title"
+                    | "presentation"
+                    | "width"
+                    | "max_height"
+                    | "corner_radius"
+                    | "confirm"
+                    | "cancel"
+                    | "transition"
+                    | "avoid_keyboard"
+                    | "accessible"
+            )
+        {
             errors.push(format!("Modal: unknown prop '{key}'"));
         }
     }
@@ -197,12 +1103,166 @@ fn validate_modal(node: &SurfaceNode) -> Vec<String> {
     errors
 }
 
+fn validate_bottom_sheet(node: &SurfaceNode) -> Vec<String> {
+    // visible, on_dismiss, title, transition
+    let mut errors = validate_dismissible("BottomSheet", "visible", node);
+
+    // Optional: detents (non-empty list of unique detent strings)
+    if let Some(prop) = node.props.get("detents") {
+        match prop {
+            PropValue::List(items) if items.is_empty() => {
+                errors.push("BottomSheet.detents: must not be empty".to_string());
+            }
+            PropValue::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    match item {
+                        PropValue::String(s) if matches!(s.as_str(), "small" | "medium" | "large") => {
+                            if items[..i].contains(item) {
+                                errors.push(format!(
+                                    "BottomSheet.detents[{i}]: duplicate detent {s:?}"
+                                ));
+                            }
+                        }
+                        _ => errors.push(format!(
+                            "BottomSheet.detents[{i}]: expected one of [small, medium, large], got {:?}",
+                            item
+                        )),
+                    }
+                }
+            }
+            other => errors.push(format!(
+                "BottomSheet.detents: expected list, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Children are allowed (BottomSheet is a container)
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("BottomSheet", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "visible" | "on_dismiss" | "title" | "detents" | "transition" | "accessible"
+            )
+        {
+            errors.push(format!("BottomSheet: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_drawer(node: &SurfaceNode) -> Vec<String> {
+    // open, on_dismiss, title, transition
+    let mut errors = validate_dismissible("Drawer", "open", node);
+
+    // Optional: side (string enum)
+    if let Some(prop) = node.props.get("side") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "start" | "end") => {}
+            _ => errors.push(format!(
+                "Drawer.side: expected one of [start, end], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Children are allowed (Drawer is a container)
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Drawer", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "open" | "on_dismiss" | "side" | "title" | "transition" | "accessible"
+            )
+        {
+            errors.push(format!("Drawer: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Validate a Modal `confirm` / `cancel` slot record.
+fn validate_modal_action(key: &str, prop: &PropValue) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let fields = match prop {
+        PropValue::Record(fields) => fields,
+        other => {
+            errors.push(format!(
+                "Modal.{key}: expected record, got {}",
+                other.type_name()
+            ));
+            return errors;
+        }
+    };
+
+    // Required: label (string)
+    match fields.get("label") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Modal.{key}.label: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("Modal.{key}.label: required field missing")),
+    }
+
+    // Required: action (action)
+    match fields.get("action") {
+        Some(PropValue::ActionRef { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Modal.{key}.action: expected action, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("Modal.{key}.action: required field missing")),
+    }
+
+    // Optional: destructive (bool)
+    if let Some(val) = fields.get("destructive") {
+        if !matches!(val, PropValue::Bool(_)) {
+            errors.push(format!(
+                "Modal.{key}.destructive: expected bool, got {}",
+                val.type_name()
+            ));
+        }
+    }
+
+    // Unknown fields
+    for field in fields.keys() {
+        if !matches!(field.as_str(), "label" | "action" | "destructive") {
+            errors.push(format!("Modal.{key}: unknown field '{field}'"));
+        }
+    }
+
+    errors
+}
+
 fn validate_toast(node: &SurfaceNode) -> Vec<String> {
     let mut errors = Vec::new();
 
     // Required: message (string)
     match node.props.get("message") {
-        Some(PropValue::String(_)) => {}
+        Some(v) if v.is_text() => {}
         Some(other) => errors.push(format!(
             "Toast.message: expected string, got {}",
             other.type_name()
@@ -232,6 +1292,61 @@ fn validate_toast(node: &SurfaceNode) -> Vec<String> {
         }
     }
 
+    // Optional: transition (record)
+    if let Some(prop) = node.props.get("transition") {
+        errors.extend(validate_transition_prop("Toast", prop));
+    }
+
+    // Optional: action (record)
+    if let Some(prop) = node.props.get("action") {
+        errors.extend(validate_toast_action(prop));
+    }
+
+    // Optional: on_dismiss (action)
+    if let Some(prop) = node.props.get("on_dismiss") {
+        if !matches!(prop, PropValue::ActionRef { .. }) {
+            errors.push(format!(
+                "Toast.on_dismiss: expected action, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: id (non-empty string)
+    if let Some(prop) = node.props.get("id") {
+        match prop {
+            PropValue::String(s) if !s.is_empty() => {}
+            PropValue::String(_) => errors.push("Toast.id: must not be empty".to_string()),
+            other => errors.push(format!(
+                "Toast.id: expected string, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Optional: priority (string enum)
+    if let Some(prop) = node.props.get("priority") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "low" | "normal" | "high") => {}
+            _ => errors.push(format!(
+                "Toast.priority: expected one of [low, normal, high], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: replace (bool, requires id)
+    if let Some(prop) = node.props.get("replace") {
+        if !matches!(prop, PropValue::Bool(_)) {
+            errors.push(format!(
+                "Toast.replace: expected bool, got {}",
+                prop.type_name()
+            ));
+        } else if !node.props.contains_key("id") {
+            errors.push("Toast.replace: requires an 'id' prop".to_string());
+        }
+    }
+
     // No children
     if !node.children.is_empty() {
         errors.push(format!(
@@ -245,12 +1360,226 @@ fn validate_toast(node: &SurfaceNode) -> Vec<String> {
         errors.extend(accessibility::validate_accessible_prop("Toast", prop));
     }
 
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
     // Unknown props
     for key in node.props.keys() {
-        if !matches!(key.as_str(), "message" | "duration" | "type" | "accessible") {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "message"
+                    | "duration"
+                    | "type"
+                    | "transition"
+                    | "action"
+                    | "on_dismiss"
+                    | "id"
+                    | "priority"
+                    | "replace"
+                    | "accessible"
+            )
+        {
             errors.push(format!("Toast: unknown prop '{key}'"));
         }
     }
 
     errors
 }
+
+/// Validate a Toast `action` record.
+fn validate_toast_action(prop: &PropValue) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    let fields = match prop {
+        PropValue::Record(fields) => fields,
+        other => {
+            errors.push(format!(
+                "Toast.action: expected record, got {}",
+                other.type_name()
+            ));
+            return errors;
+        }
+    };
+
+    // Required: label (string)
+    match fields.get("label") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Toast.action.label: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Toast.action.label: required field missing".to_string()),
+    }
+
+    // Required: action (action)
+    match fields.get("action") {
+        Some(PropValue::ActionRef { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Toast.action.action: expected action, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Toast.action.action: required field missing".to_string()),
+    }
+
+    // Unknown fields
+    for field in fields.keys() {
+        if !matches!(field.as_str(), "label" | "action") {
+            errors.push(format!("Toast.action: unknown field '{field}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_snackbar(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: message (string)
+    match node.props.get("message") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Snackbar.message: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Snackbar.message: required prop missing".to_string()),
+    }
+
+    // Optional: action_label (non-empty string) and on_action (action), set together
+    match node.props.get("action_label") {
+        Some(PropValue::String(s)) if s.trim().is_empty() => {
+            errors.push("Snackbar.action_label: must not be empty".to_string())
+        }
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Snackbar.action_label: expected string, got {}",
+            other.type_name()
+        )),
+        None => {}
+    }
+    match node.props.get("on_action") {
+        Some(PropValue::ActionRef { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Snackbar.on_action: expected action, got {}",
+            other.type_name()
+        )),
+        None => {}
+    }
+    match (
+        node.props.contains_key("action_label"),
+        node.props.contains_key("on_action"),
+    ) {
+        (true, false) => errors.push(
+            "Snackbar.on_action: required prop missing when 'action_label' is set".to_string(),
+        ),
+        (false, true) => errors.push(
+            "Snackbar.action_label: required prop missing when 'on_action' is set".to_string(),
+        ),
+        _ => {}
+    }
+
+    // Optional: duration (positive number)
+    if let Some(prop) = node.props.get("duration") {
+        match prop {
+            PropValue::Number(n) if *n > 0.0 => {}
+            PropValue::Number(n) => errors.push(format!(
+                "Snackbar.duration: expected positive number, got {n}"
+            )),
+            other => errors.push(format!(
+                "Snackbar.duration: expected number, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Optional: on_dismiss (action)
+    if let Some(prop) = node.props.get("on_dismiss") {
+        if !matches!(prop, PropValue::ActionRef { .. }) {
+            errors.push(format!(
+                "Snackbar.on_dismiss: expected action, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Snackbar: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Snackbar", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "message" | "action_label" | "on_action" | "duration" | "on_dismiss" | "accessible"
+            )
+        {
+            errors.push(format!("Snackbar: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_tooltip(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: text (string)
+    match node.props.get("text") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Tooltip.text: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Tooltip.text: required prop missing".to_string()),
+    }
+
+    // Optional: placement (string enum)
+    if let Some(prop) = node.props.get("placement") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "top" | "bottom" | "start" | "end") => {}
+            _ => errors.push(format!(
+                "Tooltip.placement: expected one of [top, bottom, start, end], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Exactly one child: the described node
+    if node.children.len() != 1 {
+        errors.push(format!(
+            "Tooltip: expected exactly 1 child, got {}",
+            node.children.len()
+        ));
+    }
+
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Tooltip", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(key.as_str(), "text" | "placement" | "accessible")
+        {
+            errors.push(format!("Tooltip: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
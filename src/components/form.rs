@@ -0,0 +1,156 @@
+//! Form component builder — Form.
+//!
+//! Form is a container component that groups input controls under a single
+//! submit action. Unlike a plain Column, a Form carries `on_submit` and a
+//! form-wide `disabled` flag, and [`Surface::validate`](crate::Surface::validate)
+//! checks that every contained input is labelled.
+
+use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::SurfaceNode;
+
+// ── FormBuilder ───────────────────────────────────────────────────────────────
+
+/// Builder for a Form component.
+///
+/// Required: `on_submit` (ActionRef).
+/// Optional: `disabled` (Bool).
+/// Accepts children (the form's fields and controls).
+pub struct FormBuilder {
+    on_submit: PropValue,
+    disabled: Option<bool>,
+    children: Vec<SurfaceNode>,
+    common: CommonFields,
+}
+
+impl FormBuilder {
+    /// Create a new FormBuilder with the required submit action.
+    ///
+    /// `on_submit` must be a `PropValue::ActionRef` — use `PropValue::action()`.
+    pub fn new(on_submit: PropValue) -> Self {
+        Self {
+            on_submit,
+            disabled: None,
+            children: Vec::new(),
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Disable every control in the form.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Add a child node to the form.
+    pub fn child(mut self, child: SurfaceNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Set all children at once.
+    pub fn children(mut self, children: Vec<SurfaceNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Form");
+        node.set_prop("on_submit", self.on_submit);
+        if let Some(disabled) = self.disabled {
+            node.set_prop("disabled", PropValue::Bool(disabled));
+        }
+        node.children = self.children;
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for FormBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+/// Validate a form component node (Form).
+///
+/// Checks the Form's own props. That every input inside the form is
+/// labelled is a tree check, done by [`Surface::validate`](crate::Surface::validate).
+pub fn validate_form_node(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Form" => validate_form(node),
+        _ => vec![format!("Unknown form component: {}", node.component_type)],
+    }
+}
+
+fn validate_form(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: on_submit (action)
+    match node.props.get("on_submit") {
+        Some(PropValue::ActionRef { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Form.on_submit: expected action, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Form.on_submit: required prop missing".to_string()),
+    }
+
+    // Optional: disabled (bool)
+    if let Some(prop) = node.props.get("disabled") {
+        if !matches!(prop, PropValue::Bool(_)) {
+            errors.push(format!(
+                "Form.disabled: expected bool, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Children are allowed (Form is a container)
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Form", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(key.as_str(), "on_submit" | "disabled" | "accessible")
+        {
+            errors.push(format!("Form: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Inputs that must carry a non-empty `label` inside a Form.
+const LABELLED_INPUTS: &[&str] = &["TextInput", "Stepper", "FilePicker"];
+
+/// The error for `node`, at `path` inside a Form, if it is an input without a
+/// label.
+pub(crate) fn check_input_label(node: &SurfaceNode, path: &NodePath) -> Option<String> {
+    if !LABELLED_INPUTS.contains(&node.component_type.as_str()) {
+        return None;
+    }
+    let labelled = match node.props.get("label") {
+        Some(PropValue::String(s)) => !s.is_empty(),
+        Some(other) => other.is_text(),
+        None => false,
+    };
+    (!labelled).then(|| {
+        format!(
+            "{path}: {}.label: required inside Form",
+            node.component_type
+        )
+    })
+}
@@ -0,0 +1,903 @@
+//! Graphics component builders — Canvas, Chart.
+//!
+//! Canvas draws a fixed list of vector shapes in its own coordinate space;
+//! Chart plots data series. Both are plain records, so hosts render them
+//! deterministically without running app code.
+
+use std::collections::BTreeMap;
+
+use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
+use crate::prop_value::PropValue;
+use crate::surface::SurfaceNode;
+use crate::types::ColorValue;
+
+// ── DrawCommand ───────────────────────────────────────────────────────────────
+
+/// The geometry of one [`DrawCommand`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawShape {
+    /// A straight line from `(x1, y1)` to `(x2, y2)`.
+    Line { x1: f64, y1: f64, x2: f64, y2: f64 },
+    /// A rectangle with its top-left corner at `(x, y)`.
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// A circle centred on `(cx, cy)`.
+    Circle { cx: f64, cy: f64, r: f64 },
+    /// SVG path data (`M`, `L`, `H`, `V`, `C`, `S`, `Q`, `T`, `A`, `Z`).
+    Path { d: String },
+}
+
+/// One Canvas drawing command: a [`DrawShape`] plus its paint.
+///
+/// Serialized as a record with an `op` field (`line`, `rect`, `circle`,
+/// `path`), the shape's geometry fields, and optional `fill`, `stroke`, and
+/// `stroke_width`. Lines are stroked only; every other shape needs a `fill`
+/// or a `stroke`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrawCommand {
+    pub shape: DrawShape,
+    pub fill: Option<PropValue>,
+    pub stroke: Option<PropValue>,
+    /// Stroke width in canvas units (hosts default to 1).
+    pub stroke_width: Option<f64>,
+}
+
+impl DrawCommand {
+    /// A line from `(x1, y1)` to `(x2, y2)`.
+    pub fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self::new(DrawShape::Line { x1, y1, x2, y2 })
+    }
+
+    /// A rectangle with its top-left corner at `(x, y)`.
+    pub fn rect(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self::new(DrawShape::Rect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    /// A circle centred on `(cx, cy)` with radius `r`.
+    pub fn circle(cx: f64, cy: f64, r: f64) -> Self {
+        Self::new(DrawShape::Circle { cx, cy, r })
+    }
+
+    /// A path from SVG path data, e.g. `"M 0 0 L 10 10 Z"`.
+    pub fn path(d: impl Into<String>) -> Self {
+        Self::new(DrawShape::Path { d: d.into() })
+    }
+
+    fn new(shape: DrawShape) -> Self {
+        Self {
+            shape,
+            fill: None,
+            stroke: None,
+            stroke_width: None,
+        }
+    }
+
+    /// Set the fill color.
+    pub fn fill(mut self, color: ColorValue) -> Self {
+        self.fill = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the fill color to a theme color token (e.g. `"primary"`).
+    pub fn fill_token(mut self, token: impl Into<String>) -> Self {
+        self.fill = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Set the stroke color.
+    pub fn stroke(mut self, color: ColorValue) -> Self {
+        self.stroke = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the stroke color to a theme color token (e.g. `"primary"`).
+    pub fn stroke_token(mut self, token: impl Into<String>) -> Self {
+        self.stroke = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Set the stroke width in canvas units.
+    pub fn stroke_width(mut self, width: f64) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    /// Convert to a `PropValue::Record` for the Surface tree.
+    pub fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        let (op, geometry) = match &self.shape {
+            DrawShape::Line { x1, y1, x2, y2 } => (
+                "line",
+                vec![("x1", *x1), ("y1", *y1), ("x2", *x2), ("y2", *y2)],
+            ),
+            DrawShape::Rect {
+                x,
+                y,
+                width,
+                height,
+            } => (
+                "rect",
+                vec![("x", *x), ("y", *y), ("width", *width), ("height", *height)],
+            ),
+            DrawShape::Circle { cx, cy, r } => {
+                ("circle", vec![("cx", *cx), ("cy", *cy), ("r", *r)])
+            }
+            DrawShape::Path { d } => {
+                fields.insert("d".to_string(), PropValue::String(d.clone()));
+                ("path", vec![])
+            }
+        };
+        fields.insert("op".to_string(), PropValue::String(op.to_string()));
+        for (key, value) in geometry {
+            fields.insert(key.to_string(), PropValue::Number(value));
+        }
+        if let Some(fill) = &self.fill {
+            fields.insert("fill".to_string(), fill.clone());
+        }
+        if let Some(stroke) = &self.stroke {
+            fields.insert("stroke".to_string(), stroke.clone());
+        }
+        if let Some(width) = self.stroke_width {
+            fields.insert("stroke_width".to_string(), PropValue::Number(width));
+        }
+        PropValue::Record(fields)
+    }
+}
+
+// ── CanvasBuilder ─────────────────────────────────────────────────────────────
+
+/// Builder for the `Canvas` component.
+///
+/// `Canvas` is a leaf component (no children) that draws its `commands` in
+/// order, later commands on top. Coordinates are in a `width` × `height`
+/// space with the origin at the top-left; hosts scale that space to the
+/// node's frame.
+///
+/// A Canvas with a `label` is announced as an image; without one it is
+/// treated as decorative and hidden from assistive technology.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{CanvasBuilder, DrawCommand};
+///
+/// let gauge = CanvasBuilder::new(100.0, 100.0)
+///     .command(DrawCommand::circle(50.0, 50.0, 40.0).stroke_token("outline").stroke_width(8.0))
+///     .command(DrawCommand::path("M 50 10 A 40 40 0 0 1 90 50").stroke_token("primary"))
+///     .label("Battery 25%")
+///     .build();
+///
+/// assert_eq!(gauge.component_type, "Canvas");
+/// ```
+pub struct CanvasBuilder {
+    width: f64,
+    height: f64,
+    commands: Vec<DrawCommand>,
+    label: Option<String>,
+    common: CommonFields,
+}
+
+impl CanvasBuilder {
+    /// Create a new `CanvasBuilder` with a `width` × `height` coordinate
+    /// space and no commands.
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            width,
+            height,
+            commands: Vec::new(),
+            label: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Append a drawing command.
+    pub fn command(mut self, command: DrawCommand) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Set all drawing commands at once.
+    pub fn commands(mut self, commands: Vec<DrawCommand>) -> Self {
+        self.commands = commands;
+        self
+    }
+
+    /// Describe the drawing for assistive technology.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Canvas");
+        node.set_prop("width", PropValue::Number(self.width));
+        node.set_prop("height", PropValue::Number(self.height));
+        node.set_prop(
+            "commands",
+            PropValue::List(
+                self.commands
+                    .iter()
+                    .map(DrawCommand::to_prop_value)
+                    .collect(),
+            ),
+        );
+        if let Some(label) = self.label {
+            node.set_prop("label", PropValue::String(label));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for CanvasBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Chart Kind Enum ───────────────────────────────────────────────────────────
+
+/// How a Chart plots its series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartKind {
+    Line,
+    Bar,
+    /// One series; each value is a slice.
+    Pie,
+    /// One series drawn as a compact line without axes or labels.
+    Sparkline,
+}
+
+impl ChartKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Bar => "bar",
+            Self::Pie => "pie",
+            Self::Sparkline => "sparkline",
+        }
+    }
+}
+
+// ── ChartSeries ───────────────────────────────────────────────────────────────
+
+/// One data series of a Chart.
+///
+/// Serialized as a record `{ name, values, color? }`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub name: String,
+    pub values: Vec<f64>,
+    /// A color value or theme color token (hosts pick a palette otherwise).
+    pub color: Option<PropValue>,
+}
+
+impl ChartSeries {
+    /// Create a series named `name`.
+    pub fn new(name: impl Into<String>, values: Vec<f64>) -> Self {
+        Self {
+            name: name.into(),
+            values,
+            color: None,
+        }
+    }
+
+    /// Set the series color.
+    pub fn color(mut self, color: ColorValue) -> Self {
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the series color to a theme color token (e.g. `"primary"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Convert to a `PropValue::Record` for the Surface tree.
+    pub fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        fields.insert("name".to_string(), PropValue::String(self.name.clone()));
+        fields.insert(
+            "values".to_string(),
+            PropValue::List(self.values.iter().map(|v| PropValue::Number(*v)).collect()),
+        );
+        if let Some(color) = &self.color {
+            fields.insert("color".to_string(), color.clone());
+        }
+        PropValue::Record(fields)
+    }
+}
+
+// ── ChartBuilder ──────────────────────────────────────────────────────────────
+
+/// Builder for the `Chart` component.
+///
+/// `Chart` is a leaf component (no children) that plots one or more
+/// [`ChartSeries`]. Every series has one value per entry in `labels` (the
+/// x-axis categories, or the slice names of a pie). Pie charts and sparklines
+/// take exactly one series; sparklines have no labels.
+///
+/// The accessible value summarizes the data (range and last value per
+/// series, or slice percentages for a pie), so screen reader users get the
+/// gist without the plot.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{ChartBuilder, ChartKind, ChartSeries};
+///
+/// let node = ChartBuilder::new(
+///     ChartKind::Bar,
+///     vec![ChartSeries::new("Steps", vec![4200.0, 8100.0, 6500.0]).color_token("primary")],
+/// )
+/// .labels(vec!["Mon", "Tue", "Wed"])
+/// .title("Steps this week")
+/// .build();
+///
+/// assert_eq!(node.component_type, "Chart");
+/// ```
+pub struct ChartBuilder {
+    kind: ChartKind,
+    series: Vec<ChartSeries>,
+    labels: Option<Vec<String>>,
+    title: Option<String>,
+    common: CommonFields,
+}
+
+impl ChartBuilder {
+    /// Create a new `ChartBuilder` with the required `kind` and `series`
+    /// props.
+    pub fn new(kind: ChartKind, series: Vec<ChartSeries>) -> Self {
+        Self {
+            kind,
+            series,
+            labels: None,
+            title: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the category labels, one per value.
+    pub fn labels<S: Into<String>>(mut self, labels: impl IntoIterator<Item = S>) -> Self {
+        self.labels = Some(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the chart title (also the accessible label).
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Chart");
+        node.set_prop("kind", PropValue::String(self.kind.as_str().to_string()));
+        node.set_prop(
+            "series",
+            PropValue::List(self.series.iter().map(ChartSeries::to_prop_value).collect()),
+        );
+        if let Some(labels) = self.labels {
+            node.set_prop(
+                "labels",
+                PropValue::List(labels.into_iter().map(PropValue::String).collect()),
+            );
+        }
+        if let Some(title) = self.title {
+            node.set_prop("title", PropValue::String(title));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for ChartBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+/// Validates a graphics component node's props.
+///
+/// Returns a list of human-readable error strings. An empty list means
+/// the node is valid.
+pub fn validate_graphics_node(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Canvas" => validate_canvas(node),
+        "Chart" => validate_chart(node),
+        _ => vec![format!(
+            "Unknown graphics component: {}",
+            node.component_type
+        )],
+    }
+}
+
+fn validate_canvas(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: width, height (positive numbers)
+    for key in ["width", "height"] {
+        match node.props.get(key) {
+            Some(PropValue::Number(n)) if *n > 0.0 => {}
+            Some(PropValue::Number(n)) => {
+                errors.push(format!("Canvas.{key}: must be positive, got {n}"))
+            }
+            Some(other) => errors.push(format!(
+                "Canvas.{key}: expected number, got {}",
+                other.type_name()
+            )),
+            None => errors.push(format!("Canvas.{key}: required prop missing")),
+        }
+    }
+
+    // Required: commands (list of command records)
+    match node.props.get("commands") {
+        Some(PropValue::List(commands)) => {
+            for (i, command) in commands.iter().enumerate() {
+                errors.extend(validate_draw_command(i, command));
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Canvas.commands: expected list, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Canvas.commands: required prop missing".to_string()),
+    }
+
+    // Optional: label (string)
+    if let Some(prop) = node.props.get("label") {
+        if !prop.is_text() {
+            errors.push(format!(
+                "Canvas.label: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Canvas: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Canvas", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "width" | "height" | "commands" | "label" | "accessible"
+            )
+        {
+            errors.push(format!("Canvas: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Validate one command record of a Canvas.
+fn validate_draw_command(index: usize, command: &PropValue) -> Vec<String> {
+    let prefix = format!("Canvas.commands[{index}]");
+    let PropValue::Record(fields) = command else {
+        return vec![format!(
+            "{prefix}: expected record, got {}",
+            command.type_name()
+        )];
+    };
+
+    // op selects the geometry fields; sizes must be non-negative
+    let op = match fields.get("op") {
+        Some(PropValue::String(op)) => op.as_str(),
+        Some(other) => {
+            return vec![format!(
+                "{prefix}.op: expected string, got {}",
+                other.type_name()
+            )]
+        }
+        None => return vec![format!("{prefix}.op: required field missing")],
+    };
+    let geometry: &[(&str, bool)] = match op {
+        "line" => &[("x1", false), ("y1", false), ("x2", false), ("y2", false)],
+        "rect" => &[
+            ("x", false),
+            ("y", false),
+            ("width", true),
+            ("height", true),
+        ],
+        "circle" => &[("cx", false), ("cy", false), ("r", true)],
+        "path" => &[],
+        _ => {
+            return vec![format!(
+                "{prefix}.op: expected one of [line, rect, circle, path], got {op:?}"
+            )]
+        }
+    };
+    let mut errors = Vec::new();
+
+    for &(key, size) in geometry {
+        match fields.get(key) {
+            Some(PropValue::Number(n)) if size && *n < 0.0 => {
+                errors.push(format!("{prefix}.{key}: must be non-negative, got {n}"))
+            }
+            Some(PropValue::Number(_)) => {}
+            Some(other) => errors.push(format!(
+                "{prefix}.{key}: expected number, got {}",
+                other.type_name()
+            )),
+            None => errors.push(format!("{prefix}.{key}: required field missing")),
+        }
+    }
+
+    if op == "path" {
+        match fields.get("d") {
+            Some(PropValue::String(d)) => {
+                if let Err(reason) = check_path_data(d) {
+                    errors.push(format!("{prefix}.d: {reason}"));
+                }
+            }
+            Some(other) => errors.push(format!(
+                "{prefix}.d: expected string, got {}",
+                other.type_name()
+            )),
+            None => errors.push(format!("{prefix}.d: required field missing")),
+        }
+    }
+
+    // Paint
+    for key in ["fill", "stroke"] {
+        if let Some(prop) = fields.get(key) {
+            if !prop.is_color() {
+                errors.push(format!(
+                    "{prefix}.{key}: expected color, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+    match fields.get("stroke_width") {
+        Some(PropValue::Number(n)) if *n <= 0.0 => {
+            errors.push(format!("{prefix}.stroke_width: must be positive, got {n}"))
+        }
+        Some(PropValue::Number(_)) | None => {}
+        Some(other) => errors.push(format!(
+            "{prefix}.stroke_width: expected number, got {}",
+            other.type_name()
+        )),
+    }
+    if op == "line" {
+        if fields.contains_key("fill") {
+            errors.push(format!("{prefix}.fill: not valid for op \"line\""));
+        }
+        if !fields.contains_key("stroke") {
+            errors.push(format!("{prefix}.stroke: required field missing"));
+        }
+    } else if !fields.contains_key("fill") && !fields.contains_key("stroke") {
+        errors.push(format!("{prefix}: expected fill or stroke"));
+    }
+
+    for key in fields.keys() {
+        let known = matches!(key.as_str(), "op" | "fill" | "stroke" | "stroke_width")
+            || geometry.iter().any(|(field, _)| field == key)
+            || (op == "path" && key == "d");
+        if !known {
+            errors.push(format!("{prefix}: unknown field '{key}' for op {op:?}"));
+        }
+    }
+
+    errors
+}
+
+fn validate_chart(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: kind
+    let kind = match node.props.get("kind") {
+        Some(PropValue::String(s))
+            if matches!(s.as_str(), "line" | "bar" | "pie" | "sparkline") =>
+        {
+            Some(s.as_str())
+        }
+        Some(other) => {
+            errors.push(format!(
+                "Chart.kind: expected one of [line, bar, pie, sparkline], got {:?}",
+                other
+            ));
+            None
+        }
+        None => {
+            errors.push("Chart.kind: required prop missing".to_string());
+            None
+        }
+    };
+    let single_series = matches!(kind, Some("pie" | "sparkline"));
+
+    // Required: series (non-empty list of series records, equal lengths)
+    let mut lengths = Vec::new();
+    match node.props.get("series") {
+        Some(PropValue::List(series)) if series.is_empty() => {
+            errors.push("Chart.series: must not be empty".to_string())
+        }
+        Some(PropValue::List(series)) => {
+            if single_series && series.len() > 1 {
+                errors.push(format!(
+                    "Chart.series: kind {:?} takes exactly one series, got {}",
+                    kind.unwrap_or_default(),
+                    series.len()
+                ));
+            }
+            for (i, entry) in series.iter().enumerate() {
+                errors.extend(validate_chart_series(i, entry, kind, &mut lengths));
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Chart.series: expected list, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Chart.series: required prop missing".to_string()),
+    }
+    if let Some(&(_, first)) = lengths.first() {
+        for &(i, len) in &lengths[1..] {
+            if len != first {
+                errors.push(format!(
+                    "Chart.series[{i}].values: expected {first} values like series[0], got {len}"
+                ));
+            }
+        }
+    }
+
+    // Optional: labels (list of strings, one per value)
+    if let Some(prop) = node.props.get("labels") {
+        match prop {
+            PropValue::List(_) if kind == Some("sparkline") => {
+                errors.push("Chart.labels: not valid for kind \"sparkline\"".to_string())
+            }
+            PropValue::List(labels) => {
+                for (i, label) in labels.iter().enumerate() {
+                    if !label.is_text() {
+                        errors.push(format!(
+                            "Chart.labels[{i}]: expected string, got {}",
+                            label.type_name()
+                        ));
+                    }
+                }
+                if let Some(&(_, count)) = lengths.first() {
+                    if labels.len() != count {
+                        errors.push(format!(
+                            "Chart.labels: expected {count} labels (one per value), got {}",
+                            labels.len()
+                        ));
+                    }
+                }
+            }
+            other => errors.push(format!(
+                "Chart.labels: expected list, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Optional: title (string)
+    if let Some(prop) = node.props.get("title") {
+        if !prop.is_text() {
+            errors.push(format!(
+                "Chart.title: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Chart: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Chart", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "kind" | "series" | "labels" | "title" | "accessible"
+            )
+        {
+            errors.push(format!("Chart: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Validate one series record of a Chart, collecting its value count into
+/// `lengths`.
+fn validate_chart_series(
+    index: usize,
+    series: &PropValue,
+    kind: Option<&str>,
+    lengths: &mut Vec<(usize, usize)>,
+) -> Vec<String> {
+    let prefix = format!("Chart.series[{index}]");
+    let PropValue::Record(fields) = series else {
+        return vec![format!(
+            "{prefix}: expected record, got {}",
+            series.type_name()
+        )];
+    };
+    let mut errors = Vec::new();
+
+    match fields.get("name") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "{prefix}.name: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("{prefix}.name: required field missing")),
+    }
+
+    match fields.get("values") {
+        Some(PropValue::List(values)) if values.is_empty() => {
+            errors.push(format!("{prefix}.values: must not be empty"))
+        }
+        Some(PropValue::List(values)) => {
+            for (i, value) in values.iter().enumerate() {
+                match value {
+                    PropValue::Number(n) if !n.is_finite() => {
+                        errors.push(format!("{prefix}.values[{i}]: must be finite, got {n}"))
+                    }
+                    PropValue::Number(n) if *n < 0.0 && kind == Some("pie") => errors.push(
+                        format!("{prefix}.values[{i}]: pie values must be non-negative, got {n}"),
+                    ),
+                    PropValue::Number(_) => {}
+                    other => errors.push(format!(
+                        "{prefix}.values[{i}]: expected number, got {}",
+                        other.type_name()
+                    )),
+                }
+            }
+            lengths.push((index, values.len()));
+        }
+        Some(other) => errors.push(format!(
+            "{prefix}.values: expected list, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("{prefix}.values: required field missing")),
+    }
+
+    if let Some(prop) = fields.get("color") {
+        if !prop.is_color() {
+            errors.push(format!(
+                "{prefix}.color: expected color, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    for key in fields.keys() {
+        if !matches!(key.as_str(), "name" | "values" | "color") {
+            errors.push(format!("{prefix}: unknown field '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Check SVG path data: commands `MLHVCSQTAZ` (either case) with the right
+/// number of arguments, starting with a moveto.
+fn check_path_data(d: &str) -> Result<(), String> {
+    let mut rest = d.trim_start();
+    let mut first = true;
+    if rest.is_empty() {
+        return Err("must not be empty".to_string());
+    }
+    while let Some(letter) = rest.chars().next() {
+        let arity = match letter.to_ascii_uppercase() {
+            'M' | 'L' | 'T' => 2,
+            'H' | 'V' => 1,
+            'C' => 6,
+            'S' | 'Q' => 4,
+            'A' => 7,
+            'Z' => 0,
+            _ => return Err(format!("expected a path command, got {letter:?}")),
+        };
+        if first && !matches!(letter, 'M' | 'm') {
+            return Err(format!("must start with a moveto (M), got {letter:?}"));
+        }
+        first = false;
+        rest = &rest[letter.len_utf8()..];
+
+        let mut count = 0;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            let len = number_len(rest);
+            if len == 0 {
+                break;
+            }
+            rest = &rest[len..];
+            count += 1;
+        }
+        let valid = if arity == 0 {
+            count == 0
+        } else {
+            count > 0 && count % arity == 0
+        };
+        if !valid {
+            return Err(format!(
+                "'{letter}' expects {} arguments, got {count}",
+                if arity == 0 {
+                    "no".to_string()
+                } else {
+                    format!("a multiple of {arity}")
+                }
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Length of the number at the start of `s` (`-1.5`, `.5`, `2e-3`), or 0.
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        i += 1;
+    }
+    let digits_start = i;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+    if i == digits_start || (i == digits_start + 1 && bytes[digits_start] == b'.') {
+        return 0;
+    }
+    if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+        let mut j = i + 1;
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        if bytes.get(j).is_some_and(u8::is_ascii_digit) {
+            i = j;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+    i
+}
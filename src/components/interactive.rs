@@ -1,9 +1,12 @@
-//! Interactive component builders — Button, TextInput.
+//! Interactive component builders — Button, TextInput, Stepper, Rating,
+//! FilePicker.
 //!
 //! These are leaf components with no children. They handle user interactions
 //! via action references (`on_tap`) or lambda callbacks (`on_change`).
 
 use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
+use crate::icons;
 use crate::prop_value::PropValue;
 use crate::surface::SurfaceNode;
 
@@ -27,6 +30,70 @@ impl ButtonVariant {
     }
 }
 
+// ── Button Size Enum ──────────────────────────────────────────────────────────
+
+/// Size preset for a Button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl ButtonSize {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
+// ── Haptic Enum ───────────────────────────────────────────────────────────────
+
+/// Haptic feedback played when a Button is tapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Haptic {
+    Light,
+    Medium,
+    Heavy,
+    Success,
+    Error,
+}
+
+impl Haptic {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Medium => "medium",
+            Self::Heavy => "heavy",
+            Self::Success => "success",
+            Self::Error => "error",
+        }
+    }
+}
+
+// ── Tap Sound Enum ────────────────────────────────────────────────────────────
+
+/// System sound played when a Button is tapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapSound {
+    Click,
+    Success,
+    Error,
+}
+
+impl TapSound {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Click => "click",
+            Self::Success => "success",
+            Self::Error => "error",
+        }
+    }
+}
+
 // ── Keyboard Type Enum ────────────────────────────────────────────────────────
 
 /// Virtual keyboard type for a TextInput.
@@ -51,19 +118,53 @@ impl KeyboardType {
     }
 }
 
+// ── Autocapitalize Enum ───────────────────────────────────────────────────────
+
+/// Automatic capitalization hint for a TextInput's virtual keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Autocapitalize {
+    None,
+    Words,
+    Sentences,
+    Characters,
+}
+
+impl Autocapitalize {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Words => "words",
+            Self::Sentences => "sentences",
+            Self::Characters => "characters",
+        }
+    }
+}
+
 // ── ButtonBuilder ─────────────────────────────────────────────────────────────
 
 /// Builder for a Button component.
 ///
 /// Required: `label` (String), `on_tap` (ActionRef).
-/// Optional: `variant`, `icon`, `disabled`, `loading`.
+/// Optional: `variant`, `size`, `full_width`, `icon`, `disabled`, `loading`,
+/// `on_long_press`, `on_press_down`, `on_press_up`, `haptic`, `sound`.
+///
+/// `haptic` and `sound` are hints: hosts play the platform-native feedback on
+/// tap and silently skip it where unsupported or disabled by the user.
 pub struct ButtonBuilder {
     label: String,
     on_tap: PropValue,
     variant: Option<ButtonVariant>,
+    size: Option<ButtonSize>,
+    full_width: Option<bool>,
     icon: Option<String>,
     disabled: Option<bool>,
     loading: Option<bool>,
+    on_long_press: Option<PropValue>,
+    on_press_down: Option<PropValue>,
+    on_press_up: Option<PropValue>,
+    haptic: Option<Haptic>,
+    sound: Option<TapSound>,
+    common: CommonFields,
 }
 
 impl ButtonBuilder {
@@ -76,9 +177,17 @@ impl ButtonBuilder {
             label: label.into(),
             on_tap,
             variant: None,
+            size: None,
+            full_width: None,
             icon: None,
             disabled: None,
             loading: None,
+            on_long_press: None,
+            on_press_down: None,
+            on_press_up: None,
+            haptic: None,
+            sound: None,
+            common: CommonFields::default(),
         }
     }
 
@@ -87,6 +196,18 @@ impl ButtonBuilder {
         self
     }
 
+    /// Set the size preset (small, medium, large).
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Stretch the button to the full width of its container.
+    pub fn full_width(mut self, full_width: bool) -> Self {
+        self.full_width = Some(full_width);
+        self
+    }
+
     pub fn icon(mut self, icon: impl Into<String>) -> Self {
         self.icon = Some(icon.into());
         self
@@ -102,6 +223,36 @@ impl ButtonBuilder {
         self
     }
 
+    /// Set the action fired on a long press (ActionRef).
+    pub fn on_long_press(mut self, on_long_press: PropValue) -> Self {
+        self.on_long_press = Some(on_long_press);
+        self
+    }
+
+    /// Set the action fired when the press begins (ActionRef).
+    pub fn on_press_down(mut self, on_press_down: PropValue) -> Self {
+        self.on_press_down = Some(on_press_down);
+        self
+    }
+
+    /// Set the action fired when the press ends (ActionRef).
+    pub fn on_press_up(mut self, on_press_up: PropValue) -> Self {
+        self.on_press_up = Some(on_press_up);
+        self
+    }
+
+    /// Set the haptic feedback played on tap.
+    pub fn haptic(mut self, haptic: Haptic) -> Self {
+        self.haptic = Some(haptic);
+        self
+    }
+
+    /// Set the sound played on tap.
+    pub fn sound(mut self, sound: TapSound) -> Self {
+        self.sound = Some(sound);
+        self
+    }
+
     pub fn build(self) -> SurfaceNode {
         let mut node = SurfaceNode::new("Button");
         node.set_prop("label", PropValue::String(self.label));
@@ -109,6 +260,12 @@ impl ButtonBuilder {
         if let Some(variant) = self.variant {
             node.set_prop("variant", PropValue::String(variant.as_str().to_string()));
         }
+        if let Some(size) = self.size {
+            node.set_prop("size", PropValue::String(size.as_str().to_string()));
+        }
+        if let Some(full_width) = self.full_width {
+            node.set_prop("full_width", PropValue::Bool(full_width));
+        }
         if let Some(icon) = self.icon {
             node.set_prop("icon", PropValue::String(icon));
         }
@@ -118,17 +275,40 @@ impl ButtonBuilder {
         if let Some(loading) = self.loading {
             node.set_prop("loading", PropValue::Bool(loading));
         }
+        if let Some(on_long_press) = self.on_long_press {
+            node.set_prop("on_long_press", on_long_press);
+        }
+        if let Some(on_press_down) = self.on_press_down {
+            node.set_prop("on_press_down", on_press_down);
+        }
+        if let Some(on_press_up) = self.on_press_up {
+            node.set_prop("on_press_up", on_press_up);
+        }
+        if let Some(haptic) = self.haptic {
+            node.set_prop("haptic", PropValue::String(haptic.as_str().to_string()));
+        }
+        if let Some(sound) = self.sound {
+            node.set_prop("sound", PropValue::String(sound.as_str().to_string()));
+        }
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for ButtonBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 // ── TextInputBuilder ──────────────────────────────────────────────────────────
 
 /// Builder for a TextInput component.
 ///
 /// Required: `value` (String), `on_change` (Lambda).
-/// Optional: `placeholder`, `label`, `keyboard`, `max_length`, `multiline`.
+/// Optional: `placeholder`, `label`, `keyboard`, `max_length`, `multiline`,
+/// `autocapitalize`, `autocorrect`.
 pub struct TextInputBuilder {
     value: String,
     on_change: PropValue,
@@ -137,6 +317,9 @@ pub struct TextInputBuilder {
     keyboard: Option<KeyboardType>,
     max_length: Option<f64>,
     multiline: Option<bool>,
+    autocapitalize: Option<Autocapitalize>,
+    autocorrect: Option<bool>,
+    common: CommonFields,
 }
 
 impl TextInputBuilder {
@@ -152,6 +335,9 @@ impl TextInputBuilder {
             keyboard: None,
             max_length: None,
             multiline: None,
+            autocapitalize: None,
+            autocorrect: None,
+            common: CommonFields::default(),
         }
     }
 
@@ -180,6 +366,18 @@ impl TextInputBuilder {
         self
     }
 
+    /// Set the keyboard auto-capitalization hint.
+    pub fn autocapitalize(mut self, autocapitalize: Autocapitalize) -> Self {
+        self.autocapitalize = Some(autocapitalize);
+        self
+    }
+
+    /// Enable or disable keyboard autocorrection.
+    pub fn autocorrect(mut self, autocorrect: bool) -> Self {
+        self.autocorrect = Some(autocorrect);
+        self
+    }
+
     pub fn build(self) -> SurfaceNode {
         let mut node = SurfaceNode::new("TextInput");
         node.set_prop("value", PropValue::String(self.value));
@@ -199,11 +397,293 @@ impl TextInputBuilder {
         if let Some(multiline) = self.multiline {
             node.set_prop("multiline", PropValue::Bool(multiline));
         }
+        if let Some(autocapitalize) = self.autocapitalize {
+            node.set_prop(
+                "autocapitalize",
+                PropValue::String(autocapitalize.as_str().to_string()),
+            );
+        }
+        if let Some(autocorrect) = self.autocorrect {
+            node.set_prop("autocorrect", PropValue::Bool(autocorrect));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for TextInputBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── StepperBuilder ────────────────────────────────────────────────────────────
+
+/// Builder for a Stepper component (a value with − and + buttons).
+///
+/// Required: `value` (Number), `on_change` (Lambda called with the new value).
+/// Optional: `min`, `max`, `step` (Number, step defaults to 1), `label`.
+///
+/// Hosts disable the − / + buttons at the bounds and announce the value to
+/// assistive technology as "{value} of {max}".
+///
+/// # Example
+/// ```
+/// use pepl_ui::{PropValue, StepperBuilder};
+///
+/// let node = StepperBuilder::new(3.0, PropValue::lambda(1))
+///     .min(1.0)
+///     .max(10.0)
+///     .label("Quantity")
+///     .build();
+///
+/// assert_eq!(node.component_type, "Stepper");
+/// ```
+pub struct StepperBuilder {
+    value: f64,
+    on_change: PropValue,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: Option<f64>,
+    label: Option<String>,
+    common: CommonFields,
+}
+
+impl StepperBuilder {
+    /// Create a new StepperBuilder with required props.
+    ///
+    /// `on_change` must be a `PropValue::Lambda` — use `PropValue::lambda(id)`.
+    pub fn new(value: f64, on_change: PropValue) -> Self {
+        Self {
+            value,
+            on_change,
+            min: None,
+            max: None,
+            step: None,
+            label: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the lower bound (inclusive).
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the upper bound (inclusive).
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the increment applied by each tap.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Stepper");
+        node.set_prop("value", PropValue::Number(self.value));
+        node.set_prop("on_change", self.on_change);
+        if let Some(min) = self.min {
+            node.set_prop("min", PropValue::Number(min));
+        }
+        if let Some(max) = self.max {
+            node.set_prop("max", PropValue::Number(max));
+        }
+        if let Some(step) = self.step {
+            node.set_prop("step", PropValue::Number(step));
+        }
+        if let Some(label) = self.label {
+            node.set_prop("label", PropValue::String(label));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for StepperBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── RatingBuilder ─────────────────────────────────────────────────────────────
+
+/// Builder for a Rating component (a row of stars or other icons).
+///
+/// Required: `value` (Number, may be fractional for display), `max` (Number,
+/// a positive integer).
+/// Optional: `on_change` (Lambda called with the tapped rating — omit for a
+/// read-only rating), `icon` (String icon name, hosts default to a star).
+///
+/// Assistive technology announces the rating as "{value} of {max} stars".
+///
+/// # Example
+/// ```
+/// use pepl_ui::{PropValue, RatingBuilder};
+///
+/// let node = RatingBuilder::new(3.0, 5)
+///     .on_change(PropValue::lambda(1))
+///     .build();
+///
+/// assert_eq!(node.component_type, "Rating");
+/// ```
+pub struct RatingBuilder {
+    value: f64,
+    max: u32,
+    on_change: Option<PropValue>,
+    icon: Option<String>,
+    common: CommonFields,
+}
+
+impl RatingBuilder {
+    /// Create a new RatingBuilder with required props.
+    pub fn new(value: f64, max: u32) -> Self {
+        Self {
+            value,
+            max,
+            on_change: None,
+            icon: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Make the rating editable.
+    ///
+    /// `on_change` must be a `PropValue::Lambda` — use `PropValue::lambda(id)`.
+    pub fn on_change(mut self, on_change: PropValue) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Rating");
+        node.set_prop("value", PropValue::Number(self.value));
+        node.set_prop("max", PropValue::Number(f64::from(self.max)));
+        if let Some(on_change) = self.on_change {
+            node.set_prop("on_change", on_change);
+        }
+        if let Some(icon) = self.icon {
+            node.set_prop("icon", PropValue::String(icon));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for RatingBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── FilePickerBuilder ─────────────────────────────────────────────────────────
+
+/// Builder for a FilePicker component (an attachment button that opens the
+/// platform document picker).
+///
+/// Required: `label` (String), `on_pick` (Lambda called with the list of
+/// picked files).
+/// Optional: `accept` (List of MIME types such as `"application/pdf"` or
+/// `"image/*"`; hosts accept any file otherwise), `multiple` (Bool),
+/// `disabled` (Bool).
+///
+/// # Example
+/// ```
+/// use pepl_ui::{FilePickerBuilder, PropValue};
+///
+/// let node = FilePickerBuilder::new("Attach receipt", PropValue::lambda(1))
+///     .accept(vec!["image/*", "application/pdf"])
+///     .multiple(true)
+///     .build();
+///
+/// assert_eq!(node.component_type, "FilePicker");
+/// ```
+pub struct FilePickerBuilder {
+    label: String,
+    on_pick: PropValue,
+    accept: Option<Vec<String>>,
+    multiple: Option<bool>,
+    disabled: Option<bool>,
+    common: CommonFields,
+}
+
+impl FilePickerBuilder {
+    /// Create a new FilePickerBuilder with required props.
+    ///
+    /// `on_pick` must be a `PropValue::Lambda` — use `PropValue::lambda(id)`.
+    pub fn new(label: impl Into<String>, on_pick: PropValue) -> Self {
+        Self {
+            label: label.into(),
+            on_pick,
+            accept: None,
+            multiple: None,
+            disabled: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Restrict the picker to these MIME types.
+    pub fn accept<S: Into<String>>(mut self, types: impl IntoIterator<Item = S>) -> Self {
+        self.accept = Some(types.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Allow picking more than one file.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = Some(multiple);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("FilePicker");
+        node.set_prop("label", PropValue::String(self.label));
+        node.set_prop("on_pick", self.on_pick);
+        if let Some(accept) = self.accept {
+            node.set_prop(
+                "accept",
+                PropValue::List(accept.into_iter().map(PropValue::String).collect()),
+            );
+        }
+        if let Some(multiple) = self.multiple {
+            node.set_prop("multiple", PropValue::Bool(multiple));
+        }
+        if let Some(disabled) = self.disabled {
+            node.set_prop("disabled", PropValue::Bool(disabled));
+        }
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for FilePickerBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 // ── Validation ────────────────────────────────────────────────────────────────
 
 /// Validate an interactive component node (Button or TextInput).
@@ -211,6 +691,9 @@ pub fn validate_interactive_node(node: &SurfaceNode) -> Vec<String> {
     match node.component_type.as_str() {
         "Button" => validate_button(node),
         "TextInput" => validate_text_input(node),
+        "Stepper" => validate_stepper(node),
+        "Rating" => validate_rating(node),
+        "FilePicker" => validate_file_picker(node),
         _ => vec![format!(
             "Unknown interactive component: {}",
             node.component_type
@@ -223,7 +706,7 @@ fn validate_button(node: &SurfaceNode) -> Vec<String> {
 
     // Required: label (string)
     match node.props.get("label") {
-        Some(PropValue::String(_)) => {}
+        Some(v) if v.is_text() => {}
         Some(other) => errors.push(format!(
             "Button.label: expected string, got {}",
             other.type_name()
@@ -252,16 +735,32 @@ fn validate_button(node: &SurfaceNode) -> Vec<String> {
         }
     }
 
-    // Optional: icon (string)
-    if let Some(prop) = node.props.get("icon") {
-        if !matches!(prop, PropValue::String(_)) {
+    // Optional: size (string enum)
+    if let Some(prop) = node.props.get("size") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "small" | "medium" | "large") => {}
+            _ => errors.push(format!(
+                "Button.size: expected one of [small, medium, large], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: full_width (bool)
+    if let Some(prop) = node.props.get("full_width") {
+        if !matches!(prop, PropValue::Bool(_)) {
             errors.push(format!(
-                "Button.icon: expected string, got {}",
+                "Button.full_width: expected bool, got {}",
                 prop.type_name()
             ));
         }
     }
 
+    // Optional: icon (canonical icon or asset:<name>)
+    if let Some(prop) = node.props.get("icon") {
+        errors.extend(icons::validate_icon_prop("Button", "icon", prop));
+    }
+
     // Optional: disabled (bool)
     if let Some(prop) = node.props.get("disabled") {
         if !matches!(prop, PropValue::Bool(_)) {
@@ -282,6 +781,44 @@ fn validate_button(node: &SurfaceNode) -> Vec<String> {
         }
     }
 
+    // Optional: on_long_press, on_press_down, on_press_up (action)
+    for key in ["on_long_press", "on_press_down", "on_press_up"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::ActionRef { .. }) {
+                errors.push(format!(
+                    "Button.{key}: expected action, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+
+    // Optional: haptic (string enum)
+    if let Some(prop) = node.props.get("haptic") {
+        match prop {
+            PropValue::String(s)
+                if matches!(
+                    s.as_str(),
+                    "light" | "medium" | "heavy" | "success" | "error"
+                ) => {}
+            _ => errors.push(format!(
+                "Button.haptic: expected one of [light, medium, heavy, success, error], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: sound (string enum)
+    if let Some(prop) = node.props.get("sound") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "click" | "success" | "error") => {}
+            _ => errors.push(format!(
+                "Button.sound: expected one of [click, success, error], got {:?}",
+                prop
+            )),
+        }
+    }
+
     // No children
     if !node.children.is_empty() {
         errors.push(format!(
@@ -295,12 +832,30 @@ fn validate_button(node: &SurfaceNode) -> Vec<String> {
         errors.extend(accessibility::validate_accessible_prop("Button", prop));
     }
 
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
     // Unknown props
     for key in node.props.keys() {
-        if !matches!(
-            key.as_str(),
-            "label" | "on_tap" | "variant" | "icon" | "disabled" | "loading" | "accessible"
-        ) {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "label"
+                    | "on_tap"
+                    | "variant"
+                    | "size"
+                    | "full_width"
+                    | "icon"
+                    | "disabled"
+                    | "loading"
+                    | "on_long_press"
+                    | "on_press_down"
+                    | "on_press_up"
+                    | "haptic"
+                    | "sound"
+                    | "accessible"
+            )
+        {
             errors.push(format!("Button: unknown prop '{key}'"));
         }
     }
@@ -333,7 +888,7 @@ fn validate_text_input(node: &SurfaceNode) -> Vec<String> {
 
     // Optional: placeholder (string)
     if let Some(prop) = node.props.get("placeholder") {
-        if !matches!(prop, PropValue::String(_)) {
+        if !prop.is_text() {
             errors.push(format!(
                 "TextInput.placeholder: expected string, got {}",
                 prop.type_name()
@@ -343,7 +898,7 @@ fn validate_text_input(node: &SurfaceNode) -> Vec<String> {
 
     // Optional: label (string)
     if let Some(prop) = node.props.get("label") {
-        if !matches!(prop, PropValue::String(_)) {
+        if !prop.is_text() {
             errors.push(format!(
                 "TextInput.label: expected string, got {}",
                 prop.type_name()
@@ -383,6 +938,28 @@ fn validate_text_input(node: &SurfaceNode) -> Vec<String> {
         }
     }
 
+    // Optional: autocapitalize (string enum)
+    if let Some(prop) = node.props.get("autocapitalize") {
+        match prop {
+            PropValue::String(s)
+                if matches!(s.as_str(), "none" | "words" | "sentences" | "characters") => {}
+            _ => errors.push(format!(
+                "TextInput.autocapitalize: expected one of [none, words, sentences, characters], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    // Optional: autocorrect (bool)
+    if let Some(prop) = node.props.get("autocorrect") {
+        if !matches!(prop, PropValue::Bool(_)) {
+            errors.push(format!(
+                "TextInput.autocorrect: expected bool, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
     // No children
     if !node.children.is_empty() {
         errors.push(format!(
@@ -396,22 +973,337 @@ fn validate_text_input(node: &SurfaceNode) -> Vec<String> {
         errors.extend(accessibility::validate_accessible_prop("TextInput", prop));
     }
 
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
     // Unknown props
     for key in node.props.keys() {
-        if !matches!(
-            key.as_str(),
-            "value"
-                | "on_change"
-                | "placeholder"
-                | "label"
-                | "keyboard"
-                | "max_length"
-                | "multiline"
-                | "accessible"
-        ) {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "value"
+                    | "on_change"
+                    | "placeholder"
+                    | "label"
+                    | "keyboard"
+                    | "max_length"
+                    | "multiline"
+                    | "autocapitalize"
+                    | "autocorrect"
+                    | "accessible"
+            )
+        {
             errors.push(format!("TextInput: unknown prop '{key}'"));
         }
     }
 
     errors
 }
+
+fn validate_stepper(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: value (number)
+    let value = match node.props.get("value") {
+        Some(PropValue::Number(n)) => Some(*n),
+        Some(other) => {
+            errors.push(format!(
+                "Stepper.value: expected number, got {}",
+                other.type_name()
+            ));
+            None
+        }
+        None => {
+            errors.push("Stepper.value: required prop missing".to_string());
+            None
+        }
+    };
+
+    // Required: on_change (lambda)
+    match node.props.get("on_change") {
+        Some(PropValue::Lambda { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Stepper.on_change: expected lambda, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Stepper.on_change: required prop missing".to_string()),
+    }
+
+    // Optional: min, max, step (number)
+    let mut number = |key: &str| match node.props.get(key) {
+        Some(PropValue::Number(n)) => Some(*n),
+        Some(other) => {
+            errors.push(format!(
+                "Stepper.{key}: expected number, got {}",
+                other.type_name()
+            ));
+            None
+        }
+        None => None,
+    };
+    let (min, max, step) = (number("min"), number("max"), number("step"));
+
+    if let Some(step) = step.filter(|step| *step <= 0.0) {
+        errors.push(format!("Stepper.step: must be positive, got {step}"));
+    }
+    match (min, max) {
+        (Some(min), Some(max)) if min > max => errors.push(format!(
+            "Stepper.min: must not exceed max, got min {min} and max {max}"
+        )),
+        _ => {
+            if let Some(value) = value {
+                if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+                    errors.push(format!(
+                        "Stepper.value: {value} is outside [{}, {}]",
+                        min.map_or("-inf".to_string(), |n| n.to_string()),
+                        max.map_or("inf".to_string(), |n| n.to_string())
+                    ));
+                }
+            }
+        }
+    }
+
+    // Optional: label (string)
+    if let Some(prop) = node.props.get("label") {
+        if !prop.is_text() {
+            errors.push(format!(
+                "Stepper.label: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Stepper: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Stepper", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "value" | "on_change" | "min" | "max" | "step" | "label" | "accessible"
+            )
+        {
+            errors.push(format!("Stepper: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_rating(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: max (positive integer)
+    let max = match node.props.get("max") {
+        Some(PropValue::Number(n)) if *n >= 1.0 && n.fract() == 0.0 => Some(*n),
+        Some(PropValue::Number(n)) => {
+            errors.push(format!("Rating.max: expected positive integer, got {n}"));
+            None
+        }
+        Some(other) => {
+            errors.push(format!(
+                "Rating.max: expected number, got {}",
+                other.type_name()
+            ));
+            None
+        }
+        None => {
+            errors.push("Rating.max: required prop missing".to_string());
+            None
+        }
+    };
+
+    // Required: value (number in [0, max])
+    match node.props.get("value") {
+        Some(PropValue::Number(n)) => {
+            if *n < 0.0 || max.is_some_and(|max| *n > max) {
+                errors.push(format!(
+                    "Rating.value: {n} is outside [0, {}]",
+                    max.map_or("max".to_string(), |max| max.to_string())
+                ));
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Rating.value: expected number, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Rating.value: required prop missing".to_string()),
+    }
+
+    // Optional: on_change (lambda)
+    if let Some(prop) = node.props.get("on_change") {
+        if !matches!(prop, PropValue::Lambda { .. }) {
+            errors.push(format!(
+                "Rating.on_change: expected lambda, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: icon (string)
+    if let Some(prop) = node.props.get("icon") {
+        if !prop.is_text() {
+            errors.push(format!(
+                "Rating.icon: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Rating: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Rating", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "value" | "max" | "on_change" | "icon" | "accessible"
+            )
+        {
+            errors.push(format!("Rating: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_file_picker(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: label (string)
+    match node.props.get("label") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "FilePicker.label: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("FilePicker.label: required prop missing".to_string()),
+    }
+
+    // Required: on_pick (lambda)
+    match node.props.get("on_pick") {
+        Some(PropValue::Lambda { .. }) => {}
+        Some(other) => errors.push(format!(
+            "FilePicker.on_pick: expected lambda, got {}",
+            other.type_name()
+        )),
+        None => errors.push("FilePicker.on_pick: required prop missing".to_string()),
+    }
+
+    // Optional: accept (non-empty list of distinct MIME types)
+    if let Some(prop) = node.props.get("accept") {
+        match prop {
+            PropValue::List(types) if types.is_empty() => {
+                errors.push("FilePicker.accept: must not be empty".to_string())
+            }
+            PropValue::List(types) => {
+                for (i, value) in types.iter().enumerate() {
+                    match value {
+                        PropValue::String(s) if !is_mime_type(s) => errors.push(format!(
+                            "FilePicker.accept[{i}]: expected MIME type (e.g. \"image/png\", \"image/*\"), got {s:?}"
+                        )),
+                        PropValue::String(s) if types[..i].contains(value) => {
+                            errors.push(format!("FilePicker.accept[{i}]: duplicate type {s:?}"))
+                        }
+                        PropValue::String(_) => {}
+                        other => errors.push(format!(
+                            "FilePicker.accept[{i}]: expected string, got {}",
+                            other.type_name()
+                        )),
+                    }
+                }
+            }
+            other => errors.push(format!(
+                "FilePicker.accept: expected list, got {}",
+                other.type_name()
+            )),
+        }
+    }
+
+    // Optional bools
+    for key in ["multiple", "disabled"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::Bool(_)) {
+                errors.push(format!(
+                    "FilePicker.{key}: expected bool, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+
+    // No children
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "FilePicker: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("FilePicker", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "label" | "on_pick" | "accept" | "multiple" | "disabled" | "accessible"
+            )
+        {
+            errors.push(format!("FilePicker: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Whether `s` is a `type/subtype` MIME type; the subtype (or both parts)
+/// may be `*`.
+fn is_mime_type(s: &str) -> bool {
+    let token = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&^_.+-".contains(c))
+    };
+    match s.split_once('/') {
+        Some(("*", "*")) => true,
+        Some((kind, "*")) => token(kind),
+        Some((kind, subtype)) => token(kind) && token(subtype),
+        None => false,
+    }
+}
@@ -1,4 +1,5 @@
-//! Layout component builders — Column, Row, Scroll.
+//! Layout component builders — Column, Row, Scroll, Tabs, Carousel, Disclosure, Overlay,
+//! AppBar, Divider, Spacer.
 //!
 //! These builders produce [`SurfaceNode`] trees with correct prop types
 //! and validated structure. They are convenience wrappers used by the
@@ -8,15 +9,110 @@
 //!
 //! | Component | Props | Children |
 //! |-----------|-------|----------|
-//! | `Column` | `spacing?: number`, `align?: alignment`, `padding?: edges` | Yes |
-//! | `Row` | `spacing?: number`, `align?: alignment`, `padding?: edges` | Yes |
-//! | `Scroll` | `direction?: "vertical"\|"horizontal"\|"both"` | Yes |
+//! | `Column` | `spacing?: number`, `align?: alignment`, `justify?: justify`, `padding?: edges`, `corner_radius?: corner_radii`, container style, gestures | Yes |
+//! | `Row` | `spacing?: number`, `align?: alignment`, `justify?: justify`, `padding?: edges`, `corner_radius?: corner_radii`, `wrap?: bool`, `run_spacing?: number`, container style, gestures | Yes |
+//! | `Scroll` | `direction?: "vertical"\|"horizontal"\|"both"`, `show_indicators?: bool`, `bounce?: bool`, `paging?: bool`, `avoid_keyboard?: bool`, container style | Yes |
+//! | `Tabs` | `tabs: list<string>`, `selected: number`, `on_select: action\|lambda` | One panel per tab |
+//! | `Carousel` | `index: number`, `on_change: lambda`, `show_indicators?: bool` | One page per child |
+//! | `Disclosure` | `title: string`, `expanded: bool`, `on_toggle: action\|lambda` | Yes (shown when expanded) |
+//! | `Overlay` | `layers?: list<{ align, offset? }>` (one per child) | Yes |
+//! | `AppBar` | `title: string`, `elevated?: bool`, `slots: list<"leading"\|"trailing">` (one per child) | Leading / trailing slots |
+//! | `Divider` | `thickness?: number`, `color?: color`, `inset?: number` | No |
+//! | `Spacer` | `size?: number` | No |
+//!
+//! # Container style
+//!
+//! Column, Row, and Scroll accept `background` (a color, theme color token,
+//! or [`Gradient`]) and `border`, a [`BorderStyle`] record:
+//!
+//! ```json
+//! "border": { "width": 1, "color": { "__color_token": "outline" }, "style": "dashed" }
+//! ```
+//!
+//! `style` is one of `"solid"` (the default), `"dashed"`, or `"dotted"`.
+//!
+//! `shadow` is a [`ShadowStyle`] record (see [`shadow_to_prop`]); the host
+//! draws it behind the container's background:
+//!
+//! ```json
+//! "shadow": { "offset_x": 0, "offset_y": 2, "blur": 8, "color": { "r": 0, "g": 0, "b": 0, "a": 0.2 } }
+//! ```
+//!
+//! # Axes
+//!
+//! Column and Row position children along two axes. `justify` distributes
+//! them along the main axis (`"start"`, `"center"`, `"end"`,
+//! `"space_between"`, `"space_around"`); `align` positions them on the cross
+//! axis (`"start"`, `"center"`, `"end"`, `"stretch"`). For backward
+//! compatibility `align` still accepts `"space_between"` and
+//! `"space_around"` when `justify` is not set.
+//!
+//! # Spacing scale
+//!
+//! Column and Row `spacing` and `padding`, and Row `run_spacing`, take
+//! either logical pixels or a `spacing.*` theme token, so screens share one
+//! scale instead of hand-entered values. [`Spacing`] names the steps:
+//!
+//! ```
+//! use pepl_ui::{ColumnBuilder, PropValue, Spacing};
+//!
+//! let node = ColumnBuilder::new()
+//!     .spacing_token(Spacing::Md)
+//!     .padding_token(Spacing::Lg)
+//!     .build();
+//!
+//! assert_eq!(node.props["spacing"], PropValue::theme_token("spacing.md"));
+//! ```
+//!
+//! # Safe area
+//!
+//! When a [`Surface`](crate::Surface) sets `respect_safe_area`, the host
+//! insets the root container from system UI (notches, status bar, home
+//! indicator). A root Column, Row, or Scroll may narrow this with
+//! `safe_area`: `true` for every edge, or a record of bools selecting edges
+//! (see [`SafeAreaEdges`]):
+//!
+//! ```json
+//! "safe_area": { "top": true, "bottom": true }
+//! ```
+//!
+//! # Weighted children
+//!
+//! Column and Row children may set the common `flex` prop to share the space
+//! left over after their other siblings, in proportion to their `flex`
+//! (see [`RowBuilder::weighted_child`]).
+//!
+//! # Wrapping rows
+//!
+//! A Row with `wrap: true` flows children that do not fit onto additional
+//! lines (chips, tags). `spacing` separates children within a line and
+//! `run_spacing` separates the lines; `run_spacing` requires `wrap`.
+//!
+//! # Gestures
+//!
+//! Column and Row accept optional gesture actions: `on_tap`, `on_double_tap`,
+//! `on_long_press`, and `on_swipe`. When dispatching `on_swipe`, the host
+//! appends the swipe direction (`"left"`, `"right"`, `"up"`, or `"down"`) as
+//! the last argument of the action.
+//!
+//! # Scroll position
+//!
+//! Scroll (and ScrollList) accept `scroll_to` — an anchor key (string) or an
+//! offset in logical pixels (number) — and `on_scroll`, a lambda called with
+//! the current offset. `scroll_event_interval` throttles `on_scroll` to at
+//! most one call per interval (ms). Any descendant of a Scroll can mark itself
+//! as a target with the common `anchor` prop.
 
 use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
 use crate::prop_value::PropValue;
 use crate::surface::SurfaceNode;
-use crate::types::{Alignment, Edges};
+use crate::types::{
+    Alignment, BorderStyle, ColorValue, CornerRadii, Edges, Gradient, Justify, SafeAreaEdges,
+    ShadowStyle, Spacing,
+};
 use serde_json;
+use std::collections::BTreeMap;
 
 // ── Column ────────────────────────────────────────────────────────────────────
 
@@ -29,10 +125,21 @@ use serde_json;
 /// }
 /// ```
 pub struct ColumnBuilder {
-    spacing: Option<f64>,
+    spacing: Option<PropValue>,
     align: Option<Alignment>,
-    padding: Option<Edges>,
+    justify: Option<Justify>,
+    padding: Option<PropValue>,
+    corner_radius: Option<CornerRadii>,
+    background: Option<PropValue>,
+    border: Option<BorderStyle>,
+    shadow: Option<ShadowStyle>,
+    safe_area: Option<SafeAreaEdges>,
+    on_tap: Option<PropValue>,
+    on_double_tap: Option<PropValue>,
+    on_swipe: Option<PropValue>,
+    on_long_press: Option<PropValue>,
     children: Vec<SurfaceNode>,
+    common: CommonFields,
 }
 
 impl ColumnBuilder {
@@ -40,13 +147,30 @@ impl ColumnBuilder {
         Self {
             spacing: None,
             align: None,
+            justify: None,
             padding: None,
+            corner_radius: None,
+            background: None,
+            border: None,
+            shadow: None,
+            safe_area: None,
+            on_tap: None,
+            on_double_tap: None,
+            on_swipe: None,
+            on_long_press: None,
             children: Vec::new(),
+            common: CommonFields::default(),
         }
     }
 
     pub fn spacing(mut self, spacing: f64) -> Self {
-        self.spacing = Some(spacing);
+        self.spacing = Some(PropValue::Number(spacing));
+        self
+    }
+
+    /// Set the spacing to a step of the theme's spacing scale.
+    pub fn spacing_token(mut self, spacing: Spacing) -> Self {
+        self.spacing = Some(PropValue::theme_token(spacing.token()));
         self
     }
 
@@ -55,8 +179,93 @@ impl ColumnBuilder {
         self
     }
 
+    /// Distribute children along the main axis.
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
     pub fn padding(mut self, padding: Edges) -> Self {
-        self.padding = Some(padding);
+        self.padding = Some(edges_to_prop(padding));
+        self
+    }
+
+    /// Pad all sides by a step of the theme's spacing scale.
+    pub fn padding_token(mut self, padding: Spacing) -> Self {
+        self.padding = Some(PropValue::theme_token(padding.token()));
+        self
+    }
+
+    /// Round the container's corners (clipping its background and children).
+    pub fn corner_radius(mut self, corner_radius: CornerRadii) -> Self {
+        self.corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Set the background color.
+    pub fn background(mut self, background: ColorValue) -> Self {
+        self.background = Some(PropValue::color(
+            background.r,
+            background.g,
+            background.b,
+            background.a,
+        ));
+        self
+    }
+
+    /// Set the background color to a theme color token (e.g. `"surface"`).
+    pub fn background_token(mut self, token: impl Into<String>) -> Self {
+        self.background = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Fill the background with a gradient.
+    pub fn background_gradient(mut self, gradient: Gradient) -> Self {
+        self.background = Some(common::gradient_to_prop(&gradient));
+        self
+    }
+
+    /// Draw a border around the container.
+    pub fn border(mut self, border: BorderStyle) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Draw a drop shadow behind the container.
+    pub fn shadow(mut self, shadow: ShadowStyle) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Inset content from system UI on the given edges. Only valid on the
+    /// root node of a surface that sets `respect_safe_area`.
+    pub fn safe_area(mut self, edges: SafeAreaEdges) -> Self {
+        self.safe_area = Some(edges);
+        self
+    }
+
+    /// Set the action fired on a tap (ActionRef).
+    pub fn on_tap(mut self, on_tap: PropValue) -> Self {
+        self.on_tap = Some(on_tap);
+        self
+    }
+
+    /// Set the action fired on a double tap (ActionRef).
+    pub fn on_double_tap(mut self, on_double_tap: PropValue) -> Self {
+        self.on_double_tap = Some(on_double_tap);
+        self
+    }
+
+    /// Set the action fired on a swipe (ActionRef). The host appends the
+    /// swipe direction as the last argument.
+    pub fn on_swipe(mut self, on_swipe: PropValue) -> Self {
+        self.on_swipe = Some(on_swipe);
+        self
+    }
+
+    /// Set the action fired on a long press (ActionRef).
+    pub fn on_long_press(mut self, on_long_press: PropValue) -> Self {
+        self.on_long_press = Some(on_long_press);
         self
     }
 
@@ -65,6 +274,13 @@ impl ColumnBuilder {
         self
     }
 
+    /// Add a child that takes a `flex` share of the remaining space.
+    pub fn weighted_child(mut self, child: SurfaceNode, flex: f64) -> Self {
+        self.children
+            .push(child.with_prop("flex", PropValue::Number(flex)));
+        self
+    }
+
     pub fn children(mut self, children: Vec<SurfaceNode>) -> Self {
         self.children = children;
         self
@@ -74,21 +290,61 @@ impl ColumnBuilder {
         let mut node = SurfaceNode::new("Column");
 
         if let Some(spacing) = self.spacing {
-            node.set_prop("spacing", PropValue::Number(spacing));
+            node.set_prop("spacing", spacing);
         }
         if let Some(align) = self.align {
             node.set_prop("align", alignment_to_prop(align));
         }
+        if let Some(justify) = self.justify {
+            node.set_prop("justify", PropValue::String(justify.as_str().to_string()));
+        }
         if let Some(padding) = self.padding {
-            node.set_prop("padding", edges_to_prop(padding));
+            node.set_prop("padding", padding);
+        }
+        if let Some(corner_radius) = &self.corner_radius {
+            node.set_prop(
+                "corner_radius",
+                common::corner_radius_to_prop(corner_radius),
+            );
+        }
+        if let Some(background) = self.background {
+            node.set_prop("background", background);
+        }
+        if let Some(border) = &self.border {
+            node.set_prop("border", border_to_prop(border));
+        }
+        if let Some(shadow) = &self.shadow {
+            node.set_prop("shadow", shadow_to_prop(shadow));
+        }
+        if let Some(safe_area) = self.safe_area {
+            node.set_prop("safe_area", safe_area_to_prop(safe_area));
+        }
+        if let Some(on_tap) = self.on_tap {
+            node.set_prop("on_tap", on_tap);
+        }
+        if let Some(on_double_tap) = self.on_double_tap {
+            node.set_prop("on_double_tap", on_double_tap);
+        }
+        if let Some(on_swipe) = self.on_swipe {
+            node.set_prop("on_swipe", on_swipe);
+        }
+        if let Some(on_long_press) = self.on_long_press {
+            node.set_prop("on_long_press", on_long_press);
         }
 
         node.children = self.children;
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for ColumnBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 impl Default for ColumnBuilder {
     fn default() -> Self {
         Self::new()
@@ -100,11 +356,26 @@ impl Default for ColumnBuilder {
 /// Builder for the `Row` layout component (horizontal stack).
 ///
 /// Same prop signature as Column but lays out children horizontally.
+/// With [`wrap`](RowBuilder::wrap), children that do not fit flow onto
+/// additional lines.
 pub struct RowBuilder {
-    spacing: Option<f64>,
+    spacing: Option<PropValue>,
     align: Option<Alignment>,
-    padding: Option<Edges>,
+    justify: Option<Justify>,
+    wrap: Option<bool>,
+    run_spacing: Option<f64>,
+    padding: Option<PropValue>,
+    corner_radius: Option<CornerRadii>,
+    background: Option<PropValue>,
+    border: Option<BorderStyle>,
+    shadow: Option<ShadowStyle>,
+    safe_area: Option<SafeAreaEdges>,
+    on_tap: Option<PropValue>,
+    on_double_tap: Option<PropValue>,
+    on_swipe: Option<PropValue>,
+    on_long_press: Option<PropValue>,
     children: Vec<SurfaceNode>,
+    common: CommonFields,
 }
 
 impl RowBuilder {
@@ -112,13 +383,32 @@ impl RowBuilder {
         Self {
             spacing: None,
             align: None,
+            justify: None,
+            wrap: None,
+            run_spacing: None,
             padding: None,
+            corner_radius: None,
+            background: None,
+            border: None,
+            shadow: None,
+            safe_area: None,
+            on_tap: None,
+            on_double_tap: None,
+            on_swipe: None,
+            on_long_press: None,
             children: Vec::new(),
+            common: CommonFields::default(),
         }
     }
 
     pub fn spacing(mut self, spacing: f64) -> Self {
-        self.spacing = Some(spacing);
+        self.spacing = Some(PropValue::Number(spacing));
+        self
+    }
+
+    /// Set the spacing to a step of the theme's spacing scale.
+    pub fn spacing_token(mut self, spacing: Spacing) -> Self {
+        self.spacing = Some(PropValue::theme_token(spacing.token()));
         self
     }
 
@@ -127,8 +417,105 @@ impl RowBuilder {
         self
     }
 
+    /// Distribute children along the main axis.
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    /// Flow children that do not fit onto additional lines.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Set the gap between wrapped lines (requires `wrap`).
+    pub fn run_spacing(mut self, run_spacing: f64) -> Self {
+        self.run_spacing = Some(run_spacing);
+        self
+    }
+
     pub fn padding(mut self, padding: Edges) -> Self {
-        self.padding = Some(padding);
+        self.padding = Some(edges_to_prop(padding));
+        self
+    }
+
+    /// Pad all sides by a step of the theme's spacing scale.
+    pub fn padding_token(mut self, padding: Spacing) -> Self {
+        self.padding = Some(PropValue::theme_token(padding.token()));
+        self
+    }
+
+    /// Round the container's corners (clipping its background and children).
+    pub fn corner_radius(mut self, corner_radius: CornerRadii) -> Self {
+        self.corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Set the background color.
+    pub fn background(mut self, background: ColorValue) -> Self {
+        self.background = Some(PropValue::color(
+            background.r,
+            background.g,
+            background.b,
+            background.a,
+        ));
+        self
+    }
+
+    /// Set the background color to a theme color token (e.g. `"surface"`).
+    pub fn background_token(mut self, token: impl Into<String>) -> Self {
+        self.background = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Fill the background with a gradient.
+    pub fn background_gradient(mut self, gradient: Gradient) -> Self {
+        self.background = Some(common::gradient_to_prop(&gradient));
+        self
+    }
+
+    /// Draw a border around the container.
+    pub fn border(mut self, border: BorderStyle) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Draw a drop shadow behind the container.
+    pub fn shadow(mut self, shadow: ShadowStyle) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Inset content from system UI on the given edges. Only valid on the
+    /// root node of a surface that sets `respect_safe_area`.
+    pub fn safe_area(mut self, edges: SafeAreaEdges) -> Self {
+        self.safe_area = Some(edges);
+        self
+    }
+
+    /// Set the action fired on a tap (ActionRef).
+    pub fn on_tap(mut self, on_tap: PropValue) -> Self {
+        self.on_tap = Some(on_tap);
+        self
+    }
+
+    /// Set the action fired on a double tap (ActionRef).
+    pub fn on_double_tap(mut self, on_double_tap: PropValue) -> Self {
+        self.on_double_tap = Some(on_double_tap);
+        self
+    }
+
+    /// Set the action fired on a swipe (ActionRef). The host appends the
+    /// swipe direction as the last argument.
+    pub fn on_swipe(mut self, on_swipe: PropValue) -> Self {
+        self.on_swipe = Some(on_swipe);
+        self
+    }
+
+    /// Set the action fired on a long press (ActionRef).
+    pub fn on_long_press(mut self, on_long_press: PropValue) -> Self {
+        self.on_long_press = Some(on_long_press);
         self
     }
 
@@ -137,6 +524,13 @@ impl RowBuilder {
         self
     }
 
+    /// Add a child that takes a `flex` share of the remaining space.
+    pub fn weighted_child(mut self, child: SurfaceNode, flex: f64) -> Self {
+        self.children
+            .push(child.with_prop("flex", PropValue::Number(flex)));
+        self
+    }
+
     pub fn children(mut self, children: Vec<SurfaceNode>) -> Self {
         self.children = children;
         self
@@ -146,21 +540,67 @@ impl RowBuilder {
         let mut node = SurfaceNode::new("Row");
 
         if let Some(spacing) = self.spacing {
-            node.set_prop("spacing", PropValue::Number(spacing));
+            node.set_prop("spacing", spacing);
         }
         if let Some(align) = self.align {
             node.set_prop("align", alignment_to_prop(align));
         }
+        if let Some(justify) = self.justify {
+            node.set_prop("justify", PropValue::String(justify.as_str().to_string()));
+        }
+        if let Some(wrap) = self.wrap {
+            node.set_prop("wrap", PropValue::Bool(wrap));
+        }
+        if let Some(run_spacing) = self.run_spacing {
+            node.set_prop("run_spacing", PropValue::Number(run_spacing));
+        }
         if let Some(padding) = self.padding {
-            node.set_prop("padding", edges_to_prop(padding));
+            node.set_prop("padding", padding);
+        }
+        if let Some(corner_radius) = &self.corner_radius {
+            node.set_prop(
+                "corner_radius",
+                common::corner_radius_to_prop(corner_radius),
+            );
+        }
+        if let Some(background) = self.background {
+            node.set_prop("background", background);
+        }
+        if let Some(border) = &self.border {
+            node.set_prop("border", border_to_prop(border));
+        }
+        if let Some(shadow) = &self.shadow {
+            node.set_prop("shadow", shadow_to_prop(shadow));
+        }
+        if let Some(safe_area) = self.safe_area {
+            node.set_prop("safe_area", safe_area_to_prop(safe_area));
+        }
+        if let Some(on_tap) = self.on_tap {
+            node.set_prop("on_tap", on_tap);
+        }
+        if let Some(on_double_tap) = self.on_double_tap {
+            node.set_prop("on_double_tap", on_double_tap);
+        }
+        if let Some(on_swipe) = self.on_swipe {
+            node.set_prop("on_swipe", on_swipe);
+        }
+        if let Some(on_long_press) = self.on_long_press {
+            node.set_prop("on_long_press", on_long_press);
         }
 
         node.children = self.children;
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for RowBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 impl Default for RowBuilder {
     fn default() -> Self {
         Self::new()
@@ -189,19 +629,62 @@ impl ScrollDirection {
     }
 }
 
+/// Target of a programmatic scroll (`scroll_to` prop).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScrollTarget {
+    /// Scroll to the descendant whose `anchor` prop (or, in a ScrollList,
+    /// whose item key) equals this string.
+    Anchor(String),
+    /// Scroll to an offset in logical pixels from the start.
+    Offset(f64),
+}
+
+impl ScrollTarget {
+    /// Scroll to an anchor key.
+    pub fn anchor(key: impl Into<String>) -> Self {
+        Self::Anchor(key.into())
+    }
+}
+
 /// Builder for the `Scroll` layout component (scrollable container).
 ///
-/// Default direction is `"vertical"`.
+/// Default direction is `"vertical"`. `show_indicators`, `bounce`, and
+/// `paging` fall back to the platform default when unset. `avoid_keyboard`
+/// asks the host to scroll focused inputs clear of the soft keyboard.
 pub struct ScrollBuilder {
     direction: ScrollDirection,
+    show_indicators: Option<bool>,
+    bounce: Option<bool>,
+    paging: Option<bool>,
+    avoid_keyboard: Option<bool>,
+    scroll_to: Option<ScrollTarget>,
+    on_scroll: Option<PropValue>,
+    scroll_event_interval: Option<f64>,
+    background: Option<PropValue>,
+    border: Option<BorderStyle>,
+    shadow: Option<ShadowStyle>,
+    safe_area: Option<SafeAreaEdges>,
     children: Vec<SurfaceNode>,
+    common: CommonFields,
 }
 
 impl ScrollBuilder {
     pub fn new() -> Self {
         Self {
             direction: ScrollDirection::default(),
+            show_indicators: None,
+            bounce: None,
+            paging: None,
+            avoid_keyboard: None,
+            scroll_to: None,
+            on_scroll: None,
+            scroll_event_interval: None,
+            background: None,
+            border: None,
+            shadow: None,
+            safe_area: None,
             children: Vec::new(),
+            common: CommonFields::default(),
         }
     }
 
@@ -210,6 +693,90 @@ impl ScrollBuilder {
         self
     }
 
+    /// Show or hide the scroll indicators (scrollbars).
+    pub fn show_indicators(mut self, show_indicators: bool) -> Self {
+        self.show_indicators = Some(show_indicators);
+        self
+    }
+
+    /// Enable or disable overscroll bounce.
+    pub fn bounce(mut self, bounce: bool) -> Self {
+        self.bounce = Some(bounce);
+        self
+    }
+
+    /// Snap scrolling to whole pages (viewport-sized steps).
+    pub fn paging(mut self, paging: bool) -> Self {
+        self.paging = Some(paging);
+        self
+    }
+
+    /// Resize and scroll so focused inputs stay visible above the soft keyboard.
+    pub fn avoid_keyboard(mut self, avoid_keyboard: bool) -> Self {
+        self.avoid_keyboard = Some(avoid_keyboard);
+        self
+    }
+
+    /// Scroll to an anchor or offset.
+    pub fn scroll_to(mut self, target: ScrollTarget) -> Self {
+        self.scroll_to = Some(target);
+        self
+    }
+
+    /// Set the scroll callback (Lambda called with the current offset).
+    pub fn on_scroll(mut self, on_scroll: PropValue) -> Self {
+        self.on_scroll = Some(on_scroll);
+        self
+    }
+
+    /// Throttle `on_scroll` to at most one call per `interval` milliseconds.
+    pub fn scroll_event_interval(mut self, interval: f64) -> Self {
+        self.scroll_event_interval = Some(interval);
+        self
+    }
+
+    /// Set the background color.
+    pub fn background(mut self, background: ColorValue) -> Self {
+        self.background = Some(PropValue::color(
+            background.r,
+            background.g,
+            background.b,
+            background.a,
+        ));
+        self
+    }
+
+    /// Set the background color to a theme color token (e.g. `"surface"`).
+    pub fn background_token(mut self, token: impl Into<String>) -> Self {
+        self.background = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Fill the background with a gradient.
+    pub fn background_gradient(mut self, gradient: Gradient) -> Self {
+        self.background = Some(common::gradient_to_prop(&gradient));
+        self
+    }
+
+    /// Draw a border around the container.
+    pub fn border(mut self, border: BorderStyle) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    /// Draw a drop shadow behind the container.
+    pub fn shadow(mut self, shadow: ShadowStyle) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Inset content from system UI on the given edges. Only valid on the
+    /// root node of a surface that sets `respect_safe_area`.
+    pub fn safe_area(mut self, edges: SafeAreaEdges) -> Self {
+        self.safe_area = Some(edges);
+        self
+    }
+
     pub fn child(mut self, child: SurfaceNode) -> Self {
         self.children.push(child);
         self
@@ -226,65 +793,903 @@ impl ScrollBuilder {
             "direction",
             PropValue::String(self.direction.as_str().to_string()),
         );
+        if let Some(show_indicators) = self.show_indicators {
+            node.set_prop("show_indicators", PropValue::Bool(show_indicators));
+        }
+        if let Some(bounce) = self.bounce {
+            node.set_prop("bounce", PropValue::Bool(bounce));
+        }
+        if let Some(paging) = self.paging {
+            node.set_prop("paging", PropValue::Bool(paging));
+        }
+        if let Some(avoid_keyboard) = self.avoid_keyboard {
+            node.set_prop("avoid_keyboard", PropValue::Bool(avoid_keyboard));
+        }
+        if let Some(target) = &self.scroll_to {
+            node.set_prop("scroll_to", scroll_target_to_prop(target));
+        }
+        if let Some(on_scroll) = self.on_scroll {
+            node.set_prop("on_scroll", on_scroll);
+        }
+        if let Some(interval) = self.scroll_event_interval {
+            node.set_prop("scroll_event_interval", PropValue::Number(interval));
+        }
+        if let Some(background) = self.background {
+            node.set_prop("background", background);
+        }
+        if let Some(border) = &self.border {
+            node.set_prop("border", border_to_prop(border));
+        }
+        if let Some(shadow) = &self.shadow {
+            node.set_prop("shadow", shadow_to_prop(shadow));
+        }
+        if let Some(safe_area) = self.safe_area {
+            node.set_prop("safe_area", safe_area_to_prop(safe_area));
+        }
         node.children = self.children;
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for ScrollBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 impl Default for ScrollBuilder {
     fn default() -> Self {
         Self::new()
     }
 }
 
-// ── Helpers ───────────────────────────────────────────────────────────────────
+// ── Tabs ──────────────────────────────────────────────────────────────────────
 
-/// Convert an `Alignment` enum to a `PropValue` for the Surface tree.
-fn alignment_to_prop(align: Alignment) -> PropValue {
-    let s = match align {
-        Alignment::Start => "start",
-        Alignment::Center => "center",
-        Alignment::End => "end",
-        Alignment::Stretch => "stretch",
-        Alignment::SpaceBetween => "space_between",
-        Alignment::SpaceAround => "space_around",
-    };
-    PropValue::String(s.to_string())
+/// Builder for the `Tabs` layout component (tab bar with one panel per tab).
+///
+/// `tabs` holds the tab labels and the node's children are the matching
+/// panels, in order. `selected` is the index of the visible panel; the host
+/// calls `on_select` with the tapped index appended as the last argument
+/// (an action) or as the only argument (a lambda).
+///
+/// ```
+/// use pepl_ui::{PropValue, TabsBuilder, TextBuilder};
+///
+/// let node = TabsBuilder::new(PropValue::action("select_tab"))
+///     .tab("Today", TextBuilder::new("3 tasks").build())
+///     .tab("Done", TextBuilder::new("12 tasks").build())
+///     .selected(1)
+///     .build();
+///
+/// assert_eq!(node.component_type, "Tabs");
+/// assert_eq!(node.children.len(), 2);
+/// ```
+pub struct TabsBuilder {
+    tabs: Vec<String>,
+    selected: usize,
+    on_select: PropValue,
+    children: Vec<SurfaceNode>,
+    common: CommonFields,
 }
 
-/// Convert an `Edges` value to a `PropValue` for the Surface tree.
-///
-/// - `Uniform(n)` → `PropValue::Number(n)` (number literal coercion)
-/// - `Sides { top, bottom, start, end }` → `PropValue::Record { top, bottom, start, end }`
-fn edges_to_prop(edges: Edges) -> PropValue {
-    match edges {
-        Edges::Uniform(n) => PropValue::Number(n),
-        Edges::Sides { .. } => {
-            let s = serde_json::to_value(&edges).expect("Edges serialization should never fail");
-            serde_json::from_value(s).expect("Edges deserialization should never fail")
+impl TabsBuilder {
+    /// Create a `TabsBuilder` with the required `on_select` handler
+    /// (ActionRef or Lambda). The first tab is selected by default.
+    pub fn new(on_select: PropValue) -> Self {
+        Self {
+            tabs: Vec::new(),
+            selected: 0,
+            on_select,
+            children: Vec::new(),
+            common: CommonFields::default(),
         }
     }
+
+    /// Add a tab with its label and panel.
+    pub fn tab(mut self, label: impl Into<String>, panel: SurfaceNode) -> Self {
+        self.tabs.push(label.into());
+        self.children.push(panel);
+        self
+    }
+
+    /// Set the index of the selected tab.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Tabs");
+        node.set_prop(
+            "tabs",
+            PropValue::List(self.tabs.into_iter().map(PropValue::String).collect()),
+        );
+        node.set_prop("selected", PropValue::Number(self.selected as f64));
+        node.set_prop("on_select", self.on_select);
+        node.children = self.children;
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
 }
 
-/// Validate that a component node has valid prop types.
-///
-/// Returns a list of validation errors. Empty means valid.
-pub fn validate_layout_node(node: &SurfaceNode) -> Vec<String> {
-    let mut errors = Vec::new();
+impl CommonProps for TabsBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
 
-    match node.component_type.as_str() {
-        "Column" | "Row" => {
-            for (key, val) in &node.props {
-                match key.as_str() {
-                    "spacing" => {
-                        if !matches!(val, PropValue::Number(_)) {
-                            errors.push(format!(
-                                "{}: 'spacing' must be a number, got {}",
-                                node.component_type,
-                                val.type_name()
-                            ));
-                        }
+// ── Carousel ──────────────────────────────────────────────────────────────────
+
+/// Builder for the `Carousel` layout component (a swipeable pager).
+///
+/// Each child is one page and `index` is the visible page. When the user
+/// swipes, the host calls the `on_change` lambda with the new index; the
+/// page only changes once the surface is rebuilt with the new `index`.
+///
+/// ```
+/// use pepl_ui::{CarouselBuilder, PropValue, TextBuilder};
+///
+/// let onboarding = CarouselBuilder::new(0, PropValue::lambda(1))
+///     .page(TextBuilder::new("Welcome").build())
+///     .page(TextBuilder::new("Track your habits").build())
+///     .show_indicators(true)
+///     .build();
+///
+/// assert_eq!(onboarding.children.len(), 2);
+/// ```
+pub struct CarouselBuilder {
+    index: usize,
+    on_change: PropValue,
+    show_indicators: Option<bool>,
+    children: Vec<SurfaceNode>,
+    common: CommonFields,
+}
+
+impl CarouselBuilder {
+    /// Create a `CarouselBuilder` showing page `index`, with the required
+    /// `on_change` lambda.
+    pub fn new(index: usize, on_change: PropValue) -> Self {
+        Self {
+            index,
+            on_change,
+            show_indicators: None,
+            children: Vec::new(),
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Add a page.
+    pub fn page(mut self, page: SurfaceNode) -> Self {
+        self.children.push(page);
+        self
+    }
+
+    pub fn children(mut self, children: Vec<SurfaceNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Show page indicator dots.
+    pub fn show_indicators(mut self, show_indicators: bool) -> Self {
+        self.show_indicators = Some(show_indicators);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Carousel");
+        node.set_prop("index", PropValue::Number(self.index as f64));
+        node.set_prop("on_change", self.on_change);
+        if let Some(show_indicators) = self.show_indicators {
+            node.set_prop("show_indicators", PropValue::Bool(show_indicators));
+        }
+        node.children = self.children;
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for CarouselBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Disclosure ────────────────────────────────────────────────────────────────
+
+/// Builder for the `Disclosure` layout component (a collapsible section).
+///
+/// The host draws a tappable header with `title` and a chevron, and shows
+/// the children only while `expanded` is true. Tapping the header calls
+/// `on_toggle` with the new expanded state appended as the last argument
+/// (an action) or as the only argument (a lambda). An accordion is a Column
+/// of Disclosures whose `on_toggle` collapses the others.
+///
+/// ```
+/// use pepl_ui::{DisclosureBuilder, PropValue, TextBuilder};
+///
+/// let faq = DisclosureBuilder::new("Can I cancel?", false, PropValue::action("toggle_faq"))
+///     .child(TextBuilder::new("Yes, at any time.").build())
+///     .build();
+///
+/// assert_eq!(faq.component_type, "Disclosure");
+/// ```
+pub struct DisclosureBuilder {
+    title: String,
+    expanded: bool,
+    on_toggle: PropValue,
+    children: Vec<SurfaceNode>,
+    common: CommonFields,
+}
+
+impl DisclosureBuilder {
+    /// Create a `DisclosureBuilder` with the required header `title`,
+    /// `expanded` state, and `on_toggle` handler (ActionRef or Lambda).
+    pub fn new(title: impl Into<String>, expanded: bool, on_toggle: PropValue) -> Self {
+        Self {
+            title: title.into(),
+            expanded,
+            on_toggle,
+            children: Vec::new(),
+            common: CommonFields::default(),
+        }
+    }
+
+    pub fn child(mut self, child: SurfaceNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: Vec<SurfaceNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Disclosure");
+        node.set_prop("title", PropValue::String(self.title));
+        node.set_prop("expanded", PropValue::Bool(self.expanded));
+        node.set_prop("on_toggle", self.on_toggle);
+        node.children = self.children;
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for DisclosureBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Overlay ───────────────────────────────────────────────────────────────────
+
+/// Where an [`Overlay`](OverlayBuilder) layer sits within the overlay's bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlayAlignment {
+    TopStart,
+    Top,
+    TopEnd,
+    Start,
+    #[default]
+    Center,
+    End,
+    BottomStart,
+    Bottom,
+    BottomEnd,
+}
+
+impl OverlayAlignment {
+    /// Returns the string value used in the Surface tree.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TopStart => "top_start",
+            Self::Top => "top",
+            Self::TopEnd => "top_end",
+            Self::Start => "start",
+            Self::Center => "center",
+            Self::End => "end",
+            Self::BottomStart => "bottom_start",
+            Self::Bottom => "bottom",
+            Self::BottomEnd => "bottom_end",
+        }
+    }
+}
+
+const OVERLAY_ALIGNMENTS: [&str; 9] = [
+    "top_start",
+    "top",
+    "top_end",
+    "start",
+    "center",
+    "end",
+    "bottom_start",
+    "bottom",
+    "bottom_end",
+];
+
+/// One layer of an Overlay: a child node with its alignment and offset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayLayer {
+    pub child: SurfaceNode,
+    pub align: OverlayAlignment,
+    /// `(x, y)` shift in logical pixels after alignment (positive `x` is
+    /// toward the end edge, positive `y` is down).
+    pub offset: Option<(f64, f64)>,
+}
+
+impl OverlayLayer {
+    /// A centered layer.
+    pub fn new(child: SurfaceNode) -> Self {
+        Self {
+            child,
+            align: OverlayAlignment::default(),
+            offset: None,
+        }
+    }
+
+    /// Set the alignment.
+    pub fn align(mut self, align: OverlayAlignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Shift the layer by `(x, y)` logical pixels.
+    pub fn offset(mut self, x: f64, y: f64) -> Self {
+        self.offset = Some((x, y));
+        self
+    }
+
+    fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "align".to_string(),
+            PropValue::String(self.align.as_str().to_string()),
+        );
+        if let Some((x, y)) = self.offset {
+            fields.insert(
+                "offset".to_string(),
+                PropValue::Record(BTreeMap::from([
+                    ("x".to_string(), PropValue::Number(x)),
+                    ("y".to_string(), PropValue::Number(y)),
+                ])),
+            );
+        }
+        PropValue::Record(fields)
+    }
+}
+
+/// Builder for the `Overlay` layout component (a z-stack).
+///
+/// Children are drawn on top of each other in order — the first child at
+/// the back, the last in front — and the overlay sizes itself to the largest
+/// child. The `layers` prop holds one `{ align, offset? }` record per child;
+/// it is omitted when every layer is centered without an offset. A child
+/// with the common `position` prop is placed at fixed edge offsets instead.
+///
+/// ```
+/// use pepl_ui::{ImageBuilder, OverlayAlignment, OverlayBuilder, OverlayLayer, TextBuilder};
+///
+/// let avatar = OverlayBuilder::new()
+///     .child(ImageBuilder::asset("avatar").alt("Ada").build())
+///     .layer(
+///         OverlayLayer::new(TextBuilder::new("3").build())
+///             .align(OverlayAlignment::TopEnd)
+///             .offset(4.0, -4.0),
+///     )
+///     .build();
+///
+/// assert_eq!(avatar.children.len(), 2);
+/// ```
+pub struct OverlayBuilder {
+    layers: Vec<OverlayLayer>,
+    common: CommonFields,
+}
+
+impl OverlayBuilder {
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Add a centered layer.
+    pub fn child(self, child: SurfaceNode) -> Self {
+        self.layer(OverlayLayer::new(child))
+    }
+
+    /// Add a layer with its alignment and offset.
+    pub fn layer(mut self, layer: OverlayLayer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Overlay");
+        if self
+            .layers
+            .iter()
+            .any(|layer| layer.align != OverlayAlignment::Center || layer.offset.is_some())
+        {
+            node.set_prop(
+                "layers",
+                PropValue::List(
+                    self.layers
+                        .iter()
+                        .map(OverlayLayer::to_prop_value)
+                        .collect(),
+                ),
+            );
+        }
+        node.children = self.layers.into_iter().map(|layer| layer.child).collect();
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for OverlayBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+impl Default for OverlayBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── AppBar ────────────────────────────────────────────────────────────────────
+
+/// Builder for an AppBar (top navigation bar). Hosts map it to native
+/// navigation chrome: a title, an optional leading control (back or menu
+/// button), and trailing actions.
+///
+/// Slot contents are children; the `slots` prop names the slot of each child
+/// in order. The leading child, if any, comes first.
+///
+/// ```
+/// use pepl_ui::{AppBarBuilder, ButtonBuilder, PropValue};
+///
+/// let bar = AppBarBuilder::new("Inbox")
+///     .leading(ButtonBuilder::new("Menu", PropValue::action("open_menu")).build())
+///     .trailing(ButtonBuilder::new("Search", PropValue::action("search")).build())
+///     .elevated(true)
+///     .build();
+///
+/// assert_eq!(bar.children.len(), 2);
+/// ```
+pub struct AppBarBuilder {
+    title: String,
+    leading: Option<SurfaceNode>,
+    trailing: Vec<SurfaceNode>,
+    elevated: Option<bool>,
+    common: CommonFields,
+}
+
+impl AppBarBuilder {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            leading: None,
+            trailing: Vec::new(),
+            elevated: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the leading control (replaces any previous one).
+    pub fn leading(mut self, node: SurfaceNode) -> Self {
+        self.leading = Some(node);
+        self
+    }
+
+    /// Add a trailing action.
+    pub fn trailing(mut self, node: SurfaceNode) -> Self {
+        self.trailing.push(node);
+        self
+    }
+
+    /// Draw a shadow / divider under the bar.
+    pub fn elevated(mut self, elevated: bool) -> Self {
+        self.elevated = Some(elevated);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("AppBar");
+        node.set_prop("title", PropValue::String(self.title));
+        if let Some(elevated) = self.elevated {
+            node.set_prop("elevated", PropValue::Bool(elevated));
+        }
+        let slot = |name: &str| PropValue::String(name.to_string());
+        let mut slots = Vec::new();
+        if let Some(leading) = self.leading {
+            slots.push(slot("leading"));
+            node.children.push(leading);
+        }
+        for trailing in self.trailing {
+            slots.push(slot("trailing"));
+            node.children.push(trailing);
+        }
+        if !slots.is_empty() {
+            node.set_prop("slots", PropValue::List(slots));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for AppBarBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Divider ───────────────────────────────────────────────────────────────────
+
+/// Builder for the `Divider` layout primitive (a hairline separator).
+///
+/// A Divider runs across its parent's cross axis: horizontal in a Column,
+/// vertical in a Row. `inset` indents it from the leading edge.
+pub struct DividerBuilder {
+    thickness: Option<f64>,
+    color: Option<PropValue>,
+    inset: Option<f64>,
+    common: CommonFields,
+}
+
+impl DividerBuilder {
+    pub fn new() -> Self {
+        Self {
+            thickness: None,
+            color: None,
+            inset: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the line thickness in logical pixels.
+    pub fn thickness(mut self, thickness: f64) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// Set the line color.
+    pub fn color(mut self, color: ColorValue) -> Self {
+        self.color = Some(PropValue::color(color.r, color.g, color.b, color.a));
+        self
+    }
+
+    /// Set the line color to a theme color token (e.g. `"outline"`).
+    pub fn color_token(mut self, token: impl Into<String>) -> Self {
+        self.color = Some(PropValue::color_token(token));
+        self
+    }
+
+    /// Indent the line from the leading edge, in logical pixels.
+    pub fn inset(mut self, inset: f64) -> Self {
+        self.inset = Some(inset);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Divider");
+        if let Some(thickness) = self.thickness {
+            node.set_prop("thickness", PropValue::Number(thickness));
+        }
+        if let Some(color) = self.color {
+            node.set_prop("color", color);
+        }
+        if let Some(inset) = self.inset {
+            node.set_prop("inset", PropValue::Number(inset));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for DividerBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+impl Default for DividerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── Spacer ────────────────────────────────────────────────────────────────────
+
+/// Builder for the `Spacer` layout primitive (empty space).
+///
+/// With `size`, a Spacer occupies that many logical pixels along its
+/// parent's main axis. Without it, the Spacer is flexible: it takes an equal
+/// share of the free space, pushing its siblings apart.
+///
+/// ```
+/// use pepl_ui::{ButtonBuilder, PropValue, RowBuilder, SpacerBuilder, TextBuilder};
+///
+/// let toolbar = RowBuilder::new()
+///     .child(TextBuilder::new("Inbox").build())
+///     .child(SpacerBuilder::new().build())
+///     .child(ButtonBuilder::new("Edit", PropValue::action("edit")).build())
+///     .build();
+///
+/// assert_eq!(toolbar.children[1].component_type, "Spacer");
+/// ```
+pub struct SpacerBuilder {
+    size: Option<f64>,
+    common: CommonFields,
+}
+
+impl SpacerBuilder {
+    /// A flexible spacer.
+    pub fn new() -> Self {
+        Self {
+            size: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Fix the spacer to `size` logical pixels.
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Spacer");
+        if let Some(size) = self.size {
+            node.set_prop("size", PropValue::Number(size));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for SpacerBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+impl Default for SpacerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// Convert an `Alignment` enum to a `PropValue` for the Surface tree.
+fn alignment_to_prop(align: Alignment) -> PropValue {
+    PropValue::String(align.as_str().to_string())
+}
+
+/// Convert an `Edges` value to a `PropValue` for the Surface tree.
+///
+/// - `Uniform(n)` → `PropValue::Number(n)` (number literal coercion)
+/// - `Sides { top, bottom, start, end }` → `PropValue::Record { top, bottom, start, end }`
+fn edges_to_prop(edges: Edges) -> PropValue {
+    match edges {
+        Edges::Uniform(n) => PropValue::Number(n),
+        Edges::Sides { .. } => {
+            let s = serde_json::to_value(&edges).expect("Edges serialization should never fail");
+            serde_json::from_value(s).expect("Edges deserialization should never fail")
+        }
+    }
+}
+
+/// Convert a [`BorderStyle`] to a `PropValue::Record { width, color, style? }`.
+fn border_to_prop(border: &BorderStyle) -> PropValue {
+    let mut fields = BTreeMap::new();
+    fields.insert("width".to_string(), PropValue::Number(border.width));
+    let ColorValue { r, g, b, a } = border.color;
+    fields.insert("color".to_string(), PropValue::color(r, g, b, a));
+    if let Some(style) = &border.style {
+        fields.insert("style".to_string(), PropValue::String(style.clone()));
+    }
+    PropValue::Record(fields)
+}
+
+/// Convert a [`ShadowStyle`] to a
+/// `PropValue::Record { offset_x, offset_y, blur, color }`.
+pub fn shadow_to_prop(shadow: &ShadowStyle) -> PropValue {
+    let mut fields = BTreeMap::new();
+    fields.insert("offset_x".to_string(), PropValue::Number(shadow.offset_x));
+    fields.insert("offset_y".to_string(), PropValue::Number(shadow.offset_y));
+    fields.insert("blur".to_string(), PropValue::Number(shadow.blur));
+    let ColorValue { r, g, b, a } = shadow.color;
+    fields.insert("color".to_string(), PropValue::color(r, g, b, a));
+    PropValue::Record(fields)
+}
+
+/// Convert [`SafeAreaEdges`] to `true` (all edges) or a record of bools.
+fn safe_area_to_prop(edges: SafeAreaEdges) -> PropValue {
+    if edges.is_all() {
+        return PropValue::Bool(true);
+    }
+    let mut fields = BTreeMap::new();
+    fields.insert("top".to_string(), PropValue::Bool(edges.top));
+    fields.insert("bottom".to_string(), PropValue::Bool(edges.bottom));
+    fields.insert("start".to_string(), PropValue::Bool(edges.start));
+    fields.insert("end".to_string(), PropValue::Bool(edges.end));
+    PropValue::Record(fields)
+}
+
+/// Validate a `safe_area` prop: a bool or a record of edge bools.
+fn validate_safe_area(component: &str, val: &PropValue) -> Vec<String> {
+    let fields = match val {
+        PropValue::Bool(_) => return Vec::new(),
+        PropValue::Record(fields) => fields,
+        other => {
+            return vec![format!(
+                "{component}: 'safe_area' must be a bool or record, got {}",
+                other.type_name()
+            )]
+        }
+    };
+    let mut errors = Vec::new();
+    for (field, value) in fields {
+        if !matches!(field.as_str(), "top" | "bottom" | "start" | "end") {
+            errors.push(format!(
+                "{component}: 'safe_area' has unknown field '{field}'"
+            ));
+        } else if !matches!(value, PropValue::Bool(_)) {
+            errors.push(format!(
+                "{component}: 'safe_area.{field}' must be a bool, got {}",
+                value.type_name()
+            ));
+        }
+    }
+    errors
+}
+
+/// Validate a container `background`, `border`, or `shadow` prop (Column,
+/// Row, Scroll).
+fn validate_container_style(component: &str, key: &str, val: &PropValue) -> Vec<String> {
+    if key == "background" {
+        return if common::is_gradient(val) {
+            common::validate_gradient_prop(component, key, val)
+        } else if val.is_color() {
+            Vec::new()
+        } else {
+            vec![format!(
+                "{component}: 'background' must be a color, got {}",
+                val.type_name()
+            )]
+        };
+    }
+
+    let fields = match val {
+        PropValue::Record(fields) => fields,
+        other => {
+            return vec![format!(
+                "{component}: '{key}' must be a record, got {}",
+                other.type_name()
+            )]
+        }
+    };
+    if key == "shadow" {
+        return validate_shadow(component, fields);
+    }
+
+    let mut errors = Vec::new();
+    match fields.get("width") {
+        Some(PropValue::Number(n)) if *n >= 0.0 => {}
+        Some(other) => errors.push(format!(
+            "{component}: 'border.width' must be a non-negative number, got {other:?}"
+        )),
+        None => errors.push(format!(
+            "{component}: 'border' missing required field 'width'"
+        )),
+    }
+    match fields.get("color") {
+        Some(color) if color.is_color() => {}
+        Some(other) => errors.push(format!(
+            "{component}: 'border.color' must be a color, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!(
+            "{component}: 'border' missing required field 'color'"
+        )),
+    }
+    if let Some(style) = fields.get("style") {
+        if !matches!(style, PropValue::String(s) if matches!(s.as_str(), "solid" | "dashed" | "dotted"))
+        {
+            errors.push(format!(
+                "{component}: 'border.style' must be one of [solid, dashed, dotted], got {style:?}"
+            ));
+        }
+    }
+    for field in fields.keys() {
+        if !matches!(field.as_str(), "width" | "color" | "style") {
+            errors.push(format!("{component}: 'border' has unknown field '{field}'"));
+        }
+    }
+    errors
+}
+
+/// Validate the fields of a `shadow` record.
+fn validate_shadow(component: &str, fields: &BTreeMap<String, PropValue>) -> Vec<String> {
+    let mut errors = Vec::new();
+    for field in ["offset_x", "offset_y", "blur"] {
+        match fields.get(field) {
+            Some(PropValue::Number(n)) if field != "blur" || *n >= 0.0 => {}
+            Some(PropValue::Number(n)) => errors.push(format!(
+                "{component}: 'shadow.blur' must be non-negative, got {n}"
+            )),
+            Some(other) => errors.push(format!(
+                "{component}: 'shadow.{field}' must be a number, got {}",
+                other.type_name()
+            )),
+            None => errors.push(format!(
+                "{component}: 'shadow' missing required field '{field}'"
+            )),
+        }
+    }
+    match fields.get("color") {
+        Some(color) if color.is_color() => {}
+        Some(other) => errors.push(format!(
+            "{component}: 'shadow.color' must be a color, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!(
+            "{component}: 'shadow' missing required field 'color'"
+        )),
+    }
+    for field in fields.keys() {
+        if !matches!(field.as_str(), "offset_x" | "offset_y" | "blur" | "color") {
+            errors.push(format!("{component}: 'shadow' has unknown field '{field}'"));
+        }
+    }
+    errors
+}
+
+/// Convert a [`ScrollTarget`] to a `PropValue` (string anchor or number offset).
+pub(crate) fn scroll_target_to_prop(target: &ScrollTarget) -> PropValue {
+    match target {
+        ScrollTarget::Anchor(key) => PropValue::String(key.clone()),
+        ScrollTarget::Offset(offset) => PropValue::Number(*offset),
+    }
+}
+
+/// Whether any descendant of `node` carries `anchor: key`.
+fn has_anchor(node: &SurfaceNode, key: &str) -> bool {
+    node.children.iter().any(|child| {
+        matches!(child.props.get("anchor"), Some(PropValue::String(a)) if a == key)
+            || has_anchor(child, key)
+    })
+}
+
+/// Validate that a component node has valid prop types.
+///
+/// Returns a list of validation errors. Empty means valid.
+pub fn validate_layout_node(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    match node.component_type.as_str() {
+        "Column" | "Row" => {
+            for (key, val) in &node.props {
+                match key.as_str() {
+                    "spacing" => {
+                        errors.extend(common::validate_dimension_prop(
+                            &node.component_type,
+                            key,
+                            val,
+                        ));
                     }
                     "align" => {
                         if let PropValue::String(s) = val {
@@ -310,9 +1715,42 @@ pub fn validate_layout_node(node: &SurfaceNode) -> Vec<String> {
                             ));
                         }
                     }
+                    "wrap" if node.component_type == "Row" => {
+                        if !matches!(val, PropValue::Bool(_)) {
+                            errors.push(format!(
+                                "Row: 'wrap' must be a bool, got {}",
+                                val.type_name()
+                            ));
+                        }
+                    }
+                    "run_spacing" if node.component_type == "Row" => {
+                        errors.extend(common::validate_dimension_prop("Row", key, val));
+                        if node.props.get("wrap") != Some(&PropValue::Bool(true)) {
+                            errors.push("Row: 'run_spacing' requires 'wrap: true'".to_string());
+                        }
+                    }
+                    "justify" => {
+                        if let PropValue::String(s) = val {
+                            let valid = ["start", "center", "end", "space_between", "space_around"];
+                            if !valid.contains(&s.as_str()) {
+                                errors.push(format!(
+                                    "{}: invalid justify '{s}'",
+                                    node.component_type
+                                ));
+                            }
+                        } else {
+                            errors.push(format!(
+                                "{}: 'justify' must be a string, got {}",
+                                node.component_type,
+                                val.type_name()
+                            ));
+                        }
+                    }
                     "padding" => {
                         // Number (Uniform coercion) or Record (Sides)
-                        if !matches!(val, PropValue::Number(_) | PropValue::Record(_)) {
+                        if !matches!(val, PropValue::Number(_) | PropValue::Record(_))
+                            && !val.is_theme_token("spacing")
+                        {
                             errors.push(format!(
                                 "{}: 'padding' must be a number or record, got {}",
                                 node.component_type,
@@ -320,17 +1758,50 @@ pub fn validate_layout_node(node: &SurfaceNode) -> Vec<String> {
                             ));
                         }
                     }
+                    "safe_area" => {
+                        errors.extend(validate_safe_area(&node.component_type, val));
+                    }
+                    "background" | "border" | "shadow" => {
+                        errors.extend(validate_container_style(&node.component_type, key, val));
+                    }
+                    "corner_radius" => {
+                        errors.extend(common::validate_corner_radius_prop(
+                            &node.component_type,
+                            val,
+                        ));
+                    }
+                    "on_tap" | "on_double_tap" | "on_swipe" | "on_long_press" => {
+                        if !matches!(val, PropValue::ActionRef { .. }) {
+                            errors.push(format!(
+                                "{}: '{key}' must be an action, got {}",
+                                node.component_type,
+                                val.type_name()
+                            ));
+                        }
+                    }
                     "accessible" => {
                         errors.extend(accessibility::validate_accessible_prop(
                             &node.component_type,
                             val,
                         ));
                     }
+                    other if common::is_common_prop(other) => {}
                     other => {
                         errors.push(format!("{}: unknown prop '{other}'", node.component_type));
                     }
                 }
             }
+            // Legacy main-axis `align` values are ambiguous next to `justify`
+            if let (Some(PropValue::String(align)), true) =
+                (node.props.get("align"), node.props.contains_key("justify"))
+            {
+                if matches!(align.as_str(), "space_between" | "space_around") {
+                    errors.push(format!(
+                        "{}: 'align' main-axis value '{align}' conflicts with 'justify'",
+                        node.component_type
+                    ));
+                }
+            }
         }
         "Scroll" => {
             for (key, val) in &node.props {
@@ -348,16 +1819,484 @@ pub fn validate_layout_node(node: &SurfaceNode) -> Vec<String> {
                             ));
                         }
                     }
+                    "show_indicators" | "bounce" | "paging" | "avoid_keyboard" => {
+                        if !matches!(val, PropValue::Bool(_)) {
+                            errors.push(format!(
+                                "Scroll: '{key}' must be a bool, got {}",
+                                val.type_name()
+                            ));
+                        }
+                    }
+                    "scroll_to" => match val {
+                        PropValue::String(s) if s.is_empty() => {
+                            errors.push("Scroll: 'scroll_to' anchor must not be empty".to_string());
+                        }
+                        PropValue::String(s) => {
+                            if !has_anchor(node, s) {
+                                errors.push(format!(
+                                    "Scroll: 'scroll_to' anchor '{s}' not found among descendants"
+                                ));
+                            }
+                        }
+                        PropValue::Number(n) if *n < 0.0 => {
+                            errors.push(format!(
+                                "Scroll: 'scroll_to' offset must be non-negative, got {n}"
+                            ));
+                        }
+                        PropValue::Number(_) => {}
+                        other => {
+                            errors.push(format!(
+                                "Scroll: 'scroll_to' must be a string (anchor) or number (offset), got {}",
+                                other.type_name()
+                            ));
+                        }
+                    },
+                    "on_scroll" => {
+                        if !matches!(val, PropValue::Lambda { .. }) {
+                            errors.push(format!(
+                                "Scroll: 'on_scroll' must be a lambda, got {}",
+                                val.type_name()
+                            ));
+                        }
+                    }
+                    "scroll_event_interval" => {
+                        if !matches!(val, PropValue::Number(n) if *n >= 0.0) {
+                            errors.push(format!(
+                                "Scroll: 'scroll_event_interval' must be a non-negative number, got {:?}",
+                                val
+                            ));
+                        }
+                        if !node.props.contains_key("on_scroll") {
+                            errors.push(
+                                "Scroll: 'scroll_event_interval' requires 'on_scroll'".to_string(),
+                            );
+                        }
+                    }
+                    "safe_area" => {
+                        errors.extend(validate_safe_area(&node.component_type, val));
+                    }
+                    "background" | "border" | "shadow" => {
+                        errors.extend(validate_container_style("Scroll", key, val));
+                    }
                     "accessible" => {
                         errors.extend(accessibility::validate_accessible_prop("Scroll", val));
                     }
+                    other if common::is_common_prop(other) => {}
                     other => {
                         errors.push(format!("Scroll: unknown prop '{other}'"));
                     }
                 }
             }
         }
-        _ => {} // Not a layout component — skip validation
+        "Tabs" => errors.extend(validate_tabs(node)),
+        "Carousel" => errors.extend(validate_carousel(node)),
+        "Disclosure" => errors.extend(validate_disclosure(node)),
+        "Overlay" => errors.extend(validate_overlay(node)),
+        "AppBar" => errors.extend(validate_app_bar(node)),
+        "Divider" | "Spacer" => {
+            for (key, val) in &node.props {
+                match (node.component_type.as_str(), key.as_str()) {
+                    ("Divider", "thickness" | "inset") | ("Spacer", "size") => {
+                        if !matches!(val, PropValue::Number(n) if *n >= 0.0) {
+                            errors.push(format!(
+                                "{}: '{key}' must be a non-negative number, got {:?}",
+                                node.component_type, val
+                            ));
+                        }
+                    }
+                    ("Divider", "color") => {
+                        if !val.is_color() {
+                            errors.push(format!(
+                                "Divider: 'color' must be a color, got {}",
+                                val.type_name()
+                            ));
+                        }
+                    }
+                    (_, "accessible") => {
+                        errors.extend(accessibility::validate_accessible_prop(
+                            &node.component_type,
+                            val,
+                        ));
+                    }
+                    (_, other) if common::is_common_prop(other) => {}
+                    (_, other) => {
+                        errors.push(format!("{}: unknown prop '{other}'", node.component_type));
+                    }
+                }
+            }
+            if !node.children.is_empty() {
+                errors.push(format!(
+                    "{}: does not accept children, but got {}",
+                    node.component_type,
+                    node.children.len()
+                ));
+            }
+        }
+        _ => return errors, // Not a layout component — skip validation
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    errors
+}
+
+fn validate_tabs(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: tabs (non-empty list of strings)
+    let tab_count = match node.props.get("tabs") {
+        Some(PropValue::List(tabs)) => {
+            if tabs.is_empty() {
+                errors.push("Tabs: 'tabs' must not be empty".to_string());
+            }
+            for (i, tab) in tabs.iter().enumerate() {
+                if !tab.is_text() {
+                    errors.push(format!(
+                        "Tabs: 'tabs[{i}]' must be a string, got {}",
+                        tab.type_name()
+                    ));
+                }
+            }
+            Some(tabs.len())
+        }
+        Some(other) => {
+            errors.push(format!(
+                "Tabs: 'tabs' must be a list, got {}",
+                other.type_name()
+            ));
+            None
+        }
+        None => {
+            errors.push("Tabs: missing required prop 'tabs'".to_string());
+            None
+        }
+    };
+
+    // Required: selected (index into tabs)
+    match node.props.get("selected") {
+        Some(PropValue::Number(n)) if n.fract() != 0.0 || *n < 0.0 => {
+            errors.push(format!(
+                "Tabs: 'selected' must be a non-negative integer, got {n}"
+            ));
+        }
+        Some(PropValue::Number(n)) => {
+            if let Some(count) = tab_count.filter(|&count| *n as usize >= count && count > 0) {
+                errors.push(format!(
+                    "Tabs: 'selected' index {n} out of bounds for {count} tabs"
+                ));
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Tabs: 'selected' must be a number, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Tabs: missing required prop 'selected'".to_string()),
+    }
+
+    // Required: on_select (action or lambda)
+    match node.props.get("on_select") {
+        Some(PropValue::ActionRef { .. } | PropValue::Lambda { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Tabs: 'on_select' must be an action or lambda, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Tabs: missing required prop 'on_select'".to_string()),
+    }
+
+    // One panel per tab
+    if let Some(count) = tab_count {
+        if node.children.len() != count {
+            errors.push(format!(
+                "Tabs: expected {count} panels (one per tab), got {}",
+                node.children.len()
+            ));
+        }
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Tabs", prop));
+    }
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "tabs" | "selected" | "on_select" | "accessible"
+            )
+        {
+            errors.push(format!("Tabs: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_carousel(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+    let pages = node.children.len();
+
+    // Required: index (page index)
+    match node.props.get("index") {
+        Some(PropValue::Number(n)) if n.fract() != 0.0 || *n < 0.0 => {
+            errors.push(format!(
+                "Carousel: 'index' must be a non-negative integer, got {n}"
+            ));
+        }
+        Some(PropValue::Number(n)) => {
+            if pages > 0 && *n as usize >= pages {
+                errors.push(format!(
+                    "Carousel: 'index' {n} out of bounds for {pages} pages"
+                ));
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Carousel: 'index' must be a number, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Carousel: missing required prop 'index'".to_string()),
+    }
+
+    // Required: on_change (lambda)
+    match node.props.get("on_change") {
+        Some(PropValue::Lambda { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Carousel: 'on_change' must be a lambda, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Carousel: missing required prop 'on_change'".to_string()),
+    }
+
+    // Optional: show_indicators (bool)
+    if let Some(val) = node.props.get("show_indicators") {
+        if !matches!(val, PropValue::Bool(_)) {
+            errors.push(format!(
+                "Carousel: 'show_indicators' must be a bool, got {}",
+                val.type_name()
+            ));
+        }
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Carousel", prop));
+    }
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "index" | "on_change" | "show_indicators" | "accessible"
+            )
+        {
+            errors.push(format!("Carousel: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_disclosure(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: title (string)
+    match node.props.get("title") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Disclosure: 'title' must be a string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Disclosure: missing required prop 'title'".to_string()),
+    }
+
+    // Required: expanded (bool)
+    match node.props.get("expanded") {
+        Some(PropValue::Bool(_)) => {}
+        Some(other) => errors.push(format!(
+            "Disclosure: 'expanded' must be a bool, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Disclosure: missing required prop 'expanded'".to_string()),
+    }
+
+    // Required: on_toggle (action or lambda)
+    match node.props.get("on_toggle") {
+        Some(PropValue::ActionRef { .. } | PropValue::Lambda { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Disclosure: 'on_toggle' must be an action or lambda, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Disclosure: missing required prop 'on_toggle'".to_string()),
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Disclosure", prop));
+    }
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "title" | "expanded" | "on_toggle" | "accessible"
+            )
+        {
+            errors.push(format!("Disclosure: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_overlay(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for (key, val) in &node.props {
+        match key.as_str() {
+            "layers" => match val {
+                PropValue::List(layers) => {
+                    if layers.len() != node.children.len() {
+                        errors.push(format!(
+                            "Overlay: expected {} layers (one per child), got {}",
+                            node.children.len(),
+                            layers.len()
+                        ));
+                    }
+                    for (i, layer) in layers.iter().enumerate() {
+                        errors.extend(validate_overlay_layer(i, layer));
+                    }
+                }
+                other => errors.push(format!(
+                    "Overlay: 'layers' must be a list, got {}",
+                    other.type_name()
+                )),
+            },
+            "accessible" => {
+                errors.extend(accessibility::validate_accessible_prop("Overlay", val));
+            }
+            other if common::is_common_prop(other) => {}
+            other => errors.push(format!("Overlay: unknown prop '{other}'")),
+        }
+    }
+
+    errors
+}
+
+/// Validate one `{ align, offset? }` record of an Overlay's `layers`.
+fn validate_overlay_layer(index: usize, layer: &PropValue) -> Vec<String> {
+    let mut errors = Vec::new();
+    let PropValue::Record(fields) = layer else {
+        return vec![format!(
+            "Overlay: 'layers[{index}]' must be a record, got {}",
+            layer.type_name()
+        )];
+    };
+    for (key, val) in fields {
+        match key.as_str() {
+            "align" => match val {
+                PropValue::String(s) if OVERLAY_ALIGNMENTS.contains(&s.as_str()) => {}
+                _ => errors.push(format!(
+                    "Overlay: 'layers[{index}].align' must be one of [{}], got {:?}",
+                    OVERLAY_ALIGNMENTS.join(", "),
+                    val
+                )),
+            },
+            "offset" => {
+                let valid = matches!(val, PropValue::Record(offset)
+                    if offset.len() == 2
+                        && matches!(offset.get("x"), Some(PropValue::Number(_)))
+                        && matches!(offset.get("y"), Some(PropValue::Number(_))));
+                if !valid {
+                    errors.push(format!(
+                        "Overlay: 'layers[{index}].offset' must be a record {{ x: number, y: number }}, got {:?}",
+                        val
+                    ));
+                }
+            }
+            other => errors.push(format!("Overlay: unknown field 'layers[{index}].{other}'")),
+        }
+    }
+    if !fields.contains_key("align") {
+        errors.push(format!(
+            "Overlay: 'layers[{index}]' missing required field 'align'"
+        ));
+    }
+    errors
+}
+
+fn validate_app_bar(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: title (string)
+    match node.props.get("title") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "AppBar: 'title' must be a string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("AppBar: missing required prop 'title'".to_string()),
+    }
+
+    // Optional: elevated (bool)
+    if let Some(val) = node.props.get("elevated") {
+        if !matches!(val, PropValue::Bool(_)) {
+            errors.push(format!(
+                "AppBar: 'elevated' must be a bool, got {}",
+                val.type_name()
+            ));
+        }
+    }
+
+    // slots: one per child, at most one leading, leading first
+    match node.props.get("slots") {
+        Some(PropValue::List(slots)) => {
+            if slots.len() != node.children.len() {
+                errors.push(format!(
+                    "AppBar: expected {} slots (one per child), got {}",
+                    node.children.len(),
+                    slots.len()
+                ));
+            }
+            for (i, slot) in slots.iter().enumerate() {
+                match slot {
+                    PropValue::String(s) if s == "leading" && i > 0 => errors.push(format!(
+                        "AppBar: 'slots[{i}]' is leading, but the leading child must come first"
+                    )),
+                    PropValue::String(s) if s == "leading" || s == "trailing" => {}
+                    _ => errors.push(format!(
+                        "AppBar: 'slots[{i}]' must be one of [leading, trailing], got {:?}",
+                        slot
+                    )),
+                }
+            }
+        }
+        Some(other) => errors.push(format!(
+            "AppBar: 'slots' must be a list, got {}",
+            other.type_name()
+        )),
+        None if !node.children.is_empty() => errors.push(format!(
+            "AppBar: missing required prop 'slots' for {} children",
+            node.children.len()
+        )),
+        None => {}
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("AppBar", prop));
+    }
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(key.as_str(), "title" | "elevated" | "slots" | "accessible")
+        {
+            errors.push(format!("AppBar: unknown prop '{key}'"));
+        }
     }
 
     errors
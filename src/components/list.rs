@@ -1,9 +1,17 @@
-//! List & Data component builder — ScrollList.
+//! List & Data component builders — ScrollList, Table.
 //!
 //! ScrollList renders a scrollable list of items using a `render` lambda
 //! and a `key` function for identity. Items come from a list prop, not children.
+//!
+//! Table renders `rows` (records) under `columns` (header definitions), one
+//! cell per row and column via a `render_cell` lambda.
+
+use std::collections::BTreeMap;
 
 use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
+use crate::components::content::TextAlign;
+use crate::components::layout::{scroll_target_to_prop, ScrollTarget};
 use crate::prop_value::PropValue;
 use crate::surface::SurfaceNode;
 
@@ -12,13 +20,18 @@ use crate::surface::SurfaceNode;
 /// Builder for a ScrollList component.
 ///
 /// Required: `items` (List), `render` (Lambda), `key` (Lambda).
-/// Optional: `on_reorder` (Lambda), `dividers` (bool).
+/// Optional: `on_reorder` (Lambda), `dividers` (bool), `scroll_to`
+/// (item key or offset), `on_scroll` (Lambda), `scroll_event_interval` (ms).
 pub struct ScrollListBuilder {
     items: PropValue,
     render: PropValue,
     key: PropValue,
     on_reorder: Option<PropValue>,
     dividers: Option<bool>,
+    scroll_to: Option<ScrollTarget>,
+    on_scroll: Option<PropValue>,
+    scroll_event_interval: Option<f64>,
+    common: CommonFields,
 }
 
 impl ScrollListBuilder {
@@ -34,6 +47,10 @@ impl ScrollListBuilder {
             key,
             on_reorder: None,
             dividers: None,
+            scroll_to: None,
+            on_scroll: None,
+            scroll_event_interval: None,
+            common: CommonFields::default(),
         }
     }
 
@@ -49,6 +66,24 @@ impl ScrollListBuilder {
         self
     }
 
+    /// Scroll to an item key ([`ScrollTarget::Anchor`]) or offset.
+    pub fn scroll_to(mut self, target: ScrollTarget) -> Self {
+        self.scroll_to = Some(target);
+        self
+    }
+
+    /// Set the scroll callback (Lambda called with the current offset).
+    pub fn on_scroll(mut self, on_scroll: PropValue) -> Self {
+        self.on_scroll = Some(on_scroll);
+        self
+    }
+
+    /// Throttle `on_scroll` to at most one call per `interval` milliseconds.
+    pub fn scroll_event_interval(mut self, interval: f64) -> Self {
+        self.scroll_event_interval = Some(interval);
+        self
+    }
+
     pub fn build(self) -> SurfaceNode {
         let mut node = SurfaceNode::new("ScrollList");
         node.set_prop("items", self.items);
@@ -60,17 +95,193 @@ impl ScrollListBuilder {
         if let Some(dividers) = self.dividers {
             node.set_prop("dividers", PropValue::Bool(dividers));
         }
+        if let Some(target) = &self.scroll_to {
+            node.set_prop("scroll_to", scroll_target_to_prop(target));
+        }
+        if let Some(on_scroll) = self.on_scroll {
+            node.set_prop("on_scroll", on_scroll);
+        }
+        if let Some(interval) = self.scroll_event_interval {
+            node.set_prop("scroll_event_interval", PropValue::Number(interval));
+        }
+        self.common.apply(&mut node);
         accessibility::ensure_accessible(&mut node);
         node
     }
 }
 
+impl CommonProps for ScrollListBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── TableColumn ───────────────────────────────────────────────────────────────
+
+/// A Table column header definition.
+///
+/// Serialized as a record `{ key, title, width?, align? }`. `key` names the
+/// row field the column shows and must be unique within the table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    pub key: String,
+    pub title: String,
+    /// Fixed width in logical pixels (columns share the rest otherwise).
+    pub width: Option<f64>,
+    pub align: Option<TextAlign>,
+}
+
+impl TableColumn {
+    /// Create a column for row field `key` with header `title`.
+    pub fn new(key: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            title: title.into(),
+            width: None,
+            align: None,
+        }
+    }
+
+    /// Fix the column width in logical pixels.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the header and cell alignment.
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Convert to a `PropValue::Record` for the Surface tree.
+    pub fn to_prop_value(&self) -> PropValue {
+        let mut fields = BTreeMap::new();
+        fields.insert("key".to_string(), PropValue::String(self.key.clone()));
+        fields.insert("title".to_string(), PropValue::String(self.title.clone()));
+        if let Some(width) = self.width {
+            fields.insert("width".to_string(), PropValue::Number(width));
+        }
+        if let Some(align) = self.align {
+            fields.insert(
+                "align".to_string(),
+                PropValue::String(align.as_str().to_string()),
+            );
+        }
+        PropValue::Record(fields)
+    }
+}
+
+// ── TableBuilder ──────────────────────────────────────────────────────────────
+
+/// Builder for a Table component.
+///
+/// Required: `columns` (List of [`TableColumn`] records), `rows` (List of
+/// records), `render_cell` (Lambda). Optional: `sortable` (bool), `on_sort`
+/// (Lambda).
+///
+/// The host calls `render_cell(row, column_key) -> Surface` for every cell.
+/// Every row must have a field for every column key; extra fields (e.g. row
+/// IDs) are allowed. Sortable tables make headers tappable and call
+/// `on_sort(column_key, direction)` with `"ascending"` or `"descending"`;
+/// sorting the rows is up to the app. Hosts map Table to native tables and
+/// the ARIA grid pattern.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{PropValue, TableBuilder, TableColumn};
+/// use std::collections::BTreeMap;
+///
+/// let row = BTreeMap::from([
+///     ("name".to_string(), PropValue::String("Tea".into())),
+///     ("price".to_string(), PropValue::Number(3.5)),
+/// ]);
+/// let node = TableBuilder::new(
+///     vec![TableColumn::new("name", "Item"), TableColumn::new("price", "Price")],
+///     PropValue::List(vec![PropValue::Record(row)]),
+///     PropValue::lambda(1),
+/// )
+/// .build();
+///
+/// assert_eq!(node.component_type, "Table");
+/// ```
+pub struct TableBuilder {
+    columns: Vec<TableColumn>,
+    rows: PropValue,
+    render_cell: PropValue,
+    sortable: Option<bool>,
+    on_sort: Option<PropValue>,
+    common: CommonFields,
+}
+
+impl TableBuilder {
+    /// Create a new TableBuilder with required props.
+    ///
+    /// - `rows` must be a `PropValue::List` of records.
+    /// - `render_cell` must be a `PropValue::Lambda` — called
+    ///   `(row, column_key) -> Surface`.
+    pub fn new(columns: Vec<TableColumn>, rows: PropValue, render_cell: PropValue) -> Self {
+        Self {
+            columns,
+            rows,
+            render_cell,
+            sortable: None,
+            on_sort: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set whether column headers can be tapped to sort.
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = Some(sortable);
+        self
+    }
+
+    /// Set the `on_sort` callback (Lambda called with the column key and
+    /// direction).
+    pub fn on_sort(mut self, on_sort: PropValue) -> Self {
+        self.on_sort = Some(on_sort);
+        self
+    }
+
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Table");
+        node.set_prop(
+            "columns",
+            PropValue::List(
+                self.columns
+                    .iter()
+                    .map(TableColumn::to_prop_value)
+                    .collect(),
+            ),
+        );
+        node.set_prop("rows", self.rows);
+        node.set_prop("render_cell", self.render_cell);
+        if let Some(sortable) = self.sortable {
+            node.set_prop("sortable", PropValue::Bool(sortable));
+        }
+        if let Some(on_sort) = self.on_sort {
+            node.set_prop("on_sort", on_sort);
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for TableBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
 // ── Validation ────────────────────────────────────────────────────────────────
 
-/// Validate a list/data component node (ScrollList).
+/// Validate a list/data component node (ScrollList or Table).
 pub fn validate_list_node(node: &SurfaceNode) -> Vec<String> {
     match node.component_type.as_str() {
         "ScrollList" => validate_scroll_list(node),
+        "Table" => validate_table(node),
         _ => vec![format!("Unknown list component: {}", node.component_type)],
     }
 }
@@ -128,6 +339,48 @@ fn validate_scroll_list(node: &SurfaceNode) -> Vec<String> {
         }
     }
 
+    // Optional: scroll_to (item key string or non-negative offset)
+    match node.props.get("scroll_to") {
+        Some(PropValue::String(s)) if s.is_empty() => {
+            errors.push("ScrollList.scroll_to: item key must not be empty".to_string())
+        }
+        Some(PropValue::Number(n)) if *n < 0.0 => errors.push(format!(
+            "ScrollList.scroll_to: offset must be non-negative, got {n}"
+        )),
+        Some(PropValue::String(_)) | Some(PropValue::Number(_)) | None => {}
+        Some(other) => errors.push(format!(
+            "ScrollList.scroll_to: expected string or number, got {}",
+            other.type_name()
+        )),
+    }
+
+    // Optional: on_scroll (lambda)
+    if let Some(prop) = node.props.get("on_scroll") {
+        if !matches!(prop, PropValue::Lambda { .. }) {
+            errors.push(format!(
+                "ScrollList.on_scroll: expected lambda, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // Optional: scroll_event_interval (non-negative number, needs on_scroll)
+    if let Some(prop) = node.props.get("scroll_event_interval") {
+        match prop {
+            PropValue::Number(n) if *n >= 0.0 => {}
+            PropValue::Number(n) => errors.push(format!(
+                "ScrollList.scroll_event_interval: must be non-negative, got {n}"
+            )),
+            other => errors.push(format!(
+                "ScrollList.scroll_event_interval: expected number, got {}",
+                other.type_name()
+            )),
+        }
+        if !node.props.contains_key("on_scroll") {
+            errors.push("ScrollList.scroll_event_interval: requires on_scroll".to_string());
+        }
+    }
+
     // No children (items rendered via render lambda)
     if !node.children.is_empty() {
         errors.push(format!(
@@ -141,15 +394,203 @@ fn validate_scroll_list(node: &SurfaceNode) -> Vec<String> {
         errors.extend(accessibility::validate_accessible_prop("ScrollList", prop));
     }
 
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
     // Unknown props
     for key in node.props.keys() {
-        if !matches!(
-            key.as_str(),
-            "items" | "render" | "key" | "on_reorder" | "dividers" | "accessible"
-        ) {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "items"
+                    | "render"
+                    | "key"
+                    | "on_reorder"
+                    | "dividers"
+                    | "scroll_to"
+                    | "on_scroll"
+                    | "scroll_event_interval"
+                    | "accessible"
+            )
+        {
             errors.push(format!("ScrollList: unknown prop '{key}'"));
         }
     }
 
     errors
 }
+
+fn validate_table(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: columns (list of { key, title, width?, align? })
+    let mut column_keys = Vec::new();
+    match node.props.get("columns") {
+        Some(PropValue::List(columns)) => {
+            if columns.is_empty() {
+                errors.push("Table.columns: must not be empty".to_string());
+            }
+            for (i, column) in columns.iter().enumerate() {
+                errors.extend(validate_table_column(i, column, &mut column_keys));
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Table.columns: expected list, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Table.columns: required prop missing".to_string()),
+    }
+
+    // Required: rows (list of records with a field per column)
+    match node.props.get("rows") {
+        Some(PropValue::List(rows)) => {
+            for (i, row) in rows.iter().enumerate() {
+                match row {
+                    PropValue::Record(fields) => {
+                        for key in &column_keys {
+                            if !fields.contains_key(key) {
+                                errors.push(format!("Table.rows[{i}]: missing column '{key}'"));
+                            }
+                        }
+                    }
+                    other => errors.push(format!(
+                        "Table.rows[{i}]: expected record, got {}",
+                        other.type_name()
+                    )),
+                }
+            }
+        }
+        Some(other) => errors.push(format!(
+            "Table.rows: expected list, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Table.rows: required prop missing".to_string()),
+    }
+
+    // Required: render_cell (lambda)
+    match node.props.get("render_cell") {
+        Some(PropValue::Lambda { .. }) => {}
+        Some(other) => errors.push(format!(
+            "Table.render_cell: expected lambda, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Table.render_cell: required prop missing".to_string()),
+    }
+
+    // Optional: sortable (bool, needs on_sort)
+    match node.props.get("sortable") {
+        Some(PropValue::Bool(true)) if !node.props.contains_key("on_sort") => {
+            errors.push("Table.sortable: requires on_sort".to_string())
+        }
+        Some(PropValue::Bool(_)) | None => {}
+        Some(other) => errors.push(format!(
+            "Table.sortable: expected bool, got {}",
+            other.type_name()
+        )),
+    }
+
+    // Optional: on_sort (lambda)
+    if let Some(prop) = node.props.get("on_sort") {
+        if !matches!(prop, PropValue::Lambda { .. }) {
+            errors.push(format!(
+                "Table.on_sort: expected lambda, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children (cells rendered via render_cell lambda)
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Table: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Table", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "columns" | "rows" | "render_cell" | "sortable" | "on_sort" | "accessible"
+            )
+        {
+            errors.push(format!("Table: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Validate one column record, collecting its key into `keys`.
+fn validate_table_column(index: usize, column: &PropValue, keys: &mut Vec<String>) -> Vec<String> {
+    let prefix = format!("Table.columns[{index}]");
+    let PropValue::Record(fields) = column else {
+        return vec![format!(
+            "{prefix}: expected record, got {}",
+            column.type_name()
+        )];
+    };
+    let mut errors = Vec::new();
+
+    match fields.get("key") {
+        Some(PropValue::String(key)) if key.is_empty() => {
+            errors.push(format!("{prefix}.key: must not be empty"))
+        }
+        Some(PropValue::String(key)) if keys.contains(key) => {
+            errors.push(format!("{prefix}.key: duplicate key '{key}'"))
+        }
+        Some(PropValue::String(key)) => keys.push(key.clone()),
+        Some(other) => errors.push(format!(
+            "{prefix}.key: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("{prefix}.key: required field missing")),
+    }
+
+    match fields.get("title") {
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "{prefix}.title: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push(format!("{prefix}.title: required field missing")),
+    }
+
+    match fields.get("width") {
+        Some(PropValue::Number(n)) if *n < 0.0 => {
+            errors.push(format!("{prefix}.width: must be non-negative, got {n}"))
+        }
+        Some(PropValue::Number(_)) | None => {}
+        Some(other) => errors.push(format!(
+            "{prefix}.width: expected number, got {}",
+            other.type_name()
+        )),
+    }
+
+    if let Some(prop) = fields.get("align") {
+        match prop {
+            PropValue::String(s) if matches!(s.as_str(), "start" | "center" | "end") => {}
+            _ => errors.push(format!(
+                "{prefix}.align: expected one of [start, center, end], got {:?}",
+                prop
+            )),
+        }
+    }
+
+    for key in fields.keys() {
+        if !matches!(key.as_str(), "key" | "title" | "width" | "align") {
+            errors.push(format!("{prefix}: unknown field '{key}'"));
+        }
+    }
+
+    errors
+}
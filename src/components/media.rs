@@ -0,0 +1,419 @@
+//! Media component builders — Video, AudioPlayer.
+//!
+//! These are leaf components with no children. The host maps them to its
+//! native player (AVPlayer, ExoPlayer, `<video>`); playback itself never
+//! touches the Surface tree.
+
+use crate::accessibility;
+use crate::components::common::{self, CommonFields, CommonProps};
+use crate::prop_value::PropValue;
+use crate::surface::SurfaceNode;
+
+// ── VideoBuilder ──────────────────────────────────────────────────────────────
+
+/// Builder for the `Video` component.
+///
+/// `Video` is a leaf component (no children) that plays a video from a URL
+/// (`https://`, `http://`) or a bundled asset (`asset:<name>`). `label`
+/// describes the video for assistive technology and is required.
+///
+/// Hosts only autoplay muted video, so `autoplay: true` requires
+/// `muted: true`.
+///
+/// # Example
+/// ```
+/// use pepl_ui::VideoBuilder;
+///
+/// let node = VideoBuilder::new("https://example.com/intro.mp4", "Product tour")
+///     .poster("asset:intro_poster")
+///     .autoplay(true)
+///     .muted(true)
+///     .looping(true)
+///     .build();
+///
+/// assert_eq!(node.component_type, "Video");
+/// ```
+pub struct VideoBuilder {
+    source: String,
+    label: String,
+    autoplay: Option<bool>,
+    looping: Option<bool>,
+    muted: Option<bool>,
+    controls: Option<bool>,
+    poster: Option<String>,
+    common: CommonFields,
+}
+
+impl VideoBuilder {
+    /// Create a new `VideoBuilder` with the required `source` and accessible
+    /// `label` props.
+    pub fn new(source: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            label: label.into(),
+            autoplay: None,
+            looping: None,
+            muted: None,
+            controls: None,
+            poster: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Start playing as soon as the video is shown (requires `muted`).
+    pub fn autoplay(mut self, autoplay: bool) -> Self {
+        self.autoplay = Some(autoplay);
+        self
+    }
+
+    /// Restart from the beginning when playback ends (the `loop` prop).
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = Some(looping);
+        self
+    }
+
+    /// Start with the sound off.
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.muted = Some(muted);
+        self
+    }
+
+    /// Show the host's playback controls (hosts default to `true`).
+    pub fn controls(mut self, controls: bool) -> Self {
+        self.controls = Some(controls);
+        self
+    }
+
+    /// Set the image shown before playback starts (a URL or `asset:<name>`).
+    pub fn poster(mut self, poster: impl Into<String>) -> Self {
+        self.poster = Some(poster.into());
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("Video");
+        node.set_prop("source", PropValue::String(self.source));
+        node.set_prop("label", PropValue::String(self.label));
+        if let Some(autoplay) = self.autoplay {
+            node.set_prop("autoplay", PropValue::Bool(autoplay));
+        }
+        if let Some(looping) = self.looping {
+            node.set_prop("loop", PropValue::Bool(looping));
+        }
+        if let Some(muted) = self.muted {
+            node.set_prop("muted", PropValue::Bool(muted));
+        }
+        if let Some(controls) = self.controls {
+            node.set_prop("controls", PropValue::Bool(controls));
+        }
+        if let Some(poster) = self.poster {
+            node.set_prop("poster", PropValue::String(poster));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for VideoBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── AudioPlayerBuilder ────────────────────────────────────────────────────────
+
+/// Builder for the `AudioPlayer` component.
+///
+/// `AudioPlayer` is a leaf component (no children) that plays audio from a
+/// URL (`https://`, `http://`) or a bundled asset (`asset:<name>`). Playback
+/// state lives in the app: `playing` says whether audio should be playing,
+/// and the host invokes `on_toggle` when the user presses play or pause.
+///
+/// # Example
+/// ```
+/// use pepl_ui::{AudioPlayerBuilder, PropValue};
+///
+/// let node = AudioPlayerBuilder::new(
+///     "https://example.com/episode-12.mp3",
+///     false,
+///     PropValue::action("toggle_playback"),
+/// )
+/// .title("Episode 12")
+/// .artwork("asset:show_cover")
+/// .build();
+///
+/// assert_eq!(node.component_type, "AudioPlayer");
+/// ```
+pub struct AudioPlayerBuilder {
+    source: String,
+    playing: bool,
+    on_toggle: PropValue,
+    title: Option<String>,
+    artwork: Option<String>,
+    common: CommonFields,
+}
+
+impl AudioPlayerBuilder {
+    /// Create a new `AudioPlayerBuilder` with the required `source`,
+    /// `playing`, and `on_toggle` props.
+    ///
+    /// `on_toggle` must be a `PropValue::ActionRef` — use `PropValue::action()`.
+    pub fn new(source: impl Into<String>, playing: bool, on_toggle: PropValue) -> Self {
+        Self {
+            source: source.into(),
+            playing,
+            on_toggle,
+            title: None,
+            artwork: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    /// Set the track title (also the accessible label).
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the cover image (a URL or `asset:<name>`).
+    pub fn artwork(mut self, artwork: impl Into<String>) -> Self {
+        self.artwork = Some(artwork.into());
+        self
+    }
+
+    /// Build the `SurfaceNode`.
+    pub fn build(self) -> SurfaceNode {
+        let mut node = SurfaceNode::new("AudioPlayer");
+        node.set_prop("source", PropValue::String(self.source));
+        node.set_prop("playing", PropValue::Bool(self.playing));
+        node.set_prop("on_toggle", self.on_toggle);
+        if let Some(title) = self.title {
+            node.set_prop("title", PropValue::String(title));
+        }
+        if let Some(artwork) = self.artwork {
+            node.set_prop("artwork", PropValue::String(artwork));
+        }
+        self.common.apply(&mut node);
+        accessibility::ensure_accessible(&mut node);
+        node
+    }
+}
+
+impl CommonProps for AudioPlayerBuilder {
+    fn common_mut(&mut self) -> &mut CommonFields {
+        &mut self.common
+    }
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+/// Validates a media component node's props.
+///
+/// Returns a list of human-readable error strings. An empty list means
+/// the node is valid.
+pub fn validate_media_node(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Video" => validate_video(node),
+        "AudioPlayer" => validate_audio_player(node),
+        _ => vec![format!("Unknown media component: {}", node.component_type)],
+    }
+}
+
+fn validate_video(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: source, optional: poster (URL or asset reference)
+    errors.extend(validate_source(
+        "Video",
+        "source",
+        node.props.get("source"),
+        true,
+    ));
+    errors.extend(validate_source(
+        "Video",
+        "poster",
+        node.props.get("poster"),
+        false,
+    ));
+
+    // Required: label (non-empty string)
+    match node.props.get("label") {
+        Some(PropValue::String(s)) if s.trim().is_empty() => {
+            errors.push("Video.label: must not be empty".to_string())
+        }
+        Some(v) if v.is_text() => {}
+        Some(other) => errors.push(format!(
+            "Video.label: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Video.label: required prop missing".to_string()),
+    }
+
+    // Optional: autoplay, loop, muted, controls (bool)
+    for key in ["autoplay", "loop", "muted", "controls"] {
+        if let Some(prop) = node.props.get(key) {
+            if !matches!(prop, PropValue::Bool(_)) {
+                errors.push(format!(
+                    "Video.{key}: expected bool, got {}",
+                    prop.type_name()
+                ));
+            }
+        }
+    }
+
+    // Autoplay is only allowed without sound
+    if node.props.get("autoplay") == Some(&PropValue::Bool(true))
+        && node.props.get("muted") != Some(&PropValue::Bool(true))
+    {
+        errors.push("Video.autoplay: requires muted: true".to_string());
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "Video: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("Video", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "source"
+                    | "label"
+                    | "autoplay"
+                    | "loop"
+                    | "muted"
+                    | "controls"
+                    | "poster"
+                    | "accessible"
+            )
+        {
+            errors.push(format!("Video: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+fn validate_audio_player(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // Required: source, optional: artwork (URL or asset reference)
+    errors.extend(validate_source(
+        "AudioPlayer",
+        "source",
+        node.props.get("source"),
+        true,
+    ));
+    errors.extend(validate_source(
+        "AudioPlayer",
+        "artwork",
+        node.props.get("artwork"),
+        false,
+    ));
+
+    // Required: playing (bool)
+    match node.props.get("playing") {
+        Some(PropValue::Bool(_)) => {}
+        Some(other) => errors.push(format!(
+            "AudioPlayer.playing: expected bool, got {}",
+            other.type_name()
+        )),
+        None => errors.push("AudioPlayer.playing: required prop missing".to_string()),
+    }
+
+    // Required: on_toggle (action)
+    match node.props.get("on_toggle") {
+        Some(PropValue::ActionRef { .. }) => {}
+        Some(other) => errors.push(format!(
+            "AudioPlayer.on_toggle: expected action, got {}",
+            other.type_name()
+        )),
+        None => errors.push("AudioPlayer.on_toggle: required prop missing".to_string()),
+    }
+
+    // Optional: title (string)
+    if let Some(prop) = node.props.get("title") {
+        if !prop.is_text() {
+            errors.push(format!(
+                "AudioPlayer.title: expected string, got {}",
+                prop.type_name()
+            ));
+        }
+    }
+
+    // No children allowed
+    if !node.children.is_empty() {
+        errors.push(format!(
+            "AudioPlayer: does not accept children, but got {}",
+            node.children.len()
+        ));
+    }
+
+    // Optional: accessible (record)
+    if let Some(prop) = node.props.get("accessible") {
+        errors.extend(accessibility::validate_accessible_prop("AudioPlayer", prop));
+    }
+
+    // Common props (focus, lifecycle, ...)
+    errors.extend(common::validate_common_props(node));
+
+    // Check for unknown props
+    for key in node.props.keys() {
+        if !common::is_common_prop(key)
+            && !matches!(
+                key.as_str(),
+                "source" | "playing" | "on_toggle" | "title" | "artwork" | "accessible"
+            )
+        {
+            errors.push(format!("AudioPlayer: unknown prop '{key}'"));
+        }
+    }
+
+    errors
+}
+
+/// Validate a media source prop: a URL (`https://`, `http://`) or
+/// `asset:<name>`.
+fn validate_source(
+    component: &str,
+    key: &str,
+    prop: Option<&PropValue>,
+    required: bool,
+) -> Vec<String> {
+    match prop {
+        Some(PropValue::String(s)) if is_media_source(s) => vec![],
+        Some(PropValue::String(s)) => vec![format!(
+            "{component}.{key}: expected url (https://, http://) or asset:<name>, got {s:?}"
+        )],
+        Some(other) => vec![format!(
+            "{component}.{key}: expected string, got {}",
+            other.type_name()
+        )],
+        None if required => vec![format!("{component}.{key}: required prop missing")],
+        None => vec![],
+    }
+}
+
+/// Whether `source` is a supported URL scheme or a non-empty asset reference.
+fn is_media_source(source: &str) -> bool {
+    match source.strip_prefix("asset:") {
+        Some(name) => !name.trim().is_empty(),
+        None => ["https://", "http://"]
+            .iter()
+            .any(|scheme| source.len() > scheme.len() && source.starts_with(scheme)),
+    }
+}
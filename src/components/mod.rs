@@ -1,5 +1,36 @@
+pub mod common;
 pub mod content;
 pub mod feedback;
+pub mod form;
+pub mod graphics;
 pub mod interactive;
 pub mod layout;
 pub mod list;
+pub mod media;
+
+use crate::surface::SurfaceNode;
+
+/// Validate a single node with the validator for its component type.
+///
+/// Does not descend into children. Unknown component types yield one error.
+pub fn validate_node(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Column" | "Row" | "Scroll" | "Tabs" | "Carousel" | "Disclosure" | "Overlay" | "AppBar"
+        | "Divider" | "Spacer" => layout::validate_layout_node(node),
+        "Text" | "RichText" | "ProgressBar" | "Spinner" | "Icon" | "Image" | "Skeleton" => {
+            content::validate_content_node(node)
+        }
+        "Button" | "TextInput" | "Stepper" | "Rating" | "FilePicker" => {
+            interactive::validate_interactive_node(node)
+        }
+        "ScrollList" | "Table" => list::validate_list_node(node),
+        "Modal" | "BottomSheet" | "Drawer" | "Toast" | "Snackbar" | "Tooltip" => {
+            feedback::validate_feedback_node(node)
+        }
+        "Form" => form::validate_form_node(node),
+        "Video" | "AudioPlayer" => media::validate_media_node(node),
+        "Canvas" | "Chart" => graphics::validate_graphics_node(node),
+        crate::slots::SLOT => crate::slots::validate_slot(node),
+        other => vec![format!("Unknown component: {other}")],
+    }
+}
@@ -0,0 +1,163 @@
+//! Render-cost estimation.
+//!
+//! [`CostModel`] assigns each component a weight and sums it over a
+//! [`Surface`], so the evaluator can warn when a single view function
+//! produces a tree likely to blow the frame budget on low-end devices.
+//!
+//! Weights are in estimated microseconds of layout + draw time on a
+//! low-end reference device; the default budget is one 60fps frame (16ms).
+//! Hosts with measurements for their own View Layer replace the weights
+//! with [`CostModel::with_weight`].
+//!
+//! ```
+//! use pepl_ui::{CostModel, Surface, TextBuilder};
+//!
+//! let surface = Surface::new(TextBuilder::new("Hi").build());
+//! let model = CostModel::default();
+//! assert!(model.estimate(&surface) > 0.0);
+//! assert!(model.check(&surface).is_empty());
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::surface::{Surface, SurfaceNode};
+
+/// Default per-component weights (µs).
+const DEFAULT_WEIGHTS: &[(&str, f64)] = &[
+    ("Column", 20.0),
+    ("Row", 20.0),
+    ("Scroll", 60.0),
+    ("Tabs", 80.0),
+    ("Carousel", 80.0),
+    ("Disclosure", 40.0),
+    ("Overlay", 30.0),
+    ("AppBar", 60.0),
+    ("Divider", 10.0),
+    ("Spacer", 5.0),
+    ("Text", 40.0),
+    ("RichText", 50.0),
+    ("ProgressBar", 30.0),
+    ("Spinner", 20.0),
+    ("Icon", 15.0),
+    ("Image", 100.0),
+    ("Skeleton", 10.0),
+    ("Button", 60.0),
+    ("TextInput", 120.0),
+    ("Stepper", 70.0),
+    ("Rating", 40.0),
+    ("FilePicker", 60.0),
+    ("ScrollList", 200.0),
+    ("Table", 250.0),
+    ("Modal", 150.0),
+    ("BottomSheet", 150.0),
+    ("Drawer", 150.0),
+    ("Toast", 80.0),
+    ("Snackbar", 80.0),
+    ("Tooltip", 30.0),
+    ("Form", 20.0),
+    ("Video", 150.0),
+    ("AudioPlayer", 60.0),
+    ("Canvas", 100.0),
+    ("Chart", 150.0),
+];
+
+/// Per-component cost weights and a frame budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostModel {
+    weights: BTreeMap<String, f64>,
+    default_weight: f64,
+    prop_weight: f64,
+    budget: f64,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            weights: DEFAULT_WEIGHTS
+                .iter()
+                .map(|(name, weight)| (name.to_string(), *weight))
+                .collect(),
+            default_weight: 50.0,
+            prop_weight: 2.0,
+            budget: 16_000.0,
+        }
+    }
+}
+
+impl CostModel {
+    /// A model with the default weights and a 16ms budget.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the weight of one component type.
+    pub fn with_weight(mut self, component: impl Into<String>, weight: f64) -> Self {
+        self.weights.insert(component.into(), weight);
+        self
+    }
+
+    /// Set the weight used for component types without an explicit weight.
+    pub fn with_default_weight(mut self, weight: f64) -> Self {
+        self.default_weight = weight;
+        self
+    }
+
+    /// Set the extra cost added for each prop on a node.
+    pub fn with_prop_weight(mut self, weight: f64) -> Self {
+        self.prop_weight = weight;
+        self
+    }
+
+    /// Set the budget used by [`CostModel::check`].
+    pub fn with_budget(mut self, budget: f64) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// The weight of `component`, falling back to the default weight.
+    pub fn weight(&self, component: &str) -> f64 {
+        self.weights
+            .get(component)
+            .copied()
+            .unwrap_or(self.default_weight)
+    }
+
+    /// The configured budget.
+    pub fn budget(&self) -> f64 {
+        self.budget
+    }
+
+    /// Estimated cost of `surface`: the sum of [`estimate_node`](Self::estimate_node)
+    /// over the content tree and every layer.
+    pub fn estimate(&self, surface: &Surface) -> f64 {
+        surface
+            .layer_roots()
+            .map(|(_, root)| self.estimate_node(root))
+            .sum()
+    }
+
+    /// Estimated cost of the subtree at `node`: each node costs its component
+    /// weight plus the prop weight per prop.
+    pub fn estimate_node(&self, node: &SurfaceNode) -> f64 {
+        let own = self.weight(&node.component_type) + self.prop_weight * node.props.len() as f64;
+        own + node
+            .children
+            .iter()
+            .map(|child| self.estimate_node(child))
+            .sum::<f64>()
+    }
+
+    /// Warn when `surface` is estimated to exceed the budget. Empty means
+    /// within budget.
+    pub fn check(&self, surface: &Surface) -> Vec<String> {
+        let cost = self.estimate(surface);
+        if cost > self.budget {
+            vec![format!(
+                "Surface: estimated cost {cost:.0} exceeds budget {:.0}",
+                self.budget
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Human-readable structural differences between two surfaces.
+//!
+//! Intended for test failures: instead of two walls of JSON, each difference
+//! is one line annotated with the node path and the prop path inside it:
+//!
+//! ```text
+//! root/1/0 props.label: "OK" != "Ok"
+//! root/2 props.spacing: 8.0 != (missing)
+//! root/3: (missing) != Text
+//! ```
+//!
+//! Values are printed as compact JSON; `(missing)` marks an absent side.
+//! Use [`assert_surface_eq!`](crate::assert_surface_eq) in tests.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+const MISSING: &str = "(missing)";
+
+impl Surface {
+    /// Describe how `self` differs from `other`, one difference per line.
+    ///
+    /// Returns an empty string when the surfaces are structurally equal.
+    pub fn diff_report(&self, other: &Surface) -> String {
+        let mut lines = Vec::new();
+        diff_node(&self.root, &other.root, &NodePath::root(), &mut lines);
+        diff_layers(self, other, &mut lines);
+        diff_map("shortcuts", &self.shortcuts, &other.shortcuts, &mut lines);
+        if self.route != other.route {
+            lines.push(format!(
+                "route: {} != {}",
+                json_or_missing(self.route.as_ref()),
+                json_or_missing(other.route.as_ref())
+            ));
+        }
+        if self.respect_safe_area != other.respect_safe_area {
+            lines.push(format!(
+                "respect_safe_area: {} != {}",
+                self.respect_safe_area, other.respect_safe_area
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+impl SurfaceNode {
+    /// Describe how the subtree at `self` differs from `other`.
+    ///
+    /// Paths are relative to `self`, which is reported as `root`.
+    pub fn diff_report(&self, other: &SurfaceNode) -> String {
+        let mut lines = Vec::new();
+        diff_node(self, other, &NodePath::root(), &mut lines);
+        lines.join("\n")
+    }
+}
+
+fn diff_node(left: &SurfaceNode, right: &SurfaceNode, path: &NodePath, lines: &mut Vec<String>) {
+    if left.component_type != right.component_type {
+        // Props and children of different components are not comparable.
+        lines.push(format!(
+            "{path} type: {} != {}",
+            left.component_type, right.component_type
+        ));
+        return;
+    }
+    if left.test_id != right.test_id {
+        lines.push(format!(
+            "{path} test_id: {} != {}",
+            json_or_missing(left.test_id.as_ref()),
+            json_or_missing(right.test_id.as_ref())
+        ));
+    }
+    if left.key != right.key {
+        lines.push(format!(
+            "{path} key: {} != {}",
+            json_or_missing(left.key.as_ref()),
+            json_or_missing(right.key.as_ref())
+        ));
+    }
+    diff_map(&format!("{path} props"), &left.props, &right.props, lines);
+
+    let len = left.children.len().max(right.children.len());
+    for i in 0..len {
+        let child_path = path.child(i);
+        match (left.children.get(i), right.children.get(i)) {
+            (Some(l), Some(r)) => diff_node(l, r, &child_path, lines),
+            (Some(l), None) => {
+                lines.push(format!("{child_path}: {} != {MISSING}", l.component_type))
+            }
+            (None, Some(r)) => {
+                lines.push(format!("{child_path}: {MISSING} != {}", r.component_type))
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+fn diff_map(
+    prefix: &str,
+    left: &BTreeMap<String, PropValue>,
+    right: &BTreeMap<String, PropValue>,
+    lines: &mut Vec<String>,
+) {
+    let keys: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+    for key in keys {
+        diff_value(
+            &format!("{prefix}.{key}"),
+            left.get(key),
+            right.get(key),
+            lines,
+        );
+    }
+}
+
+fn diff_value(
+    at: &str,
+    left: Option<&PropValue>,
+    right: Option<&PropValue>,
+    lines: &mut Vec<String>,
+) {
+    match (left, right) {
+        (Some(PropValue::Record(l)), Some(PropValue::Record(r))) => diff_map(at, l, r, lines),
+        (Some(PropValue::List(l)), Some(PropValue::List(r))) => {
+            for i in 0..l.len().max(r.len()) {
+                diff_value(&format!("{at}[{i}]"), l.get(i), r.get(i), lines);
+            }
+        }
+        (l, r) if l != r => lines.push(format!(
+            "{at}: {} != {}",
+            json_or_missing(l),
+            json_or_missing(r)
+        )),
+        _ => {}
+    }
+}
+
+/// Compare layers by name, then their order if the names match.
+fn diff_layers(left: &Surface, right: &Surface, lines: &mut Vec<String>) {
+    let names = |surface: &Surface| -> Vec<String> {
+        surface
+            .layers
+            .iter()
+            .map(|layer| layer.name.clone())
+            .collect()
+    };
+    let (left_names, right_names) = (names(left), names(right));
+    let all: BTreeSet<&String> = left_names.iter().chain(&right_names).collect();
+    for name in all {
+        let path = NodePath::layer_root(name);
+        match (left.layer(name), right.layer(name)) {
+            (Some(l), Some(r)) => diff_node(l, r, &path, lines),
+            (Some(l), None) => lines.push(format!("{path}: {} != {MISSING}", l.component_type)),
+            (None, Some(r)) => lines.push(format!("{path}: {MISSING} != {}", r.component_type)),
+            (None, None) => {}
+        }
+    }
+    let mut sorted = (left_names.clone(), right_names.clone());
+    sorted.0.sort();
+    sorted.1.sort();
+    if sorted.0 == sorted.1 && left_names != right_names {
+        lines.push(format!(
+            "layers: {} != {}",
+            json_or_missing(Some(&left_names)),
+            json_or_missing(Some(&right_names))
+        ));
+    }
+}
+
+fn json_or_missing<T: serde::Serialize>(value: Option<&T>) -> String {
+    match value {
+        Some(value) => serde_json::to_string(value).unwrap_or_else(|_| "?".to_string()),
+        None => MISSING.to_string(),
+    }
+}
+
+/// Assert that two [`Surface`]s (or two [`SurfaceNode`]s) are equal.
+///
+/// On failure, panics with a [`diff_report`](Surface::diff_report) listing
+/// each difference on its own path-annotated line.
+///
+/// ```
+/// use pepl_ui::{assert_surface_eq, Surface, TextBuilder};
+///
+/// let a = Surface::new(TextBuilder::new("Hi").build());
+/// let b = Surface::new(TextBuilder::new("Hi").build());
+/// assert_surface_eq!(a, b);
+/// ```
+#[macro_export]
+macro_rules! assert_surface_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if left != right {
+            let report = left.diff_report(right);
+            panic!(
+                "assertion `left == right` failed: surfaces differ\n{}",
+                if report.is_empty() {
+                    format!("left: {:?}\nright: {:?}", left, right)
+                } else {
+                    report
+                }
+            );
+        }
+    }};
+}
@@ -0,0 +1,100 @@
+//! C ABI for native hosts (feature `ffi`).
+//!
+//! Swift and Kotlin hosts call into the canonical validator instead of
+//! re-porting it. All strings are NUL-terminated UTF-8; results are JSON.
+//! Every non-null string returned by this module is owned by the caller and
+//! must be released with [`pepl_ui_string_free`]. The C declarations live in
+//! `include/pepl_ui.h`.
+
+use std::ffi::{c_char, CStr, CString};
+
+use crate::complexity::parse_untrusted;
+use crate::registry::ComponentRegistry;
+use crate::surface::Surface;
+
+/// Validate a surface JSON payload.
+///
+/// Returns a JSON array of error strings; `[]` means valid. Null, non-UTF-8,
+/// or unparseable input, or input over the default
+/// [`Limits`](crate::complexity::Limits), yields a single error describing
+/// the problem.
+///
+/// # Safety
+///
+/// `json` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pepl_ui_validate(json: *const c_char) -> *mut c_char {
+    let errors = match parse(json) {
+        Ok(surface) => surface.validate(),
+        Err(error) => vec![error],
+    };
+    into_c_string(serde_json::to_string(&errors).expect("string list serialization"))
+}
+
+/// Compare two surface JSON payloads.
+///
+/// Returns a JSON array of path-annotated differences (see
+/// [`Surface::diff_report`]); `[]` means equal. Returns null if either
+/// payload is not a valid surface — use [`pepl_ui_validate`] for the reason.
+///
+/// # Safety
+///
+/// `old` and `new` must each be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pepl_ui_diff(old: *const c_char, new: *const c_char) -> *mut c_char {
+    let (Ok(old), Ok(new)) = (parse(old), parse(new)) else {
+        return std::ptr::null_mut();
+    };
+    let report = old.diff_report(&new);
+    let lines: Vec<&str> = report.lines().collect();
+    into_c_string(serde_json::to_string(&lines).expect("string list serialization"))
+}
+
+/// The component registry as JSON. See [`ComponentRegistry::to_json`].
+#[no_mangle]
+pub extern "C" fn pepl_ui_registry_json() -> *mut c_char {
+    into_c_string(ComponentRegistry::new().to_json())
+}
+
+/// Whether `name` is a registered component type.
+///
+/// # Safety
+///
+/// `name` must be null or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pepl_ui_is_component(name: *const c_char) -> bool {
+    to_str(name).is_ok_and(|name| ComponentRegistry::new().is_valid(name))
+}
+
+/// Release a string returned by this module. Null is a no-op.
+///
+/// # Safety
+///
+/// `s` must be null or a pointer returned by a `pepl_ui_*` function that has
+/// not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pepl_ui_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Result<&'a str, String> {
+    if s.is_null() {
+        return Err("null input".to_string());
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|e| format!("input is not UTF-8: {e}"))
+}
+
+unsafe fn parse(json: *const c_char) -> Result<Surface, String> {
+    parse_untrusted(to_str(json)?)
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // JSON output escapes control characters, so it never contains NUL.
+    CString::new(s)
+        .expect("JSON output contains no NUL bytes")
+        .into_raw()
+}
@@ -0,0 +1,164 @@
+//! Focus management for keyboard and TV hosts.
+//!
+//! Focus behaviour is described with three props accepted on every component
+//! (see [`components::common`](crate::components::common)):
+//!
+//! - `focus_group` — nodes sharing a group name form one directional-navigation
+//!   region (arrow keys / D-pad move within the group first).
+//! - `initial_focus` — marks the node that should receive focus when the
+//!   surface appears.
+//! - `focus_trap` — focus cannot leave this subtree. A `Modal`, `BottomSheet`,
+//!   or `Drawer` traps focus unless it sets `focus_trap: false`.
+//!
+//! [`compute_initial_focus`] resolves these props into a single node so every
+//! host picks the same starting point.
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+/// Returns `true` if `node` confines focus to its subtree.
+///
+/// An explicit `focus_trap` prop wins; otherwise only `Modal`, `BottomSheet`,
+/// and `Drawer` trap.
+pub fn traps_focus(node: &SurfaceNode) -> bool {
+    match node.props.get("focus_trap") {
+        Some(PropValue::Bool(trap)) => *trap,
+        _ => matches!(
+            node.component_type.as_str(),
+            "Modal" | "BottomSheet" | "Drawer"
+        ),
+    }
+}
+
+/// What a component in [`FOCUSABLE_COMPONENTS`] needs to be interactive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusGate {
+    /// Always interactive.
+    Always,
+    /// Interactive only with this optional handler prop, e.g. a Rating's
+    /// `on_change`.
+    Handler(&'static str),
+    /// Interactive unless this flag is `false`, e.g. a Video's `controls`.
+    Flag(&'static str),
+}
+
+/// Components that can receive keyboard focus on their own, each paired with
+/// what it needs to be interactive.
+///
+/// Every new interactive component must be added here.
+pub const FOCUSABLE_COMPONENTS: &[(&str, FocusGate)] = &[
+    ("Button", FocusGate::Always),
+    ("TextInput", FocusGate::Always),
+    ("FilePicker", FocusGate::Always),
+    ("Stepper", FocusGate::Always),
+    ("Rating", FocusGate::Handler("on_change")),
+    ("Tabs", FocusGate::Always),
+    ("Disclosure", FocusGate::Always),
+    ("Carousel", FocusGate::Always),
+    ("AudioPlayer", FocusGate::Always),
+    ("Video", FocusGate::Flag("controls")),
+    ("Snackbar", FocusGate::Handler("on_action")),
+    ("Table", FocusGate::Handler("on_sort")),
+];
+
+/// Returns `true` if `node` can receive keyboard focus on its own.
+///
+/// The component must be listed in [`FOCUSABLE_COMPONENTS`] and pass its
+/// [gate](FocusGate) (a read-only `Rating` without `on_change` does not),
+/// and must not set `disabled: true`.
+pub fn is_focusable(node: &SurfaceNode) -> bool {
+    let interactive = FOCUSABLE_COMPONENTS.iter().any(|(name, gate)| {
+        node.component_type == *name
+            && match gate {
+                FocusGate::Always => true,
+                FocusGate::Handler(prop) => node.props.contains_key(*prop),
+                FocusGate::Flag(prop) => node.props.get(*prop) != Some(&PropValue::Bool(false)),
+            }
+    });
+    interactive && node.props.get("disabled") != Some(&PropValue::Bool(true))
+}
+
+/// Compute the node that should receive focus when `surface` appears.
+///
+/// Layers are searched from the top down, ending with the content tree, so
+/// a dialog in the `overlays` layer takes focus from the controls behind it.
+///
+/// 1. If any visible node traps focus, the search is limited to the top-most
+///    one: the last in document order in the highest layer that has one.
+/// 2. Within that scope, the first node with `initial_focus: true` wins,
+///    taken from the highest layer that has one.
+/// 3. Otherwise the first [focusable](is_focusable) node wins, again from the
+///    highest layer that has one.
+///
+/// Subtrees with `visible: false` (any hidden node, e.g. a closed Modal) and closed Drawers
+/// (`open: false`) are skipped.
+/// Returns `None` when nothing in scope can take focus. The path is a
+/// [layer path](NodePath::layer_root) when the node is in a layer.
+pub fn compute_initial_focus(surface: &Surface) -> Option<NodePath> {
+    let layers: Vec<(&SurfaceNode, NodePath)> = surface
+        .layer_roots()
+        .map(|(name, root)| (root, NodePath::layer_root(name)))
+        .collect();
+    let trap = layers.iter().rev().find_map(|(root, path)| {
+        let mut scope = None;
+        find_trap(root, path.clone(), &mut scope);
+        scope
+    });
+    let scopes = match trap {
+        Some(scope) => vec![scope],
+        None => layers.into_iter().rev().collect(),
+    };
+
+    let explicit = |n: &SurfaceNode| n.props.get("initial_focus") == Some(&PropValue::Bool(true));
+    scopes
+        .iter()
+        .find_map(|(node, path)| find_first(node, path.clone(), &explicit))
+        .or_else(|| {
+            scopes
+                .iter()
+                .find_map(|(node, path)| find_first(node, path.clone(), &is_focusable))
+        })
+}
+
+/// Whether `node` is hidden along with its subtree.
+fn is_hidden(node: &SurfaceNode) -> bool {
+    node.props.get("visible") == Some(&PropValue::Bool(false))
+        || (node.component_type == "Drawer"
+            && node.props.get("open") == Some(&PropValue::Bool(false)))
+}
+
+/// Record the last visible trapping node (pre-order) in `scope`.
+fn find_trap<'a>(
+    node: &'a SurfaceNode,
+    path: NodePath,
+    scope: &mut Option<(&'a SurfaceNode, NodePath)>,
+) {
+    if is_hidden(node) {
+        return;
+    }
+    if traps_focus(node) {
+        *scope = Some((node, path.clone()));
+    }
+    for (i, child) in node.children.iter().enumerate() {
+        find_trap(child, path.child(i), scope);
+    }
+}
+
+/// First visible node (pre-order) matching `pred`.
+fn find_first(
+    node: &SurfaceNode,
+    path: NodePath,
+    pred: &dyn Fn(&SurfaceNode) -> bool,
+) -> Option<NodePath> {
+    if is_hidden(node) {
+        return None;
+    }
+    if pred(node) {
+        return Some(path);
+    }
+    node.children
+        .iter()
+        .enumerate()
+        .find_map(|(i, child)| find_first(child, path.child(i), pred))
+}
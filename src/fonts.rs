@@ -0,0 +1,114 @@
+//! Logical font families.
+//!
+//! Text's `font` prop names a *logical* family such as `"brand"` or `"mono"`
+//! rather than a platform font, so one Surface tree renders the same way on
+//! every host. A [`FontRegistry`] maps each logical name to platform font
+//! names in preference order; the host uses the first one it has installed
+//! and falls back to its system font.
+//!
+//! ```
+//! use pepl_ui::fonts::FontRegistry;
+//! use pepl_ui::{Surface, TextBuilder};
+//!
+//! let fonts = FontRegistry::standard().with_family("brand", ["Inter", "Roboto"]);
+//! let surface = Surface::new(TextBuilder::new("Welcome").font("brand").build());
+//!
+//! assert!(surface.validate_fonts(&fonts).is_empty());
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::icons;
+use crate::prop_value::PropValue;
+use crate::surface::Surface;
+
+/// Logical font families and the platform fonts they map to.
+///
+/// Serialized as a map from logical name to platform font names:
+///
+/// ```json
+/// { "brand": ["Inter", "Roboto"], "mono": ["SF Mono", "Roboto Mono", "monospace"] }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FontRegistry {
+    pub families: BTreeMap<String, Vec<String>>,
+}
+
+impl FontRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The families every host provides:
+    ///
+    /// | Family | Platform fonts |
+    /// |--------|----------------|
+    /// | `system` | `system-ui` |
+    /// | `serif` | `New York`, `Noto Serif`, `serif` |
+    /// | `mono` | `SF Mono`, `Roboto Mono`, `monospace` |
+    pub fn standard() -> Self {
+        Self::new()
+            .with_family("system", ["system-ui"])
+            .with_family("serif", ["New York", "Noto Serif", "serif"])
+            .with_family("mono", ["SF Mono", "Roboto Mono", "monospace"])
+    }
+
+    /// Add or replace a family, with its platform fonts in preference order.
+    pub fn with_family<I, S>(mut self, name: impl Into<String>, fonts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.families
+            .insert(name.into(), fonts.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether `name` is a registered family.
+    pub fn contains(&self, name: &str) -> bool {
+        self.families.contains_key(name)
+    }
+
+    /// The platform fonts for `name`, in preference order.
+    pub fn fonts(&self, name: &str) -> Option<&[String]> {
+        self.families.get(name).map(Vec::as_slice)
+    }
+
+    /// Serialize to JSON for the host.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("FontRegistry serialization should never fail")
+    }
+}
+
+impl Surface {
+    /// Check every Text `font` against the families in `fonts`. Empty means
+    /// every family is registered.
+    ///
+    /// Errors look like `root/0: Text.font: unknown font family 'mnoo' (did
+    /// you mean 'mono'?)`.
+    pub fn validate_fonts(&self, fonts: &FontRegistry) -> Vec<String> {
+        let mut errors = Vec::new();
+        self.walk(|node, path| {
+            if node.component_type != "Text" {
+                return;
+            }
+            let Some(PropValue::String(name)) = node.props.get("font") else {
+                return;
+            };
+            if !fonts.contains(name) {
+                let known = fonts.families.keys().cloned().collect();
+                errors.push(match icons::closest(name, known) {
+                    Some(suggestion) => format!(
+                        "{path}: Text.font: unknown font family '{name}' (did you mean '{suggestion}'?)"
+                    ),
+                    None => format!("{path}: Text.font: unknown font family '{name}'"),
+                });
+            }
+        });
+        errors
+    }
+}
@@ -0,0 +1,39 @@
+//! Panic-free entry points for fuzzing the wire format.
+//!
+//! Hosts receive surfaces as untrusted bytes. These functions run the same
+//! steps a host pipeline does — parse, re-serialize, validate, fill in
+//! accessibility, resolve initial focus — and report failures as values.
+//! The cargo-fuzz targets in `fuzz/` call them directly; any panic found
+//! there is a bug.
+
+use crate::accessibility;
+use crate::complexity::Limits;
+use crate::focus::compute_initial_focus;
+use crate::surface::Surface;
+
+/// Parse `data` as a JSON [`Surface`].
+///
+/// Returns `None` for anything that is not a well-formed surface within the
+/// default [`Limits`]. A parsed surface is re-serialized before returning, so
+/// serialization is covered too.
+pub fn fuzz_parse(data: &[u8]) -> Option<Surface> {
+    let json = std::str::from_utf8(data).ok()?;
+    let surface = Surface::from_json_limited(json, &Limits::default()).ok()?;
+    serde_json::to_vec(&surface).ok()?;
+    Some(surface)
+}
+
+/// Parse and validate `data`, returning every validation error.
+///
+/// Returns `None` when `data` does not parse. Otherwise the surface is
+/// checked with [`Surface::validate`], then the accessibility fill-in and
+/// initial-focus passes are run over the tree and its layers.
+pub fn fuzz_validate(data: &[u8]) -> Option<Vec<String>> {
+    let mut surface = fuzz_parse(data)?;
+
+    let errors = surface.validate();
+    surface.walk_mut(|node, _| accessibility::ensure_accessible(node));
+    compute_initial_focus(&surface);
+
+    Some(errors)
+}
@@ -0,0 +1,563 @@
+//! `arbitrary` support for property and fuzz testing (feature `arbitrary`).
+//!
+//! Three levels of generated input:
+//!
+//! - [`PropValue`] — any value shape, nested up to a small depth.
+//! - [`SurfaceNode`] / [`Surface`] — structurally well-formed trees with
+//!   arbitrary component types and props, some nodes keyed (sibling keys may
+//!   repeat), and up to two [layers](crate::layers). Useful for "the
+//!   validator never panics" style properties.
+//! - [`ValidSurface`] — trees assembled from the component builders, so every
+//!   node passes its component validator. Keys are unique among siblings.
+//!
+//! Generated values survive a JSON roundtrip unchanged: numbers are finite and
+//! exactly representable, and record keys never collide with the reserved
+//! `Color` / `__action` / `__lambda` / `__color_token` / `__theme_token` / `__i18n` /
+//! `__timestamp` / `__bytes` / `__dim` shapes of the untagged wire format.
+
+use std::collections::BTreeMap;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::components::content::{
+    IconBuilder, ImageBuilder, ProgressBarBuilder, RichTextBuilder, SkeletonBuilder, SkeletonShape,
+    SpinnerBuilder, TextBuilder, TextSpan,
+};
+use crate::components::feedback::{
+    BottomSheetBuilder, DrawerBuilder, DrawerSide, ModalBuilder, SheetDetent, SnackbarBuilder,
+    ToastBuilder, TooltipBuilder,
+};
+use crate::components::form::FormBuilder;
+use crate::components::graphics::{
+    CanvasBuilder, ChartBuilder, ChartKind, ChartSeries, DrawCommand,
+};
+use crate::components::interactive::{
+    ButtonBuilder, FilePickerBuilder, RatingBuilder, StepperBuilder, TextInputBuilder,
+};
+use crate::components::layout::{
+    AppBarBuilder, CarouselBuilder, ColumnBuilder, DisclosureBuilder, DividerBuilder,
+    OverlayAlignment, OverlayBuilder, OverlayLayer, RowBuilder, ScrollBuilder, SpacerBuilder,
+    TabsBuilder,
+};
+use crate::components::list::{ScrollListBuilder, TableBuilder, TableColumn};
+use crate::components::media::{AudioPlayerBuilder, VideoBuilder};
+use crate::icons::CANONICAL_ICONS;
+use crate::layers::{OVERLAYS, TOASTS};
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+use crate::timestamp::Timestamp;
+use crate::types::Dimension;
+
+/// Maximum nesting depth for generated lists, records, and child nodes.
+const MAX_DEPTH: usize = 3;
+
+/// Maximum number of entries in a generated list, record, or child list.
+const MAX_LEN: usize = 4;
+
+/// Component types used for [`SurfaceNode`] generation, plus one unknown name.
+const COMPONENT_TYPES: &[&str] = &[
+    "Column",
+    "Row",
+    "Scroll",
+    "Tabs",
+    "Carousel",
+    "Disclosure",
+    "Overlay",
+    "AppBar",
+    "Divider",
+    "Spacer",
+    "Text",
+    "RichText",
+    "ProgressBar",
+    "Spinner",
+    "Icon",
+    "Image",
+    "Skeleton",
+    "Button",
+    "TextInput",
+    "Stepper",
+    "Rating",
+    "FilePicker",
+    "ScrollList",
+    "Table",
+    "Modal",
+    "BottomSheet",
+    "Drawer",
+    "Toast",
+    "Snackbar",
+    "Tooltip",
+    "Form",
+    "Video",
+    "AudioPlayer",
+    "Canvas",
+    "Chart",
+    "Unknown",
+];
+
+/// Prop keys used for generated props and record fields.
+///
+/// Deliberately excludes `r`, `g`, `b`, `a` and `__`-prefixed keys so a
+/// generated `Record` never deserializes back as a `Color` or reference.
+const KEYS: &[&str] = &[
+    "label",
+    "value",
+    "on_tap",
+    "on_change",
+    "disabled",
+    "visible",
+    "spacing",
+    "title",
+    "items",
+    "accessible",
+];
+
+/// Theme color token names used for generated `ColorToken` values.
+const COLOR_TOKENS: &[&str] = &["primary", "surface", "on_surface", "error"];
+
+/// Design token references used for generated `ThemeToken` values.
+const THEME_TOKENS: &[&str] = &[
+    "color.primary",
+    "spacing.md",
+    "radius.sm",
+    "typography.body",
+];
+
+/// Message keys used for generated `I18n` values.
+const MESSAGE_KEYS: &[&str] = &["greeting", "cart.items", "save"];
+
+/// Words used for generated labels and text values in [`ValidSurface`].
+const WORDS: &[&str] = &["OK", "Cancel", "Save", "Name", "Hello", "Total: 3"];
+
+// ── PropValue ─────────────────────────────────────────────────────────────────
+
+impl<'a> Arbitrary<'a> for PropValue {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        prop_value(u, MAX_DEPTH)
+    }
+}
+
+fn prop_value(u: &mut Unstructured<'_>, depth: usize) -> Result<PropValue> {
+    // Containers are only offered while depth remains.
+    let variants = if depth == 0 { 6 } else { 8 };
+    Ok(match u.choose_index(variants)? {
+        0 => PropValue::String(u.arbitrary()?),
+        1 => PropValue::Number(number(u)?),
+        2 => PropValue::Bool(u.arbitrary()?),
+        3 => PropValue::Nil,
+        4 => PropValue::color(unit(u)?, unit(u)?, unit(u)?, unit(u)?),
+        5 => match u.choose_index(9)? {
+            0 => PropValue::action(identifier(u)?),
+            1 => PropValue::action_with_args(identifier(u)?, list(u, depth.saturating_sub(1))?),
+            2 => PropValue::color_token(*u.choose(COLOR_TOKENS)?),
+            3 => PropValue::i18n_with_args(
+                *u.choose(MESSAGE_KEYS)?,
+                record(u, depth.saturating_sub(1))?,
+            ),
+            4 => PropValue::timestamp(timestamp(u)?),
+            5 => {
+                // `bytes` fails rather than come up short
+                let len = u.int_in_range(0..=16)?.min(u.len());
+                PropValue::bytes(u.bytes(len)?)
+            }
+            6 => PropValue::dimension(match u.choose_index(4)? {
+                0 => Dimension::Px(number(u)?.abs()),
+                1 => Dimension::Auto,
+                2 => Dimension::Fill,
+                _ => Dimension::Percent(f64::from(u.int_in_range(0..=400)?) / 4.0),
+            }),
+            7 => PropValue::theme_token(*u.choose(THEME_TOKENS)?),
+            _ => PropValue::lambda(u.arbitrary()?),
+        },
+        6 => PropValue::List(list(u, depth - 1)?),
+        _ => PropValue::Record(record(u, depth - 1)?),
+    })
+}
+
+fn list(u: &mut Unstructured<'_>, depth: usize) -> Result<Vec<PropValue>> {
+    let len = u.int_in_range(0..=MAX_LEN)?;
+    (0..len).map(|_| prop_value(u, depth)).collect()
+}
+
+fn record(u: &mut Unstructured<'_>, depth: usize) -> Result<BTreeMap<String, PropValue>> {
+    let len = u.int_in_range(0..=MAX_LEN)?;
+    let mut fields = BTreeMap::new();
+    for _ in 0..len {
+        fields.insert(u.choose(KEYS)?.to_string(), prop_value(u, depth)?);
+    }
+    Ok(fields)
+}
+
+/// A finite number that serializes and parses back to the same `f64`.
+fn number(u: &mut Unstructured<'_>) -> Result<f64> {
+    Ok(f64::from(u.int_in_range(-40_000..=40_000)?) / 4.0)
+}
+
+/// A number in 0.0–1.0 with exact JSON representation.
+fn unit(u: &mut Unstructured<'_>) -> Result<f64> {
+    Ok(f64::from(u.int_in_range(0..=16)?) / 16.0)
+}
+
+/// A valid date or UTC date-time.
+fn timestamp(u: &mut Unstructured<'_>) -> Result<Timestamp> {
+    let date = format!(
+        "{:04}-{:02}-{:02}",
+        u.int_in_range(1970..=2100)?,
+        u.int_in_range(1..=12)?,
+        u.int_in_range(1..=28)?
+    );
+    let text = if u.arbitrary()? {
+        format!(
+            "{date}T{:02}:{:02}:{:02}Z",
+            u.int_in_range(0..=23)?,
+            u.int_in_range(0..=59)?,
+            u.int_in_range(0..=59)?
+        )
+    } else {
+        date
+    };
+    Ok(Timestamp::parse(&text).expect("generated timestamps are valid"))
+}
+
+fn identifier(u: &mut Unstructured<'_>) -> Result<String> {
+    Ok(format!("action_{}", u.int_in_range(0..=9)?))
+}
+
+// ── SurfaceNode / Surface ─────────────────────────────────────────────────────
+
+impl<'a> Arbitrary<'a> for SurfaceNode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        surface_node(u, MAX_DEPTH)
+    }
+}
+
+fn surface_node(u: &mut Unstructured<'_>, depth: usize) -> Result<SurfaceNode> {
+    let mut node = SurfaceNode::new(*u.choose(COMPONENT_TYPES)?);
+    if u.ratio(1, 4)? {
+        node.test_id = Some(format!("node-{}", u.int_in_range(0..=99)?));
+    }
+    // Few distinct keys, so siblings sometimes share one
+    if u.ratio(1, 3)? {
+        node.key = Some(format!("k{}", u.int_in_range(0..=3)?));
+    }
+    node.props = record(u, depth.saturating_sub(1))?;
+    if depth > 0 {
+        let len = u.int_in_range(0..=MAX_LEN)?;
+        for _ in 0..len {
+            node.add_child(surface_node(u, depth - 1)?);
+        }
+    }
+    Ok(node)
+}
+
+impl<'a> Arbitrary<'a> for Surface {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut surface = Surface::new(u.arbitrary()?);
+        for name in layer_names(u)? {
+            surface = surface.with_layer(name, u.arbitrary()?);
+        }
+        Ok(surface)
+    }
+}
+
+/// Zero to two distinct layer names, in arbitrary order.
+fn layer_names(u: &mut Unstructured<'_>) -> Result<Vec<&'static str>> {
+    let mut names = vec![OVERLAYS, TOASTS];
+    if u.arbitrary()? {
+        names.reverse();
+    }
+    names.truncate(u.int_in_range(0..=2)?);
+    Ok(names)
+}
+
+// ── ValidSurface ──────────────────────────────────────────────────────────────
+
+/// A [`Surface`] whose every node passes its component validator.
+///
+/// Nodes are built with the component builders, so required props are always
+/// present with the right types and only containers (Column, Row, Scroll,
+/// Tabs, Carousel, Disclosure, Overlay, AppBar, Modal, BottomSheet, Drawer,
+/// Tooltip, Form) receive children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidSurface(pub Surface);
+
+impl<'a> Arbitrary<'a> for ValidSurface {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut surface = Surface::new(valid_node(u, MAX_DEPTH)?);
+        for name in layer_names(u)? {
+            surface = surface.with_layer(name, valid_node(u, MAX_DEPTH - 1)?);
+        }
+        surface.walk_mut(|node, _| {
+            // Index-based keys never repeat among siblings. Running out of
+            // input just leaves the rest unkeyed.
+            for (i, child) in node.children.iter_mut().enumerate() {
+                if u.ratio(1, 2).unwrap_or(false) {
+                    child.key = Some(format!("item-{i}"));
+                }
+            }
+        });
+        Ok(ValidSurface(surface))
+    }
+}
+
+fn valid_node(u: &mut Unstructured<'_>, depth: usize) -> Result<SurfaceNode> {
+    // Leaves only once depth is exhausted.
+    let kinds = if depth == 0 { 22 } else { 35 };
+    Ok(match u.choose_index(kinds)? {
+        0 => {
+            let mut text = TextBuilder::new(*u.choose(WORDS)?);
+            if u.arbitrary()? {
+                text = text.max_lines(f64::from(u.int_in_range(1..=5)?));
+            }
+            text.build()
+        }
+        1 => {
+            if u.ratio(1, 4)? {
+                ProgressBarBuilder::indeterminate().build()
+            } else {
+                ProgressBarBuilder::new(unit(u)?).build()
+            }
+        }
+        2 => ButtonBuilder::new(*u.choose(WORDS)?, PropValue::action(identifier(u)?))
+            .disabled(u.arbitrary()?)
+            .build(),
+        3 => TextInputBuilder::new(*u.choose(WORDS)?, PropValue::lambda(u.arbitrary()?))
+            .label(*u.choose(WORDS)?)
+            .build(),
+        4 => {
+            let items = (0..u.int_in_range(0..=MAX_LEN)?)
+                .map(|_| Ok(PropValue::String(u.choose(WORDS)?.to_string())))
+                .collect::<Result<Vec<_>>>()?;
+            ScrollListBuilder::new(
+                PropValue::List(items),
+                PropValue::lambda(u.arbitrary()?),
+                PropValue::lambda(u.arbitrary()?),
+            )
+            .build()
+        }
+        5 => ToastBuilder::new(*u.choose(WORDS)?).build(),
+        6 => ImageBuilder::asset(*u.choose(WORDS)?)
+            .alt(*u.choose(WORDS)?)
+            .build(),
+        7 => DividerBuilder::new()
+            .thickness(f64::from(u.int_in_range(1..=4)?))
+            .build(),
+        8 => {
+            if u.arbitrary()? {
+                SpacerBuilder::new()
+                    .size(f64::from(u.int_in_range(0..=32)?))
+                    .build()
+            } else {
+                SpacerBuilder::new().build()
+            }
+        }
+        9 => {
+            let rows = (0..u.int_in_range(0..=MAX_LEN)?)
+                .map(|_| {
+                    Ok(PropValue::Record(BTreeMap::from([(
+                        "name".to_string(),
+                        PropValue::String(u.choose(WORDS)?.to_string()),
+                    )])))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            TableBuilder::new(
+                vec![TableColumn::new("name", *u.choose(WORDS)?)],
+                PropValue::List(rows),
+                PropValue::lambda(u.arbitrary()?),
+            )
+            .build()
+        }
+        10 => {
+            let min = f64::from(u.int_in_range(0..=5)?);
+            let max = min + f64::from(u.int_in_range(0..=20)?);
+            StepperBuilder::new(
+                f64::from(u.int_in_range(min as i32..=max as i32)?),
+                PropValue::lambda(u.arbitrary()?),
+            )
+            .min(min)
+            .max(max)
+            .build()
+        }
+        11 => {
+            let shape = *u.choose(&[
+                SkeletonShape::Text,
+                SkeletonShape::Rect,
+                SkeletonShape::Circle,
+            ])?;
+            let skeleton = SkeletonBuilder::new(shape);
+            if shape == SkeletonShape::Text {
+                skeleton.lines(u.int_in_range(1..=3)?).build()
+            } else {
+                skeleton.build()
+            }
+        }
+        12 => SpinnerBuilder::new()
+            .size(f64::from(u.int_in_range(8..=64)?))
+            .build(),
+        13 => {
+            let max = u.int_in_range(1..=10)?;
+            let rating = RatingBuilder::new(f64::from(u.int_in_range(0..=max)?), max);
+            if u.arbitrary()? {
+                rating.on_change(PropValue::lambda(u.arbitrary()?)).build()
+            } else {
+                rating.build()
+            }
+        }
+        14 => {
+            let words = (0..u.int_in_range(1..=3)?)
+                .map(|_| Ok(TextSpan::new(*u.choose(WORDS)?)))
+                .collect::<Result<Vec<_>>>()?;
+            RichTextBuilder::new(words).build()
+        }
+        15 => {
+            let muted = u.arbitrary()?;
+            VideoBuilder::new(format!("asset:{}", u.choose(WORDS)?), *u.choose(WORDS)?)
+                .muted(muted)
+                .autoplay(muted && u.arbitrary()?)
+                .build()
+        }
+        16 => AudioPlayerBuilder::new(
+            format!("asset:{}", u.choose(WORDS)?),
+            u.arbitrary()?,
+            PropValue::action(identifier(u)?),
+        )
+        .title(*u.choose(WORDS)?)
+        .build(),
+        17 => {
+            let size = f64::from(u.int_in_range(1..=100)?);
+            CanvasBuilder::new(size, size)
+                .command(DrawCommand::rect(0.0, 0.0, size, size).fill_token("surface"))
+                .command(DrawCommand::line(0.0, 0.0, size, size).stroke_token("primary"))
+                .build()
+        }
+        18 => {
+            let values = (0..u.int_in_range(1..=MAX_LEN)?)
+                .map(|_| Ok(f64::from(u.int_in_range(0..=100)?)))
+                .collect::<Result<Vec<_>>>()?;
+            let kind = *u.choose(&[
+                ChartKind::Line,
+                ChartKind::Bar,
+                ChartKind::Pie,
+                ChartKind::Sparkline,
+            ])?;
+            ChartBuilder::new(kind, vec![ChartSeries::new(*u.choose(WORDS)?, values)]).build()
+        }
+        19 => FilePickerBuilder::new(*u.choose(WORDS)?, PropValue::lambda(u.arbitrary()?))
+            .accept(vec!["image/*"])
+            .multiple(u.arbitrary()?)
+            .build(),
+        20 => {
+            let snackbar = SnackbarBuilder::new(*u.choose(WORDS)?);
+            if u.arbitrary()? {
+                snackbar
+                    .action(*u.choose(WORDS)?, PropValue::action(identifier(u)?))
+                    .build()
+            } else {
+                snackbar.build()
+            }
+        }
+        21 => {
+            let icon = IconBuilder::new(*u.choose(CANONICAL_ICONS)?);
+            if u.arbitrary()? {
+                icon.label(*u.choose(WORDS)?).build()
+            } else {
+                icon.build()
+            }
+        }
+        22 => ColumnBuilder::new()
+            .spacing(f64::from(u.int_in_range(0..=32)?))
+            .children(valid_children(u, depth - 1)?)
+            .build(),
+        23 => RowBuilder::new()
+            .spacing(f64::from(u.int_in_range(0..=32)?))
+            .children(valid_children(u, depth - 1)?)
+            .build(),
+        24 => ScrollBuilder::new()
+            .children(valid_children(u, depth - 1)?)
+            .build(),
+        25 => {
+            let mut tabs = TabsBuilder::new(PropValue::lambda(u.arbitrary()?));
+            let count = u.int_in_range(1..=MAX_LEN)?;
+            for _ in 0..count {
+                tabs = tabs.tab(*u.choose(WORDS)?, valid_node(u, depth - 1)?);
+            }
+            tabs.selected(u.choose_index(count)?).build()
+        }
+        26 => {
+            let mut modal = ModalBuilder::new(u.arbitrary()?, PropValue::action(identifier(u)?));
+            for child in valid_children(u, depth - 1)? {
+                modal = modal.child(child);
+            }
+            modal.build()
+        }
+        27 => TooltipBuilder::new(*u.choose(WORDS)?, valid_node(u, depth - 1)?).build(),
+        28 => {
+            let mut overlay = OverlayBuilder::new();
+            for child in valid_children(u, depth - 1)? {
+                let align = *u.choose(&[
+                    OverlayAlignment::Center,
+                    OverlayAlignment::TopEnd,
+                    OverlayAlignment::BottomStart,
+                ])?;
+                overlay = overlay.layer(OverlayLayer::new(child).align(align));
+            }
+            overlay.build()
+        }
+        29 => DisclosureBuilder::new(
+            *u.choose(WORDS)?,
+            u.arbitrary()?,
+            PropValue::action(identifier(u)?),
+        )
+        .children(valid_children(u, depth - 1)?)
+        .build(),
+        30 => {
+            let mut sheet =
+                BottomSheetBuilder::new(u.arbitrary()?, PropValue::action(identifier(u)?));
+            if u.arbitrary()? {
+                sheet = sheet.detent(SheetDetent::Medium).detent(SheetDetent::Large);
+            }
+            for child in valid_children(u, depth - 1)? {
+                sheet = sheet.child(child);
+            }
+            sheet.build()
+        }
+        31 => {
+            let side = *u.choose(&[DrawerSide::Start, DrawerSide::End])?;
+            let mut drawer =
+                DrawerBuilder::new(u.arbitrary()?, PropValue::action(identifier(u)?)).side(side);
+            for child in valid_children(u, depth - 1)? {
+                drawer = drawer.child(child);
+            }
+            drawer.build()
+        }
+        32 => {
+            let mut bar = AppBarBuilder::new(*u.choose(WORDS)?).elevated(u.arbitrary()?);
+            if u.arbitrary()? {
+                bar = bar.leading(valid_node(u, depth - 1)?);
+            }
+            for _ in 0..u.int_in_range(0..=2)? {
+                bar = bar.trailing(valid_node(u, depth - 1)?);
+            }
+            bar.build()
+        }
+        33 => {
+            let pages = valid_children(u, depth - 1)?;
+            let index = if pages.is_empty() {
+                0
+            } else {
+                u.choose_index(pages.len())?
+            };
+            CarouselBuilder::new(index, PropValue::lambda(u.arbitrary()?))
+                .show_indicators(u.arbitrary()?)
+                .children(pages)
+                .build()
+        }
+        _ => FormBuilder::new(PropValue::action(identifier(u)?))
+            .children(valid_children(u, depth - 1)?)
+            .build(),
+    })
+}
+
+fn valid_children(u: &mut Unstructured<'_>, depth: usize) -> Result<Vec<SurfaceNode>> {
+    let len = u.int_in_range(0..=MAX_LEN)?;
+    (0..len).map(|_| valid_node(u, depth)).collect()
+}
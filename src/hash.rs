@@ -0,0 +1,253 @@
+//! Structural hashing of surface trees.
+//!
+//! [`Surface::structural_hash`] gives a cheap "did anything change?" check
+//! between evaluations, and [`Surface::subtree_hashes`] lets callers skip
+//! unchanged subtrees when diffing large trees. Hashes are FNV-1a over an
+//! explicit encoding of every field — not `std::hash` (randomly seeded) and
+//! not serde — so they are identical across runs, platforms, and crate
+//! feature sets. Equal trees always hash equal; `-0.0` and `0.0` hash the
+//! same because they compare equal.
+//!
+//! ```
+//! use pepl_ui::{Surface, TextBuilder};
+//!
+//! let a = Surface::new(TextBuilder::new("3 items").build());
+//! let b = Surface::new(TextBuilder::new("3 items").build());
+//! assert_eq!(a.structural_hash(), b.structural_hash());
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+use crate::types::Dimension;
+
+impl Surface {
+    /// Hash of the whole surface: the tree plus layers, shortcuts, route,
+    /// and `respect_safe_area`.
+    pub fn structural_hash(&self) -> u64 {
+        let mut hasher = Fnv::new();
+        hasher.u64(self.root.structural_hash());
+        hasher.map(&self.shortcuts);
+        match &self.route {
+            Some(route) => {
+                hasher.u8(1);
+                hasher.str(&route.path);
+                hasher.usize(route.params.len());
+                for (name, value) in &route.params {
+                    hasher.str(name);
+                    hasher.str(value);
+                }
+            }
+            None => hasher.u8(0),
+        }
+        hasher.u8(u8::from(self.respect_safe_area));
+        // Last, and only if present, so surfaces without layers keep the
+        // hashes they had before layers existed.
+        if !self.layers.is_empty() {
+            hasher.usize(self.layers.len());
+            for layer in &self.layers {
+                hasher.str(&layer.name);
+                hasher.u64(layer.root.structural_hash());
+            }
+        }
+        hasher.finish()
+    }
+
+    /// The [`SurfaceNode::structural_hash`] of every node, layers included,
+    /// by path.
+    pub fn subtree_hashes(&self) -> BTreeMap<NodePath, u64> {
+        let mut hashes = BTreeMap::new();
+        for (name, root) in self.layer_roots() {
+            subtree_hash(root, NodePath::layer_root(name), &mut hashes);
+        }
+        hashes
+    }
+}
+
+impl SurfaceNode {
+    /// Hash of this node's subtree: type, `test_id`, key, props, and
+    /// children in order.
+    pub fn structural_hash(&self) -> u64 {
+        let child_hashes = self.children.iter().map(SurfaceNode::structural_hash);
+        node_hash(self, child_hashes)
+    }
+}
+
+fn subtree_hash(node: &SurfaceNode, path: NodePath, hashes: &mut BTreeMap<NodePath, u64>) -> u64 {
+    let child_hashes: Vec<u64> = node
+        .children
+        .iter()
+        .enumerate()
+        .map(|(i, child)| subtree_hash(child, path.child(i), hashes))
+        .collect();
+    let hash = node_hash(node, child_hashes.into_iter());
+    hashes.insert(path, hash);
+    hash
+}
+
+fn node_hash(node: &SurfaceNode, child_hashes: impl ExactSizeIterator<Item = u64>) -> u64 {
+    let mut hasher = Fnv::new();
+    hasher.str(&node.component_type);
+    hasher.opt_str(node.test_id.as_deref());
+    hasher.opt_str(node.key.as_deref());
+    hasher.map(&node.props);
+    hasher.usize(child_hashes.len());
+    for hash in child_hashes {
+        hasher.u64(hash);
+    }
+    hasher.finish()
+}
+
+/// 64-bit FNV-1a. Variable-length fields are length-prefixed so that
+/// different trees cannot produce the same byte stream.
+struct Fnv(u64);
+
+impl Fnv {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes(&[value]);
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn usize(&mut self, value: usize) {
+        self.u64(value as u64);
+    }
+
+    fn f64(&mut self, value: f64) {
+        // `-0.0 == 0.0`, so they must hash alike
+        self.u64(if value == 0.0 { 0 } else { value.to_bits() });
+    }
+
+    fn str(&mut self, value: &str) {
+        self.usize(value.len());
+        self.bytes(value.as_bytes());
+    }
+
+    fn opt_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.u8(1);
+                self.str(value);
+            }
+            None => self.u8(0),
+        }
+    }
+
+    fn map(&mut self, map: &BTreeMap<String, PropValue>) {
+        self.usize(map.len());
+        for (key, value) in map {
+            self.str(key);
+            self.value(value);
+        }
+    }
+
+    fn value(&mut self, value: &PropValue) {
+        match value {
+            PropValue::String(s) => {
+                self.u8(0);
+                self.str(s);
+            }
+            PropValue::Number(n) => {
+                self.u8(1);
+                self.f64(*n);
+            }
+            PropValue::Bool(b) => {
+                self.u8(2);
+                self.u8(u8::from(*b));
+            }
+            PropValue::Nil => self.u8(3),
+            PropValue::Color { r, g, b, a } => {
+                self.u8(4);
+                [r, g, b, a].into_iter().for_each(|c| self.f64(*c));
+            }
+            PropValue::ActionRef { action, args } => {
+                self.u8(5);
+                self.str(action);
+                match args {
+                    Some(args) => {
+                        self.u8(1);
+                        self.list(args);
+                    }
+                    None => self.u8(0),
+                }
+            }
+            PropValue::Lambda { lambda_id } => {
+                self.u8(6);
+                self.u64(u64::from(*lambda_id));
+            }
+            PropValue::ColorToken { token } => {
+                self.u8(7);
+                self.str(token);
+            }
+            PropValue::ThemeToken { token } => {
+                self.u8(8);
+                self.str(token);
+            }
+            PropValue::I18n { key, args } => {
+                self.u8(9);
+                self.str(key);
+                self.map(args);
+            }
+            PropValue::Timestamp { timestamp } => {
+                self.u8(10);
+                self.str(timestamp.as_str());
+            }
+            PropValue::Bytes { data } => {
+                self.u8(11);
+                self.usize(data.len());
+                self.bytes(data);
+            }
+            PropValue::Dimension { dim } => {
+                self.u8(12);
+                match dim {
+                    Dimension::Px(px) => {
+                        self.u8(0);
+                        self.f64(*px);
+                    }
+                    Dimension::Auto => self.u8(1),
+                    Dimension::Fill => self.u8(2),
+                    Dimension::Percent(percent) => {
+                        self.u8(3);
+                        self.f64(*percent);
+                    }
+                }
+            }
+            PropValue::List(items) => {
+                self.u8(13);
+                self.list(items);
+            }
+            PropValue::Record(fields) => {
+                self.u8(14);
+                self.map(fields);
+            }
+        }
+    }
+
+    fn list(&mut self, items: &[PropValue]) {
+        self.usize(items.len());
+        for item in items {
+            self.value(item);
+        }
+    }
+}
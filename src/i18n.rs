@@ -0,0 +1,259 @@
+//! Internationalized string references.
+//!
+//! User-visible string props may hold a [`PropValue::I18n`] reference instead
+//! of literal text. A [`StringCatalog`] maps message keys to translations for
+//! one locale, and [`Surface::localize`] replaces every reference with its
+//! resolved text before the surface is sent to the host.
+//!
+//! Messages use the template placeholder syntax (`{name}`, `{{`, `}}`), with
+//! placeholders bound from the reference's `args`. Plural messages select a
+//! form by the numeric `count` argument using the catalog locale's plural
+//! rules.
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//! use pepl_ui::i18n::{PluralCategory, StringCatalog};
+//! use pepl_ui::{PropValue, Surface, TextBuilder};
+//!
+//! let catalog = StringCatalog::new("en").with_plural(
+//!     "cart.items",
+//!     [(PluralCategory::One, "{count} item"), (PluralCategory::Other, "{count} items")],
+//! );
+//! let args = BTreeMap::from([("count".to_string(), PropValue::Number(3.0))]);
+//! let surface = Surface::new(TextBuilder::i18n_with_args("cart.items", args).build());
+//!
+//! let localized = surface.localize(&catalog);
+//! assert_eq!(localized.root.props["value"], PropValue::String("3 items".into()));
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::layers::Layer;
+use crate::prop_value::PropValue;
+use crate::surface::{check_values, Surface, SurfaceNode};
+use crate::template::{interpolate, Bindings};
+
+/// CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// A catalog entry: plain text, or one text per plural category.
+///
+/// Serialized as a string or as an object keyed by category
+/// (`{ "one": "{count} item", "other": "{count} items" }`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Message {
+    Text(String),
+    Plural(BTreeMap<PluralCategory, String>),
+}
+
+/// Translations for one locale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StringCatalog {
+    locale: String,
+    #[serde(default)]
+    messages: BTreeMap<String, Message>,
+}
+
+impl StringCatalog {
+    /// An empty catalog for `locale` (a BCP 47 tag such as `en`, `fr-CA`).
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: locale.into(),
+            messages: BTreeMap::new(),
+        }
+    }
+
+    /// Add a plain message.
+    pub fn with_message(mut self, key: impl Into<String>, text: impl Into<String>) -> Self {
+        self.messages.insert(key.into(), Message::Text(text.into()));
+        self
+    }
+
+    /// Add a plural message. Include [`PluralCategory::Other`]; it is the
+    /// fallback for categories without a form.
+    pub fn with_plural<S: Into<String>>(
+        mut self,
+        key: impl Into<String>,
+        forms: impl IntoIterator<Item = (PluralCategory, S)>,
+    ) -> Self {
+        let forms = forms
+            .into_iter()
+            .map(|(category, text)| (category, text.into()))
+            .collect();
+        self.messages.insert(key.into(), Message::Plural(forms));
+        self
+    }
+
+    /// The catalog locale.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// The message stored under `key`.
+    pub fn get(&self, key: &str) -> Option<&Message> {
+        self.messages.get(key)
+    }
+
+    /// Resolve `key` with `args`, or `None` if the catalog has no such key.
+    ///
+    /// Plural messages pick the form for the numeric `count` argument
+    /// (falling back to `other`); a missing or non-numeric `count` selects
+    /// `other`.
+    pub fn resolve(&self, key: &str, args: &Bindings) -> Option<String> {
+        let text = match self.messages.get(key)? {
+            Message::Text(text) => text,
+            Message::Plural(forms) => {
+                let category = match args.get("count") {
+                    Some(PropValue::Number(n)) => plural_category(&self.locale, *n),
+                    _ => PluralCategory::Other,
+                };
+                forms
+                    .get(&category)
+                    .or_else(|| forms.get(&PluralCategory::Other))?
+            }
+        };
+        Some(interpolate(text, args))
+    }
+
+    /// Resolve an [`I18n`](PropValue::I18n) reference. Nested references in
+    /// `args` are resolved first; a missing key resolves to the key itself.
+    fn resolve_value(&self, key: &str, args: &Bindings) -> String {
+        let args: Bindings = args
+            .iter()
+            .map(|(name, value)| (name.clone(), self.localize_value(value)))
+            .collect();
+        self.resolve(key, &args).unwrap_or_else(|| key.to_string())
+    }
+
+    fn localize_value(&self, value: &PropValue) -> PropValue {
+        match value {
+            PropValue::I18n { key, args } => PropValue::String(self.resolve_value(key, args)),
+            PropValue::List(items) => {
+                PropValue::List(items.iter().map(|v| self.localize_value(v)).collect())
+            }
+            PropValue::Record(fields) => PropValue::Record(
+                fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), self.localize_value(v)))
+                    .collect(),
+            ),
+            PropValue::ActionRef {
+                action,
+                args: Some(args),
+            } => PropValue::ActionRef {
+                action: action.clone(),
+                args: Some(args.iter().map(|v| self.localize_value(v)).collect()),
+            },
+            other => other.clone(),
+        }
+    }
+
+    fn localize_node(&self, node: &SurfaceNode) -> SurfaceNode {
+        SurfaceNode {
+            component_type: node.component_type.clone(),
+            test_id: node.test_id.clone(),
+            key: node.key.clone(),
+            props: node
+                .props
+                .iter()
+                .map(|(k, v)| (k.clone(), self.localize_value(v)))
+                .collect(),
+            children: node
+                .children
+                .iter()
+                .map(|child| self.localize_node(child))
+                .collect(),
+        }
+    }
+}
+
+/// The plural category of `n` in `locale`.
+///
+/// Covers the CLDR cardinal rules for common languages: English-like
+/// (default), French/Portuguese/Hindi, East and Southeast Asian languages
+/// without plurals, East Slavic, Polish, Czech/Slovak, and Arabic.
+pub fn plural_category(locale: &str, n: f64) -> PluralCategory {
+    use PluralCategory::*;
+
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let n = n.abs();
+    let integer = n.fract() == 0.0;
+    let (mod10, mod100) = ((n % 10.0) as u64, (n % 100.0) as u64);
+    let slavic_few = integer && (2..=4).contains(&mod10) && !(12..=14).contains(&mod100);
+
+    match language.as_str() {
+        "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" => Other,
+        "fr" | "pt" | "hi" if n < 2.0 => One,
+        "fr" | "pt" | "hi" => Other,
+        "ru" | "uk" | "be" if !integer => Other,
+        "ru" | "uk" | "be" if mod10 == 1 && mod100 != 11 => One,
+        "ru" | "uk" | "be" if slavic_few => Few,
+        "ru" | "uk" | "be" => Many,
+        "pl" if n == 1.0 => One,
+        "pl" if slavic_few => Few,
+        "pl" if integer => Many,
+        "pl" => Other,
+        "cs" | "sk" if n == 1.0 => One,
+        "cs" | "sk" if integer && (2.0..=4.0).contains(&n) => Few,
+        "cs" | "sk" if !integer => Many,
+        "cs" | "sk" => Other,
+        "ar" if n == 0.0 => Zero,
+        "ar" if n == 1.0 => One,
+        "ar" if n == 2.0 => Two,
+        "ar" if integer && (3..=10).contains(&mod100) => Few,
+        "ar" if integer && (11..=99).contains(&mod100) => Many,
+        "ar" => Other,
+        _ if n == 1.0 => One,
+        _ => Other,
+    }
+}
+
+impl Surface {
+    /// A copy of this surface with every [`PropValue::I18n`] reference
+    /// replaced by its text from `catalog`. Keys missing from the catalog
+    /// resolve to the key itself; use [`Surface::validate_i18n`] to find them.
+    pub fn localize(&self, catalog: &StringCatalog) -> Surface {
+        Surface {
+            root: catalog.localize_node(&self.root),
+            layers: self
+                .layers
+                .iter()
+                .map(|layer| Layer::new(layer.name.clone(), catalog.localize_node(&layer.root)))
+                .collect(),
+            shortcuts: self.shortcuts.clone(),
+            route: self.route.clone(),
+            respect_safe_area: self.respect_safe_area,
+        }
+    }
+
+    /// Check that every [`PropValue::I18n`] key exists in `catalog`. Empty
+    /// means the surface localizes completely.
+    ///
+    /// Errors look like
+    /// `root/0: Text.value: missing translation 'greeting' for locale 'fr'`.
+    pub fn validate_i18n(&self, catalog: &StringCatalog) -> Vec<String> {
+        check_values(self, &|value| match value {
+            PropValue::I18n { key, .. } if catalog.get(key).is_none() => Some(format!(
+                "missing translation '{key}' for locale '{}'",
+                catalog.locale()
+            )),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,214 @@
+//! Icon names.
+//!
+//! Button `icon` and the `Icon` component take an icon *name*, which the host
+//! maps to its platform glyph (SF Symbols, Material Symbols, ...). Names are
+//! checked against [`CANONICAL_ICONS`], the set every host ships, so a typo
+//! fails validation instead of rendering a blank square:
+//!
+//! ```text
+//! Icon.name: unknown icon 'serch' (did you mean 'search'?)
+//! ```
+//!
+//! Hosts that bundle more icons reference them as `asset:<name>`, which node
+//! validation accepts unchecked. An [`IconRegistry`] lists the host's extra
+//! names, and [`Surface::validate_icons`] checks every reference against it:
+//!
+//! ```
+//! use pepl_ui::icons::IconRegistry;
+//! use pepl_ui::{IconBuilder, Surface};
+//!
+//! let registry = IconRegistry::new().with_icon("brand_logo");
+//! let surface = Surface::new(IconBuilder::new("asset:brand_logo").build());
+//!
+//! assert!(surface.validate_icons(&registry).is_empty());
+//! ```
+
+use std::collections::BTreeSet;
+
+use crate::prop_value::PropValue;
+use crate::surface::Surface;
+
+/// The icon names every host provides, in alphabetical order.
+pub const CANONICAL_ICONS: &[&str] = &[
+    "add",
+    "arrow_back",
+    "arrow_down",
+    "arrow_forward",
+    "arrow_up",
+    "bell",
+    "bookmark",
+    "calendar",
+    "camera",
+    "check",
+    "chevron_down",
+    "chevron_left",
+    "chevron_right",
+    "chevron_up",
+    "clock",
+    "close",
+    "copy",
+    "download",
+    "edit",
+    "error",
+    "filter",
+    "heart",
+    "help",
+    "home",
+    "image",
+    "info",
+    "link",
+    "lock",
+    "mail",
+    "menu",
+    "minus",
+    "more",
+    "pause",
+    "phone",
+    "play",
+    "refresh",
+    "search",
+    "send",
+    "settings",
+    "share",
+    "star",
+    "stop",
+    "trash",
+    "upload",
+    "user",
+    "warning",
+];
+
+/// The props that hold an icon name, as `(component, prop)`.
+pub(crate) const ICON_PROPS: &[(&str, &str)] = &[("Button", "icon"), ("Icon", "name")];
+
+/// The canonical icons plus the host's bundled (`asset:<name>`) icons.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IconRegistry {
+    assets: BTreeSet<String>,
+}
+
+impl IconRegistry {
+    /// A registry of the canonical icons only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a host icon, referenced in the tree as `asset:<name>`.
+    pub fn with_icon(mut self, name: impl Into<String>) -> Self {
+        self.assets.insert(name.into());
+        self
+    }
+
+    /// Whether `name` is a canonical icon or a registered `asset:<name>`.
+    pub fn contains(&self, name: &str) -> bool {
+        match name.strip_prefix("asset:") {
+            Some(asset) => self.assets.contains(asset),
+            None => CANONICAL_ICONS.contains(&name),
+        }
+    }
+
+    /// Every known name: the canonical icons, then `asset:<name>` for each
+    /// registered host icon.
+    pub fn names(&self) -> Vec<String> {
+        CANONICAL_ICONS
+            .iter()
+            .map(|name| name.to_string())
+            .chain(self.assets.iter().map(|asset| format!("asset:{asset}")))
+            .collect()
+    }
+
+    /// The known name closest to `name`, if any is within two edits.
+    pub fn suggest(&self, name: &str) -> Option<String> {
+        closest(name, self.names())
+    }
+
+    /// The error for an unknown icon `name` in `component.key`, or `None`
+    /// if it is known.
+    pub fn check(&self, component: &str, key: &str, name: &str) -> Option<String> {
+        (!self.contains(name)).then(|| unknown_icon(component, key, name, self.suggest(name)))
+    }
+}
+
+/// Validate an icon name prop against the canonical icons. `asset:<name>`
+/// references are accepted; check them with [`Surface::validate_icons`].
+pub(crate) fn validate_icon_prop(component: &str, key: &str, prop: &PropValue) -> Vec<String> {
+    match prop {
+        PropValue::String(name) => match name.strip_prefix("asset:") {
+            Some(asset) if asset.trim().is_empty() => {
+                vec![format!(
+                    "{component}.{key}: asset icon name must not be empty"
+                )]
+            }
+            Some(_) => Vec::new(),
+            None => IconRegistry::new()
+                .check(component, key, name)
+                .into_iter()
+                .collect(),
+        },
+        other => vec![format!(
+            "{component}.{key}: expected string, got {}",
+            other.type_name()
+        )],
+    }
+}
+
+fn unknown_icon(component: &str, key: &str, name: &str, suggestion: Option<String>) -> String {
+    match suggestion {
+        Some(suggestion) => {
+            format!("{component}.{key}: unknown icon '{name}' (did you mean '{suggestion}'?)")
+        }
+        None => format!("{component}.{key}: unknown icon '{name}'"),
+    }
+}
+
+/// The candidate with the smallest edit distance to `name`, if within two
+/// edits. Ties go to the earlier candidate.
+pub(crate) fn closest(name: &str, candidates: Vec<String>) -> Option<String> {
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl Surface {
+    /// Check every icon reference (Button `icon`, Icon `name`) against
+    /// `registry`, including host `asset:<name>` icons. Empty means every
+    /// icon is known.
+    ///
+    /// Errors look like `root/0: Icon.name: unknown icon 'asset:brnd_logo'
+    /// (did you mean 'asset:brand_logo'?)`.
+    pub fn validate_icons(&self, registry: &IconRegistry) -> Vec<String> {
+        let mut errors = Vec::new();
+        self.walk(|node, path| {
+            for (component, key) in ICON_PROPS {
+                if node.component_type != *component {
+                    continue;
+                }
+                if let Some(PropValue::String(name)) = node.props.get(*key) {
+                    if let Some(error) = registry.check(component, key, name) {
+                        errors.push(format!("{path}: {error}"));
+                    }
+                }
+            }
+        });
+        errors
+    }
+}
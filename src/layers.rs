@@ -0,0 +1,152 @@
+//! Named layers stacked above the content tree.
+//!
+//! [`Surface::root`] is the `content` layer. Modals, toasts, and other
+//! floating UI go in their own layers, each with its own root, so they do
+//! not have to be threaded through the content layout and the host can
+//! order them on screen:
+//!
+//! ```
+//! use pepl_ui::layers::{OVERLAYS, TOASTS};
+//! use pepl_ui::{ColumnBuilder, Surface, ToastBuilder};
+//!
+//! let surface = Surface::new(ColumnBuilder::new().build())
+//!     .with_layer(OVERLAYS, ColumnBuilder::new().build())
+//!     .with_layer(TOASTS, ToastBuilder::new("Saved").build());
+//!
+//! let names: Vec<&str> = surface.layer_roots().map(|(name, _)| name).collect();
+//! assert_eq!(names, ["content", "overlays", "toasts"]);
+//! ```
+//!
+//! Layers serialize as a list, bottom to top, after the content root; a
+//! surface without layers serializes exactly as before:
+//!
+//! ```json
+//! {
+//!   "root": { "type": "Column", "props": {}, "children": [] },
+//!   "layers": [
+//!     { "name": "overlays", "root": { "type": "Column", "props": {}, "children": [] } },
+//!     { "name": "toasts", "root": { "type": "Toast", "props": { "message": "Saved" }, "children": [] } }
+//!   ]
+//! }
+//! ```
+//!
+//! Hosts draw `content` first and each later layer above the ones before
+//! it. Nodes in a layer are addressed by paths that start with the layer
+//! name instead of `root` (see [`NodePath::layer_root`]), so errors read
+//! `toasts: Toast.message: required prop missing` or
+//! `overlays/0: Text.value: expected string, got number`.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::path::NodePath;
+use crate::surface::{Surface, SurfaceNode};
+
+/// The name of the layer held in [`Surface::root`].
+pub const CONTENT: &str = "content";
+
+/// Conventional layer for modals, sheets, and popovers.
+pub const OVERLAYS: &str = "overlays";
+
+/// Conventional layer for toasts, above overlays.
+pub const TOASTS: &str = "toasts";
+
+/// A named tree drawn above the content tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Layer {
+    /// Layer name: unique within the surface, without `/`, and neither
+    /// `content` nor `root`.
+    pub name: String,
+    /// The root node of this layer's tree.
+    pub root: SurfaceNode,
+}
+
+impl Layer {
+    /// A layer named `name` with tree `root`.
+    pub fn new(name: impl Into<String>, root: SurfaceNode) -> Self {
+        Self {
+            name: name.into(),
+            root,
+        }
+    }
+}
+
+impl Surface {
+    /// Set layer `name` to `root`. An existing layer keeps its place in the
+    /// stack; a new one goes on top. `content` replaces [`Surface::root`].
+    pub fn with_layer(mut self, name: impl Into<String>, root: SurfaceNode) -> Self {
+        let name = name.into();
+        match self.layer_mut(&name) {
+            Some(existing) => *existing = root,
+            None => self.layers.push(Layer::new(name, root)),
+        }
+        self
+    }
+
+    /// The root of layer `name`; `content` is [`Surface::root`].
+    pub fn layer(&self, name: &str) -> Option<&SurfaceNode> {
+        self.layer_roots()
+            .find(|(layer, _)| *layer == name)
+            .map(|(_, root)| root)
+    }
+
+    /// The root of layer `name`, mutably; `content` is [`Surface::root`].
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut SurfaceNode> {
+        if name == CONTENT {
+            return Some(&mut self.root);
+        }
+        self.layers
+            .iter_mut()
+            .find(|layer| layer.name == name)
+            .map(|layer| &mut layer.root)
+    }
+
+    /// Remove layer `name` and return its root. The content layer cannot
+    /// be removed, so `content` yields `None`.
+    pub fn remove_layer(&mut self, name: &str) -> Option<SurfaceNode> {
+        let index = self.layers.iter().position(|layer| layer.name == name)?;
+        Some(self.layers.remove(index).root)
+    }
+
+    /// Every layer as `(name, root)`, bottom to top, starting with
+    /// `content`.
+    pub fn layer_roots(&self) -> impl Iterator<Item = (&str, &SurfaceNode)> {
+        std::iter::once((CONTENT, &self.root)).chain(
+            self.layers
+                .iter()
+                .map(|layer| (layer.name.as_str(), &layer.root)),
+        )
+    }
+
+    /// Check layer names and validate each layer's tree.
+    pub(crate) fn validate_layers(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut seen = BTreeSet::new();
+        for layer in &self.layers {
+            let name = &layer.name;
+            if name.trim().is_empty() {
+                errors.push("Surface.layers: layer name must not be empty".to_string());
+                continue;
+            } else if name == CONTENT || name == "root" {
+                errors.push(format!(
+                    "Surface.layers: '{name}' is reserved for Surface.root"
+                ));
+                continue;
+            } else if name.contains('/') {
+                errors.push(format!(
+                    "Surface.layers: layer name '{name}' must not contain '/'"
+                ));
+                continue;
+            } else if !seen.insert(name.as_str()) {
+                errors.push(format!("Surface.layers: duplicate layer '{name}'"));
+                continue;
+            }
+            errors.extend(crate::surface::validate_root(
+                &layer.root,
+                &NodePath::layer_root(name),
+            ));
+        }
+        errors
+    }
+}
@@ -18,30 +18,122 @@
 //! | Interactive | Button, TextInput |
 //! | List & Data | ScrollList |
 //! | Feedback | Modal, Toast |
+//!
+//! # Additional Components
+//!
+//! | Category | Components |
+//! |----------|-----------|
+//! | Form | Form |
+//! | Content | RichText, Spinner, Icon, Image, Skeleton |
+//! | Layout | Tabs, Carousel, Disclosure, Overlay, AppBar, Divider, Spacer |
+//! | Feedback | Tooltip, BottomSheet, Drawer, Snackbar |
+//! | List & Data | Table |
+//! | Interactive | Stepper, Rating, FilePicker |
+//! | Media | Video, AudioPlayer |
+//! | Graphics | Canvas, Chart |
 
 pub mod accessibility;
+mod ascii_tree;
+mod bytes;
+pub mod codegen;
+pub mod complexity;
 pub mod components;
+pub mod cost;
+mod diff_report;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod focus;
+pub mod fonts;
+pub mod fuzz;
+#[cfg(feature = "arbitrary")]
+mod generators;
+mod hash;
+pub mod i18n;
+pub mod icons;
+pub mod layers;
+pub mod navigation;
+pub mod node_ids;
+mod normalize;
+pub mod patch;
+mod path;
 mod prop_value;
+#[cfg(feature = "python")]
+mod python;
 mod registry;
+pub mod route;
+pub mod selector;
+pub mod shortcuts;
+pub mod slots;
 mod surface;
+pub mod template;
+pub mod theme;
+mod timestamp;
+pub mod trace;
 mod types;
+mod walk;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+pub use complexity::{ComplexityReport, SurfaceStats};
+pub use components::common::{
+    animate_prop, animation_to_prop, corner_radius_to_prop, gradient_to_prop, is_animatable,
+    is_common_prop, is_gradient, validate_animate_prop, validate_common_props,
+    validate_corner_radius_prop, validate_dimension_prop, validate_gradient_prop, CommonFields,
+    CommonProps,
+};
 pub use components::content::{
-    validate_content_node, ProgressBarBuilder, TextAlign, TextBuilder, TextOverflow, TextSize,
-    TextWeight,
+    validate_content_node, IconBuilder, ImageBuilder, ImageFit, ProgressBarBuilder, ProgressShape,
+    RichTextBuilder, SkeletonBuilder, SkeletonShape, SpinnerBuilder, TextAlign, TextBuilder,
+    TextOverflow, TextSize, TextSpan, TextWeight,
+};
+pub use components::feedback::{
+    transition_to_prop, validate_feedback_node, validate_transition_prop, BottomSheetBuilder,
+    DrawerBuilder, DrawerSide, ModalAction, ModalBuilder, ModalPresentation, SheetDetent,
+    SnackbarBuilder, ToastAction, ToastBuilder, ToastPriority, ToastType, TooltipBuilder,
+    TooltipPlacement,
+};
+pub use components::form::{validate_form_node, FormBuilder};
+pub use components::graphics::{
+    validate_graphics_node, CanvasBuilder, ChartBuilder, ChartKind, ChartSeries, DrawCommand,
+    DrawShape,
 };
-pub use components::feedback::{validate_feedback_node, ModalBuilder, ToastBuilder, ToastType};
 pub use components::interactive::{
-    validate_interactive_node, ButtonBuilder, ButtonVariant, KeyboardType, TextInputBuilder,
+    validate_interactive_node, Autocapitalize, ButtonBuilder, ButtonSize, ButtonVariant,
+    FilePickerBuilder, Haptic, KeyboardType, RatingBuilder, StepperBuilder, TapSound,
+    TextInputBuilder,
 };
 pub use components::layout::{
-    validate_layout_node, ColumnBuilder, RowBuilder, ScrollBuilder, ScrollDirection,
+    shadow_to_prop, validate_layout_node, AppBarBuilder, CarouselBuilder, ColumnBuilder,
+    DisclosureBuilder, DividerBuilder, OverlayAlignment, OverlayBuilder, OverlayLayer, RowBuilder,
+    ScrollBuilder, ScrollDirection, ScrollTarget, SpacerBuilder, TabsBuilder,
 };
-pub use components::list::{validate_list_node, ScrollListBuilder};
+pub use components::list::{validate_list_node, ScrollListBuilder, TableBuilder, TableColumn};
+pub use components::media::{validate_media_node, AudioPlayerBuilder, VideoBuilder};
+pub use components::validate_node;
+pub use cost::CostModel;
+pub use focus::compute_initial_focus;
+pub use fuzz::{fuzz_parse, fuzz_validate};
+#[cfg(feature = "arbitrary")]
+pub use generators::ValidSurface;
+pub use layers::Layer;
+pub use navigation::{BackOutcome, Screen, ScreenStack};
+pub use node_ids::NodeIds;
+pub use patch::{PatchOp, SurfacePatch};
+pub use path::{NodePath, PathSegment};
 pub use prop_value::PropValue;
 pub use registry::{ComponentDef, ComponentRegistry, PropDef, PropRequirement};
+pub use route::Route;
+pub use selector::Selector;
+pub use shortcuts::{validate_key_combo, validate_shortcuts};
 pub use surface::{Surface, SurfaceNode};
-pub use types::{Alignment, BorderStyle, ColorValue, Dimension, Edges, ShadowStyle};
+pub use template::Template;
+pub use timestamp::Timestamp;
+pub use trace::{EventTrace, TraceEvent};
+pub use types::{
+    AdaptiveColor, AlignSelf, Alignment, AnimationSpec, BorderStyle, ColorParseError, ColorValue,
+    CornerRadii, Dimension, Easing, Edges, Gradient, GradientStop, Justify, Position,
+    SafeAreaEdges, ShadowStyle, Spacing, Spring, TransitionKind, TransitionSpec,
+};
 
 // Accessibility
 pub use accessibility::{
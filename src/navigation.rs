@@ -0,0 +1,171 @@
+//! Screen-stack navigation model.
+//!
+//! Multi-screen PEPL apps describe navigation as a [`ScreenStack`] of named
+//! [`Surface`]s. The stack serializes alongside the Surface so every host sees
+//! the same history:
+//!
+//! ```json
+//! {
+//!   "screens": [
+//!     { "name": "inbox", "surface": { "root": { ... } } },
+//!     { "name": "message", "surface": { "root": { ... } },
+//!       "on_back": { "__action": "confirm_discard" } }
+//!   ]
+//! }
+//! ```
+//!
+//! The last screen is the one shown. A system back gesture/button is resolved
+//! by [`ScreenStack::back`]: a screen's `on_back` action takes precedence over
+//! popping, and the root screen is never popped.
+
+use crate::prop_value::PropValue;
+use crate::surface::Surface;
+use serde::{Deserialize, Serialize};
+
+/// A named screen in a [`ScreenStack`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Screen {
+    /// Screen name (e.g. `"inbox"`, `"settings"`). Need not be unique.
+    pub name: String,
+    /// The screen's UI tree.
+    pub surface: Surface,
+    /// Action dispatched instead of popping when the user navigates back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_back: Option<PropValue>,
+}
+
+impl Screen {
+    /// Create a screen with no `on_back` handler.
+    pub fn new(name: impl Into<String>, surface: Surface) -> Self {
+        Self {
+            name: name.into(),
+            surface,
+            on_back: None,
+        }
+    }
+
+    /// Intercept back navigation with an action (`PropValue::ActionRef`).
+    pub fn on_back(mut self, on_back: PropValue) -> Self {
+        self.on_back = Some(on_back);
+        self
+    }
+}
+
+/// Result of a back navigation request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackOutcome {
+    /// The current screen intercepts back; dispatch this action.
+    Action(PropValue),
+    /// The current screen was popped and is returned.
+    Popped(Box<Screen>),
+    /// Only the root screen remains (or the stack is empty); the host should
+    /// apply its platform default (e.g. leave the app).
+    Exit,
+}
+
+/// Ordered stack of screens; the last one is shown.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScreenStack {
+    screens: Vec<Screen>,
+}
+
+impl ScreenStack {
+    /// Create a stack with a single root screen.
+    pub fn new(root: Screen) -> Self {
+        Self {
+            screens: vec![root],
+        }
+    }
+
+    /// Push a screen on top of the stack.
+    pub fn push(&mut self, screen: Screen) {
+        self.screens.push(screen);
+    }
+
+    /// Pop the top screen. The root screen is never popped.
+    pub fn pop(&mut self) -> Option<Screen> {
+        if self.screens.len() > 1 {
+            self.screens.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Replace the top screen, returning the previous one.
+    ///
+    /// On an empty stack the screen becomes the root and `None` is returned.
+    pub fn replace(&mut self, screen: Screen) -> Option<Screen> {
+        let previous = self.screens.pop();
+        self.screens.push(screen);
+        previous
+    }
+
+    /// Resolve a back request against the current screen.
+    pub fn back(&mut self) -> BackOutcome {
+        if let Some(action) = self.current().and_then(|s| s.on_back.clone()) {
+            return BackOutcome::Action(action);
+        }
+        match self.pop() {
+            Some(screen) => BackOutcome::Popped(Box::new(screen)),
+            None => BackOutcome::Exit,
+        }
+    }
+
+    /// The screen currently shown.
+    pub fn current(&self) -> Option<&Screen> {
+        self.screens.last()
+    }
+
+    /// All screens, root first.
+    pub fn screens(&self) -> &[Screen] {
+        &self.screens
+    }
+
+    /// Screen names, root first.
+    pub fn names(&self) -> Vec<&str> {
+        self.screens.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    /// Number of screens.
+    pub fn len(&self) -> usize {
+        self.screens.len()
+    }
+
+    /// Whether the stack has no screens.
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty()
+    }
+
+    /// Validate the stack. Each screen's surface is checked with
+    /// [`Surface::validate`], its errors prefixed with the screen index.
+    ///
+    /// Returns a list of validation errors. Empty means valid.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.screens.is_empty() {
+            errors.push("ScreenStack: must contain at least one screen".to_string());
+        }
+        for (i, screen) in self.screens.iter().enumerate() {
+            if screen.name.is_empty() {
+                errors.push(format!("ScreenStack.screens[{i}].name: must not be empty"));
+            }
+            if let Some(on_back) = &screen.on_back {
+                if !matches!(on_back, PropValue::ActionRef { .. }) {
+                    errors.push(format!(
+                        "ScreenStack.screens[{i}].on_back: expected action, got {}",
+                        on_back.type_name()
+                    ));
+                }
+            }
+            for error in screen.surface.validate() {
+                errors.push(format!("ScreenStack.screens[{i}]: {error}"));
+            }
+        }
+        errors
+    }
+
+    /// Serialize this stack to JSON (deterministic output).
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("ScreenStack serialization should never fail")
+    }
+}
@@ -0,0 +1,144 @@
+//! Deterministic node IDs.
+//!
+//! [`Surface::assign_ids`] gives every node an ID derived from its ancestry:
+//! each node contributes a segment made of its component type and its key —
+//! the node's `key` if set, else its `test_id`, else a string `anchor` prop,
+//! else its child index.
+//! Keyed nodes keep their ID when siblings are inserted or reordered; unkeyed
+//! nodes keep it as long as their index does. A [layer](crate::layers)'s
+//! chain starts with a `+name` segment, so its IDs never collide with the
+//! content tree's. IDs are the FNV-1a hash of the
+//! segment chain, rendered as `n` + 16 hex digits, so they are identical
+//! across runs, platforms, and frames.
+//!
+//! IDs are not part of the wire format. [`Surface::to_json_debug`] includes
+//! them for logs and debugging.
+
+use std::collections::BTreeMap;
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+/// Node IDs for one [`Surface`], addressable by path or by ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeIds {
+    by_path: BTreeMap<NodePath, String>,
+}
+
+impl NodeIds {
+    /// The ID of the node at `path`.
+    pub fn get(&self, path: &NodePath) -> Option<&str> {
+        self.by_path.get(path).map(String::as_str)
+    }
+
+    /// The path of the node with `id`.
+    pub fn path_of(&self, id: &str) -> Option<&NodePath> {
+        self.by_path
+            .iter()
+            .find_map(|(path, node_id)| (node_id == id).then_some(path))
+    }
+
+    /// All `(path, id)` pairs in path order.
+    pub fn iter(&self) -> impl Iterator<Item = (&NodePath, &str)> {
+        self.by_path.iter().map(|(path, id)| (path, id.as_str()))
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.by_path.len()
+    }
+
+    /// Whether there are no nodes (never true for a real surface).
+    pub fn is_empty(&self) -> bool {
+        self.by_path.is_empty()
+    }
+}
+
+impl Surface {
+    /// Assign a stable ID to every node. See the [module docs](crate::node_ids).
+    pub fn assign_ids(&self) -> NodeIds {
+        let mut ids = NodeIds::default();
+        let root_hash = extend(FNV_OFFSET, &segment(&self.root, None));
+        assign(&self.root, NodePath::root(), root_hash, &mut ids);
+        for layer in &self.layers {
+            let layer_hash = extend(FNV_OFFSET, &format!("+{}", layer.name));
+            let root_hash = extend(layer_hash, &segment(&layer.root, None));
+            assign(
+                &layer.root,
+                NodePath::layer_root(&layer.name),
+                root_hash,
+                &mut ids,
+            );
+        }
+        ids
+    }
+
+    /// Pretty-printed JSON with an `"id"` field on every node, for logs and
+    /// debugging. Not accepted back as a surface payload.
+    pub fn to_json_debug(&self) -> String {
+        let ids = self.assign_ids();
+        let mut json = serde_json::to_value(self).expect("Surface serialization should never fail");
+        insert_ids(&mut json["root"], &NodePath::root(), &ids);
+        for (i, layer) in self.layers.iter().enumerate() {
+            let path = NodePath::layer_root(&layer.name);
+            insert_ids(&mut json["layers"][i]["root"], &path, &ids);
+        }
+        serde_json::to_string_pretty(&json).expect("JSON values always serialize")
+    }
+}
+
+fn assign(node: &SurfaceNode, path: NodePath, hash: u64, ids: &mut NodeIds) {
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+    for (i, child) in node.children.iter().enumerate() {
+        let mut segment = segment(child, Some(i));
+        // Disambiguate repeated keys among siblings by occurrence.
+        let count = seen.entry(segment.clone()).or_default();
+        if *count > 0 {
+            segment.push_str(&format!("~{count}"));
+        }
+        *count += 1;
+        assign(child, path.child(i), extend(hash, &segment), ids);
+    }
+    ids.by_path.insert(path, format!("n{hash:016x}"));
+}
+
+/// `Type$key`, `Type#test_id`, `Type@anchor`, or `Type[index]` (root: `Type`).
+fn segment(node: &SurfaceNode, index: Option<usize>) -> String {
+    let component = &node.component_type;
+    if let Some(key) = &node.key {
+        return format!("{component}${key}");
+    }
+    match (&node.test_id, node.props.get("anchor"), index) {
+        (Some(test_id), _, _) => format!("{component}#{test_id}"),
+        (None, Some(PropValue::String(anchor)), _) => format!("{component}@{anchor}"),
+        (None, _, Some(i)) => format!("{component}[{i}]"),
+        (None, _, None) => component.clone(),
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Continue an FNV-1a hash with `/` and `segment`.
+fn extend(hash: u64, segment: &str) -> u64 {
+    std::iter::once(b'/')
+        .chain(segment.bytes())
+        .fold(hash, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
+fn insert_ids(node: &mut serde_json::Value, path: &NodePath, ids: &NodeIds) {
+    let Some(object) = node.as_object_mut() else {
+        return;
+    };
+    if let Some(id) = ids.get(path) {
+        object.insert("id".to_string(), id.into());
+    }
+    if let Some(serde_json::Value::Array(children)) = object.get_mut("children") {
+        for (i, child) in children.iter_mut().enumerate() {
+            insert_ids(child, &path.child(i), ids);
+        }
+    }
+}
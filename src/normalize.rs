@@ -0,0 +1,155 @@
+//! Canonical minimal trees; see [`Surface::normalize`].
+
+use crate::accessibility;
+use crate::components::common::validates_own_prop;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+impl Surface {
+    /// Rewrite the tree into its canonical minimal form.
+    ///
+    /// Evaluator output tends to over-nest: a `Column` wrapping a single
+    /// child for no visual reason, a `Scroll` inside a `Scroll`. Normalizing
+    /// means hosts lay out fewer views and diffs ([`Surface::diff`]) don't
+    /// churn on wrappers that come and go:
+    ///
+    /// 1. Props set to their documented default are removed: the common props
+    ///    `opacity: 1` and `visible: true` (not Modal or BottomSheet
+    ///    `visible`, which is required), `spacing: 0` and `padding: 0` on Column
+    ///    and Row, `wrap: false` and `run_spacing: 0` on Row, and
+    ///    `direction: "vertical"` on Scroll.
+    /// 2. A Column or Row with exactly one child and nothing else — no key, no
+    ///    `test_id`, and no props beyond the auto-generated `accessible` — is
+    ///    replaced by that child, unless the child positions itself against its
+    ///    parent (`flex`, `align_self`).
+    /// 3. A Scroll whose only child is a bare Scroll of the same direction takes
+    ///    over that Scroll's children.
+    /// 4. Hex color strings are converted to colors (see
+    ///    [`Surface::coerce_colors`]).
+    ///
+    /// Layers are normalized the same way. Normalizing twice gives the same
+    /// tree as normalizing once.
+    pub fn normalize(&mut self) {
+        normalize_root(&mut self.root);
+        for layer in &mut self.layers {
+            normalize_root(&mut layer.root);
+        }
+        self.coerce_colors();
+    }
+
+    /// Convert hex strings (`"#RRGGBB"`, `"#RRGGBBAA"`) in color positions to
+    /// [`PropValue::Color`].
+    ///
+    /// Color props accept hex strings, but hosts only decode colors, so run
+    /// this (or [`Surface::normalize`]) before serializing a tree built from
+    /// hand-written props or JSON. Color positions are the `color`,
+    /// `background`, `fill`, and `stroke` props and record fields at any depth
+    /// (borders, shadows, gradient stops, canvas commands, chart series, text
+    /// spans), and both sides of an adaptive color. Other strings, and hex
+    /// strings that do not parse, are left alone.
+    pub fn coerce_colors(&mut self) {
+        self.walk_mut(|node, _| {
+            for (key, value) in &mut node.props {
+                coerce_color(key, value);
+            }
+        });
+    }
+}
+
+/// Keys whose string values are colors.
+const COLOR_KEYS: &[&str] = &["color", "background", "fill", "stroke", "__light", "__dark"];
+
+fn coerce_color(key: &str, value: &mut PropValue) {
+    match value {
+        PropValue::String(_) if COLOR_KEYS.contains(&key) => {
+            if let Some(color) = value.as_color() {
+                *value = color.into();
+            }
+        }
+        PropValue::Record(fields) => {
+            for (field, item) in fields {
+                coerce_color(field, item);
+            }
+        }
+        PropValue::List(items) => {
+            for item in items {
+                coerce_color("", item);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn normalize_root(root: &mut SurfaceNode) {
+    root.walk_post_mut(|node, _| {
+        remove_default_props(node);
+        for child in &mut node.children {
+            if let Some(only) = unwrap_target(child) {
+                *child = only;
+            }
+        }
+        merge_nested_scroll(node);
+    });
+    while let Some(only) = unwrap_target(root) {
+        *root = only;
+    }
+}
+
+fn remove_default_props(node: &mut SurfaceNode) {
+    let component = node.component_type.clone();
+    node.props
+        .retain(|key, value| !is_default(&component, key, value));
+}
+
+fn is_default(component: &str, key: &str, value: &PropValue) -> bool {
+    // A component's own prop of that name has its own meaning and default
+    if validates_own_prop(component, key) {
+        return false;
+    }
+    let zero = PropValue::Number(0.0);
+    match (component, key) {
+        (_, "opacity") => *value == PropValue::Number(1.0),
+        (_, "visible") => *value == PropValue::Bool(true),
+        ("Column" | "Row", "spacing" | "padding") | ("Row", "run_spacing") => *value == zero,
+        ("Row", "wrap") => *value == PropValue::Bool(false),
+        ("Scroll", "direction") => *value == PropValue::String("vertical".into()),
+        _ => false,
+    }
+}
+
+/// If `node` is a wrapper that can be dropped, take its only child.
+fn unwrap_target(node: &mut SurfaceNode) -> Option<SurfaceNode> {
+    let is_wrapper = matches!(node.component_type.as_str(), "Column" | "Row")
+        && node.children.len() == 1
+        && is_bare(node, &[])
+        && !["flex", "align_self"]
+            .iter()
+            .any(|key| node.children[0].props.contains_key(*key));
+    is_wrapper.then(|| node.children.remove(0))
+}
+
+fn merge_nested_scroll(node: &mut SurfaceNode) {
+    if node.component_type != "Scroll" || node.children.len() != 1 {
+        return;
+    }
+    let inner = &node.children[0];
+    if inner.component_type == "Scroll"
+        && inner.props.get("direction") == node.props.get("direction")
+        && is_bare(inner, &["direction"])
+    {
+        node.children = std::mem::take(&mut node.children[0].children);
+    }
+}
+
+/// Whether `node` carries nothing but its type, the props in `except`, and
+/// the `accessible` prop its builder would have generated.
+fn is_bare(node: &SurfaceNode, except: &[&str]) -> bool {
+    if node.key.is_some() || node.test_id.is_some() {
+        return false;
+    }
+    let generated =
+        accessibility::auto_accessible(&node.component_type, &Default::default()).to_prop_value();
+    node.props.iter().all(|(key, value)| {
+        except.contains(&key.as_str()) || (key == "accessible" && *value == generated)
+    })
+}
@@ -0,0 +1,348 @@
+//! Incremental updates between two surfaces.
+//!
+//! [`Surface::diff`] turns the change from one evaluation's tree to the next
+//! into a list of [`PatchOp`]s, so a host can update its native views in
+//! place instead of rebuilding the whole tree:
+//!
+//! ```
+//! use pepl_ui::patch::PatchOp;
+//! use pepl_ui::{ColumnBuilder, NodePath, PropValue, Surface};
+//!
+//! let before = Surface::new(ColumnBuilder::new().spacing(8.0).build());
+//! let after = Surface::new(ColumnBuilder::new().spacing(12.0).build());
+//!
+//! assert_eq!(
+//!     before.diff(&after),
+//!     vec![PatchOp::SetProp {
+//!         path: NodePath::root(),
+//!         key: "spacing".into(),
+//!         value: PropValue::Number(12.0),
+//!     }]
+//! );
+//! ```
+//!
+//! Ops apply in order, and each op's paths address the tree as left by the
+//! ops before it. Children are matched by [`key`](SurfaceNode::key) where
+//! set — a keyed child that moved produces a `move_child` rather than a
+//! rebuild, so the host keeps its focus, scroll, and animation state.
+//! Unkeyed children are matched in order. A node whose type, key, or
+//! `test_id` changed is replaced whole. Paths address the content tree;
+//! changed layers, shortcuts, route, or `respect_safe_area` follow the
+//! tree ops, each replaced whole.
+//!
+//! # Wire format
+//!
+//! Evaluators send a [`SurfacePatch`] ([`Surface::diff_patch`]): the ops
+//! plus the format version, so a host can reject patches it does not
+//! understand and ask for a full surface instead. Hosts replay it with
+//! [`Surface::apply_patch`]. Each op is a record tagged with `"op"`:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "ops": [
+//!     { "op": "move_child", "path": "root/1", "from": 3, "to": 0 },
+//!     { "op": "set_prop", "path": "root/1/0", "key": "value", "value": "Done" },
+//!     { "op": "set_route", "route": null }
+//!   ]
+//! }
+//! ```
+//!
+//! Serialization is deterministic: ops come in diff order and record fields
+//! in declaration order, and props inside nodes sort by key.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::layers::Layer;
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::route::Route;
+use crate::surface::{Surface, SurfaceNode};
+
+/// The [`SurfacePatch`] format version this crate writes and accepts.
+pub const PATCH_FORMAT_VERSION: u32 = 1;
+
+/// One step of an incremental update. `path` addresses the node the op
+/// changes; child ops take the parent's path and a child index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PatchOp {
+    /// Set (add or overwrite) a prop.
+    SetProp {
+        path: NodePath,
+        key: String,
+        value: PropValue,
+    },
+    /// Remove a prop.
+    RemoveProp { path: NodePath, key: String },
+    /// Insert `node` as child `index`, shifting later children right.
+    InsertChild {
+        path: NodePath,
+        index: usize,
+        node: SurfaceNode,
+    },
+    /// Remove child `index`, shifting later children left.
+    RemoveChild { path: NodePath, index: usize },
+    /// Take child `from` out and reinsert it at `to`.
+    MoveChild {
+        path: NodePath,
+        from: usize,
+        to: usize,
+    },
+    /// Replace the node and its subtree.
+    ReplaceNode { path: NodePath, node: SurfaceNode },
+    /// Replace the surface's layers (everything above the content tree).
+    SetLayers { layers: Vec<Layer> },
+    /// Replace the surface's keyboard shortcuts.
+    SetShortcuts {
+        shortcuts: BTreeMap<String, PropValue>,
+    },
+    /// Replace (or clear, with `null`) the surface's route.
+    SetRoute { route: Option<Route> },
+    /// Set the surface's `respect_safe_area` flag.
+    SetRespectSafeArea { respect: bool },
+}
+
+/// A versioned list of [`PatchOp`]s: one incremental update from the
+/// evaluator to the host.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SurfacePatch {
+    /// Format version; see [`PATCH_FORMAT_VERSION`].
+    pub version: u32,
+    /// The ops, applied in order.
+    pub ops: Vec<PatchOp>,
+}
+
+impl SurfacePatch {
+    /// A patch in the current format version.
+    pub fn new(ops: Vec<PatchOp>) -> Self {
+        Self {
+            version: PATCH_FORMAT_VERSION,
+            ops,
+        }
+    }
+
+    /// Whether the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Serialize to JSON (deterministic output).
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("SurfacePatch serialization should never fail")
+    }
+}
+
+impl Surface {
+    /// The ops that turn this surface into `other`. Empty when the surfaces
+    /// are equal. See the [module docs](crate::patch).
+    pub fn diff(&self, other: &Surface) -> Vec<PatchOp> {
+        let mut ops = Vec::new();
+        diff_node(&self.root, &other.root, &NodePath::root(), &mut ops);
+        if self.layers != other.layers {
+            ops.push(PatchOp::SetLayers {
+                layers: other.layers.clone(),
+            });
+        }
+        if self.shortcuts != other.shortcuts {
+            ops.push(PatchOp::SetShortcuts {
+                shortcuts: other.shortcuts.clone(),
+            });
+        }
+        if self.route != other.route {
+            ops.push(PatchOp::SetRoute {
+                route: other.route.clone(),
+            });
+        }
+        if self.respect_safe_area != other.respect_safe_area {
+            ops.push(PatchOp::SetRespectSafeArea {
+                respect: other.respect_safe_area,
+            });
+        }
+        ops
+    }
+
+    /// [`Surface::diff`] wrapped in a [`SurfacePatch`] for the wire.
+    pub fn diff_patch(&self, other: &Surface) -> SurfacePatch {
+        SurfacePatch::new(self.diff(other))
+    }
+
+    /// Apply `patch`, turning this surface into the one it was diffed
+    /// against. All or nothing: on an unsupported version or an op whose
+    /// path or index does not exist, the surface is left unchanged.
+    ///
+    /// Errors look like `root/4: no such node`.
+    pub fn apply_patch(&mut self, patch: &SurfacePatch) -> Result<(), String> {
+        if patch.version != PATCH_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported patch version {} (expected {PATCH_FORMAT_VERSION})",
+                patch.version
+            ));
+        }
+        let mut patched = self.clone();
+        for op in &patch.ops {
+            apply(&mut patched, op)?;
+        }
+        *self = patched;
+        Ok(())
+    }
+}
+
+fn diff_node(old: &SurfaceNode, new: &SurfaceNode, path: &NodePath, ops: &mut Vec<PatchOp>) {
+    if old.component_type != new.component_type || old.key != new.key || old.test_id != new.test_id
+    {
+        ops.push(PatchOp::ReplaceNode {
+            path: path.clone(),
+            node: new.clone(),
+        });
+        return;
+    }
+    for key in old.props.keys().filter(|key| !new.props.contains_key(*key)) {
+        ops.push(PatchOp::RemoveProp {
+            path: path.clone(),
+            key: key.clone(),
+        });
+    }
+    for (key, value) in &new.props {
+        if old.props.get(key) != Some(value) {
+            ops.push(PatchOp::SetProp {
+                path: path.clone(),
+                key: key.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+    diff_children(old, new, path, ops);
+}
+
+/// A child's identity among its siblings: its key, or `None` for unkeyed
+/// children, plus its occurrence among siblings with the same key.
+type Identity<'a> = (Option<&'a str>, usize);
+
+fn identities(children: &[SurfaceNode]) -> Vec<Identity<'_>> {
+    let mut seen: BTreeMap<Option<&str>, usize> = BTreeMap::new();
+    children
+        .iter()
+        .map(|child| {
+            let key = child.key.as_deref();
+            let count = seen.entry(key).or_default();
+            *count += 1;
+            (key, *count - 1)
+        })
+        .collect()
+}
+
+fn diff_children(old: &SurfaceNode, new: &SurfaceNode, path: &NodePath, ops: &mut Vec<PatchOp>) {
+    let old_ids = identities(&old.children);
+    let new_ids = identities(&new.children);
+    let wanted: BTreeSet<&Identity> = new_ids.iter().collect();
+
+    // Remove children with no counterpart, last first so indices hold.
+    // `current` tracks the live children as indices into `old`; inserted
+    // children are `None`.
+    let mut current: Vec<Option<usize>> = (0..old.children.len()).map(Some).collect();
+    for i in (0..old.children.len()).rev() {
+        if !wanted.contains(&old_ids[i]) {
+            ops.push(PatchOp::RemoveChild {
+                path: path.clone(),
+                index: i,
+            });
+            current.remove(i);
+        }
+    }
+
+    // Settle each position in order: keep, move into place, or insert.
+    let mut matched: Vec<Option<usize>> = Vec::with_capacity(new_ids.len());
+    for (to, id) in new_ids.iter().enumerate() {
+        let is_match = |at: &Option<usize>| at.is_some_and(|at| old_ids[at] == *id);
+        if current.get(to).is_some_and(is_match) {
+            matched.push(current[to]);
+            continue;
+        }
+        // Positions before `to` are settled, so look only after it.
+        let from = (to..current.len()).find(|&pos| is_match(&current[pos]));
+        match from {
+            Some(from) => {
+                ops.push(PatchOp::MoveChild {
+                    path: path.clone(),
+                    from,
+                    to,
+                });
+                let at = current.remove(from);
+                current.insert(to, at);
+                matched.push(at);
+            }
+            None => {
+                ops.push(PatchOp::InsertChild {
+                    path: path.clone(),
+                    index: to,
+                    node: new.children[to].clone(),
+                });
+                current.insert(to, None);
+                matched.push(None);
+            }
+        }
+    }
+
+    for (i, at) in matched.into_iter().enumerate() {
+        if let Some(at) = at {
+            diff_node(&old.children[at], &new.children[i], &path.child(i), ops);
+        }
+    }
+}
+
+fn apply(surface: &mut Surface, op: &PatchOp) -> Result<(), String> {
+    match op {
+        PatchOp::SetProp { path, key, value } => {
+            node_mut(surface, path)?
+                .props
+                .insert(key.clone(), value.clone());
+        }
+        PatchOp::RemoveProp { path, key } => {
+            node_mut(surface, path)?.props.remove(key);
+        }
+        PatchOp::InsertChild { path, index, node } => {
+            let parent = node_mut(surface, path)?;
+            check_index(path, *index, parent.children.len() + 1)?;
+            parent.children.insert(*index, node.clone());
+        }
+        PatchOp::RemoveChild { path, index } => {
+            let parent = node_mut(surface, path)?;
+            check_index(path, *index, parent.children.len())?;
+            parent.children.remove(*index);
+        }
+        PatchOp::MoveChild { path, from, to } => {
+            let parent = node_mut(surface, path)?;
+            check_index(path, *from, parent.children.len())?;
+            check_index(path, *to, parent.children.len())?;
+            let child = parent.children.remove(*from);
+            parent.children.insert(*to, child);
+        }
+        PatchOp::ReplaceNode { path, node } => {
+            *node_mut(surface, path)? = node.clone();
+        }
+        PatchOp::SetLayers { layers } => surface.layers = layers.clone(),
+        PatchOp::SetShortcuts { shortcuts } => surface.shortcuts = shortcuts.clone(),
+        PatchOp::SetRoute { route } => surface.route = route.clone(),
+        PatchOp::SetRespectSafeArea { respect } => surface.respect_safe_area = *respect,
+    }
+    Ok(())
+}
+
+fn node_mut<'a>(surface: &'a mut Surface, path: &NodePath) -> Result<&'a mut SurfaceNode, String> {
+    surface
+        .get_mut(path)
+        .ok_or_else(|| format!("{path}: no such node"))
+}
+
+/// `index` must be below `bound` (the child count, or one past it for
+/// inserts).
+fn check_index(path: &NodePath, index: usize, bound: usize) -> Result<(), String> {
+    if index < bound {
+        Ok(())
+    } else {
+        Err(format!("{path}: child index {index} out of range"))
+    }
+}
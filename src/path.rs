@@ -0,0 +1,182 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::layers::CONTENT;
+
+/// One step of a [`NodePath`]: a child by position or by
+/// [`key`](crate::SurfaceNode::key).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    /// The child at this index.
+    Index(usize),
+    /// The child with this key. Keys are unique among siblings.
+    Key(String),
+}
+
+impl PathSegment {
+    /// The position of the child this segment selects among `children`.
+    pub(crate) fn position(&self, children: &[crate::SurfaceNode]) -> Option<usize> {
+        match self {
+            PathSegment::Index(index) => (*index < children.len()).then_some(*index),
+            PathSegment::Key(key) => children
+                .iter()
+                .position(|child| child.key.as_deref() == Some(key)),
+        }
+    }
+}
+
+/// Address of a node inside a [`Surface`](crate::Surface) tree.
+///
+/// A path is the sequence of steps from the root of the content tree or of
+/// a [layer](crate::layers); each step selects a child by index or by key.
+/// The empty path addresses the root itself. Paths display, parse, and
+/// serialize as `root/1/0` in the content tree and as `<layer>/1/0` in a
+/// layer, e.g. `overlays/0` for the first child of the `overlays` layer's
+/// root. Key steps are written `#key`, e.g. `root/#list/#item-3`; a key
+/// containing `/` can be used with [`NodePath::keyed`] but has no string
+/// form.
+///
+/// Paths reported by the crate (walks, validation, diffs, patches) use
+/// indices only.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct NodePath {
+    layer: Option<String>,
+    segments: Vec<PathSegment>,
+}
+
+impl NodePath {
+    /// The path of the root node.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// The path of layer `name`'s root node; `content` is [`NodePath::root`].
+    pub fn layer_root(name: &str) -> Self {
+        Self {
+            layer: (name != CONTENT).then(|| name.to_string()),
+            segments: Vec::new(),
+        }
+    }
+
+    /// The path of the `index`-th child of this node.
+    pub fn child(&self, index: usize) -> Self {
+        self.with_segment(PathSegment::Index(index))
+    }
+
+    /// The path of this node's child with key `key`.
+    pub fn keyed(&self, key: impl Into<String>) -> Self {
+        self.with_segment(PathSegment::Key(key.into()))
+    }
+
+    fn with_segment(&self, segment: PathSegment) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(segment);
+        Self {
+            layer: self.layer.clone(),
+            segments,
+        }
+    }
+
+    /// The layer this path is in: `content` for the content tree.
+    pub fn layer(&self) -> &str {
+        self.layer.as_deref().unwrap_or(CONTENT)
+    }
+
+    /// Steps from the root.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Whether this path addresses the root node of its layer.
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Number of steps from the root (0 for the root itself).
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// The path of this node's parent, or `None` for the root.
+    pub fn parent(&self) -> Option<NodePath> {
+        self.segments.split_last().map(|(_, parent)| Self {
+            layer: self.layer.clone(),
+            segments: parent.to_vec(),
+        })
+    }
+
+    /// This node's index among its siblings, or `None` for the root or a
+    /// path ending in a key step.
+    pub fn index(&self) -> Option<usize> {
+        match self.segments.last() {
+            Some(PathSegment::Index(index)) => Some(*index),
+            _ => None,
+        }
+    }
+}
+
+impl From<Vec<usize>> for NodePath {
+    fn from(indices: Vec<usize>) -> Self {
+        Self {
+            layer: None,
+            segments: indices.into_iter().map(PathSegment::Index).collect(),
+        }
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.layer.as_deref().unwrap_or("root"))?;
+        for segment in &self.segments {
+            match segment {
+                PathSegment::Index(index) => write!(f, "/{index}")?,
+                PathSegment::Key(key) => write!(f, "/#{key}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NodePath {
+    type Err = String;
+
+    /// Parse the display form, e.g. `root`, `root/1/0`, `root/#list/0`, or
+    /// `overlays/0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid node path '{s}': expected root/<index or #key>/...");
+        let mut segments = s.split('/');
+        let layer = match segments.next() {
+            Some("root") => None,
+            Some(name) if !name.is_empty() && name != CONTENT => Some(name.to_string()),
+            _ => return Err(invalid()),
+        };
+        let segments = segments
+            .map(|segment| match segment.strip_prefix('#') {
+                Some(key) if !key.is_empty() => Ok(PathSegment::Key(key.to_string())),
+                Some(_) => Err(invalid()),
+                None => segment
+                    .parse()
+                    .map(PathSegment::Index)
+                    .map_err(|_| invalid()),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { layer, segments })
+    }
+}
+
+impl TryFrom<String> for NodePath {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<NodePath> for String {
+    fn from(path: NodePath) -> Self {
+        path.to_string()
+    }
+}
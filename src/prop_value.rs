@@ -1,6 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::BTreeMap;
 
+use crate::timestamp::Timestamp;
+use crate::types::{AdaptiveColor, ColorValue, Dimension};
+
 /// A property value in the Surface tree.
 ///
 /// Matches the JSON representation used in the host WASM contract.
@@ -20,7 +23,9 @@ pub enum PropValue {
     /// Null / absent value.
     Nil,
 
-    /// RGBA color as `{ r, g, b, a }` — each 0.0–1.0.
+    /// RGBA color as `{ r, g, b, a }` — each 0.0–1.0. Color props also
+    /// accept `"#RRGGBB"` / `"#RRGGBBAA"` strings (see [`PropValue::as_color`])
+    /// and light/dark pairs (see [`AdaptiveColor`]).
     Color { r: f64, g: f64, b: f64, a: f64 },
 
     /// Action reference (e.g., `on_tap: "increment"`).
@@ -40,11 +45,89 @@ pub enum PropValue {
         lambda_id: u32,
     },
 
+    /// Theme color token (e.g., `color: "primary"`), resolved by the host.
+    /// Accepted anywhere a [`Color`](PropValue::Color) is.
+    /// Serialized as `{ "__color_token": "primary" }`.
+    ColorToken {
+        #[serde(rename = "__color_token")]
+        token: String,
+    },
+
+    /// Design token (e.g., `spacing: spacing.md`), replaced by its value
+    /// from a [`Theme`](crate::theme::Theme). Written `group.name`; accepted
+    /// where the group's values are (see [`theme`](crate::theme)).
+    /// Serialized as `{ "__theme_token": "spacing.md" }`.
+    ThemeToken {
+        #[serde(rename = "__theme_token")]
+        token: String,
+    },
+
+    /// Localized string reference, resolved against a
+    /// [`StringCatalog`](crate::i18n::StringCatalog). Accepted anywhere a
+    /// user-visible string is. Serialized as
+    /// `{ "__i18n": "cart.items", "__args": { "count": 3 } }`.
+    I18n {
+        #[serde(rename = "__i18n")]
+        key: String,
+        #[serde(rename = "__args", default, skip_serializing_if = "BTreeMap::is_empty")]
+        args: BTreeMap<String, PropValue>,
+    },
+
+    /// Date or date-time (e.g., `due: 2026-10-16`), validated on parse.
+    /// Serialized as `{ "__timestamp": "2026-10-16T09:30:00Z" }`.
+    Timestamp {
+        #[serde(rename = "__timestamp")]
+        timestamp: Timestamp,
+    },
+
+    /// Inline binary data (e.g., a small thumbnail) for hosts without an
+    /// asset pipeline. Serialized as `{ "__bytes": "<base64>" }` in JSON and
+    /// as raw bytes in binary formats. Size is capped by
+    /// [`Surface::validate_inline_bytes`](crate::Surface::validate_inline_bytes).
+    Bytes {
+        #[serde(rename = "__bytes", with = "crate::bytes")]
+        data: Vec<u8>,
+    },
+
+    /// Layout dimension (e.g., `width: 50%`). Accepted anywhere a size is,
+    /// alongside plain numbers, which coerce to [`Px`](Dimension::Px).
+    /// Serialized as `{ "__dim": "50%" }`, `{ "__dim": "fill" }`,
+    /// `{ "__dim": "auto" }`, or `{ "__dim": 120 }`.
+    Dimension {
+        #[serde(rename = "__dim", with = "crate::types::dimension_wire")]
+        dim: Dimension,
+    },
+
     /// Ordered list of values.
     List(Vec<PropValue>),
 
     /// Named fields. Uses `BTreeMap` for deterministic ordering.
-    Record(BTreeMap<String, PropValue>),
+    Record(#[serde(deserialize_with = "record")] BTreeMap<String, PropValue>),
+}
+
+/// Keys of validated wire shapes. A record carrying one of them is a value
+/// that failed validation, not a plain record.
+const VALIDATED_KEYS: &[&str] = &["__timestamp", "__bytes", "__dim"];
+
+/// Deserialize a record, rejecting an invalid `__timestamp`, `__bytes`, or
+/// `__dim` that would otherwise fall through the untagged variants as a plain
+/// record.
+fn record<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<String, PropValue>, D::Error> {
+    use serde::de::Error;
+
+    let fields = BTreeMap::<String, PropValue>::deserialize(deserializer)?;
+    if let Some(PropValue::String(s)) = fields.get("__timestamp") {
+        Timestamp::parse(s).map_err(D::Error::custom)?;
+    }
+    if let Some(PropValue::String(s)) = fields.get("__dim") {
+        Dimension::parse(s).map_err(D::Error::custom)?;
+    }
+    match VALIDATED_KEYS.iter().find(|key| fields.contains_key(**key)) {
+        Some(key) => Err(D::Error::custom(format!("{key}: invalid value"))),
+        None => Ok(fields),
+    }
 }
 
 // ── Constructors ──────────────────────────────────────────────────────────────
@@ -76,6 +159,118 @@ impl PropValue {
         PropValue::Color { r, g, b, a }
     }
 
+    /// Create a theme color token reference.
+    pub fn color_token(token: impl Into<String>) -> Self {
+        PropValue::ColorToken {
+            token: token.into(),
+        }
+    }
+
+    /// Create a design token reference (`group.name`).
+    pub fn theme_token(token: impl Into<String>) -> Self {
+        PropValue::ThemeToken {
+            token: token.into(),
+        }
+    }
+
+    /// Create a localized string reference without arguments.
+    pub fn i18n(key: impl Into<String>) -> Self {
+        PropValue::I18n {
+            key: key.into(),
+            args: BTreeMap::new(),
+        }
+    }
+
+    /// Create a localized string reference with interpolation arguments.
+    pub fn i18n_with_args(key: impl Into<String>, args: BTreeMap<String, PropValue>) -> Self {
+        PropValue::I18n {
+            key: key.into(),
+            args,
+        }
+    }
+
+    /// Create a timestamp value.
+    pub fn timestamp(timestamp: Timestamp) -> Self {
+        PropValue::Timestamp { timestamp }
+    }
+
+    /// Create an inline binary value.
+    pub fn bytes(data: impl Into<Vec<u8>>) -> Self {
+        PropValue::Bytes { data: data.into() }
+    }
+
+    /// Create a dimension value.
+    pub fn dimension(dim: Dimension) -> Self {
+        PropValue::Dimension { dim }
+    }
+
+    /// Coerce to a [`Dimension`]: numbers become [`Px`](Dimension::Px),
+    /// dimension values are returned as-is, anything else is `None`.
+    pub fn as_dimension(&self) -> Option<Dimension> {
+        match self {
+            PropValue::Number(n) => Some(Dimension::Px(*n)),
+            PropValue::Dimension { dim } => Some(dim.clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether this value is accepted by user-visible string props: a literal
+    /// [`String`](PropValue::String) or an [`I18n`](PropValue::I18n) reference.
+    pub fn is_text(&self) -> bool {
+        matches!(self, PropValue::String(_) | PropValue::I18n { .. })
+    }
+
+    /// Coerce to a [`ColorValue`]: colors are returned as-is, hex strings
+    /// (`"#RRGGBB"`, `"#RRGGBBAA"`) are parsed, anything else (including
+    /// tokens) is `None`. [`Surface::coerce_colors`](crate::Surface::coerce_colors)
+    /// applies this to a whole tree before it is sent to hosts.
+    pub fn as_color(&self) -> Option<ColorValue> {
+        match self {
+            PropValue::Color { r, g, b, a } => Some(ColorValue::new(*r, *g, *b, *a)),
+            PropValue::String(s) => ColorValue::from_hex(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Whether this value is accepted by color props: a literal
+    /// [`Color`](PropValue::Color), a hex string, a
+    /// [`ColorToken`](PropValue::ColorToken), a `color.*`
+    /// [`ThemeToken`](PropValue::ThemeToken), or an
+    /// [`AdaptiveColor`] record of two of those.
+    pub fn is_color(&self) -> bool {
+        self.is_single_color() || self.is_adaptive_color()
+    }
+
+    /// Whether this value is an [`AdaptiveColor`] record: exactly `__light`
+    /// and `__dark`, each a non-adaptive color.
+    pub fn is_adaptive_color(&self) -> bool {
+        match self {
+            PropValue::Record(fields) => {
+                fields.len() == 2
+                    && ["__light", "__dark"]
+                        .iter()
+                        .all(|key| fields.get(*key).is_some_and(PropValue::is_single_color))
+            }
+            _ => false,
+        }
+    }
+
+    fn is_single_color(&self) -> bool {
+        matches!(self, PropValue::ColorToken { .. })
+            || self.is_theme_token("color")
+            || self.as_color().is_some()
+    }
+
+    /// Whether this value is a [`ThemeToken`](PropValue::ThemeToken) in
+    /// `group`, e.g. `spacing.md` for `"spacing"`.
+    pub fn is_theme_token(&self, group: &str) -> bool {
+        matches!(
+            self,
+            PropValue::ThemeToken { token }
+                if token.split_once('.').is_some_and(|(g, name)| g == group && !name.is_empty())
+        )
+    }
+
     /// Returns the type name for error messages.
     pub fn type_name(&self) -> &'static str {
         match self {
@@ -86,6 +281,12 @@ impl PropValue {
             PropValue::Color { .. } => "color",
             PropValue::ActionRef { .. } => "action",
             PropValue::Lambda { .. } => "lambda",
+            PropValue::ColorToken { .. } => "color token",
+            PropValue::ThemeToken { .. } => "theme token",
+            PropValue::I18n { .. } => "i18n",
+            PropValue::Timestamp { .. } => "timestamp",
+            PropValue::Bytes { .. } => "bytes",
+            PropValue::Dimension { .. } => "dimension",
             PropValue::List(_) => "list",
             PropValue::Record(_) => "record",
         }
@@ -118,6 +319,34 @@ impl From<i64> for PropValue {
     }
 }
 
+impl From<Timestamp> for PropValue {
+    fn from(timestamp: Timestamp) -> Self {
+        PropValue::Timestamp { timestamp }
+    }
+}
+
+impl From<Dimension> for PropValue {
+    fn from(dim: Dimension) -> Self {
+        PropValue::Dimension { dim }
+    }
+}
+
+impl From<ColorValue> for PropValue {
+    fn from(color: ColorValue) -> Self {
+        let ColorValue { r, g, b, a } = color;
+        PropValue::Color { r, g, b, a }
+    }
+}
+
+impl From<AdaptiveColor> for PropValue {
+    fn from(color: AdaptiveColor) -> Self {
+        PropValue::Record(BTreeMap::from([
+            ("__light".to_string(), color.light.into()),
+            ("__dark".to_string(), color.dark.into()),
+        ]))
+    }
+}
+
 impl From<bool> for PropValue {
     fn from(b: bool) -> Self {
         PropValue::Bool(b)
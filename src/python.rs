@@ -0,0 +1,104 @@
+//! Python bindings (feature `python`).
+//!
+//! Builds the `pepl_ui` extension module for design tooling and CI scripts:
+//!
+//! ```python
+//! import pepl_ui
+//!
+//! surface = pepl_ui.Surface.from_json(open("surface.json").read())
+//! for error in surface.validate():
+//!     print(error)
+//! print(surface.ascii_tree())
+//! ```
+//!
+//! Parse failures raise `ValueError`.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::accessibility;
+use crate::complexity::parse_untrusted;
+use crate::surface::Surface;
+
+/// A parsed surface.
+#[pyclass(name = "Surface", module = "pepl_ui")]
+struct PySurface {
+    inner: Surface,
+}
+
+#[pymethods]
+impl PySurface {
+    /// Parse a surface from its JSON wire format.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        parse(json).map(|inner| PySurface { inner })
+    }
+
+    /// Serialize back to compact JSON.
+    fn to_json(&self) -> String {
+        self.inner.to_json()
+    }
+
+    /// Validation errors, one string per error; empty means valid.
+    fn validate(&self) -> Vec<String> {
+        self.inner.validate()
+    }
+
+    /// Path-annotated differences from `other`; empty means equal.
+    fn diff(&self, other: &PySurface) -> Vec<String> {
+        lines(&self.inner.diff_report(&other.inner))
+    }
+
+    /// ASCII dump of the node tree.
+    fn ascii_tree(&self) -> String {
+        self.inner.ascii_tree()
+    }
+
+    /// Accessibility findings; empty means none.
+    fn audit(&self) -> Vec<String> {
+        accessibility::audit(&self.inner)
+    }
+
+    fn __eq__(&self, other: &PySurface) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<pepl_ui.Surface {}>", self.inner.root.component_type)
+    }
+}
+
+/// Validate a surface JSON payload.
+#[pyfunction]
+fn validate(json: &str) -> PyResult<Vec<String>> {
+    Ok(parse(json)?.validate())
+}
+
+/// Path-annotated differences between two surface JSON payloads.
+#[pyfunction]
+fn diff(old: &str, new: &str) -> PyResult<Vec<String>> {
+    Ok(lines(&parse(old)?.diff_report(&parse(new)?)))
+}
+
+/// ASCII dump of a surface JSON payload.
+#[pyfunction]
+fn ascii_tree(json: &str) -> PyResult<String> {
+    Ok(parse(json)?.ascii_tree())
+}
+
+#[pymodule]
+fn pepl_ui(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySurface>()?;
+    m.add_function(wrap_pyfunction!(validate, m)?)?;
+    m.add_function(wrap_pyfunction!(diff, m)?)?;
+    m.add_function(wrap_pyfunction!(ascii_tree, m)?)?;
+    Ok(())
+}
+
+fn parse(json: &str) -> PyResult<Surface> {
+    parse_untrusted(json).map_err(PyValueError::new_err)
+}
+
+fn lines(report: &str) -> Vec<String> {
+    report.lines().map(str::to_string).collect()
+}
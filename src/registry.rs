@@ -20,12 +20,38 @@ pub enum PropType {
     Record,
     /// One of a fixed set of string values (e.g., `"filled"|"outlined"|"text"`).
     StringEnum(&'static [&'static str]),
-    /// Dimension type (Px, Auto, Fill, Percent).
+    /// Dimension type (Px, Auto, Fill, Percent), or a `spacing.*` theme token.
     Dimension,
-    /// Edges type (Uniform or Sides).
+    /// Edges type (Uniform or Sides), or a `spacing.*` theme token.
     Edges,
+    /// Corner radii (Uniform or Corners).
+    CornerRadii,
     /// Alignment enum.
     Alignment,
+    /// Scroll target: anchor key (string) or offset (number).
+    ScrollTarget,
+}
+
+impl PropType {
+    /// Lowercase type name used in [`ComponentRegistry::to_json`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            PropType::String => "string",
+            PropType::Number => "number",
+            PropType::Bool => "bool",
+            PropType::Color => "color",
+            PropType::Action => "action",
+            PropType::Lambda => "lambda",
+            PropType::List => "list",
+            PropType::Record => "record",
+            PropType::StringEnum(_) => "enum",
+            PropType::Dimension => "dimension",
+            PropType::Edges => "edges",
+            PropType::CornerRadii => "corner_radii",
+            PropType::Alignment => "alignment",
+            PropType::ScrollTarget => "scroll_target",
+        }
+    }
 }
 
 /// Definition of a single prop on a component.
@@ -52,11 +78,23 @@ impl PropDef {
             prop_type,
         }
     }
+
+    fn to_json_value(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "name": self.name,
+            "type": self.prop_type.name(),
+            "required": self.requirement == PropRequirement::Required,
+        });
+        if let PropType::StringEnum(values) = self.prop_type {
+            value["values"] = serde_json::json!(values);
+        }
+        value
+    }
 }
 
 /// Definition of a PEPL UI component.
 ///
-/// Each of the 10 Phase 0 components has a static definition specifying
+/// Each built-in component has a static definition specifying
 /// its name, props, and whether it accepts children.
 pub trait ComponentDef {
     /// Component type name (e.g., "Column", "Text", "Button").
@@ -69,7 +107,7 @@ pub trait ComponentDef {
     fn props(&self) -> &[PropDef];
 }
 
-/// Registry of all Phase 0 components.
+/// Registry of all built-in components.
 ///
 /// Provides lookup by name and validation of component usage.
 pub struct ComponentRegistry {
@@ -77,7 +115,7 @@ pub struct ComponentRegistry {
 }
 
 impl ComponentRegistry {
-    /// Create a registry with all 10 Phase 0 components registered.
+    /// Create a registry with all built-in components registered.
     pub fn new() -> Self {
         let mut components: BTreeMap<&'static str, Box<dyn ComponentDef>> = BTreeMap::new();
 
@@ -85,21 +123,52 @@ impl ComponentRegistry {
         components.insert("Column", Box::new(ColumnDef));
         components.insert("Row", Box::new(RowDef));
         components.insert("Scroll", Box::new(ScrollDef));
+        components.insert("Tabs", Box::new(TabsDef));
+        components.insert("Carousel", Box::new(CarouselDef));
+        components.insert("Disclosure", Box::new(DisclosureDef));
+        components.insert("Overlay", Box::new(OverlayDef));
+        components.insert("AppBar", Box::new(AppBarDef));
+        components.insert("Divider", Box::new(DividerDef));
+        components.insert("Spacer", Box::new(SpacerDef));
 
         // Content
         components.insert("Text", Box::new(TextDef));
+        components.insert("RichText", Box::new(RichTextDef));
         components.insert("ProgressBar", Box::new(ProgressBarDef));
+        components.insert("Spinner", Box::new(SpinnerDef));
+        components.insert("Icon", Box::new(IconDef));
+        components.insert("Image", Box::new(ImageDef));
+        components.insert("Skeleton", Box::new(SkeletonDef));
 
         // Interactive
         components.insert("Button", Box::new(ButtonDef));
         components.insert("TextInput", Box::new(TextInputDef));
+        components.insert("Stepper", Box::new(StepperDef));
+        components.insert("Rating", Box::new(RatingDef));
+        components.insert("FilePicker", Box::new(FilePickerDef));
 
         // List & Data
         components.insert("ScrollList", Box::new(ScrollListDef));
+        components.insert("Table", Box::new(TableDef));
 
         // Feedback & Overlay
         components.insert("Modal", Box::new(ModalDef));
+        components.insert("BottomSheet", Box::new(BottomSheetDef));
+        components.insert("Drawer", Box::new(DrawerDef));
         components.insert("Toast", Box::new(ToastDef));
+        components.insert("Snackbar", Box::new(SnackbarDef));
+        components.insert("Tooltip", Box::new(TooltipDef));
+
+        // Form
+        components.insert("Form", Box::new(FormDef));
+
+        // Media
+        components.insert("Video", Box::new(VideoDef));
+        components.insert("AudioPlayer", Box::new(AudioPlayerDef));
+
+        // Graphics
+        components.insert("Canvas", Box::new(CanvasDef));
+        components.insert("Chart", Box::new(ChartDef));
 
         Self { components }
     }
@@ -128,8 +197,71 @@ impl ComponentRegistry {
     pub fn is_empty(&self) -> bool {
         self.components.is_empty()
     }
+
+    /// Props accepted on every component in addition to its own
+    /// [`ComponentDef::props`].
+    pub fn common_props() -> &'static [PropDef] {
+        COMMON_PROPS
+    }
+
+    /// Describe every component and the common props as JSON, for tooling
+    /// outside Rust (playground, host SDKs).
+    ///
+    /// ```json
+    /// {
+    ///   "components": [
+    ///     { "name": "Button", "accepts_children": false,
+    ///       "props": [{ "name": "label", "type": "string", "required": true }, ...] }
+    ///   ],
+    ///   "common_props": [...]
+    /// }
+    /// ```
+    ///
+    /// `StringEnum` props have type `"enum"` and a `values` list.
+    pub fn to_json(&self) -> String {
+        let components: Vec<serde_json::Value> = self
+            .components
+            .values()
+            .map(|def| {
+                serde_json::json!({
+                    "name": def.name(),
+                    "accepts_children": def.accepts_children(),
+                    "props": def.props().iter().map(PropDef::to_json_value).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        let common: Vec<serde_json::Value> =
+            COMMON_PROPS.iter().map(PropDef::to_json_value).collect();
+        serde_json::json!({ "components": components, "common_props": common }).to_string()
+    }
 }
 
+/// Props accepted on every component (see `components::common`).
+static COMMON_PROPS: &[PropDef] = &[
+    PropDef::optional("focus_group", PropType::String),
+    PropDef::optional("initial_focus", PropType::Bool),
+    PropDef::optional("focus_trap", PropType::Bool),
+    PropDef::optional("on_appear", PropType::Action),
+    PropDef::optional("on_disappear", PropType::Action),
+    PropDef::optional("animate", PropType::Record),
+    PropDef::optional("anchor", PropType::String),
+    PropDef::optional("draggable", PropType::Bool),
+    PropDef::optional("drag_payload", PropType::Record),
+    PropDef::optional("drop_target", PropType::Bool),
+    PropDef::optional("on_drop", PropType::Action),
+    PropDef::optional("width", PropType::Dimension),
+    PropDef::optional("height", PropType::Dimension),
+    PropDef::optional("opacity", PropType::Number),
+    PropDef::optional("visible", PropType::Bool),
+    PropDef::optional("flex", PropType::Number),
+    PropDef::optional(
+        "align_self",
+        PropType::StringEnum(&["start", "center", "end", "stretch"]),
+    ),
+    PropDef::optional("pinned", PropType::Bool),
+    PropDef::optional("position", PropType::Record),
+];
+
 impl Default for ComponentRegistry {
     fn default() -> Self {
         Self::new()
@@ -153,18 +285,74 @@ impl ComponentDef for ColumnDef {
             PropDef {
                 name: "spacing",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::Number,
+                prop_type: PropType::Dimension,
             },
             PropDef {
                 name: "align",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Alignment,
             },
+            PropDef {
+                name: "justify",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&[
+                    "start",
+                    "center",
+                    "end",
+                    "space_between",
+                    "space_around",
+                ]),
+            },
             PropDef {
                 name: "padding",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Edges,
             },
+            PropDef {
+                name: "corner_radius",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::CornerRadii,
+            },
+            PropDef {
+                name: "background",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "border",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "shadow",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "safe_area",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "on_tap",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_double_tap",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_swipe",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_long_press",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
             PropDef {
                 name: "accessible",
                 requirement: PropRequirement::Optional,
@@ -188,18 +376,84 @@ impl ComponentDef for RowDef {
             PropDef {
                 name: "spacing",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::Number,
+                prop_type: PropType::Dimension,
             },
             PropDef {
                 name: "align",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Alignment,
             },
+            PropDef {
+                name: "justify",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&[
+                    "start",
+                    "center",
+                    "end",
+                    "space_between",
+                    "space_around",
+                ]),
+            },
+            PropDef {
+                name: "wrap",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "run_spacing",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
             PropDef {
                 name: "padding",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Edges,
             },
+            PropDef {
+                name: "corner_radius",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::CornerRadii,
+            },
+            PropDef {
+                name: "background",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "border",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "shadow",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "safe_area",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "on_tap",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_double_tap",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_swipe",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_long_press",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
             PropDef {
                 name: "accessible",
                 requirement: PropRequirement::Optional,
@@ -226,63 +480,59 @@ impl ComponentDef for ScrollDef {
                 prop_type: PropType::StringEnum(&["vertical", "horizontal", "both"]),
             },
             PropDef {
-                name: "accessible",
+                name: "show_indicators",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::Record,
+                prop_type: PropType::Bool,
             },
-        ];
-        PROPS
-    }
-}
-
-// ══════════════════════════════════════════════════════════════════════════════
-// Content components
-// ══════════════════════════════════════════════════════════════════════════════
-
-struct TextDef;
-impl ComponentDef for TextDef {
-    fn name(&self) -> &'static str {
-        "Text"
-    }
-    fn accepts_children(&self) -> bool {
-        false
-    }
-    fn props(&self) -> &[PropDef] {
-        static PROPS: &[PropDef] = &[
             PropDef {
-                name: "value",
-                requirement: PropRequirement::Required,
-                prop_type: PropType::String,
+                name: "bounce",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
             },
             PropDef {
-                name: "size",
+                name: "paging",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["small", "body", "title", "heading", "display"]),
+                prop_type: PropType::Bool,
             },
             PropDef {
-                name: "weight",
+                name: "avoid_keyboard",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["normal", "medium", "bold"]),
+                prop_type: PropType::Bool,
             },
             PropDef {
-                name: "color",
+                name: "scroll_to",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::Color,
+                prop_type: PropType::ScrollTarget,
             },
             PropDef {
-                name: "align",
+                name: "on_scroll",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["start", "center", "end"]),
+                prop_type: PropType::Lambda,
             },
             PropDef {
-                name: "max_lines",
+                name: "scroll_event_interval",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Number,
             },
             PropDef {
-                name: "overflow",
+                name: "background",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["clip", "ellipsis", "wrap"]),
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "border",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "shadow",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "safe_area",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
             },
             PropDef {
                 name: "accessible",
@@ -294,35 +544,31 @@ impl ComponentDef for TextDef {
     }
 }
 
-struct ProgressBarDef;
-impl ComponentDef for ProgressBarDef {
+struct TabsDef;
+impl ComponentDef for TabsDef {
     fn name(&self) -> &'static str {
-        "ProgressBar"
+        "Tabs"
     }
     fn accepts_children(&self) -> bool {
-        false
+        true
     }
     fn props(&self) -> &[PropDef] {
         static PROPS: &[PropDef] = &[
             PropDef {
-                name: "value",
+                name: "tabs",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::Number,
-            },
-            PropDef {
-                name: "color",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::Color,
+                prop_type: PropType::List,
             },
             PropDef {
-                name: "background",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::Color,
+                name: "selected",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Number,
             },
+            // ActionRef or Lambda (checked by the validator).
             PropDef {
-                name: "height",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::Number,
+                name: "on_select",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
             },
             PropDef {
                 name: "accessible",
@@ -334,47 +580,28 @@ impl ComponentDef for ProgressBarDef {
     }
 }
 
-// ══════════════════════════════════════════════════════════════════════════════
-// Interactive components
-// ══════════════════════════════════════════════════════════════════════════════
-
-struct ButtonDef;
-impl ComponentDef for ButtonDef {
+struct CarouselDef;
+impl ComponentDef for CarouselDef {
     fn name(&self) -> &'static str {
-        "Button"
+        "Carousel"
     }
     fn accepts_children(&self) -> bool {
-        false
+        true
     }
     fn props(&self) -> &[PropDef] {
         static PROPS: &[PropDef] = &[
             PropDef {
-                name: "label",
+                name: "index",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::String,
+                prop_type: PropType::Number,
             },
             PropDef {
-                name: "on_tap",
+                name: "on_change",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::Action,
-            },
-            PropDef {
-                name: "variant",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["filled", "outlined", "text"]),
-            },
-            PropDef {
-                name: "icon",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::String,
-            },
-            PropDef {
-                name: "disabled",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::Bool,
+                prop_type: PropType::Lambda,
             },
             PropDef {
-                name: "loading",
+                name: "show_indicators",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Bool,
             },
@@ -388,50 +615,31 @@ impl ComponentDef for ButtonDef {
     }
 }
 
-struct TextInputDef;
-impl ComponentDef for TextInputDef {
+struct DisclosureDef;
+impl ComponentDef for DisclosureDef {
     fn name(&self) -> &'static str {
-        "TextInput"
+        "Disclosure"
     }
     fn accepts_children(&self) -> bool {
-        false
+        true
     }
     fn props(&self) -> &[PropDef] {
         static PROPS: &[PropDef] = &[
             PropDef {
-                name: "value",
+                name: "title",
                 requirement: PropRequirement::Required,
                 prop_type: PropType::String,
             },
             PropDef {
-                name: "on_change",
+                name: "expanded",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::Lambda,
-            },
-            PropDef {
-                name: "placeholder",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::String,
-            },
-            PropDef {
-                name: "label",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::String,
-            },
-            PropDef {
-                name: "keyboard",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["text", "number", "email", "phone", "url"]),
-            },
-            PropDef {
-                name: "max_length",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::Number,
+                prop_type: PropType::Bool,
             },
+            // ActionRef or Lambda (checked by the validator).
             PropDef {
-                name: "multiline",
-                requirement: PropRequirement::Optional,
-                prop_type: PropType::Bool,
+                name: "on_toggle",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
             },
             PropDef {
                 name: "accessible",
@@ -443,14 +651,1036 @@ impl ComponentDef for TextInputDef {
     }
 }
 
-// ══════════════════════════════════════════════════════════════════════════════
-// List & Data components
-// ══════════════════════════════════════════════════════════════════════════════
-
-struct ScrollListDef;
-impl ComponentDef for ScrollListDef {
+struct OverlayDef;
+impl ComponentDef for OverlayDef {
     fn name(&self) -> &'static str {
-        "ScrollList"
+        "Overlay"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            // One `{ align, offset? }` record per child.
+            PropDef {
+                name: "layers",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct AppBarDef;
+impl ComponentDef for AppBarDef {
+    fn name(&self) -> &'static str {
+        "AppBar"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "title",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "elevated",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            // One "leading" | "trailing" entry per child; required with children.
+            PropDef {
+                name: "slots",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct DividerDef;
+impl ComponentDef for DividerDef {
+    fn name(&self) -> &'static str {
+        "Divider"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "thickness",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "color",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "inset",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct SpacerDef;
+impl ComponentDef for SpacerDef {
+    fn name(&self) -> &'static str {
+        "Spacer"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            // Omitted: the spacer is flexible.
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Content components
+// ══════════════════════════════════════════════════════════════════════════════
+
+struct TextDef;
+impl ComponentDef for TextDef {
+    fn name(&self) -> &'static str {
+        "Text"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "value",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["small", "body", "title", "heading", "display"]),
+            },
+            PropDef {
+                name: "weight",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["normal", "medium", "bold"]),
+            },
+            PropDef {
+                name: "color",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "align",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["start", "center", "end"]),
+            },
+            PropDef {
+                name: "max_lines",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "overflow",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["clip", "ellipsis", "wrap"]),
+            },
+            PropDef {
+                name: "selectable",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "copyable",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "style",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "font",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct ProgressBarDef;
+impl ComponentDef for ProgressBarDef {
+    fn name(&self) -> &'static str {
+        "ProgressBar"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            // Required unless `indeterminate: true` (checked by the validator).
+            PropDef {
+                name: "value",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "indeterminate",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "shape",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["linear", "circular"]),
+            },
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "thickness",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "color",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "background",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "height",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct ImageDef;
+impl ComponentDef for ImageDef {
+    fn name(&self) -> &'static str {
+        "Image"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "source",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "fit",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["contain", "cover", "fill"]),
+            },
+            PropDef {
+                name: "width",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "height",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "alt",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct SpinnerDef;
+impl ComponentDef for SpinnerDef {
+    fn name(&self) -> &'static str {
+        "Spinner"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "color",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct IconDef;
+impl ComponentDef for IconDef {
+    fn name(&self) -> &'static str {
+        "Icon"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "name",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "color",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Color,
+            },
+            PropDef {
+                name: "label",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct RichTextDef;
+impl ComponentDef for RichTextDef {
+    fn name(&self) -> &'static str {
+        "RichText"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        // Exactly one of spans, markdown (checked by the validator).
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "spans",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "markdown",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["small", "body", "title", "heading", "display"]),
+            },
+            PropDef {
+                name: "align",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["start", "center", "end"]),
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct SkeletonDef;
+impl ComponentDef for SkeletonDef {
+    fn name(&self) -> &'static str {
+        "Skeleton"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "shape",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::StringEnum(&["text", "rect", "circle"]),
+            },
+            PropDef {
+                name: "width",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "height",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "lines",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Interactive components
+// ══════════════════════════════════════════════════════════════════════════════
+
+struct ButtonDef;
+impl ComponentDef for ButtonDef {
+    fn name(&self) -> &'static str {
+        "Button"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "label",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "on_tap",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "variant",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["filled", "outlined", "text"]),
+            },
+            PropDef {
+                name: "size",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["small", "medium", "large"]),
+            },
+            PropDef {
+                name: "full_width",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "icon",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "disabled",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "loading",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "on_long_press",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_press_down",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "on_press_up",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "haptic",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["light", "medium", "heavy", "success", "error"]),
+            },
+            PropDef {
+                name: "sound",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["click", "success", "error"]),
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct TextInputDef;
+impl ComponentDef for TextInputDef {
+    fn name(&self) -> &'static str {
+        "TextInput"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "value",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "on_change",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "placeholder",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "label",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "keyboard",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["text", "number", "email", "phone", "url"]),
+            },
+            PropDef {
+                name: "max_length",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "multiline",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "autocapitalize",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["none", "words", "sentences", "characters"]),
+            },
+            PropDef {
+                name: "autocorrect",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct StepperDef;
+impl ComponentDef for StepperDef {
+    fn name(&self) -> &'static str {
+        "Stepper"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "value",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "on_change",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "min",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "max",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "step",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "label",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct RatingDef;
+impl ComponentDef for RatingDef {
+    fn name(&self) -> &'static str {
+        "Rating"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "value",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "max",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "on_change",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "icon",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct FilePickerDef;
+impl ComponentDef for FilePickerDef {
+    fn name(&self) -> &'static str {
+        "FilePicker"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "label",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "on_pick",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "accept",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "multiple",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "disabled",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// List & Data components
+// ══════════════════════════════════════════════════════════════════════════════
+
+struct ScrollListDef;
+impl ComponentDef for ScrollListDef {
+    fn name(&self) -> &'static str {
+        "ScrollList"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "items",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "render",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "key",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "on_reorder",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "dividers",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "scroll_to",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::ScrollTarget,
+            },
+            PropDef {
+                name: "on_scroll",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "scroll_event_interval",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct TableDef;
+impl ComponentDef for TableDef {
+    fn name(&self) -> &'static str {
+        "Table"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "columns",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "rows",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "render_cell",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "sortable",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "on_sort",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Lambda,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Feedback & Overlay components
+// ══════════════════════════════════════════════════════════════════════════════
+
+struct ModalDef;
+impl ComponentDef for ModalDef {
+    fn name(&self) -> &'static str {
+        "Modal"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "visible",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "on_dismiss",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "title",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "presentation",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["center", "fullscreen", "sheet"]),
+            },
+            PropDef {
+                name: "width",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "max_height",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Dimension,
+            },
+            PropDef {
+                name: "corner_radius",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::CornerRadii,
+            },
+            PropDef {
+                name: "confirm",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "cancel",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "transition",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "avoid_keyboard",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct BottomSheetDef;
+impl ComponentDef for BottomSheetDef {
+    fn name(&self) -> &'static str {
+        "BottomSheet"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "visible",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "on_dismiss",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "title",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "detents",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "transition",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct DrawerDef;
+impl ComponentDef for DrawerDef {
+    fn name(&self) -> &'static str {
+        "Drawer"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "open",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "on_dismiss",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "side",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["start", "end"]),
+            },
+            PropDef {
+                name: "title",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "transition",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct ToastDef;
+impl ComponentDef for ToastDef {
+    fn name(&self) -> &'static str {
+        "Toast"
     }
     fn accepts_children(&self) -> bool {
         false
@@ -458,27 +1688,156 @@ impl ComponentDef for ScrollListDef {
     fn props(&self) -> &[PropDef] {
         static PROPS: &[PropDef] = &[
             PropDef {
-                name: "items",
+                name: "message",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::List,
+                prop_type: PropType::String,
             },
             PropDef {
-                name: "render",
+                name: "duration",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "type",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["info", "success", "warning", "error"]),
+            },
+            PropDef {
+                name: "transition",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "action",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+            PropDef {
+                name: "on_dismiss",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "id",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "priority",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::StringEnum(&["low", "normal", "high"]),
+            },
+            PropDef {
+                name: "replace",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct SnackbarDef;
+impl ComponentDef for SnackbarDef {
+    fn name(&self) -> &'static str {
+        "Snackbar"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "message",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::Lambda,
+                prop_type: PropType::String,
             },
             PropDef {
-                name: "key",
+                name: "action_label",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "on_action",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "duration",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "on_dismiss",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct TooltipDef;
+impl ComponentDef for TooltipDef {
+    fn name(&self) -> &'static str {
+        "Tooltip"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "text",
                 requirement: PropRequirement::Required,
-                prop_type: PropType::Lambda,
+                prop_type: PropType::String,
             },
             PropDef {
-                name: "on_reorder",
+                name: "placement",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::Lambda,
+                prop_type: PropType::StringEnum(&["top", "bottom", "start", "end"]),
             },
             PropDef {
-                name: "dividers",
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Form components
+// ══════════════════════════════════════════════════════════════════════════════
+
+struct FormDef;
+impl ComponentDef for FormDef {
+    fn name(&self) -> &'static str {
+        "Form"
+    }
+    fn accepts_children(&self) -> bool {
+        true
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "on_submit",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Action,
+            },
+            PropDef {
+                name: "disabled",
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::Bool,
             },
@@ -493,26 +1852,86 @@ impl ComponentDef for ScrollListDef {
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
-// Feedback & Overlay components
+// Media components
 // ══════════════════════════════════════════════════════════════════════════════
 
-struct ModalDef;
-impl ComponentDef for ModalDef {
+struct VideoDef;
+impl ComponentDef for VideoDef {
     fn name(&self) -> &'static str {
-        "Modal"
+        "Video"
     }
     fn accepts_children(&self) -> bool {
-        true
+        false
     }
     fn props(&self) -> &[PropDef] {
         static PROPS: &[PropDef] = &[
             PropDef {
-                name: "visible",
+                name: "source",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "label",
                 requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "autoplay",
+                requirement: PropRequirement::Optional,
                 prop_type: PropType::Bool,
             },
             PropDef {
-                name: "on_dismiss",
+                name: "loop",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "muted",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "controls",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "poster",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "accessible",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct AudioPlayerDef;
+impl ComponentDef for AudioPlayerDef {
+    fn name(&self) -> &'static str {
+        "AudioPlayer"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "source",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::String,
+            },
+            PropDef {
+                name: "playing",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Bool,
+            },
+            PropDef {
+                name: "on_toggle",
                 requirement: PropRequirement::Required,
                 prop_type: PropType::Action,
             },
@@ -521,6 +1940,11 @@ impl ComponentDef for ModalDef {
                 requirement: PropRequirement::Optional,
                 prop_type: PropType::String,
             },
+            PropDef {
+                name: "artwork",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
+            },
             PropDef {
                 name: "accessible",
                 requirement: PropRequirement::Optional,
@@ -531,10 +1955,14 @@ impl ComponentDef for ModalDef {
     }
 }
 
-struct ToastDef;
-impl ComponentDef for ToastDef {
+// ══════════════════════════════════════════════════════════════════════════════
+// Graphics components
+// ══════════════════════════════════════════════════════════════════════════════
+
+struct CanvasDef;
+impl ComponentDef for CanvasDef {
     fn name(&self) -> &'static str {
-        "Toast"
+        "Canvas"
     }
     fn accepts_children(&self) -> bool {
         false
@@ -542,19 +1970,64 @@ impl ComponentDef for ToastDef {
     fn props(&self) -> &[PropDef] {
         static PROPS: &[PropDef] = &[
             PropDef {
-                name: "message",
+                name: "width",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "height",
                 requirement: PropRequirement::Required,
+                prop_type: PropType::Number,
+            },
+            PropDef {
+                name: "commands",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "label",
+                requirement: PropRequirement::Optional,
                 prop_type: PropType::String,
             },
             PropDef {
-                name: "duration",
+                name: "accessible",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::Number,
+                prop_type: PropType::Record,
+            },
+        ];
+        PROPS
+    }
+}
+
+struct ChartDef;
+impl ComponentDef for ChartDef {
+    fn name(&self) -> &'static str {
+        "Chart"
+    }
+    fn accepts_children(&self) -> bool {
+        false
+    }
+    fn props(&self) -> &[PropDef] {
+        static PROPS: &[PropDef] = &[
+            PropDef {
+                name: "kind",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::StringEnum(&["line", "bar", "pie", "sparkline"]),
             },
             PropDef {
-                name: "type",
+                name: "series",
+                requirement: PropRequirement::Required,
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "labels",
                 requirement: PropRequirement::Optional,
-                prop_type: PropType::StringEnum(&["info", "success", "warning", "error"]),
+                prop_type: PropType::List,
+            },
+            PropDef {
+                name: "title",
+                requirement: PropRequirement::Optional,
+                prop_type: PropType::String,
             },
             PropDef {
                 name: "accessible",
@@ -0,0 +1,187 @@
+//! Deep-link route metadata for a [`Surface`](crate::Surface).
+//!
+//! A route pairs a path pattern with the values of its parameters, so hosts
+//! can map OS deep links (`myapp://orders/42`) to the right PEPL screen:
+//!
+//! ```json
+//! "route": { "path": "/orders/:id", "params": { "id": "42" } }
+//! ```
+//!
+//! # Pattern grammar
+//!
+//! - The pattern starts with `/`; `/` alone is the root route.
+//! - Segments are separated by single `/` characters (no empty segments,
+//!   no trailing slash).
+//! - A segment is a literal (`[a-z0-9._-]+`), a parameter `:name`, or — as the
+//!   last segment only — a catch-all `*name` that matches the remaining path.
+//! - Parameter names match `[a-z_][a-z0-9_]*` and must be unique.
+//!
+//! `params` must provide a value for exactly the parameters in the pattern.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Route metadata: a path pattern plus the current parameter values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Route {
+    /// Path pattern, e.g. `/orders/:id`.
+    pub path: String,
+    /// Parameter values, keyed by parameter name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+/// One parsed segment of a route pattern.
+enum Segment<'a> {
+    Literal(&'a str),
+    Param(&'a str),
+    CatchAll(&'a str),
+}
+
+impl Route {
+    /// Create a route with no parameter values.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Builder: set a parameter value.
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Parameter names declared by the pattern, in order.
+    ///
+    /// Returns an error if the pattern is invalid.
+    pub fn param_names(&self) -> Result<Vec<&str>, String> {
+        Ok(parse_pattern(&self.path)?
+            .into_iter()
+            .filter_map(|seg| match seg {
+                Segment::Param(name) | Segment::CatchAll(name) => Some(name),
+                Segment::Literal(_) => None,
+            })
+            .collect())
+    }
+
+    /// Match a concrete path (e.g. from a deep link) against the pattern.
+    ///
+    /// Returns the extracted parameter values, or `None` if the path does not
+    /// match or the pattern is invalid.
+    pub fn match_path(&self, path: &str) -> Option<BTreeMap<String, String>> {
+        let pattern = parse_pattern(&self.path).ok()?;
+        let rest = path.strip_prefix('/')?;
+        let parts: Vec<&str> = if rest.is_empty() {
+            Vec::new()
+        } else {
+            rest.split('/').collect()
+        };
+        let mut params = BTreeMap::new();
+        for (i, seg) in pattern.iter().enumerate() {
+            match seg {
+                Segment::CatchAll(name) => {
+                    if i >= parts.len() {
+                        return None;
+                    }
+                    params.insert(name.to_string(), parts[i..].join("/"));
+                    return Some(params);
+                }
+                Segment::Literal(lit) => {
+                    if parts.get(i) != Some(lit) {
+                        return None;
+                    }
+                }
+                Segment::Param(name) => match parts.get(i) {
+                    Some(value) if !value.is_empty() => {
+                        params.insert(name.to_string(), value.to_string());
+                    }
+                    _ => return None,
+                },
+            }
+        }
+        (parts.len() == pattern.len()).then_some(params)
+    }
+
+    /// Validate the pattern and parameter values.
+    ///
+    /// Returns a list of validation errors. Empty means valid.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let names = match self.param_names() {
+            Ok(names) => names,
+            Err(reason) => {
+                errors.push(format!(
+                    "Surface.route.path: invalid pattern '{}': {reason}",
+                    self.path
+                ));
+                return errors;
+            }
+        };
+        for name in &names {
+            if !self.params.contains_key(*name) {
+                errors.push(format!("Surface.route.params: missing value for '{name}'"));
+            }
+        }
+        for key in self.params.keys() {
+            if !names.contains(&key.as_str()) {
+                errors.push(format!(
+                    "Surface.route.params: '{key}' is not a parameter of '{}'",
+                    self.path
+                ));
+            }
+        }
+        errors
+    }
+}
+
+/// Parse a route pattern into segments, enforcing the grammar.
+fn parse_pattern(pattern: &str) -> Result<Vec<Segment<'_>>, String> {
+    let rest = pattern
+        .strip_prefix('/')
+        .ok_or_else(|| "must start with '/'".to_string())?;
+    if rest.is_empty() {
+        return Ok(Vec::new());
+    }
+    let raw: Vec<&str> = rest.split('/').collect();
+    let mut segments = Vec::with_capacity(raw.len());
+    let mut seen: Vec<&str> = Vec::new();
+    for (i, part) in raw.iter().enumerate() {
+        let segment = if let Some(name) = part.strip_prefix(':') {
+            Segment::Param(name)
+        } else if let Some(name) = part.strip_prefix('*') {
+            if i + 1 != raw.len() {
+                return Err(format!("catch-all '{part}' must be the last segment"));
+            }
+            Segment::CatchAll(name)
+        } else if part.is_empty() {
+            return Err("empty segment".to_string());
+        } else if part
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-'))
+        {
+            Segment::Literal(part)
+        } else {
+            return Err(format!("invalid literal segment '{part}'"));
+        };
+        if let Segment::Param(name) | Segment::CatchAll(name) = segment {
+            if !is_param_name(name) {
+                return Err(format!("invalid parameter name '{name}'"));
+            }
+            if seen.contains(&name) {
+                return Err(format!("duplicate parameter '{name}'"));
+            }
+            seen.push(name);
+        }
+        segments.push(segment);
+    }
+    Ok(segments)
+}
+
+/// `[a-z_][a-z0-9_]*`
+fn is_param_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
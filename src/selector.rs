@@ -0,0 +1,102 @@
+//! Locating nodes in a surface tree.
+//!
+//! A [`Selector`] matches nodes by component type, prop values, key, and
+//! test ID; every condition must hold. [`Surface::find_all`] returns the
+//! paths of all matches in pre-order:
+//!
+//! ```
+//! use pepl_ui::{ButtonBuilder, ColumnBuilder, NodePath, PropValue, Selector, Surface};
+//!
+//! let surface = Surface::new(
+//!     ColumnBuilder::new()
+//!         .child(ButtonBuilder::new("Cancel", PropValue::action("cancel")).build())
+//!         .child(ButtonBuilder::new("OK", PropValue::action("confirm")).build())
+//!         .build(),
+//! );
+//!
+//! let ok = Selector::component("Button").prop("label", "OK");
+//! assert_eq!(surface.find_all(&ok), vec![NodePath::root().child(1)]);
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+/// Conditions a node must meet to match. An empty selector matches every
+/// node.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Selector {
+    component: Option<String>,
+    props: BTreeMap<String, PropValue>,
+    key: Option<String>,
+    test_id: Option<String>,
+}
+
+impl Selector {
+    /// A selector matching every node.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Match nodes of one component type, e.g. `"Button"`.
+    pub fn component(component_type: impl Into<String>) -> Self {
+        Self::any().with_component(component_type)
+    }
+
+    /// Also require the component type.
+    pub fn with_component(mut self, component_type: impl Into<String>) -> Self {
+        self.component = Some(component_type.into());
+        self
+    }
+
+    /// Also require prop `key` to equal `value`.
+    pub fn prop(mut self, key: impl Into<String>, value: impl Into<PropValue>) -> Self {
+        self.props.insert(key.into(), value.into());
+        self
+    }
+
+    /// Also require the node's [`key`](SurfaceNode::key).
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Also require the node's [`test_id`](SurfaceNode::test_id).
+    pub fn test_id(mut self, test_id: impl Into<String>) -> Self {
+        self.test_id = Some(test_id.into());
+        self
+    }
+
+    /// Whether `node` meets every condition. Children are not considered.
+    pub fn matches(&self, node: &SurfaceNode) -> bool {
+        self.component
+            .as_ref()
+            .is_none_or(|component| node.component_type == *component)
+            && (self.key.is_none() || node.key == self.key)
+            && (self.test_id.is_none() || node.test_id == self.test_id)
+            && self
+                .props
+                .iter()
+                .all(|(key, value)| node.props.get(key) == Some(value))
+    }
+}
+
+impl Surface {
+    /// Paths of every node matching `selector`, in pre-order.
+    pub fn find_all(&self, selector: &Selector) -> Vec<NodePath> {
+        let mut found = Vec::new();
+        self.walk(|node, path| {
+            if selector.matches(node) {
+                found.push(path.clone());
+            }
+        });
+        found
+    }
+
+    /// Path of the first node (in pre-order) matching `selector`.
+    pub fn find(&self, selector: &Selector) -> Option<NodePath> {
+        self.find_all(selector).into_iter().next()
+    }
+}
@@ -0,0 +1,104 @@
+//! Surface-level keyboard shortcuts.
+//!
+//! A [`Surface`](crate::Surface) may declare global shortcuts that desktop and
+//! web hosts register while the surface is shown. Each entry maps a key-combo
+//! string to an `ActionRef`:
+//!
+//! ```json
+//! { "root": { ... }, "shortcuts": { "primary+s": { "__action": "save" } } }
+//! ```
+//!
+//! # Key-combo grammar
+//!
+//! `modifier+...+key`, all lowercase:
+//!
+//! - Modifiers, in this order, each at most once: `primary`, `ctrl`, `alt`,
+//!   `shift`, `meta`. `primary` is Cmd on Apple platforms and Ctrl elsewhere.
+//! - Key: a single letter or digit, or one of the named keys below, or
+//!   `f1`–`f12`. Letters and digits require at least one modifier so that
+//!   shortcuts never swallow ordinary typing.
+
+use crate::prop_value::PropValue;
+use std::collections::BTreeMap;
+
+/// Modifier names in canonical order.
+const MODIFIERS: &[&str] = &["primary", "ctrl", "alt", "shift", "meta"];
+
+/// Named (non-printable) keys.
+const NAMED_KEYS: &[&str] = &[
+    "enter",
+    "escape",
+    "tab",
+    "space",
+    "backspace",
+    "delete",
+    "up",
+    "down",
+    "left",
+    "right",
+    "home",
+    "end",
+    "page_up",
+    "page_down",
+];
+
+/// Validate a single key-combo string against the grammar above.
+///
+/// Returns a description of the problem on failure.
+pub fn validate_key_combo(combo: &str) -> Result<(), String> {
+    let parts: Vec<&str> = combo.split('+').collect();
+    let (key, modifiers) = parts.split_last().expect("split yields at least one part");
+
+    let mut last_rank = None;
+    for modifier in modifiers {
+        let rank = MODIFIERS
+            .iter()
+            .position(|m| m == modifier)
+            .ok_or_else(|| format!("unknown modifier '{modifier}'"))?;
+        if last_rank.is_some_and(|last| rank <= last) {
+            return Err(format!(
+                "modifiers must appear once each, in the order {}",
+                MODIFIERS.join(", ")
+            ));
+        }
+        last_rank = Some(rank);
+    }
+
+    let printable = key.len() == 1
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+    let function = key
+        .strip_prefix('f')
+        .and_then(|n| n.parse::<u8>().ok())
+        .is_some_and(|n| (1..=12).contains(&n));
+    if printable {
+        if modifiers.is_empty() {
+            return Err(format!("key '{key}' requires a modifier"));
+        }
+    } else if !function && !NAMED_KEYS.contains(key) {
+        return Err(format!("unknown key '{key}'"));
+    }
+    Ok(())
+}
+
+/// Validate a Surface `shortcuts` map.
+///
+/// Returns a list of validation errors. Empty means valid.
+pub fn validate_shortcuts(shortcuts: &BTreeMap<String, PropValue>) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (combo, action) in shortcuts {
+        if let Err(reason) = validate_key_combo(combo) {
+            errors.push(format!(
+                "Surface.shortcuts: invalid key combo '{combo}': {reason}"
+            ));
+        }
+        if !matches!(action, PropValue::ActionRef { .. }) {
+            errors.push(format!(
+                "Surface.shortcuts['{combo}']: expected action, got {}",
+                action.type_name()
+            ));
+        }
+    }
+    errors
+}
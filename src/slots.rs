@@ -0,0 +1,107 @@
+//! Reusable layout shells with named slots.
+//!
+//! A shell — app chrome, a card layout — is an ordinary surface with `Slot`
+//! placeholder nodes where per-screen content goes. [`Surface::compose`]
+//! fills them by name:
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//! use pepl_ui::{ColumnBuilder, Surface, SurfaceNode, TextBuilder};
+//!
+//! let shell = Surface::new(
+//!     ColumnBuilder::new()
+//!         .child(SurfaceNode::slot("header"))
+//!         .child(SurfaceNode::slot("body"))
+//!         .build(),
+//! );
+//!
+//! let screen = shell.compose(BTreeMap::from([
+//!     ("header".to_string(), TextBuilder::new("Inbox").build()),
+//!     ("body".to_string(), TextBuilder::new("No messages").build()),
+//! ]));
+//! assert!(screen.validate().is_empty());
+//! ```
+//!
+//! A Slot is `{ "type": "Slot", "props": { "name": "body" } }` with at most
+//! one child, the fallback used when no content is given for it. Slots are
+//! never sent to hosts: validation reports one left in the tree as unfilled.
+//! Content is inserted as given, so Slots inside it survive for a later
+//! `compose`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+/// Component type of a slot placeholder.
+pub const SLOT: &str = "Slot";
+
+impl SurfaceNode {
+    /// A slot placeholder named `name`, filled by [`Surface::compose`].
+    pub fn slot(name: impl Into<String>) -> Self {
+        SurfaceNode::new(SLOT).with_prop("name", PropValue::String(name.into()))
+    }
+
+    /// A slot placeholder with `fallback` content for when it is not filled.
+    pub fn slot_with_fallback(name: impl Into<String>, fallback: SurfaceNode) -> Self {
+        SurfaceNode::slot(name).with_child(fallback)
+    }
+
+    /// The slot name, if this node is a slot.
+    pub fn slot_name(&self) -> Option<&str> {
+        match (self.component_type.as_str(), self.props.get("name")) {
+            (SLOT, Some(PropValue::String(name))) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+impl Surface {
+    /// A copy of this surface with every slot replaced by its content from
+    /// `slots`, or by its fallback child. Slots with neither stay in place.
+    pub fn compose(&self, slots: BTreeMap<String, SurfaceNode>) -> Surface {
+        self.clone().map_nodes(|mut node| {
+            let Some(name) = node.slot_name() else {
+                return node;
+            };
+            match slots.get(name) {
+                Some(content) => content.clone(),
+                None if node.children.len() == 1 => node.children.remove(0),
+                None => node,
+            }
+        })
+    }
+
+    /// Names of the slots in this surface (sorted, deduplicated).
+    pub fn slot_names(&self) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        self.walk(|node, _| names.extend(node.slot_name().map(str::to_string)));
+        names
+    }
+}
+
+/// Validate a `Slot` node. Any slot is an error — it should have been
+/// filled by [`Surface::compose`] — but a malformed one says why.
+pub(crate) fn validate_slot(node: &SurfaceNode) -> Vec<String> {
+    let mut errors = Vec::new();
+    match node.props.get("name") {
+        Some(PropValue::String(name)) if name.trim().is_empty() => {
+            errors.push("Slot.name: must not be empty".to_string())
+        }
+        Some(PropValue::String(name)) => errors.push(format!(
+            "Slot: unfilled slot '{name}' (fill it with Surface::compose)"
+        )),
+        Some(other) => errors.push(format!(
+            "Slot.name: expected string, got {}",
+            other.type_name()
+        )),
+        None => errors.push("Slot.name: required prop missing".to_string()),
+    }
+    if node.children.len() > 1 {
+        errors.push(format!(
+            "Slot: accepts at most one fallback child, but got {}",
+            node.children.len()
+        ));
+    }
+    errors
+}
@@ -1,4 +1,7 @@
+use crate::layers::Layer;
+use crate::path::NodePath;
 use crate::prop_value::PropValue;
+use crate::route::Route;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
@@ -8,8 +11,29 @@ use std::collections::BTreeMap;
 /// The host serializes this to JSON and renders it via its View Layer.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Surface {
-    /// The root node of the UI tree.
+    /// The root node of the UI tree: the `content` layer.
     pub root: SurfaceNode,
+
+    /// Named layers drawn above `root`, bottom to top.
+    /// See [`layers`](crate::layers).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<Layer>,
+
+    /// Global keyboard shortcuts: key combo → `ActionRef`.
+    /// See [`shortcuts`](crate::shortcuts) for the key-combo grammar.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub shortcuts: BTreeMap<String, PropValue>,
+
+    /// Deep-link route this surface answers to.
+    /// See [`route`](crate::route) for the pattern grammar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub route: Option<Route>,
+
+    /// Whether the host insets the root container from system UI (notches,
+    /// status bar, home indicator). The root's `safe_area` prop selects the
+    /// edges; without it every edge is inset.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub respect_safe_area: bool,
 }
 
 /// A single node in the abstract UI tree.
@@ -30,6 +54,17 @@ pub struct SurfaceNode {
     #[serde(rename = "type")]
     pub component_type: String,
 
+    /// Stable identifier for UI automation (XCUITest, Espresso, Playwright).
+    /// Serialized next to `type`, not inside `props`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_id: Option<String>,
+
+    /// Stable identity across re-renders, so hosts can keep focus, scroll
+    /// position, and animation state when siblings move. Must be unique
+    /// among siblings. Serialized next to `type`, not inside `props`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+
     /// Component properties. Uses `BTreeMap` for deterministic key ordering.
     pub props: BTreeMap<String, PropValue>,
 
@@ -42,7 +77,148 @@ pub struct SurfaceNode {
 impl Surface {
     /// Create a new Surface wrapping a root node.
     pub fn new(root: SurfaceNode) -> Self {
-        Self { root }
+        Self {
+            root,
+            layers: Vec::new(),
+            shortcuts: BTreeMap::new(),
+            route: None,
+            respect_safe_area: false,
+        }
+    }
+
+    /// Builder: register a global keyboard shortcut.
+    ///
+    /// `action` must be a `PropValue::ActionRef`. Check the result with
+    /// [`Surface::validate_shortcuts`].
+    pub fn with_shortcut(mut self, combo: impl Into<String>, action: PropValue) -> Self {
+        self.shortcuts.insert(combo.into(), action);
+        self
+    }
+
+    /// Validate the `shortcuts` map. Empty means valid.
+    pub fn validate_shortcuts(&self) -> Vec<String> {
+        crate::shortcuts::validate_shortcuts(&self.shortcuts)
+    }
+
+    /// Builder: set the deep-link route.
+    pub fn with_route(mut self, route: Route) -> Self {
+        self.route = Some(route);
+        self
+    }
+
+    /// Builder: ask the host to inset the root container from system UI.
+    pub fn with_respect_safe_area(mut self, respect: bool) -> Self {
+        self.respect_safe_area = respect;
+        self
+    }
+
+    /// Validate the `route`, if any. Empty means valid.
+    pub fn validate_route(&self) -> Vec<String> {
+        self.route.as_ref().map(Route::validate).unwrap_or_default()
+    }
+
+    /// Validate every node, the layers, the shortcuts, and the route. Empty
+    /// means valid.
+    ///
+    /// Node errors are prefixed with the node's path, e.g.
+    /// `root/1/0: Button.label: expected string, got number`, and in a
+    /// layer also with the layer name.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = validate_root(&self.root, &NodePath::root());
+        if self.root.props.contains_key("safe_area") && !self.respect_safe_area {
+            errors.push(format!(
+                "root: {}.safe_area: requires Surface.respect_safe_area",
+                self.root.component_type
+            ));
+        }
+        errors.extend(self.validate_layers());
+        errors.extend(self.validate_shortcuts());
+        errors.extend(self.validate_route());
+        errors
+    }
+
+    /// Check every [`PropValue::ColorToken`] against a theme's token names.
+    /// Empty means every token is known.
+    ///
+    /// Errors look like `root/0: Text.color: unknown color token 'primray'`.
+    pub fn validate_color_tokens(&self, tokens: &[&str]) -> Vec<String> {
+        check_values(self, &|value| match value {
+            PropValue::ColorToken { token } if !tokens.contains(&token.as_str()) => {
+                Some(format!("unknown color token '{token}'"))
+            }
+            _ => None,
+        })
+    }
+
+    /// Suggested cap for [`Surface::validate_inline_bytes`]: 64 KiB per value.
+    pub const DEFAULT_MAX_INLINE_BYTES: usize = 64 * 1024;
+
+    /// Check every [`PropValue::Bytes`] against a per-value size cap. Empty
+    /// means every inline asset fits.
+    ///
+    /// Errors look like `root/0: Image.src: inline bytes 70000 exceed limit 65536`.
+    pub fn validate_inline_bytes(&self, max_len: usize) -> Vec<String> {
+        check_values(self, &|value| match value {
+            PropValue::Bytes { data } if data.len() > max_len => Some(format!(
+                "inline bytes {} exceed limit {max_len}",
+                data.len()
+            )),
+            _ => None,
+        })
+    }
+
+    /// The node at `path`, if the tree has one there.
+    ///
+    /// Each step of the path selects a child of the previous node by index or
+    /// by [`key`](SurfaceNode::key) (see [`NodePath::keyed`]), so a keyed node
+    /// is reached in one lookup that only scans the siblings along the way,
+    /// with no [`Surface::find`] over the whole tree. A key step matches the
+    /// first child with that key.
+    pub fn get(&self, path: &NodePath) -> Option<&SurfaceNode> {
+        path.segments()
+            .iter()
+            .try_fold(self.layer(path.layer())?, |node, segment| {
+                node.children.get(segment.position(&node.children)?)
+            })
+    }
+
+    /// The node at `path`, mutably, if the tree has one there.
+    pub fn get_mut(&mut self, path: &NodePath) -> Option<&mut SurfaceNode> {
+        let root = self.layer_mut(path.layer())?;
+        path.segments().iter().try_fold(root, |node, segment| {
+            let position = segment.position(&node.children)?;
+            node.children.get_mut(position)
+        })
+    }
+
+    /// Replace the node at `path` (and its subtree) with `node`, returning
+    /// the old node. `None`, and no change, if there is no node at `path`.
+    pub fn replace(&mut self, path: &NodePath, node: SurfaceNode) -> Option<SurfaceNode> {
+        self.get_mut(path).map(|slot| std::mem::replace(slot, node))
+    }
+
+    /// Remove the node at `path` from its parent, returning it. Later
+    /// siblings shift left. `None` for the root or a missing node.
+    pub fn remove(&mut self, path: &NodePath) -> Option<SurfaceNode> {
+        let segment = path.segments().last()?;
+        let parent = self.get_mut(&path.parent()?)?;
+        let position = segment.position(&parent.children)?;
+        Some(parent.children.remove(position))
+    }
+
+    /// The event handler (`ActionRef` or `Lambda`) bound to `prop` on the
+    /// node at `path`, e.g. `resolve_event(&path, "on_tap")`. `None` if the
+    /// node is missing or the prop is not a handler.
+    ///
+    /// See [`EventTrace`](crate::trace::EventTrace) for recording and
+    /// replaying sequences of events.
+    pub fn resolve_event(&self, path: &NodePath, prop: &str) -> Option<&PropValue> {
+        self.get(path)?.props.get(prop).filter(|value| {
+            matches!(
+                value,
+                PropValue::ActionRef { .. } | PropValue::Lambda { .. }
+            )
+        })
     }
 
     /// Serialize this Surface to JSON (deterministic output).
@@ -56,11 +232,139 @@ impl Surface {
     }
 }
 
+/// Validate the tree under `root`, at `path`, prefixing errors with node
+/// paths.
+pub(crate) fn validate_root(root: &SurfaceNode, path: &NodePath) -> Vec<String> {
+    let mut errors = Vec::new();
+    validate_tree(root, None, false, false, path, &mut errors);
+    errors
+}
+
+fn validate_tree(
+    node: &SurfaceNode,
+    parent: Option<&SurfaceNode>,
+    in_scroll: bool,
+    in_form: bool,
+    path: &NodePath,
+    errors: &mut Vec<String>,
+) {
+    for error in crate::components::validate_node(node) {
+        errors.push(format!("{path}: {error}"));
+    }
+    // `flex` and `align_self` refer to a parent Row or Column
+    let in_stack = parent.is_some_and(|p| matches!(p.component_type.as_str(), "Row" | "Column"));
+    for key in ["flex", "align_self"] {
+        if node.props.contains_key(key) && !in_stack {
+            errors.push(format!(
+                "{path}: {}.{key}: only valid on a direct child of Row or Column",
+                node.component_type
+            ));
+        }
+    }
+    // `position` offsets a node from the edges of a stacking container
+    if node.props.contains_key("position")
+        && !parent.is_some_and(|p| matches!(p.component_type.as_str(), "Overlay" | "Modal"))
+    {
+        errors.push(format!(
+            "{path}: {}.position: only valid on a direct child of Overlay or Modal",
+            node.component_type
+        ));
+    }
+    // `safe_area` insets the whole surface, so only the root may set it
+    if parent.is_some() && node.props.contains_key("safe_area") {
+        errors.push(format!(
+            "{path}: {}.safe_area: only valid on the root node",
+            node.component_type
+        ));
+    }
+    // `pinned` sticks to the viewport of an enclosing scroll container
+    if !in_scroll && node.props.contains_key("pinned") {
+        errors.push(format!(
+            "{path}: {}.pinned: only valid inside Scroll or ScrollList",
+            node.component_type
+        ));
+    }
+    // Every input in a Form must be labelled
+    if in_form {
+        errors.extend(crate::components::form::check_input_label(node, path));
+    }
+    // `key` identifies a node among its siblings
+    let mut keys: BTreeMap<&str, usize> = BTreeMap::new();
+    for (i, child) in node.children.iter().enumerate() {
+        let Some(key) = child.key.as_deref() else {
+            continue;
+        };
+        match keys.get(key) {
+            Some(&first) => errors.push(format!(
+                "{}: {}.key: duplicate key {key:?} (also used by {})",
+                path.child(i),
+                child.component_type,
+                path.child(first)
+            )),
+            None => {
+                keys.insert(key, i);
+            }
+        }
+    }
+    let in_scroll = in_scroll || matches!(node.component_type.as_str(), "Scroll" | "ScrollList");
+    let in_form = in_form || node.component_type == "Form";
+    for (i, child) in node.children.iter().enumerate() {
+        validate_tree(
+            child,
+            Some(node),
+            in_scroll,
+            in_form,
+            &path.child(i),
+            errors,
+        );
+    }
+}
+
+/// Run `check` on every prop value in the surface and its layers, including
+/// values nested in lists, records, and arguments, reporting each finding
+/// against its prop.
+pub(crate) fn check_values(
+    surface: &Surface,
+    check: &dyn Fn(&PropValue) -> Option<String>,
+) -> Vec<String> {
+    fn visit(
+        value: &PropValue,
+        check: &dyn Fn(&PropValue) -> Option<String>,
+        found: &mut Vec<String>,
+    ) {
+        found.extend(check(value));
+        match value {
+            PropValue::List(items)
+            | PropValue::ActionRef {
+                args: Some(items), ..
+            } => items.iter().for_each(|item| visit(item, check, found)),
+            PropValue::Record(fields) | PropValue::I18n { args: fields, .. } => {
+                fields.values().for_each(|field| visit(field, check, found))
+            }
+            _ => {}
+        }
+    }
+
+    let mut errors = Vec::new();
+    surface.walk(|node, path| {
+        for (key, value) in &node.props {
+            let mut found = Vec::new();
+            visit(value, check, &mut found);
+            for message in found {
+                errors.push(format!("{path}: {}.{key}: {message}", node.component_type));
+            }
+        }
+    });
+    errors
+}
+
 impl SurfaceNode {
     /// Create a new node with the given component type and no props or children.
     pub fn new(component_type: impl Into<String>) -> Self {
         Self {
             component_type: component_type.into(),
+            test_id: None,
+            key: None,
             props: BTreeMap::new(),
             children: Vec::new(),
         }
@@ -72,6 +376,18 @@ impl SurfaceNode {
         self
     }
 
+    /// Builder: set the automation test ID.
+    pub fn with_test_id(mut self, test_id: impl Into<String>) -> Self {
+        self.test_id = Some(test_id.into());
+        self
+    }
+
+    /// Builder: set the stable identity key.
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
     /// Builder: add a child node.
     pub fn with_child(mut self, child: SurfaceNode) -> Self {
         self.children.push(child);
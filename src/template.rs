@@ -0,0 +1,263 @@
+//! Surface templates with `{placeholder}` substitution.
+//!
+//! A [`Template`] wraps a [`Surface`] whose string props contain
+//! placeholders. Parsing happens once in [`Template::new`]; each
+//! [`Template::render`] clones the surface and rewrites only the strings that
+//! contained placeholders, so static screens can be stamped with data cheaply.
+//!
+//! # Syntax
+//!
+//! - `{name}` — placeholder; `name` matches `[A-Za-z_][A-Za-z0-9_]*`.
+//! - `{{` and `}}` — a literal `{` / `}`.
+//! - Any other brace is kept literally.
+//!
+//! # Substitution
+//!
+//! - A string that is exactly one placeholder (`"{progress}"`) is replaced by
+//!   the bound [`PropValue`] itself, so numbers and actions keep their type.
+//! - Otherwise each placeholder is replaced by the value's text: strings as-is,
+//!   integral numbers without a fraction (`3`, not `3.0`), `true`/`false`,
+//!   `Nil` as the empty string, timestamps as written, anything else as
+//!   compact JSON.
+//! - Unbound placeholders are left verbatim (`{name}`); use
+//!   [`Template::unbound`] to detect them.
+//!
+//! Placeholders are recognised in every string prop value, including inside
+//! lists, records, and action arguments. Prop keys, component types, and
+//! `test_id`s and `key`s are never substituted.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::Surface;
+
+/// Values bound to placeholder names.
+pub type Bindings = BTreeMap<String, PropValue>;
+
+/// A precompiled surface template. See the [module docs](self).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    surface: Surface,
+    slots: Vec<Slot>,
+}
+
+/// A templated string: where it lives and its parsed parts.
+#[derive(Debug, Clone, PartialEq)]
+struct Slot {
+    node: NodePath,
+    prop: Vec<Step>,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    Placeholder(String),
+}
+
+impl Template {
+    /// Parse every string prop of `surface` for placeholders and escapes.
+    pub fn new(surface: Surface) -> Self {
+        let mut slots = Vec::new();
+        surface.walk(|node, path| {
+            for (key, value) in &node.props {
+                collect_value(value, path, &mut vec![Step::Key(key.clone())], &mut slots);
+            }
+        });
+        Self { surface, slots }
+    }
+
+    /// The surface as written, with placeholders unresolved.
+    pub fn surface(&self) -> &Surface {
+        &self.surface
+    }
+
+    /// All placeholder names used by this template (sorted, deduplicated).
+    pub fn placeholders(&self) -> BTreeSet<&str> {
+        self.slots
+            .iter()
+            .flat_map(|slot| &slot.parts)
+            .filter_map(|part| match part {
+                Part::Placeholder(name) => Some(name.as_str()),
+                Part::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Placeholder names with no value in `bindings` (sorted).
+    pub fn unbound(&self, bindings: &Bindings) -> Vec<String> {
+        self.placeholders()
+            .into_iter()
+            .filter(|name| !bindings.contains_key(*name))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Produce a surface with every placeholder substituted from `bindings`.
+    pub fn render(&self, bindings: &Bindings) -> Surface {
+        let mut surface = self.surface.clone();
+        for slot in &self.slots {
+            let node = surface
+                .get_mut(&slot.node)
+                .expect("template slots address nodes of the template's own surface");
+            let value = prop_at(&mut node.props, &slot.prop);
+            *value = substitute(&slot.parts, bindings);
+        }
+        surface
+    }
+}
+
+fn collect_value(value: &PropValue, node: &NodePath, prop: &mut Vec<Step>, slots: &mut Vec<Slot>) {
+    let mut nested = |step: Step, value: &PropValue, slots: &mut Vec<Slot>| {
+        prop.push(step);
+        collect_value(value, node, prop, slots);
+        prop.pop();
+    };
+    match value {
+        PropValue::String(s) if s.contains(['{', '}']) => {
+            let parts = parse(s);
+            if parts != [Part::Text(s.clone())] {
+                slots.push(Slot {
+                    node: node.clone(),
+                    prop: prop.clone(),
+                    parts,
+                });
+            }
+        }
+        PropValue::List(items)
+        | PropValue::ActionRef {
+            args: Some(items), ..
+        } => {
+            for (i, item) in items.iter().enumerate() {
+                nested(Step::Index(i), item, slots);
+            }
+        }
+        PropValue::Record(fields) => {
+            for (key, field) in fields {
+                nested(Step::Key(key.clone()), field, slots);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Split a template string into literal text and placeholders.
+fn parse(s: &str) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            text.push(c);
+            rest = &rest[2..];
+            continue;
+        }
+        if c == '{' {
+            if let Some(end) = rest.find('}') {
+                let name = &rest[1..end];
+                if is_placeholder_name(name) {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Placeholder(name.to_string()));
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+        text.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    if !text.is_empty() || parts.is_empty() {
+        parts.push(Part::Text(text));
+    }
+    parts
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn substitute(parts: &[Part], bindings: &Bindings) -> PropValue {
+    if let [Part::Placeholder(name)] = parts {
+        if let Some(value) = bindings.get(name) {
+            return value.clone();
+        }
+    }
+    PropValue::String(parts_to_text(parts, bindings))
+}
+
+/// Substitute `{name}` placeholders in `s` with the text of bound values.
+///
+/// Same syntax and escapes as templates; the result is always a string, and
+/// unbound placeholders are left verbatim.
+pub(crate) fn interpolate(s: &str, bindings: &Bindings) -> String {
+    if !s.contains(['{', '}']) {
+        return s.to_string();
+    }
+    parts_to_text(&parse(s), bindings)
+}
+
+fn parts_to_text(parts: &[Part], bindings: &Bindings) -> String {
+    let mut out = String::new();
+    for part in parts {
+        match part {
+            Part::Text(text) => out.push_str(text),
+            Part::Placeholder(name) => match bindings.get(name) {
+                Some(value) => out.push_str(&to_text(value)),
+                None => {
+                    out.push('{');
+                    out.push_str(name);
+                    out.push('}');
+                }
+            },
+        }
+    }
+    out
+}
+
+fn to_text(value: &PropValue) -> String {
+    match value {
+        PropValue::String(s) => s.clone(),
+        PropValue::Number(n) if n.fract() == 0.0 && n.abs() < 1e15 => format!("{}", *n as i64),
+        PropValue::Number(n) => n.to_string(),
+        PropValue::Bool(b) => b.to_string(),
+        PropValue::Nil => String::new(),
+        PropValue::Timestamp { timestamp } => timestamp.to_string(),
+        other => serde_json::to_string(other).unwrap_or_default(),
+    }
+}
+
+/// Follow `steps` from a node's props to the addressed value.
+///
+/// Steps were recorded from this same tree, so every step is present.
+fn prop_at<'a>(props: &'a mut BTreeMap<String, PropValue>, steps: &[Step]) -> &'a mut PropValue {
+    let (Step::Key(first), rest) = steps.split_first().expect("slot has a prop key") else {
+        unreachable!("slot paths start with a prop key");
+    };
+    let mut value = props.get_mut(first).expect("slot prop exists");
+    for step in rest {
+        value = match (value, step) {
+            (PropValue::Record(fields), Step::Key(key)) => fields.get_mut(key),
+            (PropValue::List(items), Step::Index(i))
+            | (
+                PropValue::ActionRef {
+                    args: Some(items), ..
+                },
+                Step::Index(i),
+            ) => items.get_mut(*i),
+            _ => None,
+        }
+        .expect("slot path exists");
+    }
+    value
+}
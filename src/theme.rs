@@ -0,0 +1,437 @@
+//! Design tokens and themes.
+//!
+//! Props may hold a [`PropValue::ThemeToken`] instead of a concrete value: a
+//! `group.name` reference such as `color.primary`, `spacing.md`,
+//! `radius.lg`, or `typography.title`. A [`Theme`] maps each group's names to
+//! values, and [`resolve_theme`] replaces every token in a surface with its
+//! value, so the same tree can be restyled per host or per brand without
+//! regenerating it.
+//!
+//! | Group | Resolves to | Accepted by |
+//! |-------|-------------|-------------|
+//! | `color` | color | color props (`color`, `background`, `fill`, ...) |
+//! | `spacing` | number | size props (`spacing`, `width`, ...) and Column/Row `padding` |
+//! | `radius` | number | `corner_radius` |
+//! | `typography` | number (font size) | Text `size` |
+//!
+//! [`ColorToken`](PropValue::ColorToken) references (`{ "__color_token":
+//! "primary" }`) resolve against the theme's colors too.
+//!
+//! Text styles are not tokens: Text's `style` prop names an entry in the
+//! [`Typography`] the host was given, which pins down font size, line height,
+//! and weight together so "title" looks the same on every platform.
+//!
+//! [`Theme::validate_surface`] checks a tree against a theme without
+//! changing it — unresolved tokens, unknown icons, and hand-entered spacing
+//! that is off the theme's scale — so CI can catch styling drift before a
+//! tree reaches a host.
+//!
+//! ```
+//! use pepl_ui::theme::{resolve_theme, Theme};
+//! use pepl_ui::{ColorValue, PropValue, Surface, SurfaceNode};
+//!
+//! let theme = Theme::new()
+//!     .with_color("primary", ColorValue { r: 0.2, g: 0.4, b: 1.0, a: 1.0 })
+//!     .with_spacing("md", 16.0);
+//! let mut surface = Surface::new(
+//!     SurfaceNode::new("Column").with_prop("spacing", PropValue::theme_token("spacing.md")),
+//! );
+//!
+//! assert!(resolve_theme(&mut surface, &theme).is_empty());
+//! assert_eq!(surface.root.props["spacing"], PropValue::Number(16.0));
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::components::content::TextSize;
+use crate::icons::{IconRegistry, ICON_PROPS};
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+use crate::types::{ColorValue, Spacing};
+
+/// Token values for one look: color roles and spacing, radius, and
+/// typography scales.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    /// Color roles (`color.<name>`), e.g. `primary`, `surface`, `on_surface`.
+    #[serde(default)]
+    pub colors: BTreeMap<String, ColorValue>,
+    /// Spacing scale in logical pixels (`spacing.<name>`), e.g. `sm`, `md`.
+    #[serde(default)]
+    pub spacing: BTreeMap<String, f64>,
+    /// Corner radius scale in logical pixels (`radius.<name>`).
+    #[serde(default)]
+    pub radii: BTreeMap<String, f64>,
+    /// Font sizes in points (`typography.<name>`), e.g. `body`, `title`.
+    #[serde(default)]
+    pub typography: BTreeMap<String, f64>,
+    /// Host icons (`asset:<name>`) available alongside the canonical set.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub icons: BTreeSet<String>,
+}
+
+impl Theme {
+    /// An empty theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a color role.
+    pub fn with_color(mut self, name: impl Into<String>, color: ColorValue) -> Self {
+        self.colors.insert(name.into(), color);
+        self
+    }
+
+    /// Add a spacing step.
+    pub fn with_spacing(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.spacing.insert(name.into(), value);
+        self
+    }
+
+    /// Add the [`Spacing`] steps at their
+    /// [default values](Spacing::default_value), keeping any already set.
+    pub fn with_default_spacing(mut self) -> Self {
+        for step in Spacing::ALL {
+            self.spacing
+                .entry(step.as_str().to_string())
+                .or_insert(step.default_value());
+        }
+        self
+    }
+
+    /// Add a corner radius step.
+    pub fn with_radius(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.radii.insert(name.into(), value);
+        self
+    }
+
+    /// Add a font size.
+    pub fn with_typography(mut self, name: impl Into<String>, size: f64) -> Self {
+        self.typography.insert(name.into(), size);
+        self
+    }
+
+    /// Add a host icon, referenced as `asset:<name>`.
+    pub fn with_icon(mut self, name: impl Into<String>) -> Self {
+        self.icons.insert(name.into());
+        self
+    }
+
+    /// The value of a `group.name` token, or `None` if the group is unknown
+    /// or the theme has no such name.
+    pub fn resolve(&self, token: &str) -> Option<PropValue> {
+        let (group, name) = token.split_once('.')?;
+        match group {
+            "color" => self.color(name),
+            "spacing" => self.spacing.get(name).copied().map(PropValue::Number),
+            "radius" => self.radii.get(name).copied().map(PropValue::Number),
+            "typography" => self.typography.get(name).copied().map(PropValue::Number),
+            _ => None,
+        }
+    }
+
+    fn color(&self, name: &str) -> Option<PropValue> {
+        let ColorValue { r, g, b, a } = self.colors.get(name)?;
+        Some(PropValue::color(*r, *g, *b, *a))
+    }
+
+    /// Replace the tokens in `value`, recording the ones the theme lacks.
+    fn resolve_value(&self, value: &mut PropValue, missing: &mut Vec<ThemeErrorKind>) {
+        let resolved = match value {
+            PropValue::ThemeToken { token } => self
+                .resolve(token)
+                .ok_or_else(|| ThemeErrorKind::UnknownToken(token.clone())),
+            PropValue::ColorToken { token } => self
+                .color(token)
+                .ok_or_else(|| ThemeErrorKind::UnknownColorToken(token.clone())),
+            PropValue::List(items)
+            | PropValue::ActionRef {
+                args: Some(items), ..
+            } => {
+                items
+                    .iter_mut()
+                    .for_each(|item| self.resolve_value(item, missing));
+                return;
+            }
+            PropValue::Record(fields) | PropValue::I18n { args: fields, .. } => {
+                fields
+                    .values_mut()
+                    .for_each(|field| self.resolve_value(field, missing));
+                return;
+            }
+            _ => return,
+        };
+        match resolved {
+            Ok(resolved) => *value = resolved,
+            Err(kind) => missing.push(kind),
+        }
+    }
+
+    fn resolve_node(&self, node: &mut SurfaceNode, path: &NodePath, errors: &mut Vec<ThemeError>) {
+        for (key, value) in node.props.iter_mut() {
+            let mut missing = Vec::new();
+            self.resolve_value(value, &mut missing);
+            errors.extend(
+                missing
+                    .into_iter()
+                    .map(|kind| ThemeError::new(path, &node.component_type, key, kind)),
+            );
+        }
+    }
+
+    /// Check `surface` and its layers against this theme without changing
+    /// it: tokens the theme does not define, icon names outside the canonical
+    /// set and [`icons`](Theme::icons), and raw Column/Row `spacing`,
+    /// `run_spacing`, and `padding` values that are not on the spacing scale
+    /// (zero always is; nothing is off-scale when the theme has no spacing).
+    /// Empty means the tree is consistent with the theme.
+    pub fn validate_surface(&self, surface: &Surface) -> Vec<ThemeError> {
+        let icons = self
+            .icons
+            .iter()
+            .fold(IconRegistry::new(), |icons, name| icons.with_icon(name));
+        let mut errors = Vec::new();
+        surface.walk(|node, path| self.validate_node(node, path, &icons, &mut errors));
+        errors
+    }
+
+    fn validate_node(
+        &self,
+        node: &SurfaceNode,
+        path: &NodePath,
+        icons: &IconRegistry,
+        errors: &mut Vec<ThemeError>,
+    ) {
+        let component = node.component_type.as_str();
+        for (key, value) in &node.props {
+            let mut kinds = Vec::new();
+            self.resolve_value(&mut value.clone(), &mut kinds);
+
+            let is_icon_prop = ICON_PROPS.contains(&(component, key.as_str()));
+            if let (true, PropValue::String(name)) = (is_icon_prop, value) {
+                if !icons.contains(name) {
+                    kinds.push(ThemeErrorKind::UnknownIcon {
+                        name: name.clone(),
+                        suggestion: icons.suggest(name),
+                    });
+                }
+            }
+
+            let is_spacing_prop = matches!(component, "Column" | "Row")
+                && matches!(key.as_str(), "spacing" | "run_spacing" | "padding");
+            if is_spacing_prop {
+                kinds.extend(
+                    self.off_scale(value)
+                        .into_iter()
+                        .map(ThemeErrorKind::OffScaleSpacing),
+                );
+            }
+
+            errors.extend(
+                kinds
+                    .into_iter()
+                    .map(|kind| ThemeError::new(path, &node.component_type, key, kind)),
+            );
+        }
+    }
+
+    /// The raw numbers in a spacing or padding value that are not on the
+    /// spacing scale.
+    fn off_scale(&self, value: &PropValue) -> Vec<f64> {
+        if self.spacing.is_empty() {
+            return Vec::new();
+        }
+        let numbers: Vec<f64> = match value {
+            PropValue::Number(n) => vec![*n],
+            PropValue::Record(sides) => sides
+                .values()
+                .filter_map(|side| match side {
+                    PropValue::Number(n) => Some(*n),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        numbers
+            .into_iter()
+            .filter(|n| *n != 0.0 && !self.spacing.values().any(|step| step == n))
+            .collect()
+    }
+}
+
+/// A styling problem found by [`Theme::validate_surface`] or
+/// [`resolve_theme`].
+///
+/// Displays as `root/0: Text.color: unknown color token 'accent'`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeError {
+    /// The node with the problem.
+    pub path: NodePath,
+    /// The node's component type.
+    pub component: String,
+    /// The prop holding the offending value.
+    pub prop: String,
+    pub kind: ThemeErrorKind,
+}
+
+impl ThemeError {
+    fn new(path: &NodePath, component: &str, prop: &str, kind: ThemeErrorKind) -> Self {
+        Self {
+            path: path.clone(),
+            component: component.to_string(),
+            prop: prop.to_string(),
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}.{}: {}",
+            self.path, self.component, self.prop, self.kind
+        )
+    }
+}
+
+/// What is wrong, in a [`ThemeError`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeErrorKind {
+    /// A [`ThemeToken`](PropValue::ThemeToken) the theme does not define.
+    UnknownToken(String),
+    /// A [`ColorToken`](PropValue::ColorToken) the theme does not define.
+    UnknownColorToken(String),
+    /// An icon name that is neither canonical nor one of the theme's icons,
+    /// with the closest known name.
+    UnknownIcon {
+        name: String,
+        suggestion: Option<String>,
+    },
+    /// A raw spacing value that is not on the theme's spacing scale.
+    OffScaleSpacing(f64),
+}
+
+impl fmt::Display for ThemeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownToken(token) => write!(f, "unknown theme token '{token}'"),
+            Self::UnknownColorToken(token) => write!(f, "unknown color token '{token}'"),
+            Self::UnknownIcon {
+                name,
+                suggestion: Some(suggestion),
+            } => write!(f, "unknown icon '{name}' (did you mean '{suggestion}'?)"),
+            Self::UnknownIcon {
+                name,
+                suggestion: None,
+            } => write!(f, "unknown icon '{name}'"),
+            Self::OffScaleSpacing(value) => write!(f, "{value} is not on the spacing scale"),
+        }
+    }
+}
+
+/// Concrete metrics for one text style.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TextStyle {
+    /// Font size in points.
+    pub size: f64,
+    /// Line height in points.
+    pub line_height: f64,
+    /// Numeric font weight, 100-900 (400 regular, 700 bold).
+    pub weight: u16,
+}
+
+impl TextStyle {
+    /// Create a text style.
+    pub fn new(size: f64, line_height: f64, weight: u16) -> Self {
+        Self {
+            size,
+            line_height,
+            weight,
+        }
+    }
+}
+
+/// Named text styles, referenced by Text's `style` prop.
+///
+/// [`Typography::standard`] defines one style per [`TextSize`] preset, so
+/// hosts share one definition of "title" instead of picking their own.
+/// Serialized as a map from style name to [`TextStyle`]:
+///
+/// ```json
+/// { "body": { "size": 16.0, "line_height": 24.0, "weight": 400 }, ... }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Typography {
+    pub styles: BTreeMap<String, TextStyle>,
+}
+
+impl Typography {
+    /// An empty scale.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The default scale: one style per [`TextSize`] preset.
+    ///
+    /// | Preset | Size | Line height | Weight |
+    /// |--------|------|-------------|--------|
+    /// | `small` | 13 | 18 | 400 |
+    /// | `body` | 16 | 24 | 400 |
+    /// | `title` | 20 | 28 | 600 |
+    /// | `heading` | 28 | 36 | 700 |
+    /// | `display` | 40 | 48 | 700 |
+    pub fn standard() -> Self {
+        TextSize::ALL
+            .into_iter()
+            .fold(Self::new(), |typography, preset| {
+                let style = match preset {
+                    TextSize::Small => TextStyle::new(13.0, 18.0, 400),
+                    TextSize::Body => TextStyle::new(16.0, 24.0, 400),
+                    TextSize::Title => TextStyle::new(20.0, 28.0, 600),
+                    TextSize::Heading => TextStyle::new(28.0, 36.0, 700),
+                    TextSize::Display => TextStyle::new(40.0, 48.0, 700),
+                };
+                typography.with_style(preset.as_str(), style)
+            })
+    }
+
+    /// Add or replace a named style.
+    pub fn with_style(mut self, name: impl Into<String>, style: TextStyle) -> Self {
+        self.styles.insert(name.into(), style);
+        self
+    }
+
+    /// The style called `name`.
+    pub fn get(&self, name: &str) -> Option<&TextStyle> {
+        self.styles.get(name)
+    }
+
+    /// The style for a [`TextSize`] preset.
+    pub fn preset(&self, size: TextSize) -> Option<&TextStyle> {
+        self.get(size.as_str())
+    }
+
+    /// Serialize to JSON for the host.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Typography serialization should never fail")
+    }
+}
+
+/// Replace every [`ThemeToken`](PropValue::ThemeToken) and
+/// [`ColorToken`](PropValue::ColorToken) in `surface` with its value from
+/// `theme`, layers included.
+///
+/// Tokens the theme does not define are left in place and reported, e.g.
+/// `root/0: Text.color: unknown theme token 'color.primray'`. Empty means
+/// every token resolved.
+pub fn resolve_theme(surface: &mut Surface, theme: &Theme) -> Vec<String> {
+    let mut errors = Vec::new();
+    surface.walk_mut(|node, path| theme.resolve_node(node, path, &mut errors));
+    errors.iter().map(ThemeError::to_string).collect()
+}
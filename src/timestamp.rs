@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// An ISO-8601 date or date-time, validated on construction.
+///
+/// Two forms are accepted:
+///
+/// - calendar date: `2026-10-16` (midnight UTC for ordering purposes)
+/// - RFC 3339 date-time with an explicit offset:
+///   `2026-10-16T09:30:00Z`, `2026-10-16T09:30:00.250+02:00`
+///
+/// Local date-times without an offset are rejected because they are
+/// ambiguous across hosts. The original text is preserved on the wire.
+/// Ordering compares the instant first (dates and offsets normalized to UTC,
+/// millisecond precision), then the text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Timestamp {
+    text: String,
+    unix_millis: i64,
+}
+
+impl Timestamp {
+    /// Parse and validate `s`.
+    ///
+    /// Errors look like `invalid timestamp '2026-02-30': day out of range`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let unix_millis =
+            parse_millis(s).map_err(|reason| format!("invalid timestamp '{s}': {reason}"))?;
+        Ok(Self {
+            text: s.to_string(),
+            unix_millis,
+        })
+    }
+
+    /// The timestamp as written.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Whether this is a date-time (as opposed to a calendar date).
+    pub fn has_time(&self) -> bool {
+        self.text.len() > 10
+    }
+
+    /// Milliseconds since 1970-01-01T00:00:00Z.
+    pub fn unix_millis(&self) -> i64 {
+        self.unix_millis
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.unix_millis
+            .cmp(&other.unix_millis)
+            .then_with(|| self.text.cmp(&other.text))
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(&s)
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.text
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+// ── Parsing ───────────────────────────────────────────────────────────────────
+
+fn parse_millis(s: &str) -> Result<i64, &'static str> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return Err("expected YYYY-MM-DD");
+    }
+    let year = digits(&bytes[0..4]).ok_or("expected YYYY-MM-DD")?;
+    let month = digits(&bytes[5..7]).ok_or("expected YYYY-MM-DD")?;
+    let day = digits(&bytes[8..10]).ok_or("expected YYYY-MM-DD")?;
+    if !(1..=12).contains(&month) {
+        return Err("month out of range");
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return Err("day out of range");
+    }
+    let date_millis = days_from_civil(year, month, day) * 86_400_000;
+
+    let rest = &bytes[10..];
+    if rest.is_empty() {
+        return Ok(date_millis);
+    }
+    if rest.len() < 9 || rest[0] != b'T' || rest[3] != b':' || rest[6] != b':' {
+        return Err("expected THH:MM:SS after the date");
+    }
+    let hour = digits(&rest[1..3]).ok_or("expected THH:MM:SS after the date")?;
+    let minute = digits(&rest[4..6]).ok_or("expected THH:MM:SS after the date")?;
+    let second = digits(&rest[7..9]).ok_or("expected THH:MM:SS after the date")?;
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err("time out of range");
+    }
+
+    let mut rest = &rest[9..];
+    let mut millis = 0;
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return Err("expected digits after '.'");
+        }
+        for (i, b) in fraction[..len.min(3)].iter().enumerate() {
+            millis += i64::from(b - b'0') * [100, 10, 1][i];
+        }
+        rest = &fraction[len..];
+    }
+
+    let offset_minutes = match rest {
+        b"Z" => 0,
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let hours = digits(&[*h1, *h2]).ok_or("invalid offset")?;
+            let minutes = digits(&[*m1, *m2]).ok_or("invalid offset")?;
+            if hours > 23 || minutes > 59 {
+                return Err("offset out of range");
+            }
+            let offset = hours * 60 + minutes;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        [] => return Err("missing offset (use Z or ±HH:MM)"),
+        _ => return Err("invalid offset"),
+    };
+
+    let time_millis = ((hour * 60 + minute - offset_minutes) * 60 + second) * 1000 + millis;
+    Ok(date_millis + time_millis)
+}
+
+fn digits(bytes: &[u8]) -> Option<i64> {
+    bytes.iter().try_fold(0, |n, b| {
+        b.is_ascii_digit().then(|| n * 10 + i64::from(b - b'0'))
+    })
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
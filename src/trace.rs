@@ -0,0 +1,185 @@
+//! Event traces for record/replay testing.
+//!
+//! An [`EventTrace`] is the ordered list of handler invocations in an
+//! interaction session: which node fired (by [`NodePath`]), which event prop
+//! (`on_tap`, `on_change`, ...), the handler that was bound to it, and the
+//! runtime arguments (e.g. the text a `TextInput` reported). Traces serialize
+//! to JSON, so hosts can record sessions and the evaluator can replay them
+//! deterministically in tests.
+//!
+//! ```
+//! use pepl_ui::trace::EventTrace;
+//! use pepl_ui::{ButtonBuilder, NodePath, PropValue, Surface};
+//!
+//! let surface = Surface::new(ButtonBuilder::new("+1", PropValue::action("increment")).build());
+//!
+//! let mut trace = EventTrace::new();
+//! trace.record(&surface, NodePath::root(), "on_tap", vec![]).unwrap();
+//!
+//! let mut taps = 0;
+//! let end = trace
+//!     .replay(surface, |surface, _event| {
+//!         taps += 1;
+//!         surface.clone()
+//!     })
+//!     .unwrap();
+//! assert_eq!(taps, 1);
+//! assert_eq!(end.root.component_type, "Button");
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::Surface;
+
+/// One handler invocation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceEvent {
+    /// The node that fired the event, e.g. `root/1/0`.
+    pub path: NodePath,
+    /// The event prop, e.g. `on_tap`.
+    pub prop: String,
+    /// The handler bound to `prop` when the event fired (`ActionRef` or
+    /// `Lambda`), including any bound `__args`.
+    pub handler: PropValue,
+    /// Runtime arguments supplied by the host (e.g. the new text value).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<PropValue>,
+}
+
+impl TraceEvent {
+    /// An event without runtime arguments.
+    pub fn new(path: NodePath, prop: impl Into<String>, handler: PropValue) -> Self {
+        Self {
+            path,
+            prop: prop.into(),
+            handler,
+            args: Vec::new(),
+        }
+    }
+
+    /// Builder: set the runtime arguments.
+    pub fn with_args(mut self, args: Vec<PropValue>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Check that `surface` binds the same handler at this event's node and
+    /// prop, returning that handler.
+    ///
+    /// Errors look like `root/0: Button.on_tap: recorded {"__action":"save"},
+    /// found {"__action":"delete"}`.
+    pub fn resolve<'a>(&self, surface: &'a Surface) -> Result<&'a PropValue, String> {
+        let path = &self.path;
+        let handler = surface
+            .resolve_event(path, &self.prop)
+            .ok_or_else(|| missing_handler(surface, path, &self.prop))?;
+        if *handler != self.handler {
+            return Err(format!(
+                "{path}: {}.{}: recorded {}, found {}",
+                surface
+                    .get(path)
+                    .expect("resolved node exists")
+                    .component_type,
+                self.prop,
+                to_json(&self.handler),
+                to_json(handler)
+            ));
+        }
+        Ok(handler)
+    }
+}
+
+/// An ordered sequence of [`TraceEvent`]s. See the [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventTrace {
+    #[serde(default)]
+    events: Vec<TraceEvent>,
+}
+
+impl EventTrace {
+    /// An empty trace.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an event.
+    pub fn push(&mut self, event: TraceEvent) {
+        self.events.push(event);
+    }
+
+    /// Resolve the handler for `prop` at `path` in `surface` and append it
+    /// with `args`. Fails if the node has no such handler.
+    pub fn record(
+        &mut self,
+        surface: &Surface,
+        path: NodePath,
+        prop: &str,
+        args: Vec<PropValue>,
+    ) -> Result<(), String> {
+        let handler = surface
+            .resolve_event(&path, prop)
+            .ok_or_else(|| missing_handler(surface, &path, prop))?;
+        self.push(TraceEvent::new(path, prop, handler.clone()).with_args(args));
+        Ok(())
+    }
+
+    /// The recorded events, oldest first.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether no events were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Replay the trace starting from `surface`.
+    ///
+    /// Each event is [resolved](TraceEvent::resolve) against the current
+    /// surface, then `dispatch` produces the next surface. Returns the final
+    /// surface, or the first mismatch prefixed with the event index, e.g.
+    /// `event 2: root/1: Button has no handler 'on_tap'`.
+    pub fn replay(
+        &self,
+        surface: Surface,
+        mut dispatch: impl FnMut(&Surface, &TraceEvent) -> Surface,
+    ) -> Result<Surface, String> {
+        self.events
+            .iter()
+            .enumerate()
+            .try_fold(surface, |surface, (i, event)| {
+                event
+                    .resolve(&surface)
+                    .map_err(|error| format!("event {i}: {error}"))?;
+                Ok(dispatch(&surface, event))
+            })
+    }
+
+    /// Serialize to JSON (deterministic output).
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("EventTrace serialization should never fail")
+    }
+
+    /// Parse a trace from JSON.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("invalid event trace JSON: {e}"))
+    }
+}
+
+fn missing_handler(surface: &Surface, path: &NodePath, prop: &str) -> String {
+    match surface.get(path) {
+        Some(node) => format!("{path}: {} has no handler '{prop}'", node.component_type),
+        None => format!("{path}: no node at path"),
+    }
+}
+
+fn to_json(value: &PropValue) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
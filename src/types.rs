@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 /// Dimension type for width, height, etc.
@@ -21,6 +23,127 @@ impl Dimension {
     pub fn from_number(n: f64) -> Self {
         Dimension::Px(n)
     }
+
+    /// Parse the textual `__dim` wire form: `"auto"`, `"fill"`, or a
+    /// percentage such as `"50%"`. Pixel values travel as plain numbers.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "auto" => Ok(Dimension::Auto),
+            "fill" => Ok(Dimension::Fill),
+            _ => s
+                .strip_suffix('%')
+                .and_then(|n| n.parse::<f64>().ok())
+                .filter(|n| n.is_finite())
+                .map(Dimension::Percent)
+                .ok_or_else(|| {
+                    format!("invalid dimension '{s}': expected \"auto\", \"fill\", or \"<n>%\"")
+                }),
+        }
+    }
+
+    /// Check the value range: pixels must be finite and non-negative,
+    /// percentages within 0–100.
+    pub fn check_range(&self) -> Result<(), String> {
+        match *self {
+            Dimension::Px(n) if !n.is_finite() || n < 0.0 => {
+                Err(format!("pixels must be non-negative, got {n}"))
+            }
+            Dimension::Percent(n) if !(0.0..=100.0).contains(&n) => {
+                Err(format!("percent must be within 0-100, got {n}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Serde support for the `__dim` field of
+/// [`PropValue::Dimension`](crate::PropValue::Dimension): a number for
+/// [`Px`](Dimension::Px), otherwise the text accepted by [`Dimension::parse`].
+pub(crate) mod dimension_wire {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Dimension;
+
+    pub(crate) fn serialize<S: Serializer>(
+        dim: &Dimension,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match *dim {
+            Dimension::Px(n) => serializer.serialize_f64(n),
+            Dimension::Auto => serializer.serialize_str("auto"),
+            Dimension::Fill => serializer.serialize_str("fill"),
+            Dimension::Percent(n) => serializer.collect_str(&format_args!("{n}%")),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Dimension, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Wire {
+            Px(f64),
+            Text(String),
+        }
+
+        match Wire::deserialize(deserializer)? {
+            Wire::Px(n) => Ok(Dimension::Px(n)),
+            Wire::Text(s) => Dimension::parse(&s).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+/// A step of the theme's spacing scale, for `spacing` and `padding`.
+///
+/// Each step is a `spacing.*` theme token (e.g. `spacing.md`), resolved to
+/// logical pixels by [`resolve_theme`](crate::theme::resolve_theme);
+/// [`Theme::with_default_spacing`](crate::theme::Theme::with_default_spacing)
+/// fills in the [default values](Spacing::default_value).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spacing {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Spacing {
+    /// Every step, smallest first.
+    pub const ALL: [Spacing; 5] = [Self::Xs, Self::Sm, Self::Md, Self::Lg, Self::Xl];
+
+    /// The step name (`"xs"` ... `"xl"`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Xs => "xs",
+            Self::Sm => "sm",
+            Self::Md => "md",
+            Self::Lg => "lg",
+            Self::Xl => "xl",
+        }
+    }
+
+    /// The theme token, e.g. `"spacing.md"`.
+    pub fn token(self) -> &'static str {
+        match self {
+            Self::Xs => "spacing.xs",
+            Self::Sm => "spacing.sm",
+            Self::Md => "spacing.md",
+            Self::Lg => "spacing.lg",
+            Self::Xl => "spacing.xl",
+        }
+    }
+
+    /// The default size in logical pixels: 4, 8, 16, 24, 32.
+    pub fn default_value(self) -> f64 {
+        match self {
+            Self::Xs => 4.0,
+            Self::Sm => 8.0,
+            Self::Md => 16.0,
+            Self::Lg => 24.0,
+            Self::Xl => 32.0,
+        }
+    }
 }
 
 /// Edge insets (padding, margin, etc.).
@@ -57,7 +180,130 @@ impl Edges {
     }
 }
 
-/// Alignment for layout components (Column, Row).
+/// Which edges of a root container are inset for system UI (notches, status
+/// bar, home indicator).
+///
+/// Serialized as the `safe_area` prop: `true` for all edges, or a record of
+/// bools `{ top?, bottom?, start?, end? }` where missing edges are `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SafeAreaEdges {
+    #[serde(default)]
+    pub top: bool,
+    #[serde(default)]
+    pub bottom: bool,
+    #[serde(default)]
+    pub start: bool,
+    #[serde(default)]
+    pub end: bool,
+}
+
+impl SafeAreaEdges {
+    /// Inset every edge.
+    pub fn all() -> Self {
+        Self {
+            top: true,
+            bottom: true,
+            start: true,
+            end: true,
+        }
+    }
+
+    /// Inset only the top and bottom edges.
+    pub fn vertical() -> Self {
+        Self {
+            top: true,
+            bottom: true,
+            ..Self::default()
+        }
+    }
+
+    /// Whether every edge is inset.
+    pub fn is_all(&self) -> bool {
+        *self == Self::all()
+    }
+}
+
+/// Absolute offsets from a container's edges (the `position` prop).
+///
+/// Serialized as a record with only the set edges, e.g.
+/// `{ "top": 8, "end": 8 }`. Setting both `top` and `bottom` (or `start`
+/// and `end`) stretches the node between them.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Position {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<f64>,
+}
+
+impl Position {
+    /// Offset from the top edge.
+    pub fn top(mut self, top: f64) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Offset from the bottom edge.
+    pub fn bottom(mut self, bottom: f64) -> Self {
+        self.bottom = Some(bottom);
+        self
+    }
+
+    /// Offset from the leading edge.
+    pub fn start(mut self, start: f64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Offset from the trailing edge.
+    pub fn end(mut self, end: f64) -> Self {
+        self.end = Some(end);
+        self
+    }
+}
+
+/// Corner radii for rounded containers (`corner_radius`).
+///
+/// Number literal coercion: `corner_radius: 12` → `Uniform(12.0)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CornerRadii {
+    /// All four corners equal.
+    Uniform(f64),
+    /// Individual corners.
+    Corners {
+        top_start: f64,
+        top_end: f64,
+        bottom_start: f64,
+        bottom_end: f64,
+    },
+}
+
+impl CornerRadii {
+    /// Coerce a number to `Uniform`.
+    pub fn from_number(n: f64) -> Self {
+        CornerRadii::Uniform(n)
+    }
+
+    /// Create explicit corners.
+    pub fn corners(top_start: f64, top_end: f64, bottom_start: f64, bottom_end: f64) -> Self {
+        CornerRadii::Corners {
+            top_start,
+            top_end,
+            bottom_start,
+            bottom_end,
+        }
+    }
+}
+
+/// Cross-axis alignment for layout components (Column, Row).
+///
+/// `SpaceBetween` and `SpaceAround` distribute children along the main axis;
+/// they are kept for backward compatibility, but new code should use [`Justify`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Alignment {
@@ -69,6 +315,69 @@ pub enum Alignment {
     SpaceAround,
 }
 
+impl Alignment {
+    /// Returns the string value used in the Surface tree.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Alignment::Start => "start",
+            Alignment::Center => "center",
+            Alignment::End => "end",
+            Alignment::Stretch => "stretch",
+            Alignment::SpaceBetween => "space_between",
+            Alignment::SpaceAround => "space_around",
+        }
+    }
+}
+
+/// Cross-axis alignment of one child of a Row or Column (`align_self`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlignSelf {
+    Start,
+    Center,
+    End,
+    Stretch,
+}
+
+impl AlignSelf {
+    /// Every value, in declaration order.
+    pub const ALL: [AlignSelf; 4] = [Self::Start, Self::Center, Self::End, Self::Stretch];
+
+    /// Returns the string value used in the Surface tree.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlignSelf::Start => "start",
+            AlignSelf::Center => "center",
+            AlignSelf::End => "end",
+            AlignSelf::Stretch => "stretch",
+        }
+    }
+}
+
+/// Main-axis distribution for layout components (Column, Row).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Justify {
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceAround,
+}
+
+impl Justify {
+    /// Returns the string value used in the Surface tree.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Justify::Start => "start",
+            Justify::Center => "center",
+            Justify::End => "end",
+            Justify::SpaceBetween => "space_between",
+            Justify::SpaceAround => "space_around",
+        }
+    }
+}
+
 /// Border style definition.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BorderStyle {
@@ -95,6 +404,9 @@ pub struct ShadowStyle {
 }
 
 /// RGBA color value (each component 0.0–1.0).
+///
+/// Displays as `#RRGGBB`, or `#RRGGBBAA` when not fully opaque, the format
+/// [`from_hex`](ColorValue::from_hex) parses.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColorValue {
     pub r: f64,
@@ -104,6 +416,19 @@ pub struct ColorValue {
 }
 
 impl ColorValue {
+    pub const BLACK: ColorValue = ColorValue::opaque(0.0, 0.0, 0.0);
+    pub const WHITE: ColorValue = ColorValue::opaque(1.0, 1.0, 1.0);
+    pub const GRAY: ColorValue = ColorValue::opaque(0.5, 0.5, 0.5);
+    pub const RED: ColorValue = ColorValue::opaque(1.0, 0.0, 0.0);
+    pub const GREEN: ColorValue = ColorValue::opaque(0.0, 1.0, 0.0);
+    pub const BLUE: ColorValue = ColorValue::opaque(0.0, 0.0, 1.0);
+    pub const TRANSPARENT: ColorValue = ColorValue {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+
     /// Create a new color.
     pub fn new(r: f64, g: f64, b: f64, a: f64) -> Self {
         Self { r, g, b, a }
@@ -111,6 +436,407 @@ impl ColorValue {
 
     /// Opaque color (alpha = 1.0).
     pub fn rgb(r: f64, g: f64, b: f64) -> Self {
+        Self::opaque(r, g, b)
+    }
+
+    const fn opaque(r: f64, g: f64, b: f64) -> Self {
         Self { r, g, b, a: 1.0 }
     }
+
+    /// Parse `#RRGGBB` or `#RRGGBBAA` (hex digits in either case).
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let error = || ColorParseError {
+            input: hex.to_string(),
+        };
+        let digits = hex.strip_prefix('#').ok_or_else(error)?;
+        if !matches!(digits.len(), 6 | 8) || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let channel = |i: usize| {
+            u8::from_str_radix(&digits[i..i + 2], 16).map_or(0.0, |v| f64::from(v) / 255.0)
+        };
+        let a = if digits.len() == 8 { channel(6) } else { 1.0 };
+        Ok(Self::new(channel(0), channel(2), channel(4), a))
+    }
+
+    /// Opaque color from hue (degrees, wrapped into 0–360), saturation, and
+    /// lightness (each clamped to 0.0–1.0).
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        Self::opaque(r + m, g + m, b + m)
+    }
+
+    /// Hue (degrees, 0–360), saturation, and lightness (0.0–1.0).
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let l = (max + min) / 2.0;
+        let chroma = max - min;
+        if chroma == 0.0 {
+            return (0.0, 0.0, l);
+        }
+        let s = chroma / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == self.r {
+            ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / chroma + 2.0
+        } else {
+            (self.r - self.g) / chroma + 4.0
+        };
+        (h * 60.0, s, l)
+    }
+
+    /// Raise HSL lightness by `amount` (0.0–1.0), keeping hue and alpha.
+    pub fn lighten(&self, amount: f64) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount).with_alpha(self.a)
+    }
+
+    /// Lower HSL lightness by `amount` (0.0–1.0), keeping hue and alpha.
+    pub fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// The same color with alpha `a` (clamped to 0.0–1.0).
+    pub fn with_alpha(&self, a: f64) -> Self {
+        Self {
+            a: a.clamp(0.0, 1.0),
+            ..self.clone()
+        }
+    }
+
+    /// Blend toward `other` channel by channel, alpha included: `t = 0.0`
+    /// is `self`, `t = 1.0` is `other` (clamped).
+    pub fn mix(&self, other: &ColorValue, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |from: f64, to: f64| from + (to - from) * t;
+        Self::new(
+            lerp(self.r, other.r),
+            lerp(self.g, other.g),
+            lerp(self.b, other.b),
+            lerp(self.a, other.a),
+        )
+    }
+}
+
+impl fmt::Display for ColorValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let byte = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        write!(
+            f,
+            "#{:02X}{:02X}{:02X}",
+            byte(self.r),
+            byte(self.g),
+            byte(self.b)
+        )?;
+        if byte(self.a) != 255 {
+            write!(f, "{:02X}", byte(self.a))?;
+        }
+        Ok(())
+    }
+}
+
+/// A color with separate values for the host's light and dark appearance.
+///
+/// Accepted by every color prop, so one Surface tree serves both modes and
+/// the host switches between them without re-evaluating the app. Serialized
+/// as `{ "__light": <color>, "__dark": <color> }`.
+///
+/// ```
+/// use pepl_ui::{AdaptiveColor, ColorValue, SurfaceNode, TextBuilder};
+///
+/// let ink = AdaptiveColor::new(ColorValue::BLACK, ColorValue::WHITE);
+/// let node: SurfaceNode = TextBuilder::new("Hello").build().with_prop("color", ink.into());
+///
+/// assert!(node.props["color"].is_color());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveColor {
+    #[serde(rename = "__light")]
+    pub light: ColorValue,
+    #[serde(rename = "__dark")]
+    pub dark: ColorValue,
+}
+
+impl AdaptiveColor {
+    /// Create an adaptive color.
+    pub fn new(light: ColorValue, dark: ColorValue) -> Self {
+        Self { light, dark }
+    }
+
+    /// The color for the given appearance.
+    pub fn resolve(&self, dark: bool) -> &ColorValue {
+        if dark {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+}
+
+/// A color stop in a [`Gradient`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GradientStop {
+    /// Position along the gradient, 0.0 (start) to 1.0 (end).
+    pub offset: f64,
+    pub color: ColorValue,
+}
+
+impl GradientStop {
+    /// Create a stop.
+    pub fn new(offset: f64, color: ColorValue) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// A color gradient, accepted by ProgressBar `color` and container
+/// `background` in place of a solid color.
+///
+/// Serialized as a record tagged with `__gradient`:
+///
+/// ```json
+/// { "__gradient": "linear", "angle": 90, "stops": [{ "offset": 0, "color": { "r": 1, "g": 0, "b": 0, "a": 1 } }, ...] }
+/// ```
+///
+/// Stops are ordered by non-decreasing `offset`; at least two are required.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__gradient", rename_all = "snake_case")]
+pub enum Gradient {
+    /// Along a line at `angle` degrees, measured clockwise as in CSS:
+    /// 0 runs bottom to top, 90 start to end, 180 top to bottom.
+    Linear {
+        angle: f64,
+        stops: Vec<GradientStop>,
+    },
+    /// Outward from the center to the farthest corner.
+    Radial { stops: Vec<GradientStop> },
+}
+
+impl Gradient {
+    /// A linear gradient at `angle` degrees.
+    pub fn linear(angle: f64, stops: Vec<GradientStop>) -> Self {
+        Gradient::Linear { angle, stops }
+    }
+
+    /// A radial gradient.
+    pub fn radial(stops: Vec<GradientStop>) -> Self {
+        Gradient::Radial { stops }
+    }
+
+    /// The color stops.
+    pub fn stops(&self) -> &[GradientStop] {
+        match self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops } => stops,
+        }
+    }
+}
+
+/// Error returned by [`ColorValue::from_hex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorParseError {
+    input: String,
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid hex color {:?}: expected #RRGGBB or #RRGGBBAA",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Easing curve for transitions and animations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the string value used in the Surface tree.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Easing::Linear => "linear",
+            Easing::EaseIn => "ease_in",
+            Easing::EaseOut => "ease_out",
+            Easing::EaseInOut => "ease_in_out",
+        }
+    }
+
+    /// All valid easing string values (for validation).
+    pub fn valid_values() -> &'static [&'static str] {
+        &["linear", "ease_in", "ease_out", "ease_in_out"]
+    }
+}
+
+/// Kind of enter/exit transition for overlay components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionKind {
+    Fade,
+    Slide,
+    Scale,
+}
+
+/// Enter/exit transition for overlay components (Modal, Toast).
+///
+/// Serialized as `{ "type": "fade"|"slide"|"scale", "duration": ms, "easing"? }`.
+///
+/// Hosts honoring the platform's reduced-motion setting should render
+/// [`TransitionSpec::reduced_motion`] instead of the spec itself.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransitionSpec {
+    /// Transition kind.
+    #[serde(rename = "type")]
+    pub kind: TransitionKind,
+    /// Duration in milliseconds.
+    pub duration: f64,
+    /// Easing curve (host default when absent).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub easing: Option<Easing>,
+}
+
+impl TransitionSpec {
+    /// Maximum duration (ms) of the fallback used under reduced motion.
+    pub const REDUCED_MOTION_MAX_DURATION: f64 = 150.0;
+
+    /// Create a transition of the given kind and duration.
+    pub fn new(kind: TransitionKind, duration: f64) -> Self {
+        Self {
+            kind,
+            duration,
+            easing: None,
+        }
+    }
+
+    /// Fade transition.
+    pub fn fade(duration: f64) -> Self {
+        Self::new(TransitionKind::Fade, duration)
+    }
+
+    /// Slide transition.
+    pub fn slide(duration: f64) -> Self {
+        Self::new(TransitionKind::Slide, duration)
+    }
+
+    /// Scale transition.
+    pub fn scale(duration: f64) -> Self {
+        Self::new(TransitionKind::Scale, duration)
+    }
+
+    /// Set the easing curve.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// The transition to use when the user prefers reduced motion.
+    ///
+    /// Motion-based kinds (slide, scale) become a fade, and the duration is
+    /// capped at [`Self::REDUCED_MOTION_MAX_DURATION`].
+    pub fn reduced_motion(&self) -> Self {
+        Self {
+            kind: TransitionKind::Fade,
+            duration: self.duration.min(Self::REDUCED_MOTION_MAX_DURATION),
+            easing: self.easing,
+        }
+    }
+}
+
+/// Spring physics parameters for an [`AnimationSpec`].
+///
+/// Serialized as `{ "stiffness": n, "damping": n, "mass": n }`; all values
+/// must be positive.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Spring {
+    pub stiffness: f64,
+    pub damping: f64,
+    pub mass: f64,
+}
+
+impl Spring {
+    /// Create a spring with unit mass.
+    pub fn new(stiffness: f64, damping: f64) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Set the mass.
+    pub fn mass(mut self, mass: f64) -> Self {
+        self.mass = mass;
+        self
+    }
+}
+
+/// How a change to an animatable prop is animated.
+///
+/// Serialized as `{ "duration": ms, "delay"?: ms, "easing"?, "spring"? }`.
+/// `easing` and `spring` are mutually exclusive. With a spring, `duration` is
+/// the settle-time hint used by hosts that cannot run spring physics (they
+/// fall back to `ease_out` over `duration`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnimationSpec {
+    /// Duration in milliseconds.
+    pub duration: f64,
+    /// Delay before the animation starts, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay: Option<f64>,
+    /// Easing curve (host default when absent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub easing: Option<Easing>,
+    /// Spring physics (replaces `easing`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spring: Option<Spring>,
+}
+
+impl AnimationSpec {
+    /// Create an animation of the given duration.
+    pub fn new(duration: f64) -> Self {
+        Self {
+            duration,
+            delay: None,
+            easing: None,
+            spring: None,
+        }
+    }
+
+    /// Set the start delay.
+    pub fn delay(mut self, delay: f64) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Set the easing curve.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Use spring physics instead of an easing curve.
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.spring = Some(spring);
+        self
+    }
 }
@@ -0,0 +1,203 @@
+//! Tree traversal.
+//!
+//! Passes over a tree (validation, theming, audits, analytics) visit nodes
+//! with [`SurfaceNode::walk`] and friends instead of hand-rolling recursion.
+//! Every visit gets the node's [`NodePath`], relative to the node the walk
+//! started from:
+//!
+//! ```
+//! use pepl_ui::{ColumnBuilder, PropValue, Surface, SurfaceNode, TextBuilder};
+//!
+//! let surface = Surface::new(
+//!     ColumnBuilder::new()
+//!         .child(TextBuilder::new("Title").build())
+//!         .child(SurfaceNode::new("Row").with_child(TextBuilder::new("Body").build()))
+//!         .build(),
+//! );
+//!
+//! let mut texts = Vec::new();
+//! surface.walk(|node, path| {
+//!     if node.component_type == "Text" {
+//!         texts.push(path.to_string());
+//!     }
+//! });
+//! assert_eq!(texts, ["root/0", "root/1/0"]);
+//! ```
+//!
+//! Pre-order walks visit a node before its children, post-order walks after.
+//! In [`SurfaceNode::walk_mut`] the callback runs before the walk descends,
+//! so children it adds or removes are visited accordingly.
+//!
+//! # Transforms
+//!
+//! [`Surface::map_nodes`] and [`Surface::map_props`] rebuild the tree from a
+//! pure function instead, for passes such as theming, localization, or
+//! stripping test IDs before a snapshot:
+//!
+//! ```
+//! use pepl_ui::{CommonProps, Surface, TextBuilder};
+//!
+//! let surface = Surface::new(TextBuilder::new("Hi").test_id("greeting").build());
+//! let stripped = surface.map_nodes(|mut node| {
+//!     node.test_id = None;
+//!     node
+//! });
+//! assert_eq!(stripped.root.test_id, None);
+//! ```
+
+use crate::layers::Layer;
+use crate::path::NodePath;
+use crate::prop_value::PropValue;
+use crate::surface::{Surface, SurfaceNode};
+
+impl Surface {
+    /// Visit every node, parents before children: the content tree, then
+    /// each layer bottom to top, with [layer paths](NodePath::layer_root).
+    /// See [`SurfaceNode::walk`].
+    pub fn walk(&self, mut visit: impl FnMut(&SurfaceNode, &NodePath)) {
+        for (name, root) in self.layer_roots() {
+            pre_order(root, &NodePath::layer_root(name), &mut visit);
+        }
+    }
+
+    /// Visit every node mutably, parents before children, in the same order
+    /// as [`Surface::walk`]. See [`SurfaceNode::walk_mut`].
+    pub fn walk_mut(&mut self, mut visit: impl FnMut(&mut SurfaceNode, &NodePath)) {
+        pre_order_mut(&mut self.root, &NodePath::root(), &mut visit);
+        for layer in &mut self.layers {
+            pre_order_mut(
+                &mut layer.root,
+                &NodePath::layer_root(&layer.name),
+                &mut visit,
+            );
+        }
+    }
+
+    /// Rebuild the tree and every layer with `f` applied to every node. See
+    /// [`SurfaceNode::map_nodes`].
+    pub fn map_nodes(self, f: impl Fn(SurfaceNode) -> SurfaceNode) -> Surface {
+        Surface {
+            root: self.root.map_nodes(&f),
+            layers: self
+                .layers
+                .into_iter()
+                .map(|layer| Layer::new(layer.name, layer.root.map_nodes(&f)))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Rebuild the tree and every layer with `f` applied to every prop. See
+    /// [`SurfaceNode::map_props`].
+    pub fn map_props(self, f: impl Fn(&str, &str, PropValue) -> Option<PropValue>) -> Surface {
+        Surface {
+            root: self.root.map_props(&f),
+            layers: self
+                .layers
+                .into_iter()
+                .map(|layer| Layer::new(layer.name, layer.root.map_props(&f)))
+                .collect(),
+            ..self
+        }
+    }
+}
+
+impl SurfaceNode {
+    /// Visit this node and its descendants in pre-order (parents before
+    /// children, children in order).
+    pub fn walk(&self, mut visit: impl FnMut(&SurfaceNode, &NodePath)) {
+        pre_order(self, &NodePath::root(), &mut visit);
+    }
+
+    /// Visit this node and its descendants in post-order (children before
+    /// parents), e.g. to aggregate subtree results bottom-up.
+    pub fn walk_post(&self, mut visit: impl FnMut(&SurfaceNode, &NodePath)) {
+        post_order(self, &NodePath::root(), &mut visit);
+    }
+
+    /// Visit this node and its descendants mutably in pre-order. Each node's
+    /// children are walked as the callback left them.
+    pub fn walk_mut(&mut self, mut visit: impl FnMut(&mut SurfaceNode, &NodePath)) {
+        pre_order_mut(self, &NodePath::root(), &mut visit);
+    }
+
+    /// Visit this node and its descendants mutably in post-order, so each
+    /// callback sees its children already visited.
+    pub fn walk_post_mut(&mut self, mut visit: impl FnMut(&mut SurfaceNode, &NodePath)) {
+        post_order_mut(self, &NodePath::root(), &mut visit);
+    }
+
+    /// Rebuild this subtree bottom-up: each node's children are mapped
+    /// first, then `f` receives the node with its mapped children.
+    pub fn map_nodes(self, f: impl Fn(SurfaceNode) -> SurfaceNode) -> SurfaceNode {
+        map_node(self, &f)
+    }
+
+    /// Rebuild this subtree with `f(component_type, key, value)` applied to
+    /// every prop; returning `None` removes the prop.
+    pub fn map_props(self, f: impl Fn(&str, &str, PropValue) -> Option<PropValue>) -> SurfaceNode {
+        self.map_nodes(|mut node| {
+            let props = std::mem::take(&mut node.props);
+            node.props = props
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    let value = f(&node.component_type, &key, value)?;
+                    Some((key, value))
+                })
+                .collect();
+            node
+        })
+    }
+}
+
+fn map_node<F: Fn(SurfaceNode) -> SurfaceNode>(mut node: SurfaceNode, f: &F) -> SurfaceNode {
+    node.children = std::mem::take(&mut node.children)
+        .into_iter()
+        .map(|child| map_node(child, f))
+        .collect();
+    f(node)
+}
+
+fn pre_order<F: FnMut(&SurfaceNode, &NodePath)>(
+    node: &SurfaceNode,
+    path: &NodePath,
+    visit: &mut F,
+) {
+    visit(node, path);
+    for (i, child) in node.children.iter().enumerate() {
+        pre_order(child, &path.child(i), visit);
+    }
+}
+
+fn post_order<F: FnMut(&SurfaceNode, &NodePath)>(
+    node: &SurfaceNode,
+    path: &NodePath,
+    visit: &mut F,
+) {
+    for (i, child) in node.children.iter().enumerate() {
+        post_order(child, &path.child(i), visit);
+    }
+    visit(node, path);
+}
+
+fn pre_order_mut<F: FnMut(&mut SurfaceNode, &NodePath)>(
+    node: &mut SurfaceNode,
+    path: &NodePath,
+    visit: &mut F,
+) {
+    visit(node, path);
+    for (i, child) in node.children.iter_mut().enumerate() {
+        pre_order_mut(child, &path.child(i), visit);
+    }
+}
+
+fn post_order_mut<F: FnMut(&mut SurfaceNode, &NodePath)>(
+    node: &mut SurfaceNode,
+    path: &NodePath,
+    visit: &mut F,
+) {
+    for (i, child) in node.children.iter_mut().enumerate() {
+        post_order_mut(child, &path.child(i), visit);
+    }
+    visit(node, path);
+}
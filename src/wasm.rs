@@ -0,0 +1,60 @@
+//! wasm-bindgen exports for the PEPL web playground (feature `wasm`).
+//!
+//! Everything crosses the boundary as JSON strings so the playground
+//! validates and previews surfaces with exactly the code the compiler uses.
+
+use std::collections::BTreeMap;
+
+use wasm_bindgen::prelude::*;
+
+use crate::accessibility;
+use crate::complexity::parse_untrusted;
+use crate::prop_value::PropValue;
+use crate::registry::ComponentRegistry;
+use crate::surface::SurfaceNode;
+
+/// Validate a surface JSON payload.
+///
+/// Returns a JSON array of error strings; `[]` means valid. A payload that
+/// does not parse yields a single `"invalid surface JSON: ..."` error, and
+/// one over the default [`Limits`](crate::complexity::Limits) a single limit
+/// error.
+#[wasm_bindgen]
+pub fn validate_surface_json(json: &str) -> String {
+    let errors = match parse_untrusted(json) {
+        Ok(surface) => surface.validate(),
+        Err(e) => vec![e],
+    };
+    serde_json::to_string(&errors).expect("string list serialization should never fail")
+}
+
+/// The component registry as JSON. See [`ComponentRegistry::to_json`].
+#[wasm_bindgen]
+pub fn registry_json() -> String {
+    ComponentRegistry::new().to_json()
+}
+
+/// Build a node the way the component builders do, returning its JSON.
+///
+/// `props_json` is a JSON object of props and `children_json` a JSON array of
+/// nodes. As with the Rust builders, a default `accessible` prop is filled
+/// in when absent. Use [`validate_surface_json`] to check the result.
+#[wasm_bindgen]
+pub fn build_node(
+    component_type: &str,
+    props_json: &str,
+    children_json: &str,
+) -> Result<String, String> {
+    if !ComponentRegistry::new().is_valid(component_type) {
+        return Err(format!("Unknown component: {component_type}"));
+    }
+    let props: BTreeMap<String, PropValue> =
+        serde_json::from_str(props_json).map_err(|e| format!("invalid props JSON: {e}"))?;
+    let children: Vec<SurfaceNode> =
+        serde_json::from_str(children_json).map_err(|e| format!("invalid children JSON: {e}"))?;
+
+    let mut node = SurfaceNode::new(component_type).with_children(children);
+    node.props = props;
+    accessibility::ensure_accessible(&mut node);
+    Ok(serde_json::to_string(&node).expect("SurfaceNode serialization should never fail"))
+}
@@ -5,8 +5,8 @@
 //! - 7.2 Semantic Roles (default roles, role overrides, validation)
 
 use pepl_ui::accessibility::{
-    auto_accessible, default_role, ensure_accessible, validate_accessible_prop, AccessibilityInfo,
-    LiveRegion, SemanticRole,
+    audit, auto_accessible, default_role, ensure_accessible, validate_accessible_prop,
+    AccessibilityInfo, LiveRegion, SemanticRole,
 };
 use pepl_ui::components::content::validate_content_node;
 use pepl_ui::components::feedback::validate_feedback_node;
@@ -14,11 +14,11 @@ use pepl_ui::components::interactive::validate_interactive_node;
 use pepl_ui::components::layout::validate_layout_node;
 use pepl_ui::components::list::validate_list_node;
 use pepl_ui::PropValue;
-use pepl_ui::SurfaceNode;
 use pepl_ui::{
-    ButtonBuilder, ColumnBuilder, ModalBuilder, ProgressBarBuilder, RowBuilder, ScrollBuilder,
-    ScrollListBuilder, TextBuilder, TextInputBuilder, ToastBuilder,
+    ButtonBuilder, ColumnBuilder, ImageBuilder, ModalBuilder, ProgressBarBuilder, RowBuilder,
+    ScrollBuilder, ScrollListBuilder, TextBuilder, TextInputBuilder, ToastBuilder,
 };
+use pepl_ui::{Surface, SurfaceNode};
 use std::collections::BTreeMap;
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -229,6 +229,21 @@ fn auto_accessible_text_value() {
     assert_eq!(info.role, Some(SemanticRole::Text));
 }
 
+#[test]
+fn auto_accessible_text_copyable_hint() {
+    let mut props = BTreeMap::new();
+    props.insert(
+        "value".to_string(),
+        PropValue::String("ABC-123".to_string()),
+    );
+    props.insert("copyable".to_string(), PropValue::Bool(true));
+    let info = auto_accessible("Text", &props);
+    assert_eq!(info.hint.as_deref(), Some("Action available: Copy"));
+
+    props.insert("copyable".to_string(), PropValue::Bool(false));
+    assert_eq!(auto_accessible("Text", &props).hint, None);
+}
+
 #[test]
 fn auto_accessible_text_long_value_truncated() {
     let mut props = BTreeMap::new();
@@ -256,6 +271,18 @@ fn auto_accessible_progress_bar_no_value() {
     assert_eq!(info.label, "Progress bar");
 }
 
+#[test]
+fn auto_accessible_image_alt() {
+    let node = ImageBuilder::asset("avatar").alt("Profile photo").build();
+    let info = match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => fields.clone(),
+        other => panic!("expected accessible record, got {other:?}"),
+    };
+    assert_eq!(info["label"], PropValue::String("Profile photo".into()));
+    assert_eq!(info["role"], PropValue::String("image".into()));
+    assert_eq!(auto_accessible("Image", &BTreeMap::new()).label, "Image");
+}
+
 #[test]
 fn auto_accessible_modal_title() {
     let mut props = BTreeMap::new();
@@ -422,6 +449,40 @@ fn validate_accessible_invalid_live_region() {
     assert!(errors[0].contains("expected 'polite' or 'assertive'"));
 }
 
+#[test]
+fn validate_accessible_modal_must_be_bool() {
+    let mut fields = BTreeMap::new();
+    fields.insert("label".to_string(), PropValue::String("Dialog".to_string()));
+    fields.insert("modal".to_string(), PropValue::String("yes".to_string()));
+    let prop = PropValue::Record(fields);
+    let errors = validate_accessible_prop("Modal", &prop);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("modal: expected bool"));
+}
+
+#[test]
+fn validate_accessible_hidden() {
+    let info = AccessibilityInfo::new("Loading").hidden(true);
+    let prop = info.to_prop_value();
+    match &prop {
+        PropValue::Record(fields) => assert_eq!(fields["hidden"], PropValue::Bool(true)),
+        other => panic!("expected record, got {other:?}"),
+    }
+    assert!(validate_accessible_prop("Skeleton", &prop).is_empty());
+
+    let mut fields = BTreeMap::new();
+    fields.insert(
+        "label".to_string(),
+        PropValue::String("Loading".to_string()),
+    );
+    fields.insert("hidden".to_string(), PropValue::Number(1.0));
+    let errors = validate_accessible_prop("Skeleton", &PropValue::Record(fields));
+    assert_eq!(
+        errors,
+        vec!["Skeleton.accessible.hidden: expected bool, got number"]
+    );
+}
+
 #[test]
 fn validate_accessible_unknown_field() {
     let mut fields = BTreeMap::new();
@@ -813,3 +874,45 @@ fn all_valid_roles_pass_validation() {
         );
     }
 }
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Surface audit
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn audit_clean_surface() {
+    let root = ColumnBuilder::new()
+        .child(TextBuilder::new("Title").build())
+        .child(ButtonBuilder::new("OK", PropValue::action("ok")).build())
+        .build();
+    assert!(audit(&Surface::new(root)).is_empty());
+}
+
+#[test]
+fn audit_reports_missing_and_invalid_accessible() {
+    let mut empty_label = ButtonBuilder::new("OK", PropValue::action("ok")).build();
+    empty_label.set_prop("accessible", AccessibilityInfo::new(" ").to_prop_value());
+    let mut bad_role = TextBuilder::new("hi").build();
+    bad_role.set_prop(
+        "accessible",
+        PropValue::Record(BTreeMap::from([(
+            "role".to_string(),
+            PropValue::String("banana".into()),
+        )])),
+    );
+    let root = ColumnBuilder::new()
+        .child(SurfaceNode::new("Text"))
+        .child(empty_label)
+        .child(bad_role)
+        .build();
+    let findings = audit(&Surface::new(root));
+    assert_eq!(findings[0], "root/0: Text has no accessible prop");
+    assert_eq!(
+        findings[1],
+        "root/1: Button.accessible.label: must not be empty"
+    );
+    assert!(findings[2..]
+        .iter()
+        .all(|f| f.starts_with("root/2: Text.accessible.")));
+    assert_eq!(findings.len(), 4);
+}
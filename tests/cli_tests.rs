@@ -0,0 +1,80 @@
+//! End-to-end tests for the `pepl-ui` binary. Run with `--features cli`.
+
+#![cfg(feature = "cli")]
+
+use std::path::PathBuf;
+use std::process::Command;
+
+const VALID: &str = r#"{"root":{"type":"Column","props":{},"children":[{"type":"Text","props":{"value":"Hi","accessible":{"label":"Hi"}},"children":[]}]}}"#;
+const INVALID: &str = r#"{"root":{"type":"Column","props":{},"children":[{"type":"Text","props":{"value":1},"children":[]}]}}"#;
+
+fn write(name: &str, json: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pepl-ui-cli-{}-{name}", std::process::id()));
+    std::fs::write(&path, json).unwrap();
+    path
+}
+
+fn run(args: &[&str]) -> (i32, String) {
+    let out = Command::new(env!("CARGO_BIN_EXE_pepl-ui"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        out.status.code().unwrap(),
+        String::from_utf8(out.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_validate() {
+    let ok = write("valid.json", VALID);
+    let bad = write("invalid.json", INVALID);
+    assert_eq!(run(&["validate", ok.to_str().unwrap()]), (0, String::new()));
+    let (code, out) = run(&["validate", bad.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert!(out.starts_with("root/0: Text.value"));
+}
+
+#[test]
+fn test_tree() {
+    let path = write("tree.json", VALID);
+    let (code, out) = run(&["tree", path.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    assert_eq!(out, "Column\n└── Text value=\"Hi\"\n");
+}
+
+#[test]
+fn test_diff() {
+    let a = write("a.json", VALID);
+    let b = write("b.json", &VALID.replace("\"Hi\"", "\"Ho\""));
+    let (code, out) = run(&["diff", a.to_str().unwrap(), a.to_str().unwrap()]);
+    assert_eq!((code, out.as_str()), (0, ""));
+    let (code, out) = run(&["diff", a.to_str().unwrap(), b.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert!(out.contains("root/0 props.value: \"Hi\" != \"Ho\""));
+}
+
+#[test]
+fn test_audit() {
+    let path = write("audit.json", INVALID);
+    let (code, out) = run(&["audit", path.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert!(out.contains("root: Column has no accessible prop"));
+}
+
+#[test]
+fn test_usage_and_parse_errors() {
+    assert_eq!(run(&[]).0, 2);
+    assert_eq!(run(&["frobnicate", "x.json"]).0, 2);
+    assert_eq!(run(&["validate", "/nonexistent/surface.json"]).0, 2);
+    let junk = write("junk.json", "{");
+    assert_eq!(run(&["validate", junk.to_str().unwrap()]).0, 2);
+    let oversized = write(
+        "oversized.json",
+        &format!(
+            r#"{{"root":{{"type":"Text","props":{{"value":"{}"}},"children":[]}}}}"#,
+            "x".repeat(70_000)
+        ),
+    );
+    assert_eq!(run(&["validate", oversized.to_str().unwrap()]).0, 2);
+}
@@ -0,0 +1,123 @@
+//! Tests for Swift/Kotlin data-model code generation.
+//!
+//! The generated sources cannot be compiled here, so these tests check that
+//! every key the Rust serializer emits is spelled out in both outputs.
+
+use pepl_ui::codegen::{kotlin_types, swift_types};
+use pepl_ui::layers::OVERLAYS;
+use pepl_ui::{Dimension, PropValue, Route, Surface, SurfaceNode};
+
+/// Every JSON key the wire format uses outside of user prop names.
+fn wire_keys() -> Vec<String> {
+    let node = SurfaceNode::new("Column")
+        .with_test_id("root")
+        .with_prop("x", PropValue::Nil)
+        .with_child(SurfaceNode::new("Text"));
+    let surface = Surface::new(node)
+        .with_shortcut("primary+s", PropValue::action("save"))
+        .with_route(Route::new("/notes/:id").param("id", "1"))
+        .with_respect_safe_area(true)
+        .with_layer(OVERLAYS, SurfaceNode::new("Modal"));
+    let json: serde_json::Value = serde_json::from_str(&surface.to_json()).unwrap();
+
+    let mut keys: Vec<String> = Vec::new();
+    for object in [&json, &json["root"], &json["route"], &json["layers"][0]] {
+        keys.extend(object.as_object().unwrap().keys().cloned());
+    }
+    for value in [
+        PropValue::color(0.0, 0.0, 0.0, 1.0),
+        PropValue::action_with_args("a", vec![]),
+        PropValue::lambda(1),
+        PropValue::color_token("primary"),
+        PropValue::theme_token("spacing.md"),
+        PropValue::i18n("greeting"),
+        PropValue::timestamp("2026-10-16".parse().unwrap()),
+        PropValue::bytes(vec![1, 2, 3]),
+        PropValue::dimension(Dimension::Fill),
+    ] {
+        let json = serde_json::to_value(&value).unwrap();
+        keys.extend(json.as_object().unwrap().keys().cloned());
+    }
+    keys
+}
+
+#[test]
+fn test_swift_covers_wire_keys() {
+    let swift = swift_types();
+    for key in wire_keys() {
+        let spelled = swift.contains(&format!("\"{key}\""))
+            || swift.contains(&format!("case {key}"))
+            || swift.contains(&format!(" {key},"))
+            || swift.contains(&format!(", {key}\n"));
+        assert!(spelled, "Swift output is missing wire key {key:?}");
+    }
+}
+
+#[test]
+fn test_kotlin_covers_wire_keys() {
+    let kotlin = kotlin_types("lang.pepl.ui");
+    for key in wire_keys() {
+        let spelled =
+            kotlin.contains(&format!("\"{key}\"")) || kotlin.contains(&format!("val {key}:"));
+        assert!(spelled, "Kotlin output is missing wire key {key:?}");
+    }
+}
+
+#[test]
+fn test_headers_and_package() {
+    let version = env!("CARGO_PKG_VERSION");
+    assert!(swift_types().starts_with(&format!("// Generated by pepl-ui {version}")));
+    let kotlin = kotlin_types("lang.pepl.ui");
+    assert!(kotlin.starts_with(&format!("// Generated by pepl-ui {version}")));
+    assert!(kotlin.contains("\npackage lang.pepl.ui\n"));
+}
+
+#[test]
+fn test_every_prop_value_variant_is_generated() {
+    let swift = swift_types();
+    for case in [
+        "case string(",
+        "case number(",
+        "case bool(",
+        "case null",
+        "case color(",
+        "case action(",
+        "case lambda(",
+        "case colorToken(",
+        "case themeToken(",
+        "case i18n(",
+        "case timestamp(",
+        "case bytes(",
+        "case dimension(",
+        "case list(",
+        "case record(",
+    ] {
+        assert!(swift.contains(case), "Swift PropValue missing {case}");
+    }
+    let kotlin = kotlin_types("p");
+    for class in [
+        "class Str(",
+        "class Number(",
+        "class Bool(",
+        "object Nil",
+        "class Color(",
+        "class ActionRef(",
+        "class Lambda(",
+        "class ColorToken(",
+        "class ThemeToken(",
+        "class I18n(",
+        "class Timestamp(",
+        "class Bytes(",
+        "class DimensionValue(",
+        "class ListValue(",
+        "class Record(",
+    ] {
+        assert!(kotlin.contains(class), "Kotlin PropValue missing {class}");
+    }
+}
+
+#[test]
+fn test_generation_is_deterministic() {
+    assert_eq!(swift_types(), swift_types());
+    assert_eq!(kotlin_types("p"), kotlin_types("p"));
+}
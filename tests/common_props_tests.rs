@@ -0,0 +1,781 @@
+//! Tests for props accepted on every component (test IDs, lifecycle, animations, drag and drop,
+//! sizing, ...).
+
+use pepl_ui::{
+    animate_prop, animation_to_prop, is_animatable, AnimationSpec, CommonProps, Dimension, Easing,
+    Spring,
+};
+use pepl_ui::{
+    is_common_prop, validate_content_node, validate_feedback_node, validate_form_node,
+    validate_graphics_node, validate_interactive_node, validate_layout_node, validate_list_node,
+    validate_media_node, AppBarBuilder, AudioPlayerBuilder, BottomSheetBuilder, ButtonBuilder,
+    CanvasBuilder, CarouselBuilder, ChartBuilder, ChartKind, ChartSeries, ColumnBuilder,
+    DisclosureBuilder, DividerBuilder, DrawerBuilder, FilePickerBuilder, FormBuilder, IconBuilder,
+    ImageBuilder, ModalBuilder, OverlayBuilder, ProgressBarBuilder, PropValue, RatingBuilder,
+    RichTextBuilder, RowBuilder, ScrollBuilder, ScrollListBuilder, SkeletonBuilder, SkeletonShape,
+    SnackbarBuilder, SpacerBuilder, SpinnerBuilder, StepperBuilder, Surface, SurfaceNode,
+    TableBuilder, TableColumn, TabsBuilder, TextBuilder, TextInputBuilder, ToastBuilder,
+    TooltipBuilder, VideoBuilder,
+};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+/// One built node per component type.
+fn one_of_each() -> Vec<SurfaceNode> {
+    vec![
+        ColumnBuilder::new().build(),
+        RowBuilder::new().build(),
+        ScrollBuilder::new().build(),
+        TabsBuilder::new(PropValue::action("select"))
+            .tab("One", TextBuilder::new("1").build())
+            .build(),
+        CarouselBuilder::new(0, PropValue::lambda(6)).build(),
+        DisclosureBuilder::new("More", false, PropValue::action("toggle")).build(),
+        OverlayBuilder::new().build(),
+        AppBarBuilder::new("Title").build(),
+        DividerBuilder::new().build(),
+        SpacerBuilder::new().build(),
+        TextBuilder::new("Hello").build(),
+        RichTextBuilder::markdown("**Hi**").build(),
+        ProgressBarBuilder::new(0.5).build(),
+        ImageBuilder::asset("logo").build(),
+        SkeletonBuilder::new(SkeletonShape::Rect).build(),
+        SpinnerBuilder::new().build(),
+        IconBuilder::new("search").build(),
+        ButtonBuilder::new("OK", PropValue::action("ok")).build(),
+        TextInputBuilder::new("", PropValue::lambda(1)).build(),
+        StepperBuilder::new(1.0, PropValue::lambda(5)).build(),
+        RatingBuilder::new(3.0, 5).build(),
+        FilePickerBuilder::new("Attach", PropValue::lambda(7)).build(),
+        ScrollListBuilder::new(
+            PropValue::List(vec![]),
+            PropValue::lambda(2),
+            PropValue::lambda(3),
+        )
+        .build(),
+        TableBuilder::new(
+            vec![TableColumn::new("name", "Name")],
+            PropValue::List(vec![]),
+            PropValue::lambda(4),
+        )
+        .build(),
+        ModalBuilder::new(true, PropValue::action("close")).build(),
+        BottomSheetBuilder::new(true, PropValue::action("close")).build(),
+        DrawerBuilder::new(true, PropValue::action("close")).build(),
+        ToastBuilder::new("Saved").build(),
+        SnackbarBuilder::new("Archived").build(),
+        TooltipBuilder::new("Help", TextBuilder::new("?").build()).build(),
+        FormBuilder::new(PropValue::action("submit")).build(),
+        VideoBuilder::new("asset:intro", "Intro").build(),
+        AudioPlayerBuilder::new("asset:song", false, PropValue::action("toggle")).build(),
+        CanvasBuilder::new(10.0, 10.0).build(),
+        ChartBuilder::new(ChartKind::Line, vec![ChartSeries::new("a", vec![1.0])]).build(),
+    ]
+}
+
+/// Dispatch to the category validator for `node`.
+fn validate(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Column" | "Row" | "Scroll" | "Tabs" | "Carousel" | "Disclosure" | "Overlay" | "AppBar"
+        | "Divider" | "Spacer" => validate_layout_node(node),
+        "Text" | "RichText" | "ProgressBar" | "Spinner" | "Icon" | "Image" | "Skeleton" => {
+            validate_content_node(node)
+        }
+        "Button" | "TextInput" | "Stepper" | "Rating" | "FilePicker" => {
+            validate_interactive_node(node)
+        }
+        "ScrollList" | "Table" => validate_list_node(node),
+        "Modal" | "BottomSheet" | "Drawer" | "Toast" | "Snackbar" | "Tooltip" => {
+            validate_feedback_node(node)
+        }
+        "Form" => validate_form_node(node),
+        "Video" | "AudioPlayer" => validate_media_node(node),
+        "Canvas" | "Chart" => validate_graphics_node(node),
+        other => panic!("no validator for {other}"),
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Lifecycle callbacks
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_lifecycle_props_are_common() {
+    assert!(is_common_prop("on_appear"));
+    assert!(is_common_prop("on_disappear"));
+}
+
+#[test]
+fn test_lifecycle_accepted_on_every_component() {
+    for node in one_of_each() {
+        let node = node
+            .with_prop("on_appear", PropValue::action("load_data"))
+            .with_prop("on_disappear", PropValue::action("cancel_load"));
+        let errors = validate(&node);
+        assert!(errors.is_empty(), "{}: {:?}", node.component_type, errors);
+    }
+}
+
+#[test]
+fn test_lifecycle_with_args() {
+    let row = RowBuilder::new().build().with_prop(
+        "on_appear",
+        PropValue::action_with_args("load_row", vec![PropValue::Number(7.0)]),
+    );
+    assert!(validate(&row).is_empty());
+}
+
+#[test]
+fn test_lifecycle_rejects_non_action() {
+    for node in one_of_each() {
+        let node = node
+            .with_prop("on_appear", PropValue::lambda(9))
+            .with_prop("on_disappear", PropValue::String("bye".into()));
+        let errors = validate(&node);
+        let name = node.component_type.clone();
+        assert_eq!(errors.len(), 2, "{name}: {errors:?}");
+        assert!(errors.contains(&format!("{name}.on_appear: expected action, got lambda")));
+        assert!(errors.contains(&format!("{name}.on_disappear: expected action, got string")));
+    }
+}
+
+#[test]
+fn test_lifecycle_json_roundtrip() {
+    let node = TextBuilder::new("Row")
+        .build()
+        .with_prop("on_appear", PropValue::action("seen"));
+    let surface = Surface::new(node);
+    let json = surface.to_json();
+    assert!(json.contains(r#""on_appear":{"__action":"seen"}"#));
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, surface);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Animations
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_animation_spec_to_prop() {
+    let spec = AnimationSpec::new(300.0)
+        .delay(50.0)
+        .easing(Easing::EaseOut);
+    let prop = animation_to_prop(&spec);
+    let json = serde_json::to_string(&prop).unwrap();
+    assert_eq!(
+        json,
+        r#"{"delay":50.0,"duration":300.0,"easing":"ease_out"}"#
+    );
+}
+
+#[test]
+fn test_animation_spec_spring_to_prop() {
+    let spec = AnimationSpec::new(400.0).spring(Spring::new(170.0, 26.0));
+    let json = serde_json::to_string(&animation_to_prop(&spec)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"duration":400.0,"spring":{"damping":26.0,"mass":1.0,"stiffness":170.0}}"#
+    );
+}
+
+#[test]
+fn test_animation_spec_serde_matches_prop_shape() {
+    let spec = AnimationSpec::new(250.0)
+        .delay(10.0)
+        .spring(Spring::new(120.0, 14.0).mass(2.0));
+    let via_serde = serde_json::to_value(&spec).unwrap();
+    let via_prop = serde_json::to_value(animation_to_prop(&spec)).unwrap();
+    assert_eq!(via_serde, via_prop);
+    let back: AnimationSpec = serde_json::from_value(via_serde).unwrap();
+    assert_eq!(back, spec);
+}
+
+#[test]
+fn test_animatable_props() {
+    assert!(is_animatable("Column", "opacity"));
+    assert!(is_animatable("Text", "height"));
+    assert!(is_animatable("ProgressBar", "value"));
+    assert!(!is_animatable("Text", "value"));
+    assert!(!is_animatable("Button", "label"));
+}
+
+#[test]
+fn test_animate_progress_value_valid() {
+    let node = ProgressBarBuilder::new(0.4).build().with_prop(
+        "animate",
+        animate_prop([
+            ("value", AnimationSpec::new(300.0).easing(Easing::EaseInOut)),
+            ("opacity", AnimationSpec::new(150.0)),
+        ]),
+    );
+    let errors = validate(&node);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn test_animate_accepted_on_every_component() {
+    for node in one_of_each() {
+        let node = node.with_prop(
+            "animate",
+            animate_prop([("height", AnimationSpec::new(200.0))]),
+        );
+        let errors = validate(&node);
+        assert!(errors.is_empty(), "{}: {:?}", node.component_type, errors);
+    }
+}
+
+#[test]
+fn test_animate_not_animatable() {
+    let node = TextBuilder::new("Hi").build().with_prop(
+        "animate",
+        animate_prop([("value", AnimationSpec::new(100.0))]),
+    );
+    assert_eq!(
+        validate(&node),
+        vec!["Text.animate.value: prop is not animatable".to_string()]
+    );
+}
+
+#[test]
+fn test_animate_not_record() {
+    let node = RowBuilder::new()
+        .build()
+        .with_prop("animate", PropValue::Bool(true));
+    assert_eq!(
+        validate(&node),
+        vec!["Row.animate: expected record, got bool".to_string()]
+    );
+}
+
+#[test]
+fn test_animate_invalid_spec_fields() {
+    let spec: PropValue =
+        serde_json::from_str(r#"{"duration":-5,"delay":"soon","easing":"bouncy","speed":2}"#)
+            .unwrap();
+    let mut entries = std::collections::BTreeMap::new();
+    entries.insert("opacity".to_string(), spec);
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("animate", PropValue::Record(entries));
+    let errors = validate(&node);
+    assert_eq!(errors.len(), 4, "{errors:?}");
+    assert!(errors
+        .contains(&"Column.animate.opacity.duration: must be non-negative, got -5".to_string()));
+    assert!(
+        errors.contains(&"Column.animate.opacity.delay: expected number, got string".to_string())
+    );
+    assert!(errors[2].starts_with("Column.animate.opacity.easing: expected one of"));
+    assert!(errors.contains(&"Column.animate.opacity: unknown field 'speed'".to_string()));
+}
+
+#[test]
+fn test_animate_missing_duration() {
+    let spec: PropValue = serde_json::from_str(r#"{"delay":10}"#).unwrap();
+    let mut entries = std::collections::BTreeMap::new();
+    entries.insert("height".to_string(), spec);
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("animate", PropValue::Record(entries));
+    assert_eq!(
+        validate(&node),
+        vec!["Column.animate.height.duration: required field missing".to_string()]
+    );
+}
+
+#[test]
+fn test_animate_easing_and_spring_exclusive() {
+    let spec = AnimationSpec::new(300.0)
+        .easing(Easing::Linear)
+        .spring(Spring::new(100.0, 10.0));
+    let node = ProgressBarBuilder::new(0.5)
+        .build()
+        .with_prop("animate", animate_prop([("value", spec)]));
+    assert_eq!(
+        validate(&node),
+        vec!["ProgressBar.animate.value: easing and spring are mutually exclusive".to_string()]
+    );
+}
+
+#[test]
+fn test_animate_invalid_spring() {
+    let spec = AnimationSpec::new(300.0).spring(Spring::new(0.0, 10.0).mass(-1.0));
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("animate", animate_prop([("width", spec)]));
+    let errors = validate(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Column.animate.width.spring.stiffness: must be positive, got 0".to_string(),
+            "Column.animate.width.spring.mass: must be positive, got -1".to_string(),
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Drag and drop
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn payload(card_id: f64) -> PropValue {
+    let mut fields = std::collections::BTreeMap::new();
+    fields.insert("card_id".to_string(), PropValue::Number(card_id));
+    PropValue::Record(fields)
+}
+
+#[test]
+fn test_kanban_drag_and_drop_valid() {
+    let card = ColumnBuilder::new()
+        .child(TextBuilder::new("Write docs").build())
+        .build()
+        .with_prop("draggable", PropValue::Bool(true))
+        .with_prop("drag_payload", payload(42.0));
+    let lane = ColumnBuilder::new()
+        .child(card.clone())
+        .build()
+        .with_prop("drop_target", PropValue::Bool(true))
+        .with_prop(
+            "on_drop",
+            PropValue::action_with_args("move_card", vec![PropValue::String("done".into())]),
+        );
+    assert!(validate(&card).is_empty(), "{:?}", validate(&card));
+    assert!(validate(&lane).is_empty(), "{:?}", validate(&lane));
+}
+
+#[test]
+fn test_drag_props_accepted_on_every_component() {
+    for node in one_of_each() {
+        let node = node
+            .with_prop("draggable", PropValue::Bool(true))
+            .with_prop("drag_payload", payload(1.0))
+            .with_prop("drop_target", PropValue::Bool(true))
+            .with_prop("on_drop", PropValue::action("dropped"));
+        let errors = validate(&node);
+        assert!(errors.is_empty(), "{}: {:?}", node.component_type, errors);
+    }
+}
+
+#[test]
+fn test_drag_props_wrong_types() {
+    let node = RowBuilder::new()
+        .build()
+        .with_prop("draggable", PropValue::String("yes".into()))
+        .with_prop("drop_target", PropValue::Number(1.0));
+    let errors = validate(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Row.draggable: expected bool, got string".to_string(),
+            "Row.drop_target: expected bool, got number".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_drag_payload_requires_draggable() {
+    let node = TextBuilder::new("Card")
+        .build()
+        .with_prop("drag_payload", PropValue::String("card".into()));
+    let errors = validate(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Text.drag_payload: expected record, got string".to_string(),
+            "Text.drag_payload: requires draggable: true".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_drop_target_and_on_drop_paired() {
+    let missing_handler = ColumnBuilder::new()
+        .build()
+        .with_prop("drop_target", PropValue::Bool(true));
+    assert_eq!(
+        validate(&missing_handler),
+        vec!["Column.drop_target: requires an on_drop action".to_string()]
+    );
+
+    let missing_target = ColumnBuilder::new()
+        .build()
+        .with_prop("on_drop", PropValue::lambda(1));
+    assert_eq!(
+        validate(&missing_target),
+        vec![
+            "Column.on_drop: expected action, got lambda".to_string(),
+            "Column.on_drop: requires drop_target: true".to_string(),
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Test IDs
+// ══════════════════════════════════════════════════════════════════════════════
+
+/// One built node per component type, each tagged through its builder.
+fn one_of_each_with_test_id() -> Vec<SurfaceNode> {
+    vec![
+        ColumnBuilder::new().test_id("column").build(),
+        RowBuilder::new().test_id("row").build(),
+        ScrollBuilder::new().test_id("scroll").build(),
+        TabsBuilder::new(PropValue::action("select"))
+            .tab("One", TextBuilder::new("1").build())
+            .test_id("tabs")
+            .build(),
+        CarouselBuilder::new(0, PropValue::lambda(6))
+            .test_id("carousel")
+            .build(),
+        DisclosureBuilder::new("More", false, PropValue::action("toggle"))
+            .test_id("disclosure")
+            .build(),
+        OverlayBuilder::new().test_id("overlay").build(),
+        AppBarBuilder::new("Title").test_id("app_bar").build(),
+        DividerBuilder::new().test_id("divider").build(),
+        SpacerBuilder::new().test_id("spacer").build(),
+        TextBuilder::new("Hello").test_id("text").build(),
+        RichTextBuilder::markdown("**Hi**")
+            .test_id("rich_text")
+            .build(),
+        ProgressBarBuilder::new(0.5).test_id("progress").build(),
+        ImageBuilder::asset("logo").test_id("image").build(),
+        SkeletonBuilder::new(SkeletonShape::Rect)
+            .test_id("skeleton")
+            .build(),
+        SpinnerBuilder::new().test_id("spinner").build(),
+        IconBuilder::new("search").test_id("icon").build(),
+        ButtonBuilder::new("OK", PropValue::action("ok"))
+            .test_id("button")
+            .build(),
+        TextInputBuilder::new("", PropValue::lambda(1))
+            .test_id("input")
+            .build(),
+        StepperBuilder::new(1.0, PropValue::lambda(5))
+            .test_id("stepper")
+            .build(),
+        RatingBuilder::new(3.0, 5).test_id("rating").build(),
+        FilePickerBuilder::new("Attach", PropValue::lambda(7))
+            .test_id("file_picker")
+            .build(),
+        ScrollListBuilder::new(
+            PropValue::List(vec![]),
+            PropValue::lambda(2),
+            PropValue::lambda(3),
+        )
+        .test_id("list")
+        .build(),
+        TableBuilder::new(
+            vec![TableColumn::new("name", "Name")],
+            PropValue::List(vec![]),
+            PropValue::lambda(4),
+        )
+        .test_id("table")
+        .build(),
+        ModalBuilder::new(true, PropValue::action("close"))
+            .test_id("modal")
+            .build(),
+        BottomSheetBuilder::new(true, PropValue::action("close"))
+            .test_id("sheet")
+            .build(),
+        DrawerBuilder::new(true, PropValue::action("close"))
+            .test_id("drawer")
+            .build(),
+        ToastBuilder::new("Saved").test_id("toast").build(),
+        SnackbarBuilder::new("Archived").test_id("snackbar").build(),
+        TooltipBuilder::new("Help", TextBuilder::new("?").build())
+            .test_id("tooltip")
+            .build(),
+        FormBuilder::new(PropValue::action("submit"))
+            .test_id("form")
+            .build(),
+        VideoBuilder::new("asset:intro", "Intro")
+            .test_id("video")
+            .build(),
+        AudioPlayerBuilder::new("asset:song", false, PropValue::action("toggle"))
+            .test_id("audio")
+            .build(),
+        CanvasBuilder::new(10.0, 10.0).test_id("canvas").build(),
+        ChartBuilder::new(ChartKind::Line, vec![ChartSeries::new("a", vec![1.0])])
+            .test_id("chart")
+            .build(),
+    ]
+}
+
+#[test]
+fn test_test_id_exposed_via_every_builder() {
+    let nodes = one_of_each_with_test_id();
+    assert_eq!(nodes.len(), one_of_each().len());
+    for node in nodes {
+        assert!(node.test_id.is_some(), "{}", node.component_type);
+        assert!(!node.props.contains_key("test_id"));
+        let errors = validate(&node);
+        assert!(errors.is_empty(), "{}: {:?}", node.component_type, errors);
+    }
+}
+
+#[test]
+fn test_test_id_serialized_at_node_level() {
+    let node = ButtonBuilder::new("Pay", PropValue::action("pay"))
+        .test_id("checkout.pay")
+        .build();
+    let json = Surface::new(node).to_json();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["root"]["test_id"], "checkout.pay");
+    assert!(parsed["root"]["props"].get("test_id").is_none());
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.root.test_id.as_deref(), Some("checkout.pay"));
+}
+
+#[test]
+fn test_test_id_omitted_when_unset() {
+    let json = Surface::new(TextBuilder::new("Hi").build()).to_json();
+    assert!(!json.contains("test_id"));
+}
+
+#[test]
+fn test_test_id_invalid() {
+    let empty = TextBuilder::new("Hi").test_id("").build();
+    assert_eq!(
+        validate(&empty),
+        vec!["Text.test_id: must not be empty".to_string()]
+    );
+
+    let spaced = RowBuilder::new().test_id("my row").build();
+    assert_eq!(
+        validate(&spaced),
+        vec![r#"Row.test_id: invalid character ' ' in "my row""#.to_string()]
+    );
+}
+
+#[test]
+fn test_test_id_in_props_is_misplaced() {
+    let node = TextBuilder::new("Hi")
+        .build()
+        .with_prop("test_id", PropValue::String("greeting".into()));
+    assert_eq!(
+        validate(&node),
+        vec!["Text.test_id: must be set on the node, not in props".to_string()]
+    );
+}
+
+#[test]
+fn test_surface_node_with_test_id() {
+    let node = SurfaceNode::new("Column").with_test_id("root");
+    assert_eq!(node.test_id.as_deref(), Some("root"));
+    assert!(validate(&node).is_empty());
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Keys
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_key_from_every_builder() {
+    let node = TextBuilder::new("Alice").key("user-42").build();
+    assert_eq!(node.key.as_deref(), Some("user-42"));
+    assert!(validate(&node).is_empty());
+
+    let list = ScrollListBuilder::new(
+        PropValue::List(vec![]),
+        PropValue::lambda(0),
+        PropValue::lambda(1),
+    )
+    .key("feed")
+    .build();
+    assert_eq!(list.key.as_deref(), Some("feed"));
+    assert!(validate(&list).is_empty(), "{:?}", validate(&list));
+}
+
+#[test]
+fn test_key_serialization() {
+    let json = Surface::new(SurfaceNode::new("Column").with_key("main")).to_json();
+    assert!(json.contains(r#""key":"main""#), "{json}");
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.root.key.as_deref(), Some("main"));
+    assert!(!Surface::new(TextBuilder::new("Hi").build())
+        .to_json()
+        .contains("\"key\""));
+}
+
+#[test]
+fn test_key_invalid() {
+    let empty = TextBuilder::new("Hi").key(" ").build();
+    assert_eq!(validate(&empty), vec!["Text.key: must not be empty"]);
+
+    let misplaced = TextBuilder::new("Hi")
+        .build()
+        .with_prop("key", PropValue::String("greeting".into()));
+    assert_eq!(
+        validate(&misplaced),
+        vec!["Text.key: must be set on the node, not in props"]
+    );
+}
+
+#[test]
+fn test_duplicate_sibling_keys() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("a").key("row").build())
+            .child(TextBuilder::new("b").key("other").build())
+            .child(TextBuilder::new("c").key("row").build())
+            .child(
+                ColumnBuilder::new()
+                    .child(TextBuilder::new("d").key("row").build())
+                    .build(),
+            )
+            .build(),
+    );
+    assert_eq!(
+        surface.validate(),
+        vec![r#"root/2: Text.key: duplicate key "row" (also used by root/0)"#]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Sizing
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_size_props_are_common() {
+    assert!(is_common_prop("width"));
+    assert!(is_common_prop("height"));
+}
+
+#[test]
+fn test_size_props_set_via_every_builder() {
+    let node = TextBuilder::new("Total")
+        .width(Dimension::Fill)
+        .height(Dimension::Px(40.0))
+        .build();
+    assert_eq!(
+        node.props.get("width"),
+        Some(&PropValue::dimension(Dimension::Fill))
+    );
+    assert_eq!(
+        node.props.get("height"),
+        Some(&PropValue::dimension(Dimension::Px(40.0)))
+    );
+
+    let json = serde_json::to_value(&node).unwrap();
+    assert_eq!(
+        json["props"]["width"],
+        serde_json::json!({ "__dim": "fill" })
+    );
+    assert_eq!(
+        json["props"]["height"],
+        serde_json::json!({ "__dim": 40.0 })
+    );
+}
+
+#[test]
+fn test_size_props_accepted_on_every_component() {
+    for node in one_of_each() {
+        // Canvas width/height are its numeric coordinate space
+        if node.component_type == "Canvas" {
+            continue;
+        }
+        let node = node
+            .with_prop("width", PropValue::dimension(Dimension::Percent(50.0)))
+            .with_prop("height", PropValue::Number(120.0));
+        let errors = validate(&node);
+        assert!(errors.is_empty(), "{}: {:?}", node.component_type, errors);
+    }
+}
+
+#[test]
+fn test_size_props_invalid() {
+    let node = ButtonBuilder::new("Go", PropValue::action("go"))
+        .width(Dimension::Percent(150.0))
+        .build()
+        .with_prop("height", PropValue::String("tall".into()));
+    assert_eq!(
+        validate(&node),
+        vec![
+            "Button.width: percent must be within 0-100, got 150".to_string(),
+            "Button.height: expected number or dimension, got string".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_canvas_keeps_numeric_size() {
+    let node = CanvasBuilder::new(200.0, 100.0)
+        .label("Signature")
+        .width(Dimension::Fill)
+        .build();
+    assert_eq!(
+        validate(&node),
+        vec!["Canvas.width: expected number, got dimension".to_string()]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Visibility
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_visibility_props_are_common() {
+    assert!(is_common_prop("opacity"));
+    assert!(is_common_prop("visible"));
+}
+
+#[test]
+fn test_visibility_set_via_builder() {
+    let node = TextBuilder::new("Saved")
+        .opacity(0.5)
+        .visible(false)
+        .build();
+    assert_eq!(node.props.get("opacity"), Some(&PropValue::Number(0.5)));
+    assert_eq!(node.props.get("visible"), Some(&PropValue::Bool(false)));
+}
+
+#[test]
+fn test_opacity_clamped_by_builder() {
+    let faded = SpinnerBuilder::new().opacity(-0.5).build();
+    let opaque = SpinnerBuilder::new().opacity(3.0).build();
+    assert_eq!(faded.props.get("opacity"), Some(&PropValue::Number(0.0)));
+    assert_eq!(opaque.props.get("opacity"), Some(&PropValue::Number(1.0)));
+}
+
+#[test]
+fn test_visibility_accepted_on_every_component() {
+    for node in one_of_each() {
+        let node = node
+            .with_prop("opacity", PropValue::Number(0.25))
+            .with_prop("visible", PropValue::Bool(true));
+        let errors = validate(&node);
+        assert!(errors.is_empty(), "{}: {:?}", node.component_type, errors);
+    }
+}
+
+#[test]
+fn test_visibility_props_invalid() {
+    let node = RowBuilder::new()
+        .build()
+        .with_prop("opacity", PropValue::Number(1.5))
+        .with_prop("visible", PropValue::String("no".into()));
+    assert_eq!(
+        validate(&node),
+        vec![
+            "Row.opacity: must be within 0-1, got 1.5".to_string(),
+            "Row.visible: expected bool, got string".to_string(),
+        ]
+    );
+
+    let node = TextBuilder::new("x")
+        .build()
+        .with_prop("opacity", PropValue::String("50%".into()));
+    assert_eq!(
+        validate(&node),
+        vec!["Text.opacity: expected number, got string".to_string()]
+    );
+}
+
+#[test]
+fn test_modal_visible_reported_once() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .build()
+        .with_prop("visible", PropValue::Number(1.0));
+    assert_eq!(
+        validate(&node),
+        vec!["Modal.visible: expected bool, got number".to_string()]
+    );
+}
@@ -0,0 +1,324 @@
+//! Tests for `ComplexityReport`, `Limits`, `Surface::check_limits`, and
+//! `SurfaceStats`.
+
+use pepl_ui::complexity::{LimitError, Limits, SurfaceLimits};
+use pepl_ui::layers::Layer;
+use pepl_ui::{
+    ColumnBuilder, ComplexityReport, PropValue, Route, Surface, SurfaceNode, TextBuilder,
+};
+
+fn nested(depth: usize) -> SurfaceNode {
+    (0..depth).fold(SurfaceNode::new("Text"), |child, _| {
+        SurfaceNode::new("Column").with_child(child)
+    })
+}
+
+#[test]
+fn test_single_node() {
+    let surface = Surface::new(SurfaceNode::new("Column"));
+    let report = surface.complexity();
+    assert_eq!(report.node_count, 1);
+    assert_eq!(report.max_depth, 0);
+    assert_eq!(report.prop_count, 0);
+    assert_eq!(report.prop_volume, 0);
+    assert_eq!(report.estimated_bytes, surface.to_json().len());
+}
+
+#[test]
+fn test_counts_nodes_and_depth() {
+    let report = Surface::new(nested(3)).complexity();
+    assert_eq!(report.node_count, 4);
+    assert_eq!(report.max_depth, 3);
+}
+
+#[test]
+fn test_prop_volume_counts_nested_values() {
+    let node = SurfaceNode::new("ScrollList")
+        .with_prop(
+            "items",
+            PropValue::List(vec![PropValue::Number(1.0), PropValue::Number(2.0)]),
+        )
+        .with_prop("render", PropValue::lambda(1))
+        .with_prop(
+            "on_reorder",
+            PropValue::action_with_args("move", vec![PropValue::Nil]),
+        );
+    let report = Surface::new(node).complexity();
+    assert_eq!(report.prop_count, 3);
+    // items (1 + 2) + render (1) + on_reorder (1 + 1)
+    assert_eq!(report.prop_volume, 6);
+}
+
+#[test]
+fn test_estimated_bytes_matches_json() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .spacing(8.0)
+            .child(TextBuilder::new("Hello, wörld").build())
+            .build(),
+    )
+    .with_shortcut("primary+s", PropValue::action("save"));
+    assert_eq!(
+        surface.complexity().estimated_bytes,
+        surface.to_json().len()
+    );
+}
+
+#[test]
+fn test_measure_equals_complexity() {
+    let surface = Surface::new(nested(2));
+    assert_eq!(ComplexityReport::measure(&surface), surface.complexity());
+}
+
+#[test]
+fn test_within_default_limits() {
+    let report = Surface::new(nested(10)).complexity();
+    assert!(report.check_against(&Limits::default()).is_empty());
+}
+
+#[test]
+fn test_check_against_reports_each_exceeded_limit() {
+    let report = Surface::new(nested(5)).complexity();
+    let limits = Limits {
+        max_nodes: 3,
+        max_depth: 2,
+        max_prop_volume: 100,
+        max_bytes: 10,
+        ..Limits::default()
+    };
+    let errors = report.check_against(&limits);
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0], "Surface: node count 6 exceeds limit 3");
+    assert_eq!(errors[1], "Surface: depth 5 exceeds limit 2");
+    assert!(errors[2].starts_with("Surface: serialized size"));
+}
+
+#[test]
+fn test_limits_are_inclusive() {
+    let report = Surface::new(nested(2)).complexity();
+    let limits = Limits {
+        max_nodes: 3,
+        max_depth: 2,
+        ..Limits::default()
+    };
+    assert!(report.check_against(&limits).is_empty());
+}
+
+#[test]
+fn test_stats_counts_components() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("a").build())
+            .child(nested(2))
+            .build(),
+    );
+    let stats = surface.stats();
+    assert_eq!(stats.complexity, surface.complexity());
+    assert_eq!(stats.complexity.node_count, 5);
+    assert_eq!(
+        stats.component_counts.into_iter().collect::<Vec<_>>(),
+        vec![("Column".to_string(), 3), ("Text".to_string(), 2)]
+    );
+    assert_eq!(surface.stats().count("Text"), 2);
+    assert_eq!(surface.stats().count("Button"), 0);
+}
+
+#[test]
+fn test_check_limits_structure() {
+    let surface = Surface::new(nested(5));
+    assert_eq!(surface.check_limits(&Limits::default()), Ok(()));
+
+    let shallow = Limits {
+        max_depth: 2,
+        ..Limits::default()
+    };
+    let error = surface.check_limits(&shallow).unwrap_err();
+    assert_eq!(error.to_string(), "Surface: depth 3 exceeds limit 2");
+
+    let few = Limits {
+        max_nodes: 4,
+        ..Limits::default()
+    };
+    assert_eq!(
+        surface.check_limits(&few),
+        Err(LimitError::Surface {
+            what: "node count",
+            value: 5,
+            limit: 4,
+        })
+    );
+}
+
+#[test]
+fn test_check_limits_prop_values() {
+    // Long enough for the builder's `accessible` prop name.
+    let limits = Limits {
+        max_string_len: 10,
+        max_list_len: 2,
+        ..Limits::default()
+    };
+    let long_text = Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("short").build())
+            .child(SurfaceNode::new("Text").with_prop("value", "far too long".into()))
+            .build(),
+    );
+    assert_eq!(
+        long_text.check_limits(&limits).unwrap_err().to_string(),
+        "root/1: value: string length 12 exceeds limit 10"
+    );
+
+    let nested_list = Surface::new(SurfaceNode::new("Canvas").with_prop(
+        "commands",
+        PropValue::List(vec![PropValue::Record(
+            [(
+                "points".to_string(),
+                PropValue::List(vec![1.0.into(), 2.0.into(), 3.0.into()]),
+            )]
+            .into(),
+        )]),
+    ));
+    assert_eq!(
+        nested_list.check_limits(&limits),
+        Err(LimitError::Prop {
+            path: pepl_ui::NodePath::root(),
+            key: "commands".into(),
+            what: "list length",
+            value: 3,
+            limit: 2,
+        })
+    );
+}
+
+#[test]
+fn test_check_limits_node_strings() {
+    let limits = Limits {
+        max_string_len: 8,
+        ..Limits::default()
+    };
+    let long = "x".repeat(9);
+    let cases = [
+        (SurfaceNode::new(long.as_str()), "root: component_type"),
+        (
+            SurfaceNode::new("Text").with_test_id(long.as_str()),
+            "root: test_id",
+        ),
+        (
+            SurfaceNode::new("Text").with_key(long.as_str()),
+            "root: key",
+        ),
+        (
+            SurfaceNode::new("Text").with_prop(long.as_str(), PropValue::Nil),
+            "root: prop name",
+        ),
+    ];
+    for (node, field) in cases {
+        assert_eq!(
+            Surface::new(node).check_limits(&limits),
+            Err(LimitError::Field {
+                field: field.to_string(),
+                what: "string length",
+                value: 9,
+                limit: 8,
+            })
+        );
+    }
+}
+
+#[test]
+fn test_check_limits_strings_inside_values() {
+    let limits = Limits {
+        max_string_len: 8,
+        ..Limits::default()
+    };
+    let long = "x".repeat(9);
+    let values = [
+        PropValue::action(long.as_str()),
+        PropValue::color_token(long.as_str()),
+        PropValue::i18n(long.as_str()),
+        PropValue::Record([(long.clone(), PropValue::Nil)].into()),
+    ];
+    for value in values {
+        let surface = Surface::new(SurfaceNode::new("Button").with_prop("on_tap", value.clone()));
+        assert_eq!(
+            surface.check_limits(&limits).unwrap_err().to_string(),
+            "root: on_tap: string length 9 exceeds limit 8",
+            "{value:?}"
+        );
+    }
+}
+
+#[test]
+fn test_check_limits_surface_strings() {
+    let limits = SurfaceLimits {
+        max_string_len: 8,
+        ..SurfaceLimits::default()
+    };
+    let long = "x".repeat(9);
+    let base = || Surface::new(SurfaceNode::new("Text"));
+    let mut layered = base();
+    layered.layers.push(Layer {
+        name: long.clone(),
+        root: SurfaceNode::new("Text"),
+    });
+    let cases = [
+        (layered, "Surface.layers"),
+        (
+            base().with_shortcut(long.as_str(), PropValue::action("save")),
+            "Surface.shortcuts",
+        ),
+        (
+            base().with_shortcut("Ctrl+S", PropValue::action(long.as_str())),
+            "Surface.shortcuts['Ctrl+S']",
+        ),
+        (
+            base().with_route(Route::new(long.as_str())),
+            "Surface.route.path",
+        ),
+        (
+            base().with_route(Route::new("/o/:id").param("id", long.as_str())),
+            "Surface.route.params",
+        ),
+    ];
+    for (surface, field) in cases {
+        assert_eq!(
+            surface.check_limits(&limits).unwrap_err().to_string(),
+            format!("{field}: string length 9 exceeds limit 8")
+        );
+    }
+}
+
+#[test]
+fn test_from_json_limited() {
+    let json = Surface::new(nested(3)).to_json();
+    assert_eq!(
+        Surface::from_json_limited(&json, &Limits::default()),
+        Ok(Surface::new(nested(3)))
+    );
+
+    let tiny = Limits {
+        max_bytes: 16,
+        ..Limits::default()
+    };
+    assert_eq!(
+        Surface::from_json_limited(&json, &tiny),
+        Err(LimitError::Surface {
+            what: "serialized size",
+            value: json.len(),
+            limit: 16,
+        })
+    );
+
+    let shallow = Limits {
+        max_depth: 1,
+        ..Limits::default()
+    };
+    assert!(matches!(
+        Surface::from_json_limited(&json, &shallow),
+        Err(LimitError::Surface { what: "depth", .. })
+    ));
+    assert!(matches!(
+        Surface::from_json_limited("{\"root\": 1}", &Limits::default()),
+        Err(LimitError::Parse(_))
+    ));
+}
@@ -1,4 +1,5 @@
-//! Tests for the `Text` and `ProgressBar` content components.
+//! Tests for the `Text`, `RichText`, `ProgressBar`, `Spinner`, `Icon`, `Image`, and
+//! `Skeleton` content components.
 //!
 //! Test categories:
 //! 1. Default/minimal construction
@@ -9,9 +10,13 @@
 //! 6. Edge cases (overwrite, clamping)
 //! 7. 100-iteration determinism
 
+use std::collections::BTreeMap;
+
 use pepl_ui::{
-    validate_content_node, ColorValue, ProgressBarBuilder, PropValue, Surface, SurfaceNode,
-    TextAlign, TextBuilder, TextOverflow, TextSize, TextWeight,
+    validate_content_node, ColorValue, Dimension, Gradient, GradientStop, IconBuilder,
+    ImageBuilder, ImageFit, ProgressBarBuilder, ProgressShape, PropValue, RichTextBuilder,
+    SkeletonBuilder, SkeletonShape, SpinnerBuilder, Surface, SurfaceNode, TextAlign, TextBuilder,
+    TextOverflow, TextSize, TextSpan, TextWeight,
 };
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -205,7 +210,7 @@ fn test_text_json_roundtrip() {
         .size(TextSize::Title)
         .weight(TextWeight::Bold)
         .build();
-    let surface = Surface { root: node };
+    let surface = Surface::new(node);
     let json = surface.to_json();
     let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
     assert_eq!(parsed["root"]["type"], "Text");
@@ -222,8 +227,8 @@ fn test_text_json_deterministic() {
             .color(ColorValue::rgb(1.0, 0.0, 0.0))
             .build()
     };
-    let json1 = Surface { root: build() }.to_json();
-    let json2 = Surface { root: build() }.to_json();
+    let json1 = Surface::new(build()).to_json();
+    let json2 = Surface::new(build()).to_json();
     assert_eq!(json1, json2);
 }
 
@@ -321,6 +326,82 @@ fn test_text_invalid_overflow() {
     assert!(errors[0].contains("overflow"));
 }
 
+#[test]
+fn test_text_selectable_copyable() {
+    let node = TextBuilder::new("ABC-123")
+        .selectable(true)
+        .copyable(true)
+        .build();
+    assert_eq!(node.props.get("selectable"), Some(&PropValue::Bool(true)));
+    assert_eq!(node.props.get("copyable"), Some(&PropValue::Bool(true)));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_text_selectable_invalid_type() {
+    let mut node = TextBuilder::new("hi").build();
+    node.set_prop("selectable", PropValue::String("yes".into()));
+    node.set_prop("copyable", PropValue::Number(1.0));
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Text.selectable: expected bool")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Text.copyable: expected bool")));
+}
+
+#[test]
+fn test_text_color_token() {
+    let node = TextBuilder::new("hi").color_token("primary").build();
+    assert_eq!(
+        node.props.get("color"),
+        Some(&PropValue::color_token("primary"))
+    );
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_progress_bar_color_tokens() {
+    let node = ProgressBarBuilder::new(0.5)
+        .color_token("primary")
+        .background_token("surface_variant")
+        .build();
+    assert_eq!(
+        node.props.get("background"),
+        Some(&PropValue::color_token("surface_variant"))
+    );
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_progress_bar_color_gradient() {
+    let node = ProgressBarBuilder::new(0.5)
+        .color_gradient(Gradient::linear(
+            90.0,
+            vec![
+                GradientStop::new(0.0, ColorValue::GREEN),
+                GradientStop::new(0.5, ColorValue::from_hex("#FFCC00").unwrap()),
+                GradientStop::new(1.0, ColorValue::RED),
+            ],
+        ))
+        .build();
+    assert!(pepl_ui::is_gradient(&node.props["color"]));
+    assert!(validate_content_node(&node).is_empty());
+
+    let single = ProgressBarBuilder::new(0.5)
+        .color_gradient(Gradient::radial(vec![GradientStop::new(
+            0.0,
+            ColorValue::GREEN,
+        )]))
+        .build();
+    assert_eq!(
+        validate_content_node(&single),
+        vec!["ProgressBar.color.stops: expected at least 2 stops, got 1"]
+    );
+}
+
 #[test]
 fn test_text_unknown_prop() {
     let mut node = TextBuilder::new("hi").build();
@@ -342,7 +423,7 @@ fn test_text_no_children_allowed() {
 #[test]
 fn test_text_multiple_errors() {
     let mut node = SurfaceNode::new("Text");
-    node.set_prop("size", PropValue::Number(12.0));
+    node.set_prop("size", PropValue::Bool(true));
     node.set_prop("unknown", PropValue::Bool(true));
     // missing value + invalid size + unknown prop = 3 errors
     let errors = validate_content_node(&node);
@@ -426,6 +507,56 @@ fn test_progress_bar_all_props() {
     assert_eq!(node.props.get("height"), Some(&PropValue::Number(12.0)));
 }
 
+#[test]
+fn test_progress_bar_indeterminate() {
+    let node = ProgressBarBuilder::indeterminate().height(4.0).build();
+    assert!(!node.props.contains_key("value"));
+    assert_eq!(
+        node.props.get("indeterminate"),
+        Some(&PropValue::Bool(true))
+    );
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_progress_bar_indeterminate_accessibility() {
+    let node = ProgressBarBuilder::indeterminate().build();
+    match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(
+                fields.get("label"),
+                Some(&PropValue::String("Loading".into()))
+            );
+            assert!(!fields.contains_key("value"));
+        }
+        other => panic!("Expected accessible record, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_progress_bar_circular() {
+    let node = ProgressBarBuilder::new(0.6)
+        .shape(ProgressShape::Circular)
+        .size(48.0)
+        .thickness(4.0)
+        .build();
+    assert_eq!(
+        node.props.get("shape"),
+        Some(&PropValue::String("circular".into()))
+    );
+    assert_eq!(node.props.get("size"), Some(&PropValue::Number(48.0)));
+    assert_eq!(node.props.get("thickness"), Some(&PropValue::Number(4.0)));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_progress_bar_circular_indeterminate() {
+    let node = ProgressBarBuilder::indeterminate()
+        .shape(ProgressShape::Circular)
+        .build();
+    assert!(validate_content_node(&node).is_empty());
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // ProgressBar — JSON Roundtrip
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -433,7 +564,7 @@ fn test_progress_bar_all_props() {
 #[test]
 fn test_progress_bar_json_roundtrip() {
     let node = ProgressBarBuilder::new(0.3).height(4.0).build();
-    let surface = Surface { root: node };
+    let surface = Surface::new(node);
     let json = surface.to_json();
     let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
     assert_eq!(parsed["root"]["type"], "ProgressBar");
@@ -448,8 +579,8 @@ fn test_progress_bar_json_deterministic() {
             .color(ColorValue::rgb(0.0, 1.0, 0.0))
             .build()
     };
-    let json1 = Surface { root: build() }.to_json();
-    let json2 = Surface { root: build() }.to_json();
+    let json1 = Surface::new(build()).to_json();
+    let json2 = Surface::new(build()).to_json();
     assert_eq!(json1, json2);
 }
 
@@ -481,6 +612,26 @@ fn test_progress_bar_missing_value() {
     assert!(errors[0].contains("required prop missing"));
 }
 
+#[test]
+fn test_progress_bar_explicitly_determinate_requires_value() {
+    let mut node = SurfaceNode::new("ProgressBar");
+    node.set_prop("indeterminate", PropValue::Bool(false));
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("required prop missing"));
+}
+
+#[test]
+fn test_progress_bar_wrong_indeterminate_type() {
+    let mut node = SurfaceNode::new("ProgressBar");
+    node.set_prop("indeterminate", PropValue::String("yes".into()));
+    let errors = validate_content_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("indeterminate") && e.contains("expected bool")));
+    assert!(errors.iter().any(|e| e.contains("required prop missing")));
+}
+
 #[test]
 fn test_progress_bar_wrong_value_type() {
     let mut node = SurfaceNode::new("ProgressBar");
@@ -517,10 +668,41 @@ fn test_progress_bar_invalid_height() {
     assert!(errors[0].contains("height"));
 }
 
+#[test]
+fn test_progress_bar_invalid_shape() {
+    let mut node = ProgressBarBuilder::new(0.5).build();
+    node.set_prop("shape", PropValue::String("square".into()));
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("shape"));
+}
+
+#[test]
+fn test_progress_bar_size_requires_circular() {
+    let node = ProgressBarBuilder::new(0.5)
+        .shape(ProgressShape::Linear)
+        .size(32.0)
+        .build();
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("only applies when shape is \"circular\""));
+}
+
+#[test]
+fn test_progress_bar_wrong_thickness_type() {
+    let mut node = ProgressBarBuilder::new(0.5)
+        .shape(ProgressShape::Circular)
+        .build();
+    node.set_prop("thickness", PropValue::String("thin".into()));
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("thickness") && errors[0].contains("expected number"));
+}
+
 #[test]
 fn test_progress_bar_unknown_prop() {
     let mut node = ProgressBarBuilder::new(0.5).build();
-    node.set_prop("width", PropValue::Number(100.0));
+    node.set_prop("max", PropValue::Number(100.0));
     let errors = validate_content_node(&node);
     assert_eq!(errors.len(), 1);
     assert!(errors[0].contains("unknown prop"));
@@ -545,6 +727,435 @@ fn test_progress_bar_multiple_errors() {
     assert_eq!(errors.len(), 3);
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// Spinner
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_spinner_minimal_announces_loading() {
+    let node = SpinnerBuilder::new().build();
+    assert_eq!(node.component_type, "Spinner");
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Loading".into()));
+    assert_eq!(info["role"], PropValue::String("progressbar".into()));
+    assert!(!info.contains_key("value"));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_spinner_size_and_color() {
+    let node = SpinnerBuilder::new()
+        .size(32.0)
+        .color(ColorValue::new(0.0, 0.5, 1.0, 1.0))
+        .build();
+    assert_eq!(node.props["size"], PropValue::Number(32.0));
+    assert!(node.props["color"].is_color());
+    assert!(validate_content_node(&node).is_empty());
+
+    let token = SpinnerBuilder::new().color_token("primary").build();
+    assert!(validate_content_node(&token).is_empty());
+}
+
+#[test]
+fn test_spinner_validation_errors() {
+    let node = SpinnerBuilder::new().size(0.0).build();
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["Spinner.size: must be positive, got 0"]
+    );
+
+    let mut node = SpinnerBuilder::new().build();
+    node.set_prop("color", PropValue::String("red".into()));
+    node.set_prop("value", PropValue::Number(0.5));
+    node.children.push(SurfaceNode::new("Text"));
+    assert_eq!(
+        validate_content_node(&node),
+        vec![
+            "Spinner.color: expected color, got string",
+            "Spinner: does not accept children, but got 1",
+            "Spinner: unknown prop 'value'",
+        ]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Icon
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_icon_labelled() {
+    let node = IconBuilder::new("warning")
+        .size(20.0)
+        .color_token("error")
+        .label("Warning")
+        .build();
+    assert_eq!(node.component_type, "Icon");
+    assert_eq!(node.props["name"], PropValue::String("warning".into()));
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Warning".into()));
+    assert_eq!(info["role"], PropValue::String("image".into()));
+    assert!(!info.contains_key("hidden"));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_icon_unlabelled_is_decorative() {
+    let node = IconBuilder::new("chevron_right").build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["hidden"], PropValue::Bool(true));
+    assert_eq!(info["role"], PropValue::String("none".into()));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_icon_validation_errors() {
+    let node = IconBuilder::new("serch").size(0.0).build();
+    assert_eq!(
+        validate_content_node(&node),
+        vec![
+            "Icon.name: unknown icon 'serch' (did you mean 'search'?)",
+            "Icon.size: must be positive, got 0",
+        ]
+    );
+
+    let mut node = SurfaceNode::new("Icon");
+    node.set_prop("color", PropValue::String("red".into()));
+    node.set_prop("value", PropValue::Number(1.0));
+    assert_eq!(
+        validate_content_node(&node),
+        vec![
+            "Icon.name: required prop missing",
+            "Icon.color: expected color, got string",
+            "Icon: unknown prop 'value'",
+        ]
+    );
+}
+
+#[test]
+fn test_icon_asset_names() {
+    assert!(validate_content_node(&IconBuilder::new("asset:brand_logo").build()).is_empty());
+    assert_eq!(
+        validate_content_node(&IconBuilder::new("asset:").build()),
+        vec!["Icon.name: asset icon name must not be empty"]
+    );
+    assert_eq!(
+        validate_content_node(&IconBuilder::new("spaceship").build()),
+        vec!["Icon.name: unknown icon 'spaceship'"]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Image
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_image_minimal() {
+    let node = ImageBuilder::new("https://example.com/cat.png").build();
+    assert_eq!(node.component_type, "Image");
+    assert_eq!(
+        node.props.get("source"),
+        Some(&PropValue::String("https://example.com/cat.png".into()))
+    );
+    assert!(node.children.is_empty());
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_image_all_props() {
+    let node = ImageBuilder::asset("hero")
+        .fit(ImageFit::Cover)
+        .width(Dimension::Fill)
+        .height(Dimension::Px(180.0))
+        .alt("Mountain at sunrise")
+        .build();
+    assert_eq!(
+        node.props.get("source"),
+        Some(&PropValue::String("asset:hero".into()))
+    );
+    assert_eq!(
+        node.props.get("fit"),
+        Some(&PropValue::String("cover".into()))
+    );
+    assert_eq!(
+        node.props.get("width"),
+        Some(&PropValue::dimension(Dimension::Fill))
+    );
+    assert_eq!(
+        node.props.get("alt"),
+        Some(&PropValue::String("Mountain at sunrise".into()))
+    );
+    assert!(validate_content_node(&node).is_empty());
+
+    let json = Surface::new(node.clone()).to_json();
+    let parsed: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.root, node);
+}
+
+#[test]
+fn test_image_accepted_sources() {
+    for source in [
+        "https://example.com/a.png",
+        "http://localhost:8080/a.png",
+        "data:image/png;base64,iVBORw0KGgo=",
+        "asset:icons/star",
+    ] {
+        let node = ImageBuilder::new(source).build();
+        assert!(validate_content_node(&node).is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn test_image_rejected_sources() {
+    for source in [
+        "",
+        "cat.png",
+        "ftp://example.com/a.png",
+        "https://",
+        "asset:",
+        "data:text/plain,hi",
+    ] {
+        let errors = validate_content_node(&ImageBuilder::new(source).build());
+        assert_eq!(errors.len(), 1, "{source}: {errors:?}");
+        assert!(errors[0].starts_with("Image.source: expected url"));
+    }
+}
+
+#[test]
+fn test_image_missing_source() {
+    let errors = validate_content_node(&SurfaceNode::new("Image"));
+    assert_eq!(errors, vec!["Image.source: required prop missing"]);
+}
+
+#[test]
+fn test_image_invalid_props() {
+    let mut node = ImageBuilder::asset("logo").build();
+    node.set_prop("fit", PropValue::String("tile".into()));
+    node.set_prop("width", PropValue::String("wide".into()));
+    node.set_prop("alt", PropValue::Number(1.0));
+    node.set_prop("src", PropValue::String("x".into()));
+    node.add_child(TextBuilder::new("caption").build());
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 5, "{errors:?}");
+    assert!(errors[0].starts_with("Image.fit: expected one of [contain, cover, fill]"));
+    assert_eq!(
+        errors[1],
+        "Image.width: expected number or dimension, got string"
+    );
+    assert_eq!(errors[2], "Image.alt: expected string, got number");
+    assert_eq!(errors[3], "Image: does not accept children, but got 1");
+    assert_eq!(errors[4], "Image: unknown prop 'src'");
+}
+
+#[test]
+fn test_image_number_width_is_valid() {
+    let mut node = ImageBuilder::asset("logo").build();
+    node.set_prop("width", PropValue::Number(64.0));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// RichText
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rich_text_spans() {
+    let node = RichTextBuilder::new(vec![
+        TextSpan::new("Read the "),
+        TextSpan::new("terms")
+            .weight(TextWeight::Bold)
+            .color_token("primary")
+            .link("https://example.com/terms"),
+    ])
+    .build();
+    assert_eq!(node.component_type, "RichText");
+    let PropValue::List(spans) = &node.props["spans"] else {
+        panic!("expected spans list");
+    };
+    let PropValue::Record(link) = &spans[1] else {
+        panic!("expected span record");
+    };
+    assert_eq!(link["weight"], PropValue::String("bold".into()));
+    assert_eq!(link["color"], PropValue::color_token("primary"));
+    assert_eq!(
+        link["link"],
+        PropValue::String("https://example.com/terms".into())
+    );
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Read the terms".into()));
+    assert_eq!(info["role"], PropValue::String("text".into()));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_rich_text_markdown_label_strips_markup() {
+    let node = RichTextBuilder::markdown("See **the** [docs](https://example.com) for `x`")
+        .size(TextSize::Small)
+        .align(TextAlign::Center)
+        .build();
+    assert_eq!(
+        node.props["markdown"],
+        PropValue::String("See **the** [docs](https://example.com) for `x`".into())
+    );
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(
+        info["label"],
+        PropValue::String("See the docs for x".into())
+    );
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_rich_text_requires_exactly_one_source() {
+    assert_eq!(
+        validate_content_node(&SurfaceNode::new("RichText")),
+        vec!["RichText: required prop missing, expected one of 'spans' or 'markdown'"]
+    );
+
+    let mut node = RichTextBuilder::markdown("*hi*").build();
+    node.set_prop(
+        "spans",
+        PropValue::List(vec![TextSpan::new("hi").to_prop_value()]),
+    );
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["RichText: expected one of 'spans' or 'markdown', got both"]
+    );
+
+    let node = RichTextBuilder::new(vec![]).build();
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["RichText.spans: must not be empty"]
+    );
+}
+
+#[test]
+fn test_rich_text_span_validation() {
+    let mut bad = BTreeMap::new();
+    bad.insert("weight".to_string(), PropValue::String("heavy".into()));
+    bad.insert("color".to_string(), PropValue::Number(1.0));
+    bad.insert("link".to_string(), PropValue::String("javascript:x".into()));
+    bad.insert("italic".to_string(), PropValue::Bool(true));
+    let mut node = RichTextBuilder::new(vec![TextSpan::new("ok")]).build();
+    node.set_prop(
+        "spans",
+        PropValue::List(vec![
+            TextSpan::new("ok").to_prop_value(),
+            PropValue::Record(bad),
+            PropValue::String("plain".into()),
+        ]),
+    );
+    assert_eq!(
+        validate_content_node(&node),
+        vec![
+            "RichText.spans[1].text: required field missing",
+            "RichText.spans[1].weight: expected one of [normal, medium, bold], got String(\"heavy\")",
+            "RichText.spans[1].color: expected color, got number",
+            "RichText.spans[1].link: expected url (https://, http://, mailto:), got \"javascript:x\"",
+            "RichText.spans[1]: unknown field 'italic'",
+            "RichText.spans[2]: expected record, got string",
+        ]
+    );
+}
+
+#[test]
+fn test_rich_text_no_children_and_unknown_prop() {
+    let mut node = RichTextBuilder::markdown("hi").build();
+    node.children.push(SurfaceNode::new("Text"));
+    node.set_prop("value", PropValue::String("hi".into()));
+    assert_eq!(
+        validate_content_node(&node),
+        vec![
+            "RichText: does not accept children, but got 1",
+            "RichText: unknown prop 'value'",
+        ]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Skeleton
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_skeleton_minimal_is_hidden() {
+    let node = SkeletonBuilder::new(SkeletonShape::Rect).build();
+    assert_eq!(node.component_type, "Skeleton");
+    assert_eq!(node.props["shape"], PropValue::String("rect".into()));
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Loading".into()));
+    assert_eq!(info["hidden"], PropValue::Bool(true));
+    assert_eq!(info["role"], PropValue::String("none".into()));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_skeleton_all_props() {
+    let node = SkeletonBuilder::new(SkeletonShape::Text)
+        .width(Dimension::Percent(80.0))
+        .height(Dimension::Px(14.0))
+        .lines(3)
+        .build();
+    assert_eq!(node.props["shape"], PropValue::String("text".into()));
+    assert_eq!(node.props["lines"], PropValue::Number(3.0));
+    assert!(node.props.contains_key("width"));
+    assert!(node.props.contains_key("height"));
+    assert!(validate_content_node(&node).is_empty());
+}
+
+#[test]
+fn test_skeleton_invalid_shape_and_missing() {
+    let errors = validate_content_node(&SurfaceNode::new("Skeleton"));
+    assert_eq!(errors, vec!["Skeleton.shape: required prop missing"]);
+
+    let mut node = SkeletonBuilder::new(SkeletonShape::Rect).build();
+    node.set_prop("shape", PropValue::String("square".into()));
+    let errors = validate_content_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("Skeleton.shape: expected one of [text, rect, circle]"));
+}
+
+#[test]
+fn test_skeleton_lines_validation() {
+    let node = SkeletonBuilder::new(SkeletonShape::Circle).lines(2).build();
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["Skeleton.lines: only valid with shape \"text\", got shape \"circle\""]
+    );
+
+    let mut node = SkeletonBuilder::new(SkeletonShape::Text).build();
+    node.set_prop("lines", PropValue::Number(1.5));
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["Skeleton.lines: expected positive integer, got 1.5"]
+    );
+    node.set_prop("lines", PropValue::String("2".into()));
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["Skeleton.lines: expected number, got string"]
+    );
+}
+
+#[test]
+fn test_skeleton_no_children() {
+    let mut node = SkeletonBuilder::new(SkeletonShape::Rect).build();
+    node.children.push(SurfaceNode::new("Text"));
+    assert_eq!(
+        validate_content_node(&node),
+        vec!["Skeleton: does not accept children, but got 1"]
+    );
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Unknown Component
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -573,9 +1184,9 @@ fn test_text_determinism_100() {
             .overflow(TextOverflow::Ellipsis)
             .build()
     };
-    let baseline = Surface { root: build() }.to_json();
+    let baseline = Surface::new(build()).to_json();
     for _ in 0..100 {
-        assert_eq!(Surface { root: build() }.to_json(), baseline);
+        assert_eq!(Surface::new(build()).to_json(), baseline);
     }
 }
 
@@ -588,8 +1199,8 @@ fn test_progress_bar_determinism_100() {
             .height(10.0)
             .build()
     };
-    let baseline = Surface { root: build() }.to_json();
+    let baseline = Surface::new(build()).to_json();
     for _ in 0..100 {
-        assert_eq!(Surface { root: build() }.to_json(), baseline);
+        assert_eq!(Surface::new(build()).to_json(), baseline);
     }
 }
@@ -0,0 +1,105 @@
+//! Tests for `CostModel`.
+
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, CostModel, PropValue, Surface, SurfaceNode, TextBuilder,
+};
+
+#[test]
+fn test_default_weights_cover_every_component() {
+    let model = CostModel::default();
+    for name in [
+        "Column",
+        "Row",
+        "Scroll",
+        "Tabs",
+        "Carousel",
+        "Disclosure",
+        "Overlay",
+        "AppBar",
+        "Divider",
+        "Spacer",
+        "Text",
+        "RichText",
+        "ProgressBar",
+        "Spinner",
+        "Icon",
+        "Image",
+        "Skeleton",
+        "Button",
+        "TextInput",
+        "Stepper",
+        "Rating",
+        "FilePicker",
+        "ScrollList",
+        "Table",
+        "Modal",
+        "BottomSheet",
+        "Drawer",
+        "Toast",
+        "Snackbar",
+        "Tooltip",
+        "Form",
+        "Video",
+        "AudioPlayer",
+        "Canvas",
+        "Chart",
+    ] {
+        assert!(model.weight(name) > 0.0, "{name}");
+    }
+    assert_eq!(model.budget(), 16_000.0);
+}
+
+#[test]
+fn test_unknown_component_uses_default_weight() {
+    let model = CostModel::new().with_default_weight(7.0);
+    assert_eq!(model.weight("Blink"), 7.0);
+}
+
+#[test]
+fn test_estimate_sums_tree() {
+    let model = CostModel::new()
+        .with_weight("Column", 10.0)
+        .with_weight("Text", 1.0)
+        .with_prop_weight(0.5);
+    let node = SurfaceNode::new("Column")
+        .with_prop("spacing", PropValue::Number(8.0))
+        .with_child(SurfaceNode::new("Text"))
+        .with_child(SurfaceNode::new("Text"));
+    // 10 + 0.5 (spacing) + 1 + 1
+    assert_eq!(model.estimate(&Surface::new(node)), 12.5);
+}
+
+#[test]
+fn test_estimate_node_matches_surface_estimate() {
+    let model = CostModel::default();
+    let root = ColumnBuilder::new()
+        .child(TextBuilder::new("Title").build())
+        .child(ButtonBuilder::new("OK", PropValue::action("ok")).build())
+        .build();
+    assert_eq!(
+        model.estimate_node(&root),
+        model.estimate(&Surface::new(root.clone()))
+    );
+}
+
+#[test]
+fn test_check_within_budget() {
+    let surface = Surface::new(TextBuilder::new("Hi").build());
+    assert!(CostModel::default().check(&surface).is_empty());
+}
+
+#[test]
+fn test_check_warns_over_budget() {
+    let children = (0..1000).map(|i| TextBuilder::new(format!("Row {i}")).build());
+    let surface = Surface::new(ColumnBuilder::new().children(children.collect()).build());
+    let warnings = CostModel::default().check(&surface);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("Surface: estimated cost"));
+    assert!(warnings[0].ends_with("exceeds budget 16000"));
+}
+
+#[test]
+fn test_custom_budget() {
+    let surface = Surface::new(TextBuilder::new("Hi").build());
+    assert_eq!(CostModel::new().with_budget(1.0).check(&surface).len(), 1);
+}
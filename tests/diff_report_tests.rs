@@ -0,0 +1,120 @@
+//! Tests for `Surface::diff_report` and `assert_surface_eq!`.
+
+use pepl_ui::{
+    assert_surface_eq, ButtonBuilder, ColumnBuilder, PropValue, Route, Surface, SurfaceNode,
+    TextBuilder,
+};
+
+fn screen(label: &str) -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .spacing(8.0)
+            .child(TextBuilder::new("Title").build())
+            .child(
+                ColumnBuilder::new()
+                    .child(ButtonBuilder::new(label, PropValue::action("ok")).build())
+                    .build(),
+            )
+            .build(),
+    )
+}
+
+#[test]
+fn test_diff_report_equal_is_empty() {
+    assert_eq!(screen("OK").diff_report(&screen("OK")), "");
+}
+
+#[test]
+fn test_diff_report_nested_prop() {
+    let report = screen("OK").diff_report(&screen("Ok"));
+    assert!(report
+        .lines()
+        .any(|line| line == r#"root/1/0 props.label: "OK" != "Ok""#));
+    // The accessible label follows the button label.
+    assert!(report
+        .lines()
+        .any(|line| line == r#"root/1/0 props.accessible.label: "OK" != "Ok""#));
+    assert_eq!(report.lines().count(), 2);
+}
+
+#[test]
+fn test_diff_report_missing_prop() {
+    let left = SurfaceNode::new("Column").with_prop("spacing", PropValue::Number(8.0));
+    let right = SurfaceNode::new("Column");
+    assert_eq!(
+        left.diff_report(&right),
+        "root props.spacing: 8.0 != (missing)"
+    );
+    assert_eq!(
+        right.diff_report(&left),
+        "root props.spacing: (missing) != 8.0"
+    );
+}
+
+#[test]
+fn test_diff_report_type_change_stops_descent() {
+    let left = SurfaceNode::new("Column").with_child(SurfaceNode::new("Text"));
+    let right = SurfaceNode::new("Row").with_child(SurfaceNode::new("Button"));
+    assert_eq!(left.diff_report(&right), "root type: Column != Row");
+}
+
+#[test]
+fn test_diff_report_extra_and_missing_children() {
+    let left = SurfaceNode::new("Column").with_child(SurfaceNode::new("Text"));
+    let right = SurfaceNode::new("Column")
+        .with_child(SurfaceNode::new("Text"))
+        .with_child(SurfaceNode::new("Button"));
+    assert_eq!(left.diff_report(&right), "root/1: (missing) != Button");
+    assert_eq!(right.diff_report(&left), "root/1: Button != (missing)");
+}
+
+#[test]
+fn test_diff_report_list_items() {
+    let left = SurfaceNode::new("ScrollList").with_prop(
+        "items",
+        PropValue::List(vec![PropValue::Number(1.0), PropValue::Number(2.0)]),
+    );
+    let right = SurfaceNode::new("ScrollList")
+        .with_prop("items", PropValue::List(vec![PropValue::Number(1.0)]));
+    assert_eq!(
+        left.diff_report(&right),
+        "root props.items[1]: 2.0 != (missing)"
+    );
+}
+
+#[test]
+fn test_diff_report_test_id() {
+    let left = SurfaceNode::new("Text").with_test_id("title");
+    let right = SurfaceNode::new("Text");
+    assert_eq!(
+        left.diff_report(&right),
+        r#"root test_id: "title" != (missing)"#
+    );
+}
+
+#[test]
+fn test_diff_report_shortcuts_and_route() {
+    let node = SurfaceNode::new("Column");
+    let left = Surface::new(node.clone())
+        .with_shortcut("primary+s", PropValue::action("save"))
+        .with_route(Route::new("/notes/:id"));
+    let right = Surface::new(node);
+    let report = left.diff_report(&right);
+    assert_eq!(
+        report,
+        "shortcuts.primary+s: {\"__action\":\"save\"} != (missing)\n\
+         route: {\"path\":\"/notes/:id\"} != (missing)"
+    );
+}
+
+#[test]
+fn test_assert_surface_eq_passes() {
+    assert_surface_eq!(screen("OK"), screen("OK"));
+    assert_surface_eq!(screen("OK").root, screen("OK").root);
+}
+
+#[test]
+#[should_panic(expected = "root/1/0 props.label: \"OK\" != \"Ok\"")]
+fn test_assert_surface_eq_reports_paths() {
+    assert_surface_eq!(screen("OK"), screen("Ok"));
+}
@@ -1,12 +1,17 @@
-//! Tests for feedback components — Modal, Toast (U6).
+//! Tests for feedback components — Modal, BottomSheet, Drawer, Toast (U6),
+//! Snackbar, Tooltip.
 //!
 //! Covers construction, JSON serialization, validation (happy + error),
-//! children handling (Modal), and 100-iteration determinism.
+//! children handling (Modal, BottomSheet, Drawer), and 100-iteration determinism.
 
+use pepl_ui::accessibility::AccessibilityInfo;
 use pepl_ui::{
-    validate_feedback_node, ModalBuilder, PropValue, Surface, SurfaceNode, TextBuilder,
-    ToastBuilder, ToastType,
+    validate_feedback_node, BottomSheetBuilder, DrawerBuilder, DrawerSide, Easing, ModalAction,
+    ModalBuilder, ModalPresentation, PropValue, SheetDetent, SnackbarBuilder, Surface, SurfaceNode,
+    TextBuilder, ToastAction, ToastBuilder, ToastPriority, ToastType, TooltipBuilder,
+    TooltipPlacement, TransitionSpec,
 };
+use pepl_ui::{ButtonBuilder, ColumnBuilder, CornerRadii};
 
 // ══════════════════════════════════════════════════════════════════════════════
 // Modal — Construction
@@ -51,6 +56,141 @@ fn modal_multiple_children() {
     assert_eq!(node.children.len(), 2);
 }
 
+#[test]
+fn modal_presentation_and_sizing() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .presentation(ModalPresentation::Sheet)
+        .width(480.0)
+        .max_height(600.0)
+        .build();
+
+    assert_eq!(
+        node.props.get("presentation"),
+        Some(&PropValue::String("sheet".into()))
+    );
+    assert_eq!(node.props.get("width"), Some(&PropValue::Number(480.0)));
+    assert_eq!(
+        node.props.get("max_height"),
+        Some(&PropValue::Number(600.0))
+    );
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn modal_corner_radius() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .corner_radius(CornerRadii::Uniform(16.0))
+        .build();
+    assert_eq!(
+        node.props.get("corner_radius"),
+        Some(&PropValue::Number(16.0))
+    );
+    assert!(validate_feedback_node(&node).is_empty());
+
+    let node = node.with_prop("corner_radius", PropValue::String("round".into()));
+    assert_eq!(
+        validate_feedback_node(&node),
+        vec!["Modal.corner_radius: expected number or record, got string"]
+    );
+}
+
+#[test]
+fn modal_fullscreen_traps_focus() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .presentation(ModalPresentation::Fullscreen)
+        .build();
+    match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(fields.get("modal"), Some(&PropValue::Bool(true)));
+        }
+        other => panic!("Expected accessible record, got {other:?}"),
+    }
+
+    let centered = ModalBuilder::new(true, PropValue::action("close"))
+        .presentation(ModalPresentation::Center)
+        .build();
+    match centered.props.get("accessible") {
+        Some(PropValue::Record(fields)) => assert!(!fields.contains_key("modal")),
+        other => panic!("Expected accessible record, got {other:?}"),
+    }
+}
+
+#[test]
+fn modal_confirm_and_cancel_slots() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .title("Delete item?")
+        .confirm(ModalAction::new("Delete", PropValue::action("delete")).destructive())
+        .cancel(ModalAction::new("Keep", PropValue::action("close")))
+        .build();
+
+    match node.props.get("confirm") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(
+                fields.get("label"),
+                Some(&PropValue::String("Delete".into()))
+            );
+            assert_eq!(fields.get("action"), Some(&PropValue::action("delete")));
+            assert_eq!(fields.get("destructive"), Some(&PropValue::Bool(true)));
+        }
+        other => panic!("Expected confirm record, got {other:?}"),
+    }
+    match node.props.get("cancel") {
+        Some(PropValue::Record(fields)) => {
+            assert!(!fields.contains_key("destructive"));
+        }
+        other => panic!("Expected cancel record, got {other:?}"),
+    }
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn modal_with_transition() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .transition(TransitionSpec::fade(150.0))
+        .build();
+
+    match node.props.get("transition") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(fields.get("type"), Some(&PropValue::String("fade".into())));
+            assert!(!fields.contains_key("easing"));
+        }
+        other => panic!("Expected transition record, got {other:?}"),
+    }
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn modal_transition_wrong_type() {
+    let mut node = ModalBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("transition", PropValue::String("fade".into()));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.transition") && e.contains("expected record")));
+}
+
+#[test]
+fn modal_avoid_keyboard() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .avoid_keyboard(true)
+        .build();
+    assert_eq!(node.props["avoid_keyboard"], PropValue::Bool(true));
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn modal_avoid_keyboard_wrong_type() {
+    let mut node = ModalBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("avoid_keyboard", PropValue::String("yes".into()));
+
+    let errors = validate_feedback_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Modal.avoid_keyboard: expected bool, got string".to_string()]
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Modal — JSON
 // ══════════════════════════════════════════════════════════════════════════════
@@ -139,6 +279,63 @@ fn modal_wrong_on_dismiss_type() {
         .any(|e| e.contains("on_dismiss") && e.contains("expected action")));
 }
 
+#[test]
+fn modal_invalid_presentation() {
+    let mut node = ModalBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("presentation", PropValue::String("popover".into()));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors.iter().any(|e| e.contains("Modal.presentation")));
+}
+
+#[test]
+fn modal_wrong_sizing_types() {
+    let mut node = ModalBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("width", PropValue::String("wide".into()));
+    node.set_prop("max_height", PropValue::Bool(true));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.width") && e.contains("expected number")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.max_height") && e.contains("expected number")));
+}
+
+#[test]
+fn modal_action_slot_wrong_shape() {
+    let mut node = ModalBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("confirm", PropValue::String("OK".into()));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.confirm") && e.contains("expected record")));
+}
+
+#[test]
+fn modal_action_slot_field_errors() {
+    let mut fields = std::collections::BTreeMap::new();
+    fields.insert("action".to_string(), PropValue::lambda(1));
+    fields.insert("destructive".to_string(), PropValue::String("yes".into()));
+    fields.insert("icon".to_string(), PropValue::String("x".into()));
+    let mut node = ModalBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("cancel", PropValue::Record(fields));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.cancel.label") && e.contains("required")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.cancel.action") && e.contains("expected action")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Modal.cancel.destructive") && e.contains("expected bool")));
+    assert!(errors.iter().any(|e| e.contains("unknown field 'icon'")));
+}
+
 #[test]
 fn modal_unknown_prop() {
     let mut node = SurfaceNode::new("Modal");
@@ -198,6 +395,164 @@ fn toast_all_types() {
     }
 }
 
+#[test]
+fn toast_with_transition() {
+    let node = ToastBuilder::new("Saved")
+        .transition(TransitionSpec::slide(200.0).easing(Easing::EaseOut))
+        .build();
+
+    match node.props.get("transition") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(fields.get("type"), Some(&PropValue::String("slide".into())));
+            assert_eq!(fields.get("duration"), Some(&PropValue::Number(200.0)));
+            assert_eq!(
+                fields.get("easing"),
+                Some(&PropValue::String("ease_out".into()))
+            );
+        }
+        other => panic!("Expected transition record, got {other:?}"),
+    }
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn toast_invalid_transition() {
+    let mut fields = std::collections::BTreeMap::new();
+    fields.insert("type".to_string(), PropValue::String("spin".into()));
+    fields.insert("duration".to_string(), PropValue::Number(-5.0));
+    fields.insert("easing".to_string(), PropValue::String("bouncy".into()));
+    let mut node = ToastBuilder::new("msg").build();
+    node.set_prop("transition", PropValue::Record(fields));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors.iter().any(|e| e.contains("Toast.transition.type")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Toast.transition.duration") && e.contains("non-negative")));
+    assert!(errors.iter().any(|e| e.contains("Toast.transition.easing")));
+}
+
+#[test]
+fn toast_with_undo_action() {
+    let node = ToastBuilder::new("Item deleted")
+        .action(ToastAction::new(
+            "Undo",
+            PropValue::action_with_args("restore", vec![PropValue::Number(3.0)]),
+        ))
+        .on_dismiss(PropValue::action("clear_undo"))
+        .build();
+
+    match node.props.get("action") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(fields.get("label"), Some(&PropValue::String("Undo".into())));
+            assert!(matches!(
+                fields.get("action"),
+                Some(PropValue::ActionRef { .. })
+            ));
+        }
+        other => panic!("Expected action record, got {other:?}"),
+    }
+    assert_eq!(
+        node.props.get("on_dismiss"),
+        Some(&PropValue::action("clear_undo"))
+    );
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn toast_action_in_accessibility_record() {
+    let node = ToastBuilder::new("Item deleted")
+        .action(ToastAction::new("Undo", PropValue::action("restore")))
+        .build();
+    match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(
+                fields.get("hint"),
+                Some(&PropValue::String("Action available: Undo".into()))
+            );
+        }
+        other => panic!("Expected accessible record, got {other:?}"),
+    }
+}
+
+#[test]
+fn toast_invalid_action_and_on_dismiss() {
+    let mut fields = std::collections::BTreeMap::new();
+    fields.insert("label".to_string(), PropValue::Number(1.0));
+    let mut node = ToastBuilder::new("msg").build();
+    node.set_prop("action", PropValue::Record(fields));
+    node.set_prop("on_dismiss", PropValue::lambda(4));
+
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Toast.action.label") && e.contains("expected string")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Toast.action.action") && e.contains("required")));
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Toast.on_dismiss") && e.contains("expected action")));
+}
+
+#[test]
+fn toast_id_and_priority() {
+    let node = ToastBuilder::new("Offline")
+        .id("connectivity")
+        .priority(ToastPriority::High)
+        .build();
+
+    assert_eq!(
+        node.props.get("id"),
+        Some(&PropValue::String("connectivity".into()))
+    );
+    assert_eq!(
+        node.props.get("priority"),
+        Some(&PropValue::String("high".into()))
+    );
+    assert!(!node.props.contains_key("replace"));
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn toast_replaceable() {
+    let node = ToastBuilder::new("Synced").replaceable("sync").build();
+    assert_eq!(
+        node.props.get("id"),
+        Some(&PropValue::String("sync".into()))
+    );
+    assert_eq!(node.props.get("replace"), Some(&PropValue::Bool(true)));
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn toast_priority_ordering() {
+    assert!(ToastPriority::High > ToastPriority::Normal);
+    assert!(ToastPriority::Normal > ToastPriority::Low);
+}
+
+#[test]
+fn toast_invalid_queue_props() {
+    let mut node = ToastBuilder::new("msg").build();
+    node.set_prop("id", PropValue::String(String::new()));
+    node.set_prop("priority", PropValue::Number(5.0));
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Toast.id") && e.contains("empty")));
+    assert!(errors.iter().any(|e| e.contains("Toast.priority")));
+}
+
+#[test]
+fn toast_replace_requires_id() {
+    let mut node = ToastBuilder::new("msg").build();
+    node.set_prop("replace", PropValue::Bool(true));
+    let errors = validate_feedback_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("Toast.replace") && e.contains("requires an 'id'")));
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Toast — JSON
 // ══════════════════════════════════════════════════════════════════════════════
@@ -305,6 +660,384 @@ fn toast_unknown_prop() {
         .any(|e| e.contains("unknown prop") && e.contains("color")));
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// Snackbar
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn snackbar_with_undo_action() {
+    let node = SnackbarBuilder::new("Message archived")
+        .action("Undo", PropValue::action("unarchive"))
+        .duration(5000.0)
+        .on_dismiss(PropValue::action("clear_undo"))
+        .build();
+
+    assert_eq!(node.component_type, "Snackbar");
+    assert_eq!(
+        node.props.get("action_label"),
+        Some(&PropValue::String("Undo".into()))
+    );
+    assert_eq!(
+        node.props.get("on_action"),
+        Some(&PropValue::action("unarchive"))
+    );
+    assert!(node.children.is_empty());
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn snackbar_accessibility_is_polite_with_action_hint() {
+    let node = SnackbarBuilder::new("Message archived")
+        .action("Undo", PropValue::action("unarchive"))
+        .build();
+    match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(fields.get("role"), Some(&PropValue::String("alert".into())));
+            assert_eq!(
+                fields.get("label"),
+                Some(&PropValue::String("Message archived".into()))
+            );
+            assert_eq!(
+                fields.get("live_region"),
+                Some(&PropValue::String("polite".into()))
+            );
+            assert_eq!(
+                fields.get("hint"),
+                Some(&PropValue::String("Action available: Undo".into()))
+            );
+        }
+        other => panic!("Expected accessible record, got {other:?}"),
+    }
+
+    let passive = SnackbarBuilder::new("Saved").build();
+    assert!(validate_feedback_node(&passive).is_empty());
+}
+
+#[test]
+fn snackbar_on_action_must_be_action() {
+    let mut node = SnackbarBuilder::new("Deleted").build();
+    node.set_prop("action_label", PropValue::String("Undo".into()));
+    node.set_prop("on_action", PropValue::lambda(3));
+
+    assert_eq!(
+        validate_feedback_node(&node),
+        vec!["Snackbar.on_action: expected action, got lambda"]
+    );
+}
+
+#[test]
+fn snackbar_action_label_and_on_action_go_together() {
+    let mut node = SnackbarBuilder::new("Deleted").build();
+    node.set_prop("action_label", PropValue::String("Undo".into()));
+    assert_eq!(
+        validate_feedback_node(&node),
+        vec!["Snackbar.on_action: required prop missing when 'action_label' is set"]
+    );
+
+    let mut node = SnackbarBuilder::new("Deleted").build();
+    node.set_prop("on_action", PropValue::action("restore"));
+    assert_eq!(
+        validate_feedback_node(&node),
+        vec!["Snackbar.action_label: required prop missing when 'on_action' is set"]
+    );
+}
+
+#[test]
+fn snackbar_validation_errors() {
+    let mut node = SurfaceNode::new("Snackbar");
+    node.set_prop("action_label", PropValue::String(" ".into()));
+    node.set_prop("on_action", PropValue::action("restore"));
+    node.set_prop("duration", PropValue::Number(0.0));
+    node.set_prop("type", PropValue::String("error".into()));
+    node.add_child(TextBuilder::new("x").build());
+
+    assert_eq!(
+        validate_feedback_node(&node),
+        vec![
+            "Snackbar.message: required prop missing",
+            "Snackbar.action_label: must not be empty",
+            "Snackbar.duration: expected positive number, got 0",
+            "Snackbar: does not accept children, but got 1",
+            "Snackbar: unknown prop 'type'",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// BottomSheet
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn bottom_sheet_minimal() {
+    let node = BottomSheetBuilder::new(true, PropValue::action("close")).build();
+    assert_eq!(node.component_type, "BottomSheet");
+    assert_eq!(node.props.get("visible"), Some(&PropValue::Bool(true)));
+    assert_eq!(
+        node.props.get("on_dismiss"),
+        Some(&PropValue::action("close"))
+    );
+    assert!(!node.props.contains_key("detents"));
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn bottom_sheet_detents_and_children() {
+    let node = BottomSheetBuilder::new(true, PropValue::action("close"))
+        .title("Share")
+        .detent(SheetDetent::Medium)
+        .detent(SheetDetent::Large)
+        .child(TextBuilder::new("Copy link").build())
+        .child(TextBuilder::new("Send").build())
+        .build();
+    assert_eq!(
+        node.props.get("detents"),
+        Some(&PropValue::List(vec![
+            PropValue::String("medium".into()),
+            PropValue::String("large".into()),
+        ]))
+    );
+    assert_eq!(node.children.len(), 2);
+    assert!(validate_feedback_node(&node).is_empty());
+
+    let json = Surface::new(node).to_json();
+    assert!(json.contains(r#""detents":["medium","large"]"#));
+}
+
+#[test]
+fn bottom_sheet_accessibility() {
+    let titled = BottomSheetBuilder::new(true, PropValue::action("close"))
+        .title("Share")
+        .build();
+    let untitled = BottomSheetBuilder::new(true, PropValue::action("close")).build();
+    let field = |node: &SurfaceNode, key: &str| match &node.props["accessible"] {
+        PropValue::Record(info) => info[key].clone(),
+        other => panic!("expected accessible record, got {other:?}"),
+    };
+    assert_eq!(field(&titled, "role"), PropValue::String("dialog".into()));
+    assert_eq!(field(&titled, "label"), PropValue::String("Share".into()));
+    assert_eq!(field(&untitled, "label"), PropValue::String("Sheet".into()));
+}
+
+#[test]
+fn bottom_sheet_missing_required() {
+    let errors = validate_feedback_node(&SurfaceNode::new("BottomSheet"));
+    assert_eq!(
+        errors,
+        vec![
+            "BottomSheet.visible: required prop missing",
+            "BottomSheet.on_dismiss: required prop missing",
+        ]
+    );
+}
+
+#[test]
+fn bottom_sheet_wrong_types() {
+    let mut node = BottomSheetBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("visible", PropValue::String("yes".into()));
+    node.set_prop("on_dismiss", PropValue::lambda(1));
+    node.set_prop("detents", PropValue::String("medium".into()));
+    let errors = validate_feedback_node(&node);
+    assert!(errors.contains(&"BottomSheet.visible: expected bool, got string".to_string()));
+    assert!(errors.contains(&"BottomSheet.on_dismiss: expected action, got lambda".to_string()));
+    assert!(errors.contains(&"BottomSheet.detents: expected list, got string".to_string()));
+}
+
+#[test]
+fn bottom_sheet_invalid_detents() {
+    let mut node = BottomSheetBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop(
+        "detents",
+        PropValue::List(vec![
+            PropValue::String("medium".into()),
+            PropValue::String("huge".into()),
+            PropValue::String("medium".into()),
+        ]),
+    );
+    let errors = validate_feedback_node(&node);
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("BottomSheet.detents[1]: expected one of [small, medium, large]"));
+    assert_eq!(
+        errors[1],
+        "BottomSheet.detents[2]: duplicate detent \"medium\""
+    );
+
+    node.set_prop("detents", PropValue::List(vec![]));
+    assert_eq!(
+        validate_feedback_node(&node),
+        vec!["BottomSheet.detents: must not be empty"]
+    );
+}
+
+#[test]
+fn bottom_sheet_unknown_prop() {
+    let mut node = BottomSheetBuilder::new(true, PropValue::action("close")).build();
+    node.set_prop("presentation", PropValue::String("sheet".into()));
+    let errors = validate_feedback_node(&node);
+    assert_eq!(errors, vec!["BottomSheet: unknown prop 'presentation'"]);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Drawer
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn drawer_minimal() {
+    let node = DrawerBuilder::new(false, PropValue::action("close_menu")).build();
+    assert_eq!(node.component_type, "Drawer");
+    assert_eq!(node.props.get("open"), Some(&PropValue::Bool(false)));
+    assert!(!node.props.contains_key("side"));
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn drawer_side_title_children() {
+    let node = DrawerBuilder::new(true, PropValue::action("close_menu"))
+        .side(DrawerSide::End)
+        .title("Navigation")
+        .child(ButtonBuilder::new("Home", PropValue::action("go_home")).build())
+        .child(ButtonBuilder::new("Settings", PropValue::action("go_settings")).build())
+        .build();
+    assert_eq!(
+        node.props.get("side"),
+        Some(&PropValue::String("end".into()))
+    );
+    assert_eq!(node.children.len(), 2);
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn drawer_accessibility_is_modal_dialog() {
+    let node = DrawerBuilder::new(true, PropValue::action("close_menu")).build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("dialog".into()));
+    assert_eq!(info["label"], PropValue::String("Menu".into()));
+    assert_eq!(info["modal"], PropValue::Bool(true));
+}
+
+#[test]
+fn drawer_missing_required() {
+    let errors = validate_feedback_node(&SurfaceNode::new("Drawer"));
+    assert_eq!(
+        errors,
+        vec![
+            "Drawer.open: required prop missing",
+            "Drawer.on_dismiss: required prop missing",
+        ]
+    );
+}
+
+#[test]
+fn drawer_invalid_side_and_detents() {
+    let mut node = DrawerBuilder::new(true, PropValue::action("close_menu")).build();
+    node.set_prop("side", PropValue::String("left".into()));
+    node.set_prop("detents", PropValue::List(vec![]));
+    let errors = validate_feedback_node(&node);
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("Drawer.side: expected one of [start, end]"));
+    assert_eq!(errors[1], "Drawer: unknown prop 'detents'");
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Tooltip
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn help_button() -> SurfaceNode {
+    ButtonBuilder::new("?", PropValue::action("help")).build()
+}
+
+fn accessible_field(node: &SurfaceNode, field: &str) -> Option<PropValue> {
+    match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => fields.get(field).cloned(),
+        _ => None,
+    }
+}
+
+#[test]
+fn tooltip_basic_construction() {
+    let node = TooltipBuilder::new("Show keyboard shortcuts", help_button())
+        .placement(TooltipPlacement::Bottom)
+        .build();
+
+    assert_eq!(node.component_type, "Tooltip");
+    assert_eq!(
+        node.props.get("text"),
+        Some(&PropValue::String("Show keyboard shortcuts".into()))
+    );
+    assert_eq!(
+        node.props.get("placement"),
+        Some(&PropValue::String("bottom".into()))
+    );
+    assert_eq!(node.children.len(), 1);
+    assert_eq!(node.children[0].component_type, "Button");
+    assert!(validate_feedback_node(&node).is_empty());
+}
+
+#[test]
+fn tooltip_propagates_hint_to_child() {
+    let node = TooltipBuilder::new("Show keyboard shortcuts", help_button()).build();
+    let child = &node.children[0];
+    assert_eq!(
+        accessible_field(child, "hint"),
+        Some(PropValue::String("Show keyboard shortcuts".into()))
+    );
+    assert_eq!(
+        accessible_field(child, "label"),
+        Some(PropValue::String("?".into()))
+    );
+    assert_eq!(
+        accessible_field(&node, "role"),
+        Some(PropValue::String("none".into()))
+    );
+}
+
+#[test]
+fn tooltip_keeps_existing_child_hint() {
+    let child = ColumnBuilder::new().build().with_prop(
+        "accessible",
+        AccessibilityInfo::new("Chart")
+            .hint("Weekly totals")
+            .to_prop_value(),
+    );
+    let node = TooltipBuilder::new("Tap for details", child).build();
+    assert_eq!(
+        accessible_field(&node.children[0], "hint"),
+        Some(PropValue::String("Weekly totals".into()))
+    );
+}
+
+#[test]
+fn tooltip_validation_errors() {
+    let mut node = TooltipBuilder::new("Help", help_button()).build();
+    node.set_prop("text", PropValue::Number(1.0));
+    node.set_prop("placement", PropValue::String("left".into()));
+    node.set_prop("delay", PropValue::Number(300.0));
+    node.add_child(help_button());
+    let errors = validate_feedback_node(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Tooltip.text: expected string, got number",
+            "Tooltip.placement: expected one of [top, bottom, start, end], got String(\"left\")",
+            "Tooltip: expected exactly 1 child, got 2",
+            "Tooltip: unknown prop 'delay'",
+        ]
+    );
+}
+
+#[test]
+fn tooltip_missing_text_and_child() {
+    let errors = validate_feedback_node(&SurfaceNode::new("Tooltip"));
+    assert_eq!(
+        errors,
+        vec![
+            "Tooltip.text: required prop missing",
+            "Tooltip: expected exactly 1 child, got 0",
+        ]
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Determinism
 // ══════════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,87 @@
+//! Tests for the C ABI. Run with `--features ffi`.
+
+#![cfg(feature = "ffi")]
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use pepl_ui::ffi::{
+    pepl_ui_diff, pepl_ui_is_component, pepl_ui_registry_json, pepl_ui_string_free,
+    pepl_ui_validate,
+};
+
+/// Copy an owned result into a `String` and free it.
+fn take(s: *mut std::ffi::c_char) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let out = unsafe { CStr::from_ptr(s) }.to_str().unwrap().to_string();
+    unsafe { pepl_ui_string_free(s) };
+    Some(out)
+}
+
+fn c(s: &str) -> CString {
+    CString::new(s).unwrap()
+}
+
+const A: &str = r#"{"root":{"type":"Text","props":{"value":"OK"},"children":[]}}"#;
+const B: &str = r#"{"root":{"type":"Text","props":{"value":"Ok"},"children":[]}}"#;
+
+#[test]
+fn test_validate() {
+    let ok = take(unsafe { pepl_ui_validate(c(A).as_ptr()) }).unwrap();
+    assert_eq!(ok, "[]");
+    let bad = c(r#"{"root":{"type":"Text","props":{},"children":[]}}"#);
+    let errors: Vec<String> =
+        serde_json::from_str(&take(unsafe { pepl_ui_validate(bad.as_ptr()) }).unwrap()).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("root: Text.value"));
+}
+
+#[test]
+fn test_validate_bad_input() {
+    let null = take(unsafe { pepl_ui_validate(ptr::null()) }).unwrap();
+    assert_eq!(null, r#"["null input"]"#);
+    let junk = take(unsafe { pepl_ui_validate(c("{").as_ptr()) }).unwrap();
+    assert!(junk.starts_with(r#"["invalid surface JSON"#));
+}
+
+#[test]
+fn test_validate_enforces_limits() {
+    let oversized = c(&format!(
+        r#"{{"root":{{"type":"Text","props":{{"value":"{}"}},"children":[]}}}}"#,
+        "x".repeat(70_000)
+    ));
+    let errors: Vec<String> =
+        serde_json::from_str(&take(unsafe { pepl_ui_validate(oversized.as_ptr()) }).unwrap())
+            .unwrap();
+    assert_eq!(
+        errors,
+        vec!["root: value: string length 70000 exceeds limit 65536"]
+    );
+}
+
+#[test]
+fn test_diff() {
+    let same = take(unsafe { pepl_ui_diff(c(A).as_ptr(), c(A).as_ptr()) }).unwrap();
+    assert_eq!(same, "[]");
+    let diff = take(unsafe { pepl_ui_diff(c(A).as_ptr(), c(B).as_ptr()) }).unwrap();
+    let lines: Vec<String> = serde_json::from_str(&diff).unwrap();
+    assert_eq!(lines, vec![r#"root props.value: "OK" != "Ok""#]);
+    assert!(take(unsafe { pepl_ui_diff(c(A).as_ptr(), ptr::null()) }).is_none());
+}
+
+#[test]
+fn test_registry() {
+    let json: serde_json::Value =
+        serde_json::from_str(&take(pepl_ui_registry_json()).unwrap()).unwrap();
+    assert!(json["components"].is_array());
+    assert!(unsafe { pepl_ui_is_component(c("Button").as_ptr()) });
+    assert!(!unsafe { pepl_ui_is_component(c("Blink").as_ptr()) });
+    assert!(!unsafe { pepl_ui_is_component(ptr::null()) });
+}
+
+#[test]
+fn test_string_free_null_is_noop() {
+    unsafe { pepl_ui_string_free(ptr::null_mut()) };
+}
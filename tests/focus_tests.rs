@@ -0,0 +1,487 @@
+//! Tests for focus management: common focus props and `compute_initial_focus`.
+
+use pepl_ui::focus::{is_focusable, traps_focus, FOCUSABLE_COMPONENTS};
+use pepl_ui::layers::{OVERLAYS, TOASTS};
+use pepl_ui::{
+    compute_initial_focus, is_common_prop, validate_content_node, validate_feedback_node,
+    validate_interactive_node, validate_layout_node, AudioPlayerBuilder, BottomSheetBuilder,
+    ButtonBuilder, CarouselBuilder, ColumnBuilder, CommonProps, ComponentRegistry,
+    DisclosureBuilder, DrawerBuilder, FilePickerBuilder, ModalBuilder, NodePath, PathSegment,
+    PropValue, RatingBuilder, RowBuilder, SnackbarBuilder, StepperBuilder, Surface, TableBuilder,
+    TableColumn, TabsBuilder, TextBuilder, TextInputBuilder, VideoBuilder,
+};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+fn button(label: &str) -> pepl_ui::SurfaceNode {
+    ButtonBuilder::new(label, PropValue::action("tap")).build()
+}
+
+fn table() -> TableBuilder {
+    TableBuilder::new(
+        vec![TableColumn::new("name", "Name")],
+        PropValue::List(vec![]),
+        PropValue::lambda(4),
+    )
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// NodePath
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_node_path_display() {
+    assert_eq!(NodePath::root().to_string(), "root");
+    assert_eq!(NodePath::root().child(1).child(0).to_string(), "root/1/0");
+}
+
+#[test]
+fn test_node_path_accessors() {
+    let path = NodePath::from(vec![2, 3]);
+    assert_eq!(
+        path.segments(),
+        &[PathSegment::Index(2), PathSegment::Index(3)]
+    );
+    assert_eq!(path.depth(), 2);
+    assert!(!path.is_root());
+    assert!(NodePath::root().is_root());
+}
+
+#[test]
+fn test_node_path_parse() {
+    assert_eq!("root".parse::<NodePath>(), Ok(NodePath::root()));
+    assert_eq!(
+        "root/1/0".parse::<NodePath>(),
+        Ok(NodePath::from(vec![1, 0]))
+    );
+    for bad in [
+        "",
+        "root/",
+        "root/x",
+        "/1",
+        "content/1",
+        "overlays/x",
+        "root/#",
+    ] {
+        assert_eq!(
+            bad.parse::<NodePath>(),
+            Err(format!(
+                "invalid node path '{bad}': expected root/<index or #key>/..."
+            ))
+        );
+    }
+    let json = serde_json::to_string(&NodePath::from(vec![2])).unwrap();
+    assert_eq!(json, r#""root/2""#);
+    assert_eq!(
+        serde_json::from_str::<NodePath>(&json).unwrap(),
+        NodePath::from(vec![2])
+    );
+}
+
+#[test]
+fn test_node_path_in_layer() {
+    let overlay = NodePath::layer_root("overlays");
+    assert_eq!(overlay.to_string(), "overlays");
+    assert_eq!(overlay.child(0).child(2).to_string(), "overlays/0/2");
+    assert_eq!(overlay.child(0).layer(), "overlays");
+    assert_eq!(overlay.child(0).parent(), Some(overlay.clone()));
+    assert!(overlay.is_root());
+    assert_eq!("overlays/0".parse::<NodePath>(), Ok(overlay.child(0)));
+    assert_eq!(NodePath::layer_root("content"), NodePath::root());
+    assert_eq!(NodePath::root().layer(), "content");
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Focus prop validation
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_focus_props_are_common() {
+    for key in ["focus_group", "initial_focus", "focus_trap"] {
+        assert!(is_common_prop(key));
+    }
+    assert!(!is_common_prop("label"));
+}
+
+#[test]
+fn test_focus_props_accepted_everywhere() {
+    let column = ColumnBuilder::new()
+        .build()
+        .with_prop("focus_group", PropValue::String("toolbar".into()))
+        .with_prop("focus_trap", PropValue::Bool(true));
+    assert!(validate_layout_node(&column).is_empty());
+
+    let text = TextBuilder::new("Hi")
+        .build()
+        .with_prop("focus_group", PropValue::String("body".into()));
+    assert!(validate_content_node(&text).is_empty());
+
+    let input = TextInputBuilder::new("", PropValue::lambda(1))
+        .build()
+        .with_prop("initial_focus", PropValue::Bool(true));
+    assert!(validate_interactive_node(&input).is_empty());
+}
+
+#[test]
+fn test_registry_lists_common_props() {
+    let names: Vec<&str> = ComponentRegistry::common_props()
+        .iter()
+        .map(|p| p.name)
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "focus_group",
+            "initial_focus",
+            "focus_trap",
+            "on_appear",
+            "on_disappear",
+            "animate",
+            "anchor",
+            "draggable",
+            "drag_payload",
+            "drop_target",
+            "on_drop",
+            "width",
+            "height",
+            "opacity",
+            "visible",
+            "flex",
+            "align_self",
+            "pinned",
+            "position"
+        ]
+    );
+}
+
+#[test]
+fn test_focus_group_wrong_type() {
+    let node = button("OK").with_prop("focus_group", PropValue::Number(1.0));
+    let errors = validate_interactive_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Button.focus_group: expected string"));
+}
+
+#[test]
+fn test_focus_group_empty() {
+    let node = RowBuilder::new()
+        .build()
+        .with_prop("focus_group", PropValue::String(String::new()));
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Row.focus_group: must not be empty".to_string()]
+    );
+}
+
+#[test]
+fn test_focus_trap_wrong_type() {
+    let node = ModalBuilder::new(true, PropValue::action("close"))
+        .build()
+        .with_prop("focus_trap", PropValue::String("yes".into()));
+    let errors = validate_feedback_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("Modal.focus_trap: expected bool"));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Trapping and focusability
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_modal_traps_by_default() {
+    let modal = ModalBuilder::new(true, PropValue::action("close")).build();
+    assert!(traps_focus(&modal));
+    assert!(!modal.props.contains_key("focus_trap"));
+}
+
+#[test]
+fn test_modal_focus_trap_opt_out() {
+    let modal = ModalBuilder::new(true, PropValue::action("close"))
+        .focus_trap(false)
+        .build();
+    assert_eq!(modal.props["focus_trap"], PropValue::Bool(false));
+    assert!(!traps_focus(&modal));
+}
+
+#[test]
+fn test_bottom_sheet_traps_by_default() {
+    let sheet = BottomSheetBuilder::new(true, PropValue::action("close")).build();
+    assert!(traps_focus(&sheet));
+    let sheet = sheet.with_prop("focus_trap", PropValue::Bool(false));
+    assert!(!traps_focus(&sheet));
+}
+
+#[test]
+fn test_column_traps_only_when_set() {
+    assert!(!traps_focus(&ColumnBuilder::new().build()));
+    let trapped = ColumnBuilder::new()
+        .build()
+        .with_prop("focus_trap", PropValue::Bool(true));
+    assert!(traps_focus(&trapped));
+}
+
+#[test]
+fn test_disabled_button_not_focusable() {
+    assert!(is_focusable(&button("OK")));
+    let disabled = ButtonBuilder::new("OK", PropValue::action("tap"))
+        .disabled(true)
+        .build();
+    assert!(!is_focusable(&disabled));
+    assert!(!is_focusable(&TextBuilder::new("Hi").build()));
+}
+
+#[test]
+fn test_file_picker_focusable() {
+    let picker = FilePickerBuilder::new("Attach", PropValue::lambda(1));
+    assert!(is_focusable(&picker.build()));
+    let disabled = FilePickerBuilder::new("Attach", PropValue::lambda(1))
+        .disabled(true)
+        .build();
+    assert!(!is_focusable(&disabled));
+}
+
+#[test]
+fn test_every_interactive_component_focusable() {
+    let nodes = [
+        button("OK"),
+        TextInputBuilder::new("", PropValue::lambda(1)).build(),
+        FilePickerBuilder::new("Attach", PropValue::lambda(1)).build(),
+        StepperBuilder::new(1.0, PropValue::lambda(1)).build(),
+        RatingBuilder::new(3.0, 5)
+            .on_change(PropValue::lambda(1))
+            .build(),
+        TabsBuilder::new(PropValue::action("select")).build(),
+        DisclosureBuilder::new("More", false, PropValue::action("toggle")).build(),
+        CarouselBuilder::new(0, PropValue::lambda(1)).build(),
+        AudioPlayerBuilder::new("asset:song", false, PropValue::action("toggle")).build(),
+        VideoBuilder::new("asset:intro", "Intro").build(),
+        SnackbarBuilder::new("Archived")
+            .action("Undo", PropValue::action("undo"))
+            .build(),
+        table().on_sort(PropValue::lambda(1)).build(),
+    ];
+    assert_eq!(nodes.len(), FOCUSABLE_COMPONENTS.len());
+    for node in &nodes {
+        assert!(
+            is_focusable(node),
+            "{} should be focusable",
+            node.component_type
+        );
+    }
+}
+
+#[test]
+fn test_read_only_rating_not_focusable() {
+    assert!(!is_focusable(&RatingBuilder::new(3.0, 5).build()));
+}
+
+#[test]
+fn test_passive_media_and_feedback_not_focusable() {
+    let video = VideoBuilder::new("asset:intro", "Intro").controls(false);
+    assert!(!is_focusable(&video.build()));
+    assert!(!is_focusable(&SnackbarBuilder::new("Archived").build()));
+    assert!(!is_focusable(&table().build()));
+}
+
+#[test]
+fn test_initial_focus_reaches_gated_components() {
+    let only = |node| {
+        let surface = Surface::new(
+            ColumnBuilder::new()
+                .child(TextBuilder::new("Title").build())
+                .child(node)
+                .build(),
+        );
+        compute_initial_focus(&surface).map(|p| p.to_string())
+    };
+    let root_1 = Some("root/1".to_string());
+    assert_eq!(
+        only(VideoBuilder::new("asset:intro", "Intro").build()),
+        root_1
+    );
+    let snackbar = SnackbarBuilder::new("Archived").action("Undo", PropValue::action("undo"));
+    assert_eq!(only(snackbar.build()), root_1);
+    assert_eq!(only(table().on_sort(PropValue::lambda(1)).build()), root_1);
+    assert_eq!(only(table().build()), None);
+}
+
+#[test]
+fn test_initial_focus_reaches_stepper() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("Qty").build())
+            .child(StepperBuilder::new(1.0, PropValue::lambda(1)).build())
+            .build(),
+    );
+    assert_eq!(
+        compute_initial_focus(&surface).map(|p| p.to_string()),
+        Some("root/1".to_string())
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// compute_initial_focus
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_initial_focus_first_focusable() {
+    let root = ColumnBuilder::new()
+        .child(TextBuilder::new("Title").build())
+        .child(
+            RowBuilder::new()
+                .child(button("A"))
+                .child(button("B"))
+                .build(),
+        )
+        .build();
+    let path = compute_initial_focus(&Surface::new(root)).unwrap();
+    assert_eq!(path.to_string(), "root/1/0");
+}
+
+#[test]
+fn test_initial_focus_explicit_wins() {
+    let root = ColumnBuilder::new()
+        .child(button("A"))
+        .child(button("B").with_prop("initial_focus", PropValue::Bool(true)))
+        .build();
+    let path = compute_initial_focus(&Surface::new(root)).unwrap();
+    assert_eq!(path, NodePath::from(vec![1]));
+}
+
+#[test]
+fn test_initial_focus_none_when_nothing_focusable() {
+    let root = ColumnBuilder::new()
+        .child(TextBuilder::new("Read only").build())
+        .build();
+    assert_eq!(compute_initial_focus(&Surface::new(root)), None);
+}
+
+#[test]
+fn test_initial_focus_inside_open_modal() {
+    let modal = ModalBuilder::new(true, PropValue::action("close"))
+        .child(TextBuilder::new("Delete?").build())
+        .child(button("Confirm"))
+        .build();
+    let root = ColumnBuilder::new()
+        .child(button("Behind"))
+        .child(modal)
+        .build();
+    let path = compute_initial_focus(&Surface::new(root)).unwrap();
+    assert_eq!(path.to_string(), "root/1/1");
+}
+
+#[test]
+fn test_initial_focus_skips_closed_modal() {
+    let modal = ModalBuilder::new(false, PropValue::action("close"))
+        .child(button("Hidden").with_prop("initial_focus", PropValue::Bool(true)))
+        .build();
+    let root = ColumnBuilder::new()
+        .child(modal)
+        .child(button("Visible"))
+        .build();
+    let path = compute_initial_focus(&Surface::new(root)).unwrap();
+    assert_eq!(path.to_string(), "root/1");
+}
+
+#[test]
+fn test_initial_focus_skips_hidden_subtree() {
+    let hidden = RowBuilder::new()
+        .visible(false)
+        .child(button("Hidden"))
+        .build();
+    let root = ColumnBuilder::new()
+        .child(hidden)
+        .child(button("Visible"))
+        .build();
+    let path = compute_initial_focus(&Surface::new(root)).unwrap();
+    assert_eq!(path.to_string(), "root/1");
+}
+
+#[test]
+fn test_initial_focus_drawer_open_and_closed() {
+    let root = |open| {
+        Surface::new(
+            ColumnBuilder::new()
+                .child(button("Content"))
+                .child(
+                    DrawerBuilder::new(open, PropValue::action("close"))
+                        .child(button("Home"))
+                        .build(),
+                )
+                .build(),
+        )
+    };
+    assert_eq!(
+        compute_initial_focus(&root(true)).unwrap().to_string(),
+        "root/1/0"
+    );
+    assert_eq!(
+        compute_initial_focus(&root(false)).unwrap().to_string(),
+        "root/0"
+    );
+}
+
+#[test]
+fn test_initial_focus_modal_without_trap() {
+    let modal = ModalBuilder::new(true, PropValue::action("close"))
+        .focus_trap(false)
+        .child(button("In modal"))
+        .build();
+    let root = ColumnBuilder::new()
+        .child(button("Behind"))
+        .child(modal)
+        .build();
+    let path = compute_initial_focus(&Surface::new(root)).unwrap();
+    assert_eq!(path.to_string(), "root/0");
+}
+
+#[test]
+fn test_initial_focus_in_modal_layer() {
+    let modal = ModalBuilder::new(true, PropValue::action("close"))
+        .child(TextBuilder::new("Delete?").build())
+        .child(button("Confirm"))
+        .build();
+    let content = ColumnBuilder::new()
+        .child(button("Behind").with_prop("initial_focus", PropValue::Bool(true)))
+        .build();
+    let surface = Surface::new(content)
+        .with_layer(OVERLAYS, modal)
+        .with_layer(TOASTS, button("Undo"));
+    let path = compute_initial_focus(&surface).unwrap();
+    assert_eq!(path.to_string(), "overlays/1");
+    assert_eq!(surface.get(&path), Some(&button("Confirm")));
+}
+
+#[test]
+fn test_initial_focus_searches_layers_top_down() {
+    let surface = Surface::new(button("Behind")).with_layer(TOASTS, button("Undo"));
+    assert_eq!(
+        compute_initial_focus(&surface).unwrap().to_string(),
+        "toasts"
+    );
+    // An explicit initial_focus beats a plain focusable node higher up
+    let surface = Surface::new(button("Behind").with_prop("initial_focus", PropValue::Bool(true)))
+        .with_layer(TOASTS, button("Undo"));
+    assert_eq!(compute_initial_focus(&surface), Some(NodePath::root()));
+}
+
+#[test]
+fn test_initial_focus_deterministic() {
+    let build = || {
+        Surface::new(
+            ColumnBuilder::new()
+                .child(button("A"))
+                .child(
+                    ModalBuilder::new(true, PropValue::action("close"))
+                        .child(button("B"))
+                        .build(),
+                )
+                .build(),
+        )
+    };
+    let first = compute_initial_focus(&build());
+    for _ in 0..100 {
+        assert_eq!(compute_initial_focus(&build()), first);
+    }
+}
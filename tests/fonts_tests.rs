@@ -0,0 +1,69 @@
+//! Tests for `FontRegistry` and the Text `font` prop.
+
+use pepl_ui::fonts::FontRegistry;
+use pepl_ui::{validate_node, ColumnBuilder, PropValue, Surface, TextBuilder};
+
+fn fonts() -> FontRegistry {
+    FontRegistry::standard().with_family("brand", ["Inter", "Roboto"])
+}
+
+#[test]
+fn test_standard_families() {
+    let fonts = FontRegistry::standard();
+    for family in ["system", "serif", "mono"] {
+        assert!(fonts.contains(family), "{family}");
+    }
+    assert_eq!(
+        fonts.fonts("mono"),
+        Some(
+            &[
+                "SF Mono".to_string(),
+                "Roboto Mono".into(),
+                "monospace".into()
+            ][..]
+        )
+    );
+    assert_eq!(fonts.fonts("brand"), None);
+}
+
+#[test]
+fn test_font_registry_json() {
+    let fonts = FontRegistry::new().with_family("brand", ["Inter", "Roboto"]);
+    assert_eq!(fonts.to_json(), r#"{"brand":["Inter","Roboto"]}"#);
+    let back: FontRegistry = serde_json::from_str(&self::fonts().to_json()).unwrap();
+    assert_eq!(back, self::fonts());
+}
+
+#[test]
+fn test_text_font_prop() {
+    let node = TextBuilder::new("fn main() {}").font("mono").build();
+    assert_eq!(node.props["font"], PropValue::String("mono".into()));
+    assert!(validate_node(&node).is_empty());
+
+    let blank = node.clone().with_prop("font", PropValue::String("".into()));
+    assert_eq!(validate_node(&blank), vec!["Text.font: must not be empty"]);
+    let number = node.with_prop("font", PropValue::Number(1.0));
+    assert_eq!(
+        validate_node(&number),
+        vec!["Text.font: expected string, got number"]
+    );
+}
+
+#[test]
+fn test_surface_validate_fonts() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("Welcome").font("brand").build())
+            .child(TextBuilder::new("let x = 1").font("mnoo").build())
+            .child(TextBuilder::new("Fine print").font("handwriting").build())
+            .build(),
+    );
+    assert!(surface.validate().is_empty());
+    assert_eq!(
+        surface.validate_fonts(&fonts()),
+        vec![
+            "root/1: Text.font: unknown font family 'mnoo' (did you mean 'mono'?)",
+            "root/2: Text.font: unknown font family 'handwriting'",
+        ]
+    );
+}
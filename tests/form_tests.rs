@@ -0,0 +1,266 @@
+//! Tests for the Form container component.
+//!
+//! Covers construction (builder), JSON round-trip, validation (including the
+//! input-label pass), and determinism.
+
+use pepl_ui::{
+    validate_form_node, ColumnBuilder, FilePickerBuilder, FormBuilder, PropValue, StepperBuilder,
+    Surface, SurfaceNode, TextInputBuilder,
+};
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Form — Construction
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn form_minimal() {
+    let node = FormBuilder::new(PropValue::action("submit")).build();
+    assert_eq!(node.component_type, "Form");
+    assert!(matches!(
+        node.props.get("on_submit"),
+        Some(PropValue::ActionRef { .. })
+    ));
+    assert!(!node.props.contains_key("disabled"));
+    assert!(node.children.is_empty());
+}
+
+#[test]
+fn form_disabled() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .disabled(true)
+        .build();
+    assert_eq!(node.props.get("disabled"), Some(&PropValue::Bool(true)));
+}
+
+#[test]
+fn form_with_children() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .child(
+            TextInputBuilder::new("", PropValue::lambda(1))
+                .label("Name")
+                .build(),
+        )
+        .child(
+            TextInputBuilder::new("", PropValue::lambda(2))
+                .label("Email")
+                .build(),
+        )
+        .build();
+    assert_eq!(node.children.len(), 2);
+    assert_eq!(node.children[0].component_type, "TextInput");
+}
+
+#[test]
+fn form_has_group_role() {
+    let node = FormBuilder::new(PropValue::action("submit")).build();
+    match node.props.get("accessible") {
+        Some(PropValue::Record(fields)) => {
+            assert_eq!(fields.get("role"), Some(&PropValue::String("group".into())));
+            assert_eq!(fields.get("label"), Some(&PropValue::String("Form".into())));
+        }
+        other => panic!("Expected accessible record, got {:?}", other),
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Form — JSON round-trip
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn form_json_roundtrip() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .disabled(false)
+        .child(
+            TextInputBuilder::new("a", PropValue::lambda(1))
+                .label("A")
+                .build(),
+        )
+        .build();
+    let surface = Surface::new(node);
+    let json = surface.to_json();
+    let parsed: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(surface, parsed);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Form — Validation
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn form_valid_with_labelled_inputs() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .child(
+            ColumnBuilder::new()
+                .child(
+                    TextInputBuilder::new("", PropValue::lambda(1))
+                        .label("Name")
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    assert!(validate_form_node(&node).is_empty());
+    assert!(Surface::new(node).validate().is_empty());
+}
+
+#[test]
+fn form_missing_on_submit() {
+    let node = SurfaceNode::new("Form");
+    let errors = validate_form_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("on_submit") && e.contains("required")));
+}
+
+#[test]
+fn form_wrong_on_submit_type() {
+    let mut node = SurfaceNode::new("Form");
+    node.set_prop("on_submit", PropValue::lambda(1));
+    let errors = validate_form_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("on_submit") && e.contains("action")));
+}
+
+#[test]
+fn form_wrong_disabled_type() {
+    let mut node = SurfaceNode::new("Form");
+    node.set_prop("on_submit", PropValue::action("submit"));
+    node.set_prop("disabled", PropValue::Number(1.0));
+    let errors = validate_form_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("disabled") && e.contains("bool")));
+}
+
+#[test]
+fn form_unknown_prop() {
+    let mut node = SurfaceNode::new("Form");
+    node.set_prop("on_submit", PropValue::action("submit"));
+    node.set_prop("method", PropValue::String("post".into()));
+    let errors = validate_form_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("unknown prop") && e.contains("method")));
+}
+
+#[test]
+fn form_unlabelled_input_reported_with_path() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .child(
+            TextInputBuilder::new("", PropValue::lambda(1))
+                .label("Name")
+                .build(),
+        )
+        .child(
+            ColumnBuilder::new()
+                .child(
+                    TextInputBuilder::new("", PropValue::lambda(2))
+                        .placeholder("Email")
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    assert_eq!(
+        Surface::new(node).validate(),
+        vec!["root/1/0: TextInput.label: required inside Form"]
+    );
+}
+
+#[test]
+fn form_nested_form_reports_input_once() {
+    let inner = FormBuilder::new(PropValue::action("inner"))
+        .child(TextInputBuilder::new("", PropValue::lambda(1)).build())
+        .build();
+    let outer = FormBuilder::new(PropValue::action("outer"))
+        .child(ColumnBuilder::new().child(inner).build())
+        .build();
+    assert_eq!(
+        Surface::new(outer).validate(),
+        vec!["root/0/0/0: TextInput.label: required inside Form"]
+    );
+}
+
+#[test]
+fn form_unlabelled_stepper_reported() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .child(
+            StepperBuilder::new(1.0, PropValue::lambda(1))
+                .label("Guests")
+                .build(),
+        )
+        .child(StepperBuilder::new(1.0, PropValue::lambda(2)).build())
+        .build();
+    assert_eq!(
+        Surface::new(node).validate(),
+        vec!["root/1: Stepper.label: required inside Form"]
+    );
+}
+
+#[test]
+fn form_unlabelled_file_picker_reported() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .child(FilePickerBuilder::new("Attach", PropValue::lambda(1)).build())
+        .child(FilePickerBuilder::new("", PropValue::lambda(2)).build())
+        .build();
+    assert_eq!(
+        Surface::new(node).validate(),
+        vec!["root/1: FilePicker.label: required inside Form"]
+    );
+}
+
+#[test]
+fn form_input_outside_form_needs_no_label() {
+    let root = ColumnBuilder::new()
+        .child(TextInputBuilder::new("", PropValue::lambda(1)).build())
+        .build();
+    assert!(Surface::new(root).validate().is_empty());
+}
+
+#[test]
+fn form_empty_label_is_unlabelled() {
+    let node = FormBuilder::new(PropValue::action("submit"))
+        .child(
+            TextInputBuilder::new("", PropValue::lambda(1))
+                .label("")
+                .build(),
+        )
+        .build();
+    assert_eq!(
+        Surface::new(node).validate(),
+        vec!["root/0: TextInput.label: required inside Form"]
+    );
+}
+
+#[test]
+fn unknown_form_component() {
+    let node = SurfaceNode::new("Fieldset");
+    let errors = validate_form_node(&node);
+    assert!(errors.iter().any(|e| e.contains("Unknown form component")));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Determinism
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn form_determinism_100_iterations() {
+    let build = || {
+        FormBuilder::new(PropValue::action_with_args(
+            "submit",
+            vec![PropValue::Number(1.0)],
+        ))
+        .disabled(false)
+        .child(
+            TextInputBuilder::new("x", PropValue::lambda(1))
+                .label("X")
+                .build(),
+        )
+        .build()
+    };
+    let reference = Surface::new(build()).to_json();
+    for _ in 0..100 {
+        assert_eq!(Surface::new(build()).to_json(), reference);
+    }
+}
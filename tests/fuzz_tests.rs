@@ -0,0 +1,249 @@
+//! Regression tests for the fuzzing entry points: malformed payloads must
+//! be rejected or reported, never panic.
+
+use pepl_ui::{
+    ensure_accessible, fuzz_parse, fuzz_validate, validate_node, ButtonBuilder, PropValue,
+    SurfaceNode,
+};
+
+/// Deterministic pseudo-random bytes (xorshift).
+fn noise(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+const VALID: &str = r#"{"root":{"type":"Column","props":{"spacing":8},"children":[{"type":"Button","props":{"label":"OK","on_tap":{"__action":"ok"}},"children":[]}]}}"#;
+
+// ══════════════════════════════════════════════════════════════════════════════
+// fuzz_parse
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fuzz_parse_valid_payload() {
+    let surface = fuzz_parse(VALID.as_bytes()).unwrap();
+    assert_eq!(surface.root.component_type, "Column");
+    assert_eq!(surface.root.children.len(), 1);
+}
+
+#[test]
+fn test_fuzz_parse_reserializes_stably() {
+    // Numbers whose nearest f64 a fast parser can miss by one ulp
+    for number in [
+        "1759e25",
+        "0.1",
+        "2.2250738585072014e-308",
+        "9007199254740993",
+    ] {
+        let json = format!(r#"{{"root":{{"type":"X","props":{{"a":{number}}},"children":[]}}}}"#);
+        let json = fuzz_parse(json.as_bytes()).unwrap().to_json();
+        let reparsed = fuzz_parse(json.as_bytes()).unwrap();
+        assert_eq!(reparsed.to_json(), json, "{number}");
+    }
+}
+
+#[test]
+fn test_fuzz_parse_rejects_malformed_payloads() {
+    let cases: &[&[u8]] = &[
+        b"",
+        b"\xff\xfe\x00",
+        b"null",
+        b"[]",
+        b"{}",
+        b"{\"root\":null}",
+        b"{\"root\":{\"props\":{},\"children\":[]}}",
+        b"{\"root\":{\"type\":1,\"props\":{},\"children\":[]}}",
+        b"{\"root\":{\"type\":\"Text\",\"props\":{\"value\":1e999},\"children\":[]}}",
+    ];
+    for case in cases {
+        assert!(fuzz_parse(case).is_none(), "accepted {case:?}");
+    }
+}
+
+#[test]
+fn test_fuzz_parse_deep_nesting_does_not_overflow() {
+    let depth = 10_000;
+    let payload = format!(
+        "{{\"root\":{{\"type\":\"Text\",\"props\":{{\"value\":{}{}}},\"children\":[]}}}}",
+        "[".repeat(depth),
+        "]".repeat(depth)
+    );
+    assert!(fuzz_parse(payload.as_bytes()).is_none());
+}
+
+#[test]
+fn test_fuzz_parse_truncated_payloads() {
+    for end in 0..VALID.len() {
+        assert!(fuzz_parse(&VALID.as_bytes()[..end]).is_none());
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// fuzz_validate
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_fuzz_validate_valid_payload_has_no_errors() {
+    assert_eq!(fuzz_validate(VALID.as_bytes()), Some(vec![]));
+}
+
+#[test]
+fn test_fuzz_validate_unparseable_is_none() {
+    assert_eq!(fuzz_validate(b"{not json"), None);
+}
+
+#[test]
+fn test_fuzz_validate_reports_nested_errors() {
+    let payload = r#"{"root":{"type":"Column","props":{},"children":[{"type":"Blink","props":{},"children":[]},{"type":"Button","props":{"label":5},"children":[]}]},"shortcuts":{"q":{"__action":"quit"}},"route":{"path":"no-slash"}}"#;
+    let errors = fuzz_validate(payload.as_bytes()).unwrap();
+    assert!(errors
+        .iter()
+        .any(|e| e == "root/0: Unknown component: Blink"));
+    assert!(errors.iter().any(|e| e.starts_with("root/1: Button.")));
+    assert!(errors.iter().any(|e| e.contains("q")));
+    assert!(errors.iter().any(|e| e.starts_with("Surface.route.path")));
+}
+
+#[test]
+fn test_fuzz_validate_carousel_index_out_of_range() {
+    for index in ["1e300", "-1", "0.5", "2", "NaN"] {
+        let payload = format!(
+            "{{\"root\":{{\"type\":\"Carousel\",\"props\":{{\"index\":{index}}},\"children\":[{{\"type\":\"Text\",\"props\":{{\"value\":\"a\"}},\"children\":[]}},{{\"type\":\"Text\",\"props\":{{\"value\":\"b\"}},\"children\":[]}}]}}}}"
+        );
+        // "NaN" is not JSON and must simply fail to parse.
+        let _ = fuzz_validate(payload.as_bytes());
+    }
+
+    let mut carousel = SurfaceNode::new("Carousel")
+        .with_prop("index", PropValue::Number(1e300))
+        .with_child(SurfaceNode::new("Text"));
+    ensure_accessible(&mut carousel);
+    let PropValue::Record(fields) = &carousel.props["accessible"] else {
+        panic!("accessible should be a record");
+    };
+    assert!(!fields.contains_key("value"));
+
+    let mut carousel = SurfaceNode::new("Carousel")
+        .with_prop("index", PropValue::Number(1.0))
+        .with_child(SurfaceNode::new("Text"))
+        .with_child(SurfaceNode::new("Text"));
+    ensure_accessible(&mut carousel);
+    let PropValue::Record(fields) = &carousel.props["accessible"] else {
+        panic!("accessible should be a record");
+    };
+    assert_eq!(fields["value"], PropValue::String("page 2 of 2".into()));
+}
+
+#[test]
+fn test_fuzz_validate_long_multibyte_text() {
+    // Accessibility labels truncate long text; must respect char boundaries.
+    let value = "é".repeat(150);
+    let payload = format!(
+        "{{\"root\":{{\"type\":\"Text\",\"props\":{{\"value\":\"{value}\"}},\"children\":[]}}}}"
+    );
+    assert!(fuzz_validate(payload.as_bytes()).is_some());
+}
+
+#[test]
+fn test_fuzz_validate_wrong_prop_shapes_on_every_component() {
+    let odd = [
+        PropValue::Nil,
+        PropValue::Number(-1.0),
+        PropValue::String(String::new()),
+        PropValue::List(vec![PropValue::Nil]),
+        PropValue::Record(Default::default()),
+        PropValue::lambda(0),
+    ];
+    let names = [
+        "Column",
+        "Row",
+        "Scroll",
+        "Tabs",
+        "Carousel",
+        "Disclosure",
+        "Overlay",
+        "AppBar",
+        "Divider",
+        "Spacer",
+        "Text",
+        "RichText",
+        "ProgressBar",
+        "Spinner",
+        "Icon",
+        "Image",
+        "Skeleton",
+        "Button",
+        "TextInput",
+        "Stepper",
+        "Rating",
+        "FilePicker",
+        "ScrollList",
+        "Table",
+        "Modal",
+        "BottomSheet",
+        "Drawer",
+        "Toast",
+        "Snackbar",
+        "Tooltip",
+        "Form",
+        "Video",
+        "AudioPlayer",
+        "Canvas",
+        "Chart",
+    ];
+    let keys = [
+        "value",
+        "label",
+        "items",
+        "scroll_to",
+        "animate",
+        "accessible",
+        "visible",
+    ];
+    for name in names {
+        for value in &odd {
+            let mut node = SurfaceNode::new(name);
+            for key in keys {
+                node.set_prop(key, value.clone());
+            }
+            let json = format!("{{\"root\":{}}}", serde_json::to_string(&node).unwrap());
+            assert!(fuzz_validate(json.as_bytes()).is_some());
+        }
+    }
+}
+
+#[test]
+fn test_fuzz_random_bytes_and_mutations() {
+    for seed in 0..512 {
+        let bytes = noise(seed, 64);
+        let _ = fuzz_validate(&bytes);
+
+        // Flip a few bytes of a valid payload.
+        let mut mutated = VALID.as_bytes().to_vec();
+        for (i, b) in noise(seed, 4).iter().enumerate() {
+            let at = (*b as usize * 7 + i * 31) % mutated.len();
+            mutated[at] = noise(seed + 1, 4)[i];
+        }
+        let _ = fuzz_validate(&mutated);
+    }
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// validate_node
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_validate_node_dispatches_by_type() {
+    let button = ButtonBuilder::new("OK", PropValue::action("ok")).build();
+    assert!(validate_node(&button).is_empty());
+    assert_eq!(
+        validate_node(&SurfaceNode::new("Blink")),
+        vec!["Unknown component: Blink".to_string()]
+    );
+}
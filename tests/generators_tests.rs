@@ -0,0 +1,164 @@
+//! Property tests driven by the `arbitrary` generators.
+//!
+//! Run with `cargo test --features arbitrary`.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use pepl_ui::{
+    validate_content_node, validate_feedback_node, validate_form_node, validate_graphics_node,
+    validate_interactive_node, validate_layout_node, validate_list_node, validate_media_node,
+    PropValue, Surface, SurfaceNode, ValidSurface,
+};
+
+/// Deterministic pseudo-random input buffers (xorshift), one per seed.
+fn inputs() -> impl Iterator<Item = Vec<u8>> {
+    (1..=256u64).map(|seed| {
+        let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        (0..512)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    })
+}
+
+fn generate<T: for<'a> Arbitrary<'a>>(bytes: &[u8]) -> T {
+    T::arbitrary(&mut Unstructured::new(bytes)).expect("generation should not fail")
+}
+
+fn validate(node: &SurfaceNode) -> Vec<String> {
+    match node.component_type.as_str() {
+        "Column" | "Row" | "Scroll" | "Tabs" | "Carousel" | "Disclosure" | "Overlay" | "AppBar"
+        | "Divider" | "Spacer" => validate_layout_node(node),
+        "Text" | "RichText" | "ProgressBar" | "Spinner" | "Icon" | "Image" | "Skeleton" => {
+            validate_content_node(node)
+        }
+        "Button" | "TextInput" | "Stepper" | "Rating" | "FilePicker" => {
+            validate_interactive_node(node)
+        }
+        "ScrollList" | "Table" => validate_list_node(node),
+        "Modal" | "BottomSheet" | "Drawer" | "Toast" | "Snackbar" | "Tooltip" => {
+            validate_feedback_node(node)
+        }
+        "Form" => validate_form_node(node),
+        "Video" | "AudioPlayer" => validate_media_node(node),
+        "Canvas" | "Chart" => validate_graphics_node(node),
+        _ => vec![format!("unknown component: {}", node.component_type)],
+    }
+}
+
+fn validate_tree(surface: &Surface, errors: &mut Vec<String>) {
+    surface.walk(|node, _| errors.extend(validate(node)));
+}
+
+#[test]
+fn test_prop_value_json_roundtrip() {
+    for bytes in inputs() {
+        let value: PropValue = generate(&bytes);
+        let json = serde_json::to_string(&value).unwrap();
+        let back: PropValue = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, value, "roundtrip changed {json}");
+    }
+}
+
+#[test]
+fn test_surface_json_roundtrip() {
+    for bytes in inputs() {
+        let surface: Surface = generate(&bytes);
+        let json = surface.to_json();
+        let back: Surface = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, surface, "roundtrip changed {json}");
+    }
+}
+
+#[test]
+fn test_validators_never_panic_on_arbitrary_nodes() {
+    for bytes in inputs() {
+        let surface: Surface = generate(&bytes);
+        let mut errors = Vec::new();
+        validate_tree(&surface, &mut errors);
+        surface.validate();
+    }
+}
+
+#[test]
+fn test_valid_surface_passes_validation() {
+    for bytes in inputs() {
+        let ValidSurface(surface) = generate(&bytes);
+        let mut errors = Vec::new();
+        validate_tree(&surface, &mut errors);
+        assert!(errors.is_empty(), "{errors:?} in {}", surface.to_json());
+        let keyed = surface
+            .validate()
+            .into_iter()
+            .filter(|e| e.contains(".key:"));
+        assert_eq!(keyed.count(), 0, "in {}", surface.to_json());
+    }
+}
+
+#[test]
+fn test_generation_is_deterministic() {
+    let bytes = inputs().next().unwrap();
+    let a: ValidSurface = generate(&bytes);
+    let b: ValidSurface = generate(&bytes);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_generators_cover_keys_and_layers() {
+    let surfaces: Vec<Surface> = inputs().map(|bytes| generate(&bytes)).collect();
+    let mut keys = 0;
+    let mut duplicate_keys = 0;
+    for surface in &surfaces {
+        surface.walk(|node, _| {
+            let mut seen = std::collections::BTreeSet::new();
+            for key in node.children.iter().filter_map(|child| child.key.as_ref()) {
+                keys += 1;
+                duplicate_keys += usize::from(!seen.insert(key));
+            }
+        });
+    }
+    assert!(
+        keys > 0 && duplicate_keys > 0,
+        "{keys} keys, {duplicate_keys} repeated"
+    );
+    for count in 0..=2 {
+        assert!(
+            surfaces.iter().any(|surface| surface.layers.len() == count),
+            "no surface with {count} layers"
+        );
+    }
+}
+
+#[test]
+fn test_diff_then_patch_after_reordering() {
+    // Unrelated surfaces mostly differ at the root; edit one in place so the
+    // keyed child matching does the work.
+    for bytes in inputs() {
+        let before: Surface = generate(&bytes);
+        let mut after = before.clone();
+        after.walk_mut(|node, path| {
+            node.children.reverse();
+            if path.depth() % 2 == 1 && !node.children.is_empty() {
+                node.children.remove(0);
+            }
+        });
+        let mut patched = before.clone();
+        patched.apply_patch(&before.diff_patch(&after)).unwrap();
+        assert_eq!(patched, after, "in {}", before.to_json());
+    }
+}
+
+#[test]
+fn test_diff_then_patch_reproduces_target() {
+    let surfaces: Vec<Surface> = inputs().map(|bytes| generate(&bytes)).collect();
+    for pair in surfaces.windows(2) {
+        let mut patched = pair[0].clone();
+        patched.apply_patch(&pair[0].diff_patch(&pair[1])).unwrap();
+        assert_eq!(patched, pair[1]);
+    }
+}
@@ -0,0 +1,402 @@
+//! Tests for the `Canvas` and `Chart` graphics components.
+
+use std::collections::BTreeMap;
+
+use pepl_ui::{
+    validate_graphics_node, CanvasBuilder, ChartBuilder, ChartKind, ChartSeries, ColorValue,
+    DrawCommand, PropValue, Surface, SurfaceNode,
+};
+
+fn canvas_with(commands: Vec<PropValue>) -> SurfaceNode {
+    let mut node = CanvasBuilder::new(100.0, 100.0).label("Chart").build();
+    node.set_prop("commands", PropValue::List(commands));
+    node
+}
+
+fn record(fields: &[(&str, PropValue)]) -> PropValue {
+    PropValue::Record(
+        fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect::<BTreeMap<_, _>>(),
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Canvas — Construction
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_canvas_commands_serialize_as_records() {
+    let node = CanvasBuilder::new(100.0, 50.0)
+        .command(DrawCommand::rect(0.0, 0.0, 100.0, 50.0).fill(ColorValue::new(1.0, 1.0, 1.0, 1.0)))
+        .command(
+            DrawCommand::circle(50.0, 25.0, 10.0)
+                .stroke_token("primary")
+                .stroke_width(2.0),
+        )
+        .command(DrawCommand::line(0.0, 50.0, 100.0, 0.0).stroke_token("outline"))
+        .command(DrawCommand::path("M 0 0 L 10 10 Z").fill_token("accent"))
+        .label("Sales gauge")
+        .build();
+    assert_eq!(node.component_type, "Canvas");
+    let PropValue::List(commands) = &node.props["commands"] else {
+        panic!("expected commands list");
+    };
+    assert_eq!(
+        commands[1],
+        record(&[
+            ("op", PropValue::String("circle".into())),
+            ("cx", PropValue::Number(50.0)),
+            ("cy", PropValue::Number(25.0)),
+            ("r", PropValue::Number(10.0)),
+            ("stroke", PropValue::color_token("primary")),
+            ("stroke_width", PropValue::Number(2.0)),
+        ])
+    );
+    assert_eq!(
+        commands[3],
+        record(&[
+            ("op", PropValue::String("path".into())),
+            ("d", PropValue::String("M 0 0 L 10 10 Z".into())),
+            ("fill", PropValue::color_token("accent")),
+        ])
+    );
+    assert!(validate_graphics_node(&node).is_empty());
+}
+
+#[test]
+fn test_canvas_accessibility() {
+    let labelled = CanvasBuilder::new(10.0, 10.0).label("Battery 25%").build();
+    let PropValue::Record(info) = &labelled.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Battery 25%".into()));
+    assert_eq!(info["role"], PropValue::String("image".into()));
+    assert!(!info.contains_key("hidden"));
+
+    let decorative = CanvasBuilder::new(10.0, 10.0).build();
+    let PropValue::Record(info) = &decorative.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("none".into()));
+    assert_eq!(info["hidden"], PropValue::Bool(true));
+}
+
+#[test]
+fn test_canvas_json_roundtrip() {
+    let surface = Surface::new(
+        CanvasBuilder::new(10.0, 10.0)
+            .command(DrawCommand::rect(1.0, 1.0, 8.0, 8.0).fill_token("primary"))
+            .build(),
+    );
+    let back: Surface = serde_json::from_str(&surface.to_json()).unwrap();
+    assert_eq!(back, surface);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Canvas — Validation
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_canvas_required_props() {
+    assert_eq!(
+        validate_graphics_node(&SurfaceNode::new("Canvas")),
+        vec![
+            "Canvas.width: required prop missing",
+            "Canvas.height: required prop missing",
+            "Canvas.commands: required prop missing",
+        ]
+    );
+    let node = CanvasBuilder::new(0.0, -1.0).build();
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec![
+            "Canvas.width: must be positive, got 0",
+            "Canvas.height: must be positive, got -1",
+        ]
+    );
+}
+
+#[test]
+fn test_canvas_command_op_errors() {
+    let node = canvas_with(vec![
+        record(&[("op", PropValue::String("ellipse".into()))]),
+        record(&[("fill", PropValue::color_token("primary"))]),
+        PropValue::Number(1.0),
+    ]);
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec![
+            "Canvas.commands[0].op: expected one of [line, rect, circle, path], got \"ellipse\"",
+            "Canvas.commands[1].op: required field missing",
+            "Canvas.commands[2]: expected record, got number",
+        ]
+    );
+}
+
+#[test]
+fn test_canvas_command_geometry_and_paint_errors() {
+    let node = canvas_with(vec![
+        record(&[
+            ("op", PropValue::String("rect".into())),
+            ("x", PropValue::Number(0.0)),
+            ("y", PropValue::String("0".into())),
+            ("width", PropValue::Number(-5.0)),
+            ("r", PropValue::Number(2.0)),
+        ]),
+        DrawCommand::line(0.0, 0.0, 1.0, 1.0)
+            .fill_token("primary")
+            .to_prop_value(),
+        DrawCommand::circle(5.0, 5.0, 5.0)
+            .stroke_token("primary")
+            .stroke_width(0.0)
+            .to_prop_value(),
+    ]);
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec![
+            "Canvas.commands[0].y: expected number, got string",
+            "Canvas.commands[0].width: must be non-negative, got -5",
+            "Canvas.commands[0].height: required field missing",
+            "Canvas.commands[0]: expected fill or stroke",
+            "Canvas.commands[0]: unknown field 'r' for op \"rect\"",
+            "Canvas.commands[1].fill: not valid for op \"line\"",
+            "Canvas.commands[1].stroke: required field missing",
+            "Canvas.commands[2].stroke_width: must be positive, got 0",
+        ]
+    );
+}
+
+#[test]
+fn test_canvas_path_data() {
+    for d in [
+        "M0,0 L10,10 Z",
+        "m 1 1 h 5 v 5 z",
+        "M 0 0 C 1 1 2 2 3 3 S 4 4 5 5 Q 1 1 2 2 T 3 3",
+        "M 50 10 A 40 40 0 0 1 90 50",
+        "M-1.5-2e1L.5.5",
+        "M 0 0 1 1 2 2",
+    ] {
+        let node = canvas_with(vec![DrawCommand::path(d)
+            .stroke_token("ink")
+            .to_prop_value()]);
+        assert!(validate_graphics_node(&node).is_empty(), "{d}");
+    }
+
+    for (d, error) in [
+        ("", "must not be empty"),
+        ("L 0 0", "must start with a moveto (M), got 'L'"),
+        (
+            "M 0 0 C 1 1 2 2",
+            "'C' expects a multiple of 6 arguments, got 4",
+        ),
+        ("M 0 0 Z 1", "'Z' expects no arguments, got 1"),
+        ("M 0 0 X 1", "expected a path command, got 'X'"),
+    ] {
+        let node = canvas_with(vec![DrawCommand::path(d)
+            .stroke_token("ink")
+            .to_prop_value()]);
+        assert_eq!(
+            validate_graphics_node(&node),
+            vec![format!("Canvas.commands[0].d: {error}")],
+            "{d}"
+        );
+    }
+}
+
+#[test]
+fn test_canvas_no_children_and_unknown_prop() {
+    let mut node = CanvasBuilder::new(10.0, 10.0).build();
+    node.children.push(SurfaceNode::new("Text"));
+    node.set_prop("scale", PropValue::Number(2.0));
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec![
+            "Canvas: does not accept children, but got 1",
+            "Canvas: unknown prop 'scale'",
+        ]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Chart
+// ═══════════════════════════════════════════════════════════════════════════════
+
+fn accessible_value(node: &SurfaceNode) -> Option<PropValue> {
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    info.get("value").cloned()
+}
+
+#[test]
+fn test_chart_bar_with_labels() {
+    let node = ChartBuilder::new(
+        ChartKind::Bar,
+        vec![ChartSeries::new("Steps", vec![4200.0, 8100.0, 6500.0]).color_token("primary")],
+    )
+    .labels(vec!["Mon", "Tue", "Wed"])
+    .title("Steps this week")
+    .build();
+    assert_eq!(node.component_type, "Chart");
+    assert_eq!(node.props["kind"], PropValue::String("bar".into()));
+    assert_eq!(
+        node.props["series"],
+        PropValue::List(vec![record(&[
+            ("name", PropValue::String("Steps".into())),
+            (
+                "values",
+                PropValue::List(vec![
+                    PropValue::Number(4200.0),
+                    PropValue::Number(8100.0),
+                    PropValue::Number(6500.0),
+                ])
+            ),
+            ("color", PropValue::color_token("primary")),
+        ])])
+    );
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Steps this week".into()));
+    assert_eq!(info["role"], PropValue::String("image".into()));
+    assert_eq!(
+        info["value"],
+        PropValue::String("Steps: 3 values from 4200 to 8100, last 6500".into())
+    );
+    assert!(validate_graphics_node(&node).is_empty());
+}
+
+#[test]
+fn test_chart_line_summarizes_each_series() {
+    let node = ChartBuilder::new(
+        ChartKind::Line,
+        vec![
+            ChartSeries::new("2025", vec![1.0, 3.0]),
+            ChartSeries::new("2026", vec![2.0, 1.5]),
+        ],
+    )
+    .build();
+    assert_eq!(
+        accessible_value(&node),
+        Some(PropValue::String(
+            "2025: 2 values from 1 to 3, last 3; 2026: 2 values from 1.5 to 2, last 1.5".into()
+        ))
+    );
+    assert!(validate_graphics_node(&node).is_empty());
+}
+
+#[test]
+fn test_chart_pie_summary_uses_percentages() {
+    let node = ChartBuilder::new(
+        ChartKind::Pie,
+        vec![ChartSeries::new("Budget", vec![1.0, 3.0])],
+    )
+    .labels(vec!["Rent"])
+    .build();
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec!["Chart.labels: expected 2 labels (one per value), got 1"]
+    );
+    assert_eq!(
+        accessible_value(&node),
+        Some(PropValue::String("Rent 25%, slice 2 75%".into()))
+    );
+}
+
+#[test]
+fn test_chart_required_props() {
+    assert_eq!(
+        validate_graphics_node(&SurfaceNode::new("Chart")),
+        vec![
+            "Chart.kind: required prop missing",
+            "Chart.series: required prop missing",
+        ]
+    );
+    let node = ChartBuilder::new(ChartKind::Line, vec![]).build();
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec!["Chart.series: must not be empty"]
+    );
+}
+
+#[test]
+fn test_chart_single_series_kinds() {
+    let series = vec![
+        ChartSeries::new("a", vec![1.0]),
+        ChartSeries::new("b", vec![2.0]),
+    ];
+    let node = ChartBuilder::new(ChartKind::Pie, series.clone()).build();
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec!["Chart.series: kind \"pie\" takes exactly one series, got 2"]
+    );
+    let node = ChartBuilder::new(ChartKind::Sparkline, series[..1].to_vec())
+        .labels(vec!["x"])
+        .build();
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec!["Chart.labels: not valid for kind \"sparkline\""]
+    );
+}
+
+#[test]
+fn test_chart_series_validation() {
+    let mut node = ChartBuilder::new(ChartKind::Pie, vec![]).build();
+    node.set_prop("kind", PropValue::String("pie".into()));
+    node.set_prop(
+        "series",
+        PropValue::List(vec![record(&[
+            (
+                "values",
+                PropValue::List(vec![
+                    PropValue::Number(-1.0),
+                    PropValue::Number(f64::NAN),
+                    PropValue::String("3".into()),
+                ]),
+            ),
+            ("color", PropValue::Number(1.0)),
+            ("stack", PropValue::Bool(true)),
+        ])]),
+    );
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec![
+            "Chart.series[0].name: required field missing",
+            "Chart.series[0].values[0]: pie values must be non-negative, got -1",
+            "Chart.series[0].values[1]: must be finite, got NaN",
+            "Chart.series[0].values[2]: expected number, got string",
+            "Chart.series[0].color: expected color, got number",
+            "Chart.series[0]: unknown field 'stack'",
+        ]
+    );
+}
+
+#[test]
+fn test_chart_series_lengths_must_match() {
+    let node = ChartBuilder::new(
+        ChartKind::Bar,
+        vec![
+            ChartSeries::new("a", vec![1.0, 2.0]),
+            ChartSeries::new("b", vec![1.0]),
+        ],
+    )
+    .build();
+    assert_eq!(
+        validate_graphics_node(&node),
+        vec!["Chart.series[1].values: expected 2 values like series[0], got 1"]
+    );
+}
+
+#[test]
+fn test_chart_invalid_kind_and_unknown_prop() {
+    let mut node =
+        ChartBuilder::new(ChartKind::Line, vec![ChartSeries::new("a", vec![1.0])]).build();
+    node.set_prop("kind", PropValue::String("radar".into()));
+    node.set_prop("legend", PropValue::Bool(true));
+    let errors = validate_graphics_node(&node);
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("Chart.kind: expected one of [line, bar, pie, sparkline]"));
+    assert_eq!(errors[1], "Chart: unknown prop 'legend'");
+}
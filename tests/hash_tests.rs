@@ -0,0 +1,107 @@
+//! Tests for `Surface::structural_hash` and `Surface::subtree_hashes`.
+
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, NodePath, PropValue, Route, Surface, SurfaceNode, TextBuilder,
+};
+
+fn path(s: &str) -> NodePath {
+    s.parse().unwrap()
+}
+
+fn screen(label: &str) -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .spacing(8.0)
+            .child(TextBuilder::new("Title").build())
+            .child(
+                ColumnBuilder::new()
+                    .child(ButtonBuilder::new(label, PropValue::action("ok")).build())
+                    .build(),
+            )
+            .build(),
+    )
+}
+
+#[test]
+fn test_equal_surfaces_hash_equal() {
+    assert_eq!(
+        screen("OK").structural_hash(),
+        screen("OK").structural_hash()
+    );
+    assert_ne!(
+        screen("OK").structural_hash(),
+        screen("Ok").structural_hash()
+    );
+}
+
+#[test]
+fn test_hash_is_stable_across_runs() {
+    // Pinned: a change here breaks hashes hosts may have cached.
+    assert_eq!(
+        Surface::new(SurfaceNode::new("Text").with_prop("value", "hi".into())).structural_hash(),
+        0xd546_5f0b_9d12_1c9e
+    );
+}
+
+#[test]
+fn test_hash_covers_every_field() {
+    let base = || SurfaceNode::new("Column").with_child(SurfaceNode::new("Text"));
+    let variants = [
+        base(),
+        base().with_test_id("a"),
+        base().with_key("a"),
+        base().with_prop("spacing", PropValue::Number(8.0)),
+        base().with_prop("spacing", PropValue::String("8".into())),
+        base().with_child(SurfaceNode::new("Text")),
+        SurfaceNode::new("Row").with_child(SurfaceNode::new("Text")),
+    ];
+    let mut hashes: Vec<u64> = variants.iter().map(SurfaceNode::structural_hash).collect();
+    hashes.sort();
+    hashes.dedup();
+    assert_eq!(hashes.len(), variants.len());
+
+    let surface = Surface::new(base());
+    let others = [
+        surface.clone().with_route(Route::new("/home")),
+        surface.clone().with_respect_safe_area(true),
+        surface
+            .clone()
+            .with_shortcut("cmd+s", PropValue::action("save")),
+    ];
+    for other in others {
+        assert_ne!(surface.structural_hash(), other.structural_hash());
+    }
+}
+
+#[test]
+fn test_child_order_matters() {
+    let a = TextBuilder::new("a").build();
+    let b = TextBuilder::new("b").build();
+    let ab = SurfaceNode::new("Column").with_children(vec![a.clone(), b.clone()]);
+    let ba = SurfaceNode::new("Column").with_children(vec![b, a]);
+    assert_ne!(ab.structural_hash(), ba.structural_hash());
+}
+
+#[test]
+fn test_negative_zero_hashes_like_zero() {
+    let zero = SurfaceNode::new("Spacer").with_prop("size", PropValue::Number(0.0));
+    let negative = SurfaceNode::new("Spacer").with_prop("size", PropValue::Number(-0.0));
+    assert_eq!(zero, negative);
+    assert_eq!(zero.structural_hash(), negative.structural_hash());
+}
+
+#[test]
+fn test_subtree_hashes() {
+    let before = screen("OK").subtree_hashes();
+    let after = screen("Ok").subtree_hashes();
+    assert_eq!(before.len(), 4);
+    assert_eq!(
+        before[&NodePath::root()],
+        screen("OK").root.structural_hash()
+    );
+    // Only the changed node and its ancestors change
+    assert_eq!(before[&path("root/0")], after[&path("root/0")]);
+    for changed in ["root", "root/1", "root/1/0"] {
+        assert_ne!(before[&path(changed)], after[&path(changed)], "{changed}");
+    }
+}
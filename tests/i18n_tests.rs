@@ -0,0 +1,243 @@
+//! Tests for `PropValue::I18n` references and `Surface::localize`.
+
+use std::collections::BTreeMap;
+
+use pepl_ui::i18n::{plural_category, Message, PluralCategory, StringCatalog};
+use pepl_ui::{
+    validate_node, ButtonBuilder, ColumnBuilder, PropValue, Surface, SurfaceNode, TextBuilder,
+};
+
+fn args(pairs: &[(&str, PropValue)]) -> BTreeMap<String, PropValue> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect()
+}
+
+fn string(s: &str) -> PropValue {
+    PropValue::String(s.into())
+}
+
+fn catalog() -> StringCatalog {
+    StringCatalog::new("en")
+        .with_message("greeting", "Hello, {name}!")
+        .with_message("save", "Save")
+        .with_plural(
+            "cart.items",
+            [
+                (PluralCategory::One, "{count} item"),
+                (PluralCategory::Other, "{count} items"),
+            ],
+        )
+}
+
+// ── Serialization ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_i18n_serialization() {
+    let plain = PropValue::i18n("save");
+    assert_eq!(
+        serde_json::to_string(&plain).unwrap(),
+        r#"{"__i18n":"save"}"#
+    );
+
+    let with_args = PropValue::i18n_with_args("greeting", args(&[("name", string("Ada"))]));
+    let json = serde_json::to_string(&with_args).unwrap();
+    assert_eq!(json, r#"{"__i18n":"greeting","__args":{"name":"Ada"}}"#);
+    assert_eq!(serde_json::from_str::<PropValue>(&json).unwrap(), with_args);
+    assert_eq!(
+        serde_json::from_str::<PropValue>(r#"{"__i18n":"save"}"#).unwrap(),
+        plain
+    );
+}
+
+#[test]
+fn test_i18n_is_text() {
+    assert!(PropValue::i18n("save").is_text());
+    assert!(string("x").is_text());
+    assert!(!PropValue::Number(1.0).is_text());
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_i18n_accepted_in_string_props() {
+    let nodes = [
+        TextBuilder::i18n("greeting").build(),
+        SurfaceNode::new("Button")
+            .with_prop("label", PropValue::i18n("save"))
+            .with_prop("on_tap", PropValue::action("save")),
+        SurfaceNode::new("TextInput")
+            .with_prop("value", string(""))
+            .with_prop("on_change", PropValue::lambda(1))
+            .with_prop("label", PropValue::i18n("name"))
+            .with_prop("placeholder", PropValue::i18n("name.hint")),
+        SurfaceNode::new("Toast").with_prop("message", PropValue::i18n("saved")),
+    ];
+    for node in nodes {
+        assert_eq!(validate_node(&node), Vec::<String>::new(), "{node:?}");
+    }
+}
+
+#[test]
+fn test_i18n_rejected_for_text_input_value() {
+    let node = SurfaceNode::new("TextInput")
+        .with_prop("value", PropValue::i18n("name"))
+        .with_prop("on_change", PropValue::lambda(1));
+    assert_eq!(
+        validate_node(&node),
+        vec!["TextInput.value: expected string, got i18n"]
+    );
+}
+
+#[test]
+fn test_builder_keeps_i18n_accessible_label() {
+    let node = TextBuilder::i18n("greeting").build();
+    let PropValue::Record(accessible) = &node.props["accessible"] else {
+        panic!("accessible should be a record");
+    };
+    assert_eq!(accessible["label"], PropValue::i18n("greeting"));
+}
+
+// ── Localization ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_localize_resolves_text_and_args() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(
+                TextBuilder::i18n_with_args("greeting", args(&[("name", string("Ada"))])).build(),
+            )
+            .child(
+                SurfaceNode::new("Button")
+                    .with_prop("label", PropValue::i18n("save"))
+                    .with_prop("on_tap", PropValue::action("save")),
+            )
+            .build(),
+    );
+    let localized = surface.localize(&catalog());
+    let text = &localized.root.children[0];
+    assert_eq!(text.props["value"], string("Hello, Ada!"));
+    let PropValue::Record(accessible) = &text.props["accessible"] else {
+        panic!("accessible should be a record");
+    };
+    assert_eq!(accessible["label"], string("Hello, Ada!"));
+    assert_eq!(localized.root.children[1].props["label"], string("Save"));
+    assert!(localized.validate().is_empty());
+}
+
+#[test]
+fn test_localize_plural() {
+    let catalog = catalog();
+    for (count, expected) in [(0.0, "0 items"), (1.0, "1 item"), (3.0, "3 items")] {
+        let text =
+            TextBuilder::i18n_with_args("cart.items", args(&[("count", PropValue::Number(count))]))
+                .build();
+        let localized = Surface::new(text).localize(&catalog);
+        assert_eq!(localized.root.props["value"], string(expected));
+    }
+}
+
+#[test]
+fn test_localize_nested_reference_in_args() {
+    let catalog = catalog();
+    let value = PropValue::i18n_with_args("greeting", args(&[("name", PropValue::i18n("save"))]));
+    let node = SurfaceNode::new("Text").with_prop("value", value);
+    let localized = Surface::new(node).localize(&catalog);
+    assert_eq!(localized.root.props["value"], string("Hello, Save!"));
+}
+
+#[test]
+fn test_localize_missing_key_falls_back_to_key() {
+    let node = SurfaceNode::new("Text").with_prop("value", PropValue::i18n("missing.key"));
+    let localized = Surface::new(node).localize(&catalog());
+    assert_eq!(localized.root.props["value"], string("missing.key"));
+}
+
+#[test]
+fn test_plural_without_form_falls_back_to_other() {
+    let catalog =
+        StringCatalog::new("ar").with_plural("n", [(PluralCategory::Other, "{count} things")]);
+    let resolved = catalog.resolve("n", &args(&[("count", PropValue::Number(2.0))]));
+    assert_eq!(resolved.as_deref(), Some("2 things"));
+    assert_eq!(
+        catalog.resolve("n", &BTreeMap::new()).as_deref(),
+        Some("{count} things")
+    );
+    assert_eq!(catalog.resolve("unknown", &BTreeMap::new()), None);
+}
+
+#[test]
+fn test_validate_i18n_reports_missing_keys() {
+    let catalog = StringCatalog::new("fr").with_message("save", "Enregistrer");
+    let root = ColumnBuilder::new()
+        .child(
+            ButtonBuilder::new("Save", PropValue::action("save"))
+                .build()
+                .with_prop("label", PropValue::i18n("save")),
+        )
+        .child(SurfaceNode::new("Text").with_prop("value", PropValue::i18n("greeting")))
+        .build();
+    assert_eq!(
+        Surface::new(root).validate_i18n(&catalog),
+        vec!["root/1: Text.value: missing translation 'greeting' for locale 'fr'"]
+    );
+}
+
+#[test]
+fn test_catalog_json_roundtrip() {
+    let json = r#"{"locale":"en","messages":{"cart.items":{"one":"{count} item","other":"{count} items"},"save":"Save"}}"#;
+    let catalog: StringCatalog = serde_json::from_str(json).unwrap();
+    assert_eq!(catalog.get("save"), Some(&Message::Text("Save".into())));
+    assert_eq!(catalog, catalog_without_greeting());
+    assert_eq!(serde_json::to_string(&catalog).unwrap(), json);
+}
+
+fn catalog_without_greeting() -> StringCatalog {
+    StringCatalog::new("en")
+        .with_message("save", "Save")
+        .with_plural(
+            "cart.items",
+            [
+                (PluralCategory::One, "{count} item"),
+                (PluralCategory::Other, "{count} items"),
+            ],
+        )
+}
+
+// ── Plural rules ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_plural_rules() {
+    use PluralCategory::*;
+    let cases = [
+        ("en", 1.0, One),
+        ("en-US", 0.0, Other),
+        ("en", 1.5, Other),
+        ("fr", 0.0, One),
+        ("fr-CA", 1.5, One),
+        ("fr", 2.0, Other),
+        ("ja", 1.0, Other),
+        ("ru", 1.0, One),
+        ("ru", 21.0, One),
+        ("ru", 11.0, Many),
+        ("ru", 3.0, Few),
+        ("ru", 13.0, Many),
+        ("ru", 5.0, Many),
+        ("ru", 1.5, Other),
+        ("pl", 1.0, One),
+        ("pl", 22.0, Few),
+        ("pl", 21.0, Many),
+        ("cs", 3.0, Few),
+        ("cs", 5.0, Other),
+        ("cs", 1.5, Many),
+        ("ar", 0.0, Zero),
+        ("ar", 2.0, Two),
+        ("ar", 103.0, Few),
+        ("ar", 111.0, Many),
+        ("ar", 100.0, Other),
+    ];
+    for (locale, n, expected) in cases {
+        assert_eq!(plural_category(locale, n), expected, "{locale} {n}");
+    }
+}
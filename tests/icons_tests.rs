@@ -0,0 +1,70 @@
+//! Tests for icon name validation and `IconRegistry`.
+
+use pepl_ui::icons::{IconRegistry, CANONICAL_ICONS};
+use pepl_ui::{validate_node, ButtonBuilder, ColumnBuilder, IconBuilder, PropValue, Surface};
+
+#[test]
+fn test_canonical_icons_sorted_and_unique() {
+    assert!(CANONICAL_ICONS.windows(2).all(|pair| pair[0] < pair[1]));
+    let registry = IconRegistry::new();
+    assert!(CANONICAL_ICONS.iter().all(|name| registry.contains(name)));
+    assert_eq!(registry.names().len(), CANONICAL_ICONS.len());
+}
+
+#[test]
+fn test_registry_host_icons() {
+    let registry = IconRegistry::new().with_icon("brand_logo");
+    assert!(registry.contains("asset:brand_logo"));
+    assert!(!registry.contains("brand_logo"));
+    assert!(!IconRegistry::new().contains("asset:brand_logo"));
+    assert_eq!(registry.names().last().unwrap(), "asset:brand_logo");
+}
+
+#[test]
+fn test_suggest_closest_name() {
+    let registry = IconRegistry::new();
+    assert_eq!(registry.suggest("serch").as_deref(), Some("search"));
+    assert_eq!(registry.suggest("setings").as_deref(), Some("settings"));
+    assert_eq!(registry.suggest("Home").as_deref(), Some("home"));
+    assert_eq!(registry.suggest("spaceship"), None);
+}
+
+#[test]
+fn test_button_icon_validated() {
+    let node = ButtonBuilder::new("Find", PropValue::action("find"))
+        .icon("serch")
+        .build();
+    assert_eq!(
+        validate_node(&node),
+        vec!["Button.icon: unknown icon 'serch' (did you mean 'search'?)"]
+    );
+
+    let node = ButtonBuilder::new("Share", PropValue::action("share"))
+        .icon("asset:share_sheet")
+        .build();
+    assert!(validate_node(&node).is_empty());
+}
+
+#[test]
+fn test_surface_validate_icons() {
+    let registry = IconRegistry::new().with_icon("brand_logo");
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(IconBuilder::new("asset:brnd_logo").build())
+            .child(
+                ButtonBuilder::new("Go", PropValue::action("go"))
+                    .icon("asset:brand_logo")
+                    .build(),
+            )
+            .child(IconBuilder::new("home").build())
+            .build(),
+    );
+    // Node validation cannot know the host's icons
+    assert!(surface.validate().is_empty());
+    assert_eq!(
+        surface.validate_icons(&registry),
+        vec![
+            "root/0: Icon.name: unknown icon 'asset:brnd_logo' (did you mean 'asset:brand_logo'?)"
+        ]
+    );
+}
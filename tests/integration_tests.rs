@@ -15,6 +15,7 @@ use pepl_ui::{
 };
 
 use pepl_ui::components::content::{TextSize, TextWeight};
+use pepl_ui::layers::{OVERLAYS, TOASTS};
 use std::collections::BTreeMap;
 use std::time::Instant;
 
@@ -22,7 +23,8 @@ use std::time::Instant;
 // Helpers
 // ══════════════════════════════════════════════════════════════════════════════
 
-/// Build a Surface tree containing all 10 Phase 0 components.
+/// Build a Surface containing all 10 Phase 0 components, with the Modal and
+/// Toast in their own layers above the content tree.
 fn all_components_tree() -> Surface {
     let text = TextBuilder::new("Hello").build();
     let progress = ProgressBarBuilder::new(0.5).build();
@@ -45,23 +47,15 @@ fn all_components_tree() -> Surface {
         .child(progress)
         .child(scroll)
         .child(scroll_list)
-        .child(modal)
-        .child(toast)
         .build();
     Surface::new(column)
-}
-
-/// Count all nodes in a Surface tree.
-fn count_nodes(node: &SurfaceNode) -> usize {
-    1 + node.children.iter().map(count_nodes).sum::<usize>()
+        .with_layer(OVERLAYS, modal)
+        .with_layer(TOASTS, toast)
 }
 
 /// Collect all component type names from a Surface tree.
 fn collect_types(node: &SurfaceNode, out: &mut Vec<String>) {
-    out.push(node.component_type.clone());
-    for child in &node.children {
-        collect_types(child, out);
-    }
+    node.walk(|node, _| out.push(node.component_type.clone()));
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -72,7 +66,9 @@ fn collect_types(node: &SurfaceNode, out: &mut Vec<String>) {
 fn test_all_10_components_in_one_tree() {
     let surface = all_components_tree();
     let mut types = Vec::new();
-    collect_types(&surface.root, &mut types);
+    for (_, root) in surface.layer_roots() {
+        collect_types(root, &mut types);
+    }
 
     let expected = [
         "Column",
@@ -423,21 +419,46 @@ fn test_full_tree_render_budget() {
 #[test]
 fn test_registry_validates_all_10_components() {
     let reg = ComponentRegistry::new();
-    assert_eq!(reg.len(), 10);
+    assert_eq!(reg.len(), 35);
     let names = reg.component_names();
     assert_eq!(
         names,
         vec![
+            "AppBar",
+            "AudioPlayer",
+            "BottomSheet",
             "Button",
+            "Canvas",
+            "Carousel",
+            "Chart",
             "Column",
+            "Disclosure",
+            "Divider",
+            "Drawer",
+            "FilePicker",
+            "Form",
+            "Icon",
+            "Image",
             "Modal",
+            "Overlay",
             "ProgressBar",
+            "Rating",
+            "RichText",
             "Row",
             "Scroll",
             "ScrollList",
+            "Skeleton",
+            "Snackbar",
+            "Spacer",
+            "Spinner",
+            "Stepper",
+            "Table",
+            "Tabs",
             "Text",
             "TextInput",
             "Toast",
+            "Tooltip",
+            "Video",
         ]
     );
 }
@@ -497,7 +518,7 @@ fn test_children_acceptance_rules() {
     let reg = ComponentRegistry::new();
 
     // Container components accept children
-    for name in &["Column", "Row", "Scroll", "Modal"] {
+    for name in &["Column", "Row", "Scroll", "Modal", "BottomSheet", "Drawer"] {
         let def = reg.get(name).unwrap();
         assert!(def.accepts_children(), "{} should accept children", name);
     }
@@ -773,9 +794,9 @@ fn test_accessible_prop_serializes_cleanly() {
 #[test]
 fn test_deep_tree_node_count() {
     let surface = all_components_tree();
-    let count = count_nodes(&surface.root);
+    let count = surface.stats().complexity.node_count;
     // Column > (Row > (Button, TextInput)), ProgressBar, (Scroll > Text),
-    //          ScrollList, (Modal > Text), Toast
+    //          ScrollList; overlays: Modal > Text; toasts: Toast
     // = 1 + (1 + 2) + 1 + (1 + 1) + 1 + (1 + 1) + 1 = 11
     assert!(
         count >= 10,
@@ -1,11 +1,13 @@
-//! Tests for interactive components — Button and TextInput.
+//! Tests for interactive components — Button, TextInput, Stepper, Rating, and
+//! FilePicker.
 //!
 //! Covers construction (builder), JSON round-trip, validation, and
 //! determinism. Follows the same pattern as content_tests.rs.
 
 use pepl_ui::{
-    validate_interactive_node, ButtonBuilder, ButtonVariant, KeyboardType, PropValue, Surface,
-    SurfaceNode, TextInputBuilder,
+    validate_interactive_node, Autocapitalize, ButtonBuilder, ButtonSize, ButtonVariant,
+    FilePickerBuilder, Haptic, KeyboardType, PropValue, RatingBuilder, StepperBuilder, Surface,
+    SurfaceNode, TapSound, TextInputBuilder,
 };
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -60,6 +62,33 @@ fn button_with_variant_text() {
     );
 }
 
+#[test]
+fn button_sizes() {
+    for (size, expected) in [
+        (ButtonSize::Small, "small"),
+        (ButtonSize::Medium, "medium"),
+        (ButtonSize::Large, "large"),
+    ] {
+        let node = ButtonBuilder::new("OK", PropValue::action("ok"))
+            .size(size)
+            .build();
+        assert_eq!(
+            node.props.get("size"),
+            Some(&PropValue::String(expected.into()))
+        );
+    }
+}
+
+#[test]
+fn button_full_width() {
+    let node = ButtonBuilder::new("Continue", PropValue::action("next"))
+        .size(ButtonSize::Large)
+        .full_width(true)
+        .build();
+    assert_eq!(node.props.get("full_width"), Some(&PropValue::Bool(true)));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
 #[test]
 fn button_with_icon() {
     let node = ButtonBuilder::new("Delete", PropValue::action("del"))
@@ -87,6 +116,65 @@ fn button_loading() {
     assert_eq!(node.props.get("loading"), Some(&PropValue::Bool(true)));
 }
 
+#[test]
+fn button_on_long_press() {
+    let node = ButtonBuilder::new("+", PropValue::action("increment"))
+        .on_long_press(PropValue::action_with_args(
+            "increment_by",
+            vec![PropValue::Number(10.0)],
+        ))
+        .build();
+    assert!(matches!(
+        node.props.get("on_long_press"),
+        Some(PropValue::ActionRef { action, .. }) if action == "increment_by"
+    ));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn button_press_state_events() {
+    let node = ButtonBuilder::new("Hold", PropValue::action("tap"))
+        .on_press_down(PropValue::action("start_repeat"))
+        .on_press_up(PropValue::action("stop_repeat"))
+        .build();
+    assert_eq!(
+        node.props.get("on_press_down"),
+        Some(&PropValue::action("start_repeat"))
+    );
+    assert_eq!(
+        node.props.get("on_press_up"),
+        Some(&PropValue::action("stop_repeat"))
+    );
+    assert!(!node.props.contains_key("on_long_press"));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn button_haptic_and_sound() {
+    let cases = [
+        (Haptic::Light, "light"),
+        (Haptic::Medium, "medium"),
+        (Haptic::Heavy, "heavy"),
+        (Haptic::Success, "success"),
+        (Haptic::Error, "error"),
+    ];
+    for (haptic, expected) in cases {
+        let node = ButtonBuilder::new("Pay", PropValue::action("pay"))
+            .haptic(haptic)
+            .sound(TapSound::Click)
+            .build();
+        assert_eq!(node.props["haptic"], PropValue::String(expected.into()));
+        assert_eq!(node.props["sound"], PropValue::String("click".into()));
+        assert!(validate_interactive_node(&node).is_empty());
+    }
+    for (sound, expected) in [(TapSound::Success, "success"), (TapSound::Error, "error")] {
+        let node = ButtonBuilder::new("Pay", PropValue::action("pay"))
+            .sound(sound)
+            .build();
+        assert_eq!(node.props["sound"], PropValue::String(expected.into()));
+    }
+}
+
 #[test]
 fn button_all_props() {
     let node = ButtonBuilder::new(
@@ -187,7 +275,7 @@ fn button_valid_minimal() {
 fn button_valid_all_props() {
     let node = ButtonBuilder::new("Go", PropValue::action("go"))
         .variant(ButtonVariant::Text)
-        .icon("arrow")
+        .icon("arrow_forward")
         .disabled(true)
         .loading(false)
         .build();
@@ -246,6 +334,36 @@ fn button_invalid_variant() {
     assert!(errors.iter().any(|e| e.contains("variant")));
 }
 
+#[test]
+fn button_invalid_size() {
+    let mut node = ButtonBuilder::new("OK", PropValue::action("ok")).build();
+    node.set_prop("size", PropValue::String("huge".into()));
+    let errors = validate_interactive_node(&node);
+    assert!(errors.iter().any(|e| e.contains("Button.size")));
+}
+
+#[test]
+fn button_invalid_haptic_and_sound() {
+    let mut node = ButtonBuilder::new("OK", PropValue::action("ok")).build();
+    node.set_prop("haptic", PropValue::String("buzz".into()));
+    node.set_prop("sound", PropValue::Bool(true));
+    let errors = validate_interactive_node(&node);
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0]
+        .starts_with("Button.haptic: expected one of [light, medium, heavy, success, error]"));
+    assert!(errors[1].starts_with("Button.sound: expected one of [click, success, error]"));
+}
+
+#[test]
+fn button_wrong_full_width_type() {
+    let mut node = ButtonBuilder::new("OK", PropValue::action("ok")).build();
+    node.set_prop("full_width", PropValue::Number(1.0));
+    let errors = validate_interactive_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("full_width") && e.contains("bool")));
+}
+
 #[test]
 fn button_wrong_icon_type() {
     let mut node = SurfaceNode::new("Button");
@@ -282,6 +400,23 @@ fn button_wrong_loading_type() {
         .any(|e| e.contains("loading") && e.contains("bool")));
 }
 
+#[test]
+fn button_wrong_press_event_types() {
+    let mut node = ButtonBuilder::new("Hold", PropValue::action("tap")).build();
+    node.set_prop("on_long_press", PropValue::lambda(1));
+    node.set_prop("on_press_down", PropValue::String("down".into()));
+    node.set_prop("on_press_up", PropValue::Bool(true));
+    let errors = validate_interactive_node(&node);
+    for key in ["on_long_press", "on_press_down", "on_press_up"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.contains(key) && e.contains("expected action")),
+            "missing error for {key}: {errors:?}"
+        );
+    }
+}
+
 #[test]
 fn button_unknown_prop() {
     let mut node = SurfaceNode::new("Button");
@@ -424,6 +559,35 @@ fn text_input_multiline() {
     assert_eq!(node.props.get("multiline"), Some(&PropValue::Bool(true)));
 }
 
+#[test]
+fn text_input_autocapitalize() {
+    for (cap, expected) in [
+        (Autocapitalize::None, "none"),
+        (Autocapitalize::Words, "words"),
+        (Autocapitalize::Sentences, "sentences"),
+        (Autocapitalize::Characters, "characters"),
+    ] {
+        let node = TextInputBuilder::new("", PropValue::lambda(10))
+            .autocapitalize(cap)
+            .build();
+        assert_eq!(
+            node.props.get("autocapitalize"),
+            Some(&PropValue::String(expected.into()))
+        );
+    }
+}
+
+#[test]
+fn text_input_autocorrect() {
+    let node = TextInputBuilder::new("", PropValue::lambda(10))
+        .keyboard(KeyboardType::Email)
+        .autocapitalize(Autocapitalize::None)
+        .autocorrect(false)
+        .build();
+    assert_eq!(node.props.get("autocorrect"), Some(&PropValue::Bool(false)));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
 #[test]
 fn text_input_all_props() {
     let node = TextInputBuilder::new("initial", PropValue::lambda(11))
@@ -616,6 +780,28 @@ fn text_input_wrong_multiline_type() {
         .any(|e| e.contains("multiline") && e.contains("bool")));
 }
 
+#[test]
+fn text_input_invalid_autocapitalize() {
+    let mut node = SurfaceNode::new("TextInput");
+    node.set_prop("value", PropValue::String("v".into()));
+    node.set_prop("on_change", PropValue::lambda(38));
+    node.set_prop("autocapitalize", PropValue::String("upper".into()));
+    let errors = validate_interactive_node(&node);
+    assert!(errors.iter().any(|e| e.contains("autocapitalize")));
+}
+
+#[test]
+fn text_input_wrong_autocorrect_type() {
+    let mut node = SurfaceNode::new("TextInput");
+    node.set_prop("value", PropValue::String("v".into()));
+    node.set_prop("on_change", PropValue::lambda(38));
+    node.set_prop("autocorrect", PropValue::String("off".into()));
+    let errors = validate_interactive_node(&node);
+    assert!(errors
+        .iter()
+        .any(|e| e.contains("autocorrect") && e.contains("bool")));
+}
+
 #[test]
 fn text_input_unknown_prop() {
     let mut node = SurfaceNode::new("TextInput");
@@ -643,6 +829,226 @@ fn text_input_multiple_errors() {
     assert!(errors.len() >= 2);
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// Stepper — Construction
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn stepper_minimal() {
+    let node = StepperBuilder::new(1.0, PropValue::lambda(1)).build();
+    assert_eq!(node.component_type, "Stepper");
+    assert_eq!(node.props.get("value"), Some(&PropValue::Number(1.0)));
+    assert_eq!(node.props.get("on_change"), Some(&PropValue::lambda(1)));
+    assert!(!node.props.contains_key("min"));
+    assert!(!node.props.contains_key("max"));
+    assert!(!node.props.contains_key("step"));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn stepper_all_props() {
+    let node = StepperBuilder::new(3.0, PropValue::lambda(2))
+        .min(1.0)
+        .max(10.0)
+        .step(1.0)
+        .label("Quantity")
+        .build();
+    assert_eq!(node.props.get("min"), Some(&PropValue::Number(1.0)));
+    assert_eq!(node.props.get("max"), Some(&PropValue::Number(10.0)));
+    assert_eq!(node.props.get("step"), Some(&PropValue::Number(1.0)));
+    assert_eq!(
+        node.props.get("label"),
+        Some(&PropValue::String("Quantity".into()))
+    );
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn stepper_accessible_value() {
+    let node = StepperBuilder::new(3.0, PropValue::lambda(1))
+        .max(10.0)
+        .label("Quantity")
+        .build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("slider".into()));
+    assert_eq!(info["label"], PropValue::String("Quantity".into()));
+    assert_eq!(info["value"], PropValue::String("3 of 10".into()));
+
+    let unbounded = StepperBuilder::new(2.5, PropValue::lambda(1)).build();
+    let PropValue::Record(info) = &unbounded.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Stepper".into()));
+    assert_eq!(info["value"], PropValue::String("2.5".into()));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Stepper — Validation
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn stepper_missing_required() {
+    let errors = validate_interactive_node(&SurfaceNode::new("Stepper"));
+    assert!(errors.contains(&"Stepper.value: required prop missing".to_string()));
+    assert!(errors.contains(&"Stepper.on_change: required prop missing".to_string()));
+}
+
+#[test]
+fn stepper_non_numeric_bounds() {
+    let mut node = StepperBuilder::new(1.0, PropValue::lambda(1)).build();
+    node.set_prop("min", PropValue::String("0".into()));
+    node.set_prop("max", PropValue::Bool(true));
+    node.set_prop("step", PropValue::Nil);
+    let errors = validate_interactive_node(&node);
+    assert!(errors.contains(&"Stepper.min: expected number, got string".to_string()));
+    assert!(errors.contains(&"Stepper.max: expected number, got bool".to_string()));
+    assert!(errors.contains(&"Stepper.step: expected number, got nil".to_string()));
+}
+
+#[test]
+fn stepper_wrong_value_type() {
+    let mut node = StepperBuilder::new(1.0, PropValue::lambda(1)).build();
+    node.set_prop("value", PropValue::String("3".into()));
+    let errors = validate_interactive_node(&node);
+    assert_eq!(errors, vec!["Stepper.value: expected number, got string"]);
+}
+
+#[test]
+fn stepper_action_on_change_rejected() {
+    let node = StepperBuilder::new(1.0, PropValue::action("set")).build();
+    let errors = validate_interactive_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Stepper.on_change: expected lambda, got action"]
+    );
+}
+
+#[test]
+fn stepper_min_exceeds_max() {
+    let node = StepperBuilder::new(5.0, PropValue::lambda(1))
+        .min(10.0)
+        .max(1.0)
+        .build();
+    let errors = validate_interactive_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Stepper.min: must not exceed max, got min 10 and max 1"]
+    );
+}
+
+#[test]
+fn stepper_value_out_of_bounds() {
+    let node = StepperBuilder::new(12.0, PropValue::lambda(1))
+        .min(0.0)
+        .max(10.0)
+        .build();
+    let errors = validate_interactive_node(&node);
+    assert_eq!(errors, vec!["Stepper.value: 12 is outside [0, 10]"]);
+
+    let node = StepperBuilder::new(-1.0, PropValue::lambda(1))
+        .min(0.0)
+        .build();
+    let errors = validate_interactive_node(&node);
+    assert_eq!(errors, vec!["Stepper.value: -1 is outside [0, inf]"]);
+}
+
+#[test]
+fn stepper_non_positive_step() {
+    let node = StepperBuilder::new(1.0, PropValue::lambda(1))
+        .step(0.0)
+        .build();
+    let errors = validate_interactive_node(&node);
+    assert_eq!(errors, vec!["Stepper.step: must be positive, got 0"]);
+}
+
+#[test]
+fn stepper_unknown_prop_and_children() {
+    let mut node = StepperBuilder::new(1.0, PropValue::lambda(1)).build();
+    node.set_prop("increment", PropValue::Number(2.0));
+    node.children.push(SurfaceNode::new("Text"));
+    let errors = validate_interactive_node(&node);
+    assert!(errors.contains(&"Stepper: unknown prop 'increment'".to_string()));
+    assert!(errors.contains(&"Stepper: does not accept children, but got 1".to_string()));
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Rating
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn rating_read_only() {
+    let node = RatingBuilder::new(3.0, 5).build();
+    assert_eq!(node.component_type, "Rating");
+    assert_eq!(node.props.get("value"), Some(&PropValue::Number(3.0)));
+    assert_eq!(node.props.get("max"), Some(&PropValue::Number(5.0)));
+    assert!(!node.props.contains_key("on_change"));
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["value"], PropValue::String("3 of 5 stars".into()));
+    assert_eq!(info["role"], PropValue::String("text".into()));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn rating_interactive_with_icon() {
+    let node = RatingBuilder::new(4.5, 5)
+        .on_change(PropValue::lambda(3))
+        .icon("heart")
+        .build();
+    assert_eq!(
+        node.props.get("icon"),
+        Some(&PropValue::String("heart".into()))
+    );
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Rating".into()));
+    assert_eq!(info["value"], PropValue::String("4.5 of 5 stars".into()));
+    assert_eq!(info["role"], PropValue::String("slider".into()));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn rating_missing_required() {
+    let errors = validate_interactive_node(&SurfaceNode::new("Rating"));
+    assert_eq!(
+        errors,
+        vec![
+            "Rating.max: required prop missing",
+            "Rating.value: required prop missing",
+        ]
+    );
+}
+
+#[test]
+fn rating_value_out_of_range() {
+    let node = RatingBuilder::new(6.0, 5).build();
+    assert_eq!(
+        validate_interactive_node(&node),
+        vec!["Rating.value: 6 is outside [0, 5]"]
+    );
+}
+
+#[test]
+fn rating_invalid_max_and_handler() {
+    let mut node = RatingBuilder::new(1.0, 5)
+        .on_change(PropValue::action("rate"))
+        .build();
+    node.set_prop("max", PropValue::Number(2.5));
+    node.set_prop("icon", PropValue::Number(1.0));
+    assert_eq!(
+        validate_interactive_node(&node),
+        vec![
+            "Rating.max: expected positive integer, got 2.5",
+            "Rating.on_change: expected lambda, got action",
+            "Rating.icon: expected string, got number",
+        ]
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Action reference serialization
 // ══════════════════════════════════════════════════════════════════════════════
@@ -683,6 +1089,99 @@ fn lambda_callback_serialization() {
     assert!(json.contains("\"__lambda\":42"));
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// FilePicker
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn file_picker_minimal() {
+    let node = FilePickerBuilder::new("Attach", PropValue::lambda(1)).build();
+    assert_eq!(node.component_type, "FilePicker");
+    assert_eq!(node.props["label"], PropValue::String("Attach".into()));
+    assert_eq!(node.props["on_pick"], PropValue::lambda(1));
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Attach".into()));
+    assert_eq!(info["role"], PropValue::String("button".into()));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn file_picker_all_props() {
+    let node = FilePickerBuilder::new("Attach receipt", PropValue::lambda(1))
+        .accept(vec!["image/*", "application/pdf", "*/*"])
+        .multiple(true)
+        .disabled(false)
+        .build();
+    assert_eq!(
+        node.props["accept"],
+        PropValue::List(vec![
+            PropValue::String("image/*".into()),
+            PropValue::String("application/pdf".into()),
+            PropValue::String("*/*".into()),
+        ])
+    );
+    assert_eq!(node.props["multiple"], PropValue::Bool(true));
+    assert_eq!(node.props["disabled"], PropValue::Bool(false));
+    assert!(validate_interactive_node(&node).is_empty());
+}
+
+#[test]
+fn file_picker_required_props() {
+    assert_eq!(
+        validate_interactive_node(&SurfaceNode::new("FilePicker")),
+        vec![
+            "FilePicker.label: required prop missing",
+            "FilePicker.on_pick: required prop missing",
+        ]
+    );
+    let node = FilePickerBuilder::new("Attach", PropValue::action("pick")).build();
+    assert_eq!(
+        validate_interactive_node(&node),
+        vec!["FilePicker.on_pick: expected lambda, got action"]
+    );
+}
+
+#[test]
+fn file_picker_accept_validation() {
+    let node = FilePickerBuilder::new("Attach", PropValue::lambda(1))
+        .accept(vec!["pdf", "*/png", "image/png", "image/png"])
+        .build();
+    assert_eq!(
+        validate_interactive_node(&node),
+        vec![
+            "FilePicker.accept[0]: expected MIME type (e.g. \"image/png\", \"image/*\"), got \"pdf\"",
+            "FilePicker.accept[1]: expected MIME type (e.g. \"image/png\", \"image/*\"), got \"*/png\"",
+            "FilePicker.accept[3]: duplicate type \"image/png\"",
+        ]
+    );
+
+    let node = FilePickerBuilder::new("Attach", PropValue::lambda(1))
+        .accept(Vec::<String>::new())
+        .build();
+    assert_eq!(
+        validate_interactive_node(&node),
+        vec!["FilePicker.accept: must not be empty"]
+    );
+}
+
+#[test]
+fn file_picker_invalid_types_and_unknown_prop() {
+    let mut node = FilePickerBuilder::new("Attach", PropValue::lambda(1)).build();
+    node.set_prop("multiple", PropValue::String("yes".into()));
+    node.set_prop("max_size", PropValue::Number(10.0));
+    node.children.push(SurfaceNode::new("Text"));
+    assert_eq!(
+        validate_interactive_node(&node),
+        vec![
+            "FilePicker.multiple: expected bool, got string",
+            "FilePicker: does not accept children, but got 1",
+            "FilePicker: unknown prop 'max_size'",
+        ]
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Unknown component type
 // ══════════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,336 @@
+//! Tests for named `Surface` layers.
+
+use std::collections::BTreeMap;
+
+use pepl_ui::accessibility;
+use pepl_ui::i18n::StringCatalog;
+use pepl_ui::layers::{CONTENT, OVERLAYS, TOASTS};
+use pepl_ui::patch::PatchOp;
+use pepl_ui::theme::{resolve_theme, Theme};
+use pepl_ui::{
+    fuzz_validate, ColorValue, ColumnBuilder, CostModel, Layer, ModalBuilder, NodePath, PropValue,
+    Selector, Surface, SurfaceNode, Template, TextBuilder, ToastBuilder,
+};
+
+fn content() -> SurfaceNode {
+    ColumnBuilder::new()
+        .child(TextBuilder::new("Inbox").build())
+        .build()
+}
+
+fn modal() -> SurfaceNode {
+    ModalBuilder::new(true, PropValue::action("close"))
+        .child(TextBuilder::new("Delete?").build())
+        .build()
+}
+
+fn layered() -> Surface {
+    Surface::new(content())
+        .with_layer(OVERLAYS, modal())
+        .with_layer(TOASTS, ToastBuilder::new("Saved").build())
+}
+
+// ── Building ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_layer_order() {
+    let surface = layered();
+    let names: Vec<&str> = surface.layer_roots().map(|(name, _)| name).collect();
+    assert_eq!(names, [CONTENT, OVERLAYS, TOASTS]);
+    assert_eq!(surface.layer(CONTENT), Some(&content()));
+    assert_eq!(surface.layer(OVERLAYS), Some(&modal()));
+    assert_eq!(surface.layer("missing"), None);
+}
+
+#[test]
+fn test_with_layer_replaces_in_place() {
+    let toast = ToastBuilder::new("Deleted").build();
+    let surface = layered()
+        .with_layer(OVERLAYS, toast.clone())
+        .with_layer(CONTENT, toast.clone());
+    assert_eq!(surface.layers[0], Layer::new(OVERLAYS, toast.clone()));
+    assert_eq!(surface.layers.len(), 2);
+    assert_eq!(surface.root, toast);
+}
+
+#[test]
+fn test_remove_layer() {
+    let mut surface = layered();
+    assert_eq!(surface.remove_layer(OVERLAYS), Some(modal()));
+    assert_eq!(surface.remove_layer(OVERLAYS), None);
+    assert_eq!(surface.remove_layer(CONTENT), None);
+    assert_eq!(surface.layers.len(), 1);
+}
+
+// ── Wire format ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_layers_serialization() {
+    let surface = Surface::new(SurfaceNode::new("Column"))
+        .with_layer(TOASTS, SurfaceNode::new("Toast"))
+        .with_layer(OVERLAYS, SurfaceNode::new("Column"));
+    let json = surface.to_json();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"root":{"type":"Column","props":{},"children":[]},"#,
+            r#""layers":[{"name":"toasts","root":{"type":"Toast","props":{},"children":[]}},"#,
+            r#"{"name":"overlays","root":{"type":"Column","props":{},"children":[]}}]}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<Surface>(&json).unwrap(), surface);
+}
+
+#[test]
+fn test_no_layers_keeps_wire_format() {
+    let surface = Surface::new(content());
+    assert!(!surface.to_json().contains("layers"));
+    let json = r#"{"root":{"type":"Column","props":{},"children":[]}}"#;
+    assert!(serde_json::from_str::<Surface>(json)
+        .unwrap()
+        .layers
+        .is_empty());
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_validate_layers() {
+    assert!(
+        layered().validate().is_empty(),
+        "{:?}",
+        layered().validate()
+    );
+
+    let mut surface = Surface::new(content())
+        .with_layer(
+            OVERLAYS,
+            ColumnBuilder::new()
+                .child(SurfaceNode::new("Text").with_prop("value", PropValue::Number(1.0)))
+                .build(),
+        )
+        .with_layer(TOASTS, SurfaceNode::new("Toast"));
+    for name in ["", CONTENT, "root", "a/b", TOASTS] {
+        surface.layers.push(Layer::new(name, content()));
+    }
+    assert_eq!(
+        surface.validate(),
+        vec![
+            "overlays/0: Text.value: expected string, got number",
+            "toasts: Toast.message: required prop missing",
+            "Surface.layers: layer name must not be empty",
+            "Surface.layers: 'content' is reserved for Surface.root",
+            "Surface.layers: 'root' is reserved for Surface.root",
+            "Surface.layers: layer name 'a/b' must not contain '/'",
+            "Surface.layers: duplicate layer 'toasts'",
+        ]
+    );
+}
+
+// ── Surface-wide operations ───────────────────────────────────────────────────
+
+#[test]
+fn test_paths_address_layers() {
+    let mut surface = layered();
+    let path: NodePath = "overlays/0".parse().unwrap();
+    assert_eq!(
+        surface.get(&path),
+        Some(&TextBuilder::new("Delete?").build())
+    );
+    assert_eq!(
+        surface.find(&Selector::component("Toast")),
+        Some(NodePath::layer_root(TOASTS))
+    );
+    assert!(surface.remove(&path).is_some());
+    assert!(surface.get(&path).is_none());
+    assert_eq!(surface.get(&"missing".parse().unwrap()), None);
+
+    let mut visited = Vec::new();
+    surface.walk(|_, path| visited.push(path.to_string()));
+    assert_eq!(visited, ["root", "root/0", "overlays", "toasts"]);
+}
+
+#[test]
+fn test_stats_count_layers() {
+    let stats = layered().stats();
+    assert_eq!(stats.complexity.node_count, 5);
+    assert_eq!(stats.count("Modal"), 1);
+    assert_eq!(stats.count("Text"), 2);
+}
+
+#[test]
+fn test_hash_includes_layers() {
+    let plain = Surface::new(content());
+    assert_ne!(plain.structural_hash(), layered().structural_hash());
+    let renamed = Surface::new(content()).with_layer("popups", modal());
+    let overlay = Surface::new(content()).with_layer(OVERLAYS, modal());
+    assert_ne!(renamed.structural_hash(), overlay.structural_hash());
+}
+
+#[test]
+fn test_diff_and_patch_layers() {
+    let before = Surface::new(content());
+    let after = layered();
+    let ops = before.diff(&after);
+    assert_eq!(
+        ops,
+        vec![PatchOp::SetLayers {
+            layers: after.layers.clone()
+        }]
+    );
+    let mut patched = before.clone();
+    patched.apply_patch(&before.diff_patch(&after)).unwrap();
+    assert_eq!(patched, after);
+}
+
+#[test]
+fn test_compose_fills_slots_in_layers() {
+    let shell = Surface::new(content()).with_layer(TOASTS, SurfaceNode::slot("toast"));
+    let toast = ToastBuilder::new("Sent").build();
+    let screen = shell.compose(BTreeMap::from([("toast".to_string(), toast.clone())]));
+    assert_eq!(screen.layer(TOASTS), Some(&toast));
+    assert!(shell.slot_names().contains("toast"));
+}
+
+// ── Whole-surface passes cover layers ─────────────────────────────────────────
+
+fn toast_layer(toast: SurfaceNode) -> Surface {
+    Surface::new(content()).with_layer(TOASTS, toast)
+}
+
+fn toast_with(key: &str, value: PropValue) -> SurfaceNode {
+    ToastBuilder::new("Saved").build().with_prop(key, value)
+}
+
+#[test]
+fn test_value_checks_cover_layers() {
+    let surface = toast_layer(toast_with("color", PropValue::color_token("accent")));
+    assert_eq!(
+        surface.validate_color_tokens(&["primary"]),
+        vec!["toasts: Toast.color: unknown color token 'accent'"]
+    );
+
+    let surface = toast_layer(toast_with("message", PropValue::bytes(vec![0; 8])));
+    assert_eq!(
+        surface.validate_inline_bytes(4),
+        vec!["toasts: Toast.message: inline bytes 8 exceed limit 4"]
+    );
+
+    let surface = toast_layer(toast_with("message", PropValue::i18n("saved")));
+    assert_eq!(
+        surface.validate_i18n(&StringCatalog::new("fr")),
+        vec!["toasts: Toast.message: missing translation 'saved' for locale 'fr'"]
+    );
+}
+
+#[test]
+fn test_diff_report_covers_layers() {
+    let before = layered();
+    assert_eq!(before.diff_report(&layered()), "");
+
+    let mut after = layered();
+    after
+        .layer_mut(TOASTS)
+        .unwrap()
+        .set_prop("message", PropValue::String("Deleted".into()));
+    assert_eq!(
+        before.diff_report(&after),
+        r#"toasts props.message: "Saved" != "Deleted""#
+    );
+
+    let after = Surface::new(content()).with_layer(TOASTS, ToastBuilder::new("Saved").build());
+    assert_eq!(before.diff_report(&after), "overlays: Modal != (missing)");
+
+    let mut swapped = layered();
+    swapped.layers.reverse();
+    assert_eq!(
+        before.diff_report(&swapped),
+        r#"layers: ["overlays","toasts"] != ["toasts","overlays"]"#
+    );
+}
+
+#[test]
+fn test_audit_covers_layers() {
+    let surface = toast_layer(SurfaceNode::new("Toast"));
+    assert_eq!(
+        accessibility::audit(&surface),
+        vec!["toasts: Toast has no accessible prop"]
+    );
+}
+
+#[test]
+fn test_node_ids_cover_layers() {
+    let surface = layered();
+    let ids = surface.assign_ids();
+    let overlay = ids.get(&NodePath::layer_root(OVERLAYS)).unwrap();
+    assert_ne!(Some(overlay), ids.get(&NodePath::root()));
+    assert_eq!(ids.len(), 5);
+    // Content IDs do not depend on layers
+    assert_eq!(
+        ids.get(&NodePath::root()),
+        Surface::new(content()).assign_ids().get(&NodePath::root())
+    );
+    let debug: serde_json::Value = serde_json::from_str(&surface.to_json_debug()).unwrap();
+    assert_eq!(debug["layers"][0]["root"]["id"], overlay);
+}
+
+#[test]
+fn test_cost_covers_layers() {
+    let model = CostModel::new();
+    assert!(model.estimate(&layered()) > model.estimate(&Surface::new(content())));
+}
+
+#[test]
+fn test_theme_covers_layers() {
+    let mut surface = toast_layer(toast_with("color", PropValue::theme_token("color.brand")));
+    let errors = Theme::new().validate_surface(&surface);
+    assert_eq!(errors[0].path, NodePath::layer_root(TOASTS));
+
+    let theme = Theme::new().with_color("brand", ColorValue::rgb(1.0, 0.0, 0.0));
+    assert!(resolve_theme(&mut surface, &theme).is_empty());
+    assert_eq!(
+        surface.layer(TOASTS).unwrap().props["color"],
+        PropValue::color(1.0, 0.0, 0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_template_covers_layers() {
+    let template = Template::new(toast_layer(ToastBuilder::new("Saved {name}").build()));
+    assert_eq!(
+        template.placeholders().into_iter().collect::<Vec<_>>(),
+        ["name"]
+    );
+    let bindings = [("name".to_string(), PropValue::String("draft".into()))].into();
+    let rendered = template.render(&bindings);
+    assert_eq!(
+        rendered.layer(TOASTS).unwrap().props["message"],
+        PropValue::String("Saved draft".into())
+    );
+}
+
+#[test]
+fn test_ascii_tree_and_hashes_cover_layers() {
+    let surface = Surface::new(SurfaceNode::new("Column")).with_layer(
+        TOASTS,
+        SurfaceNode::new("Toast").with_prop("message", PropValue::String("Saved".into())),
+    );
+    assert_eq!(
+        surface.ascii_tree(),
+        "Column\n[toasts]\nToast message=\"Saved\"\n"
+    );
+    let hashes = surface.subtree_hashes();
+    assert_eq!(
+        hashes.get(&NodePath::layer_root(TOASTS)),
+        Some(&surface.layers[0].root.structural_hash())
+    );
+}
+
+#[test]
+fn test_fuzz_validate_covers_layers() {
+    let json = toast_layer(SurfaceNode::new("Toast")).to_json();
+    assert_eq!(
+        fuzz_validate(json.as_bytes()).unwrap(),
+        vec!["toasts: Toast.message: required prop missing"]
+    );
+}
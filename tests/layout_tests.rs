@@ -1,9 +1,17 @@
-//! Integration tests for `pepl-ui` Phase 2: Layout components (Column, Row, Scroll).
+//! Integration tests for `pepl-ui` Phase 2: Layout components (Column, Row, Scroll, Tabs,
+//! Carousel, Disclosure, Overlay, AppBar, Divider, Spacer).
 
 use pepl_ui::{
-    validate_layout_node, Alignment, ColumnBuilder, Edges, PropValue, RowBuilder, ScrollBuilder,
-    ScrollDirection, Surface, SurfaceNode,
+    shadow_to_prop, AppBarBuilder, BorderStyle, CarouselBuilder, ColorValue, DisclosureBuilder,
+    DividerBuilder, Gradient, GradientStop, OverlayAlignment, OverlayBuilder, OverlayLayer,
+    Position, SafeAreaEdges, ShadowStyle, SpacerBuilder,
 };
+use pepl_ui::{
+    validate_layout_node, AlignSelf, Alignment, ColumnBuilder, CornerRadii, Edges, Justify,
+    PropValue, RowBuilder, ScrollBuilder, ScrollDirection, ScrollTarget, Spacing, Surface,
+    SurfaceNode, TabsBuilder,
+};
+use pepl_ui::{ButtonBuilder, CommonProps, TextBuilder};
 use std::collections::BTreeMap;
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
@@ -47,6 +55,41 @@ fn test_column_spacing_zero() {
     assert_eq!(node.props["spacing"], PropValue::Number(0.0));
 }
 
+#[test]
+fn test_column_spacing_and_padding_tokens() {
+    let node = ColumnBuilder::new()
+        .spacing_token(Spacing::Sm)
+        .padding_token(Spacing::Xl)
+        .build();
+    assert_eq!(node.props["spacing"], PropValue::theme_token("spacing.sm"));
+    assert_eq!(node.props["padding"], PropValue::theme_token("spacing.xl"));
+    assert!(validate_layout_node(&node).is_empty());
+
+    // A later raw value replaces the token
+    let row = RowBuilder::new()
+        .spacing_token(Spacing::Md)
+        .spacing(12.0)
+        .padding_token(Spacing::Xs)
+        .build();
+    assert_eq!(row.props["spacing"], PropValue::Number(12.0));
+    assert!(validate_layout_node(&row).is_empty());
+}
+
+#[test]
+fn test_spacing_token_wrong_group() {
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("spacing", PropValue::theme_token("radius.md"))
+        .with_prop("padding", PropValue::theme_token("color.primary"));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Column: 'padding' must be a number or record, got theme token",
+            "Column.spacing: expected number or dimension, got theme token",
+        ]
+    );
+}
+
 #[test]
 fn test_column_with_align_start() {
     let node = ColumnBuilder::new().align(Alignment::Start).build();
@@ -89,6 +132,20 @@ fn test_column_with_align_space_around() {
     );
 }
 
+#[test]
+fn test_row_justify_and_align_axes() {
+    let node = RowBuilder::new()
+        .justify(Justify::SpaceBetween)
+        .align(Alignment::Center)
+        .build();
+    assert_eq!(
+        node.props["justify"],
+        PropValue::String("space_between".into())
+    );
+    assert_eq!(node.props["align"], PropValue::String("center".into()));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
 #[test]
 fn test_column_with_padding_uniform() {
     let node = ColumnBuilder::new().padding(Edges::Uniform(16.0)).build();
@@ -558,6 +615,82 @@ fn test_edges_sides_equal_values() {
     assert!(matches!(node.props["padding"], PropValue::Record(_)));
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// Corner radius tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_corner_radius_uniform_coercion_number() {
+    let node = ColumnBuilder::new()
+        .corner_radius(CornerRadii::Uniform(12.0))
+        .build();
+    assert_eq!(node.props["corner_radius"], PropValue::Number(12.0));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_corner_radius_corners_record() {
+    let node = RowBuilder::new()
+        .corner_radius(CornerRadii::corners(16.0, 16.0, 0.0, 0.0))
+        .build();
+    let mut expected = BTreeMap::new();
+    expected.insert("top_start".to_string(), PropValue::Number(16.0));
+    expected.insert("top_end".to_string(), PropValue::Number(16.0));
+    expected.insert("bottom_start".to_string(), PropValue::Number(0.0));
+    expected.insert("bottom_end".to_string(), PropValue::Number(0.0));
+    assert_eq!(node.props["corner_radius"], PropValue::Record(expected));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_corner_radii_serde_matches_prop_shape() {
+    let radii = CornerRadii::corners(1.0, 2.0, 3.0, 4.0);
+    let json = serde_json::to_value(&radii).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "top_start": 1.0, "top_end": 2.0, "bottom_start": 3.0, "bottom_end": 4.0 })
+    );
+    let uniform: CornerRadii = serde_json::from_str("8").unwrap();
+    assert_eq!(uniform, CornerRadii::from_number(8.0));
+}
+
+#[test]
+fn test_validate_corner_radius_errors() {
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("corner_radius", PropValue::Number(-2.0));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Column.corner_radius: must be non-negative, got -2"]
+    );
+
+    let mut corners = BTreeMap::new();
+    corners.insert("top_start".to_string(), PropValue::Number(4.0));
+    corners.insert("top_end".to_string(), PropValue::String("4".into()));
+    corners.insert("bottom_start".to_string(), PropValue::Number(-1.0));
+    corners.insert("top_left".to_string(), PropValue::Number(4.0));
+    let node = RowBuilder::new()
+        .build()
+        .with_prop("corner_radius", PropValue::Record(corners));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Row.corner_radius.top_end: expected number, got string",
+            "Row.corner_radius.bottom_start: must be non-negative, got -1",
+            "Row.corner_radius.bottom_end: required field missing",
+            "Row.corner_radius: unknown field 'top_left'",
+        ]
+    );
+
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("corner_radius", PropValue::Bool(true));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Column.corner_radius: expected number or record, got bool"]
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Validation tests
 // ══════════════════════════════════════════════════════════════════════════════
@@ -611,6 +744,36 @@ fn test_validate_column_invalid_align_value() {
     assert!(errors[0].contains("invalid alignment"));
 }
 
+#[test]
+fn test_validate_column_invalid_justify() {
+    let node = SurfaceNode::new("Column").with_prop("justify", PropValue::String("stretch".into()));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Column: invalid justify 'stretch'"]
+    );
+    let node = SurfaceNode::new("Row").with_prop("justify", PropValue::Bool(true));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Row: 'justify' must be a string, got bool"]
+    );
+}
+
+#[test]
+fn test_validate_legacy_align_conflicts_with_justify() {
+    // Legacy main-axis align stays valid on its own
+    let legacy = ColumnBuilder::new().align(Alignment::SpaceAround).build();
+    assert!(validate_layout_node(&legacy).is_empty());
+
+    let node = ColumnBuilder::new()
+        .align(Alignment::SpaceAround)
+        .justify(Justify::Center)
+        .build();
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Column: 'align' main-axis value 'space_around' conflicts with 'justify'"]
+    );
+}
+
 #[test]
 fn test_validate_column_invalid_align_type() {
     let mut node = SurfaceNode::new("Column");
@@ -709,6 +872,1361 @@ fn test_validate_column_padding_record_valid() {
     assert!(errors.is_empty()); // Record is a valid type for padding
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// Container style tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn card_border() -> BorderStyle {
+    BorderStyle {
+        width: 1.0,
+        color: ColorValue {
+            r: 0.8,
+            g: 0.8,
+            b: 0.8,
+            a: 1.0,
+        },
+        style: Some("dashed".into()),
+    }
+}
+
+#[test]
+fn test_column_background_and_border() {
+    let node = ColumnBuilder::new()
+        .background_token("surface")
+        .border(card_border())
+        .child(text_node("Card"))
+        .build();
+
+    assert_eq!(
+        node.props.get("background"),
+        Some(&PropValue::color_token("surface"))
+    );
+    let mut border = BTreeMap::new();
+    border.insert("width".to_string(), PropValue::Number(1.0));
+    border.insert("color".to_string(), PropValue::color(0.8, 0.8, 0.8, 1.0));
+    border.insert("style".to_string(), PropValue::String("dashed".into()));
+    assert_eq!(node.props.get("border"), Some(&PropValue::Record(border)));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_row_and_scroll_accept_container_style() {
+    let white = ColorValue {
+        r: 1.0,
+        g: 1.0,
+        b: 1.0,
+        a: 1.0,
+    };
+    let row = RowBuilder::new()
+        .background(white.clone())
+        .border(card_border())
+        .build();
+    let scroll = ScrollBuilder::new()
+        .background(white)
+        .border(BorderStyle {
+            style: None,
+            ..card_border()
+        })
+        .build();
+
+    assert_eq!(
+        row.props.get("background"),
+        Some(&PropValue::color(1.0, 1.0, 1.0, 1.0))
+    );
+    assert!(validate_layout_node(&row).is_empty());
+    assert!(validate_layout_node(&scroll).is_empty());
+}
+
+#[test]
+fn test_container_gradient_background() {
+    let gradient = Gradient::linear(
+        180.0,
+        vec![
+            GradientStop::new(0.0, ColorValue::WHITE),
+            GradientStop::new(1.0, ColorValue::from_hex("#E0E7FF").unwrap()),
+        ],
+    );
+    let node = ColumnBuilder::new()
+        .background_gradient(gradient.clone())
+        .child(text_node("Card"))
+        .build();
+
+    let json = serde_json::to_value(&node.props["background"]).unwrap();
+    assert_eq!(json["__gradient"], serde_json::json!("linear"));
+    assert_eq!(json["angle"], serde_json::json!(180.0));
+    assert_eq!(json["stops"][1]["offset"], serde_json::json!(1.0));
+    assert_eq!(serde_json::from_value::<Gradient>(json).unwrap(), gradient);
+    assert!(validate_layout_node(&node).is_empty());
+
+    let row = RowBuilder::new()
+        .background_gradient(Gradient::radial(vec![
+            GradientStop::new(0.0, ColorValue::BLUE),
+            GradientStop::new(1.0, ColorValue::TRANSPARENT),
+        ]))
+        .build();
+    assert!(validate_layout_node(&row).is_empty());
+}
+
+#[test]
+fn test_validate_gradient_background_errors() {
+    let stop = |offset: f64, color: PropValue| {
+        PropValue::Record(BTreeMap::from([
+            ("offset".to_string(), PropValue::Number(offset)),
+            ("color".to_string(), color),
+        ]))
+    };
+    let red = PropValue::color(1.0, 0.0, 0.0, 1.0);
+    let gradient = PropValue::Record(BTreeMap::from([
+        ("__gradient".to_string(), PropValue::String("radial".into())),
+        ("angle".to_string(), PropValue::Number(90.0)),
+        (
+            "stops".to_string(),
+            PropValue::List(vec![
+                stop(0.0, red.clone()),
+                stop(0.6, red.clone()),
+                stop(0.4, PropValue::Number(1.0)),
+                stop(1.5, red),
+            ]),
+        ),
+    ]));
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("background", gradient);
+
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Column.background.stops[2].offset: must not be less than the previous offset 0.6, got 0.4",
+            "Column.background.stops[2].color: expected color, got number",
+            "Column.background.stops[3].offset: must be within 0-1, got 1.5",
+            "Column.background: unknown field 'angle'",
+        ]
+    );
+
+    let short = PropValue::Record(BTreeMap::from([
+        ("__gradient".to_string(), PropValue::String("conic".into())),
+        ("stops".to_string(), PropValue::List(vec![])),
+    ]));
+    let row = RowBuilder::new().build().with_prop("background", short);
+    assert_eq!(
+        validate_layout_node(&row),
+        vec!["Row.background.__gradient: expected one of [linear, radial], got Some(String(\"conic\"))"]
+    );
+}
+
+#[test]
+fn test_container_style_json_roundtrip() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .background_token("surface")
+            .border(card_border())
+            .build(),
+    );
+    let json = surface.to_json();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        parsed["root"]["props"]["border"]["style"],
+        serde_json::json!("dashed")
+    );
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, surface);
+}
+
+#[test]
+fn test_validate_container_style_errors() {
+    let mut border = BTreeMap::new();
+    border.insert("width".to_string(), PropValue::Number(-1.0));
+    border.insert("style".to_string(), PropValue::String("double".into()));
+    border.insert("radius".to_string(), PropValue::Number(4.0));
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("background", PropValue::String("white".into()))
+        .with_prop("border", PropValue::Record(border));
+
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Column: 'background' must be a color, got string",
+            "Column: 'border.width' must be a non-negative number, got Number(-1.0)",
+            "Column: 'border' missing required field 'color'",
+            "Column: 'border.style' must be one of [solid, dashed, dotted], got String(\"double\")",
+            "Column: 'border' has unknown field 'radius'",
+        ]
+    );
+
+    let scroll = ScrollBuilder::new()
+        .build()
+        .with_prop("border", PropValue::Number(1.0));
+    assert_eq!(
+        validate_layout_node(&scroll),
+        vec!["Scroll: 'border' must be a record, got number"]
+    );
+}
+
+fn card_shadow() -> ShadowStyle {
+    ShadowStyle {
+        offset_x: 0.0,
+        offset_y: 2.0,
+        blur: 8.0,
+        color: ColorValue {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.2,
+        },
+    }
+}
+
+#[test]
+fn test_shadow_to_prop() {
+    let mut expected = BTreeMap::new();
+    expected.insert("offset_x".to_string(), PropValue::Number(0.0));
+    expected.insert("offset_y".to_string(), PropValue::Number(2.0));
+    expected.insert("blur".to_string(), PropValue::Number(8.0));
+    expected.insert("color".to_string(), PropValue::color(0.0, 0.0, 0.0, 0.2));
+    assert_eq!(shadow_to_prop(&card_shadow()), PropValue::Record(expected));
+}
+
+#[test]
+fn test_containers_accept_shadow() {
+    let nodes = [
+        ColumnBuilder::new().shadow(card_shadow()).build(),
+        RowBuilder::new().shadow(card_shadow()).build(),
+        ScrollBuilder::new().shadow(card_shadow()).build(),
+    ];
+    for node in &nodes {
+        assert_eq!(
+            node.props.get("shadow"),
+            Some(&shadow_to_prop(&card_shadow()))
+        );
+        let errors = validate_layout_node(node);
+        assert!(errors.is_empty(), "{}: {errors:?}", node.component_type);
+    }
+}
+
+#[test]
+fn test_validate_shadow_errors() {
+    let mut shadow = BTreeMap::new();
+    shadow.insert("offset_x".to_string(), PropValue::String("2px".into()));
+    shadow.insert("blur".to_string(), PropValue::Number(-4.0));
+    shadow.insert("color".to_string(), PropValue::Number(0.0));
+    shadow.insert("spread".to_string(), PropValue::Number(1.0));
+    let node = RowBuilder::new()
+        .build()
+        .with_prop("shadow", PropValue::Record(shadow));
+
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Row: 'shadow.offset_x' must be a number, got string",
+            "Row: 'shadow' missing required field 'offset_y'",
+            "Row: 'shadow.blur' must be non-negative, got -4",
+            "Row: 'shadow.color' must be a color, got number",
+            "Row: 'shadow' has unknown field 'spread'",
+        ]
+    );
+
+    let scroll = ScrollBuilder::new()
+        .build()
+        .with_prop("shadow", PropValue::Bool(true));
+    assert_eq!(
+        validate_layout_node(&scroll),
+        vec!["Scroll: 'shadow' must be a record, got bool"]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Weighted children tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_row_weighted_children_share_space() {
+    let row = RowBuilder::new()
+        .weighted_child(
+            ButtonBuilder::new("Cancel", PropValue::action("cancel")).build(),
+            1.0,
+        )
+        .weighted_child(
+            ButtonBuilder::new("Save", PropValue::action("save")).build(),
+            1.0,
+        )
+        .build();
+    for child in &row.children {
+        assert_eq!(child.props.get("flex"), Some(&PropValue::Number(1.0)));
+    }
+    assert!(validate_layout_node(&row).is_empty());
+    assert!(Surface::new(row).validate().is_empty());
+}
+
+#[test]
+fn test_column_mixes_weighted_and_fixed_children() {
+    let column = ColumnBuilder::new()
+        .child(TextBuilder::new("Header").build())
+        .weighted_child(TextBuilder::new("Body").build(), 2.0)
+        .weighted_child(TextBuilder::new("Aside").build(), 1.0)
+        .build();
+    assert!(!column.children[0].props.contains_key("flex"));
+    assert_eq!(
+        column.children[1].props.get("flex"),
+        Some(&PropValue::Number(2.0))
+    );
+    let errors = Surface::new(column).validate();
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn test_validate_child_flex_must_be_positive() {
+    let row = RowBuilder::new()
+        .weighted_child(text_node("a"), 0.0)
+        .child(text_node("b").with_prop("flex", PropValue::String("1".into())))
+        .build();
+    assert!(validate_layout_node(&row).is_empty());
+    assert_eq!(
+        Surface::new(row).validate(),
+        vec![
+            "root/0: Text.flex: must be a positive number, got 0",
+            "root/1: Text.flex: expected number, got string",
+        ]
+    );
+    let negative = RowBuilder::new()
+        .weighted_child(text_node("a"), -1.0)
+        .build();
+    assert_eq!(
+        Surface::new(negative).validate(),
+        vec!["root/0: Text.flex: must be a positive number, got -1"]
+    );
+}
+
+#[test]
+fn test_align_self_overrides_parent_align() {
+    let column = ColumnBuilder::new()
+        .align(Alignment::Stretch)
+        .child(TextBuilder::new("Full width").build())
+        .child(
+            ButtonBuilder::new("Done", PropValue::action("done"))
+                .align_self(AlignSelf::End)
+                .build(),
+        )
+        .build();
+    assert!(!column.children[0].props.contains_key("align_self"));
+    assert_eq!(
+        column.children[1].props.get("align_self"),
+        Some(&PropValue::String("end".into()))
+    );
+    let errors = Surface::new(column).validate();
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn test_every_align_self_validates() {
+    for align in AlignSelf::ALL {
+        let row = RowBuilder::new()
+            .child(TextBuilder::new("a").align_self(align).build())
+            .build();
+        let errors = Surface::new(row).validate();
+        assert!(errors.is_empty(), "{align:?}: {errors:?}");
+    }
+}
+
+#[test]
+fn test_validate_align_self_values() {
+    let row = RowBuilder::new()
+        .child(text_node("a").with_prop("align_self", PropValue::String("space_between".into())))
+        .child(text_node("b").with_prop("align_self", PropValue::Bool(true)))
+        .build();
+    assert_eq!(
+        Surface::new(row).validate(),
+        vec![
+            "root/0: Text.align_self: expected one of [start, center, end, stretch], got String(\"space_between\")",
+            "root/1: Text.align_self: expected one of [start, center, end, stretch], got Bool(true)",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Pinned children tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_scroll_pinned_section_headers() {
+    let section = |title: &str| {
+        ColumnBuilder::new()
+            .child(TextBuilder::new(title).pinned(true).build())
+            .child(TextBuilder::new("Item").build())
+            .build()
+    };
+    let scroll = ScrollBuilder::new()
+        .child(section("A"))
+        .child(section("B"))
+        .build();
+    assert_eq!(
+        scroll.children[0].children[0].props.get("pinned"),
+        Some(&PropValue::Bool(true))
+    );
+    let errors = Surface::new(scroll).validate();
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn test_validate_pinned_outside_scroll() {
+    let root = ColumnBuilder::new()
+        .child(TextBuilder::new("Header").pinned(true).build())
+        .child(
+            ScrollBuilder::new()
+                .child(text_node("Body").with_prop("pinned", PropValue::String("yes".into())))
+                .build(),
+        )
+        .build();
+    assert_eq!(
+        Surface::new(root).validate(),
+        vec![
+            "root/0: Text.pinned: only valid inside Scroll or ScrollList",
+            "root/1/0: Text.pinned: expected bool, got string",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Safe area tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_validate_safe_area_prop() {
+    let node = RowBuilder::new()
+        .safe_area(SafeAreaEdges {
+            bottom: true,
+            ..SafeAreaEdges::default()
+        })
+        .build();
+    assert!(validate_layout_node(&node).is_empty());
+
+    let mut fields = BTreeMap::new();
+    fields.insert("top".to_string(), PropValue::Number(1.0));
+    fields.insert("left".to_string(), PropValue::Bool(true));
+    let node = SurfaceNode::new("Scroll").with_prop("safe_area", PropValue::Record(fields));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Scroll: 'safe_area' has unknown field 'left'",
+            "Scroll: 'safe_area.top' must be a bool, got number",
+        ]
+    );
+    let node = SurfaceNode::new("Column").with_prop("safe_area", PropValue::Number(1.0));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Column: 'safe_area' must be a bool or record, got number"]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Wrapping row tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_row_wrap_with_run_spacing() {
+    let node = RowBuilder::new()
+        .wrap(true)
+        .spacing(8.0)
+        .run_spacing(4.0)
+        .child(text_node("#rust"))
+        .child(text_node("#ui"))
+        .build();
+    assert_eq!(node.props.get("wrap"), Some(&PropValue::Bool(true)));
+    assert_eq!(node.props.get("run_spacing"), Some(&PropValue::Number(4.0)));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_validate_row_wrap_errors() {
+    let node = SurfaceNode::new("Row")
+        .with_prop("wrap", PropValue::String("yes".into()))
+        .with_prop("run_spacing", PropValue::Bool(true));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Row.run_spacing: expected number or dimension, got bool",
+            "Row: 'run_spacing' requires 'wrap: true'",
+            "Row: 'wrap' must be a bool, got string",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_column_rejects_wrap() {
+    let node = SurfaceNode::new("Column")
+        .with_prop("wrap", PropValue::Bool(true))
+        .with_prop("run_spacing", PropValue::Number(4.0));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Column: unknown prop 'run_spacing'",
+            "Column: unknown prop 'wrap'",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Gesture tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_column_gesture_props() {
+    let node = ColumnBuilder::new()
+        .on_tap(PropValue::action("open_card"))
+        .on_double_tap(PropValue::action("like"))
+        .on_long_press(PropValue::action("show_menu"))
+        .build();
+    assert_eq!(node.props["on_tap"], PropValue::action("open_card"));
+    assert_eq!(node.props["on_double_tap"], PropValue::action("like"));
+    assert_eq!(node.props["on_long_press"], PropValue::action("show_menu"));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_row_on_swipe() {
+    let node = RowBuilder::new()
+        .on_swipe(PropValue::action_with_args(
+            "dismiss_row",
+            vec![PropValue::Number(3.0)],
+        ))
+        .child(text_node("Swipe me"))
+        .build();
+    assert!(matches!(
+        node.props["on_swipe"],
+        PropValue::ActionRef { .. }
+    ));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_validate_gesture_not_action() {
+    for key in ["on_tap", "on_double_tap", "on_swipe", "on_long_press"] {
+        let mut node = SurfaceNode::new("Row");
+        node.set_prop(key, PropValue::String("tap".into()));
+        let errors = validate_layout_node(&node);
+        assert_eq!(errors.len(), 1, "{key}");
+        assert!(errors[0].contains(key));
+        assert!(errors[0].contains("must be an action"));
+    }
+}
+
+#[test]
+fn test_validate_scroll_rejects_gestures() {
+    let mut node = SurfaceNode::new("Scroll");
+    node.set_prop("on_tap", PropValue::action("tap"));
+    let errors = validate_layout_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("unknown prop 'on_tap'"));
+}
+
+#[test]
+fn test_scroll_behavior_props() {
+    let node = ScrollBuilder::new()
+        .direction(ScrollDirection::Horizontal)
+        .show_indicators(false)
+        .bounce(false)
+        .paging(true)
+        .build();
+    assert_eq!(node.props["show_indicators"], PropValue::Bool(false));
+    assert_eq!(node.props["bounce"], PropValue::Bool(false));
+    assert_eq!(node.props["paging"], PropValue::Bool(true));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_scroll_behavior_props_default_unset() {
+    let node = ScrollBuilder::new().build();
+    for key in ["show_indicators", "bounce", "paging"] {
+        assert!(!node.props.contains_key(key), "{key}");
+    }
+}
+
+#[test]
+fn test_validate_scroll_behavior_props_not_bool() {
+    for key in ["show_indicators", "bounce", "paging"] {
+        let node = ScrollBuilder::new()
+            .build()
+            .with_prop(key, PropValue::String("yes".into()));
+        let errors = validate_layout_node(&node);
+        assert_eq!(
+            errors,
+            vec![format!("Scroll: '{key}' must be a bool, got string")]
+        );
+    }
+}
+
+#[test]
+fn test_scroll_avoid_keyboard() {
+    let node = ScrollBuilder::new().avoid_keyboard(true).build();
+    assert_eq!(node.props["avoid_keyboard"], PropValue::Bool(true));
+    assert!(validate_layout_node(&node).is_empty());
+
+    let wrong = ScrollBuilder::new()
+        .build()
+        .with_prop("avoid_keyboard", PropValue::Number(1.0));
+    assert_eq!(
+        validate_layout_node(&wrong),
+        vec!["Scroll: 'avoid_keyboard' must be a bool, got number".to_string()]
+    );
+}
+
+#[test]
+fn test_validate_column_rejects_paging() {
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("paging", PropValue::Bool(true));
+    let errors = validate_layout_node(&node);
+    assert_eq!(errors, vec!["Column: unknown prop 'paging'".to_string()]);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// TabsBuilder tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn two_tabs() -> TabsBuilder {
+    TabsBuilder::new(PropValue::action("select_tab"))
+        .tab("Today", text_node("3 tasks"))
+        .tab("Done", text_node("12 tasks"))
+}
+
+#[test]
+fn test_tabs_builder() {
+    let node = two_tabs().selected(1).build();
+    assert_eq!(node.component_type, "Tabs");
+    assert_eq!(
+        node.props["tabs"],
+        PropValue::List(vec![
+            PropValue::String("Today".into()),
+            PropValue::String("Done".into()),
+        ])
+    );
+    assert_eq!(node.props["selected"], PropValue::Number(1.0));
+    assert_eq!(node.props["on_select"], PropValue::action("select_tab"));
+    assert_eq!(node.children.len(), 2);
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_tabs_default_selection_and_lambda_handler() {
+    let node = TabsBuilder::new(PropValue::lambda(4))
+        .tab("Only", text_node("panel"))
+        .build();
+    assert_eq!(node.props["selected"], PropValue::Number(0.0));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_tabs_accessible_value_announces_selection() {
+    let node = two_tabs().selected(1).build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("group".into()));
+    assert_eq!(info["value"], PropValue::String("Done, tab 2 of 2".into()));
+}
+
+#[test]
+fn test_tabs_selected_out_of_bounds() {
+    let node = two_tabs().selected(2).build();
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Tabs: 'selected' index 2 out of bounds for 2 tabs"]
+    );
+}
+
+#[test]
+fn test_tabs_selected_must_be_index() {
+    let mut node = two_tabs().build();
+    node.set_prop("selected", PropValue::Number(0.5));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Tabs: 'selected' must be a non-negative integer, got 0.5"]
+    );
+    node.set_prop("selected", PropValue::String("Today".into()));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Tabs: 'selected' must be a number, got string"]
+    );
+}
+
+#[test]
+fn test_tabs_panel_count_must_match() {
+    let mut node = two_tabs().build();
+    node.children.pop();
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Tabs: expected 2 panels (one per tab), got 1"]
+    );
+}
+
+#[test]
+fn test_tabs_missing_required_props() {
+    let errors = validate_layout_node(&SurfaceNode::new("Tabs"));
+    assert_eq!(
+        errors,
+        vec![
+            "Tabs: missing required prop 'tabs'",
+            "Tabs: missing required prop 'selected'",
+            "Tabs: missing required prop 'on_select'",
+        ]
+    );
+}
+
+#[test]
+fn test_tabs_invalid_props() {
+    let mut node = two_tabs().build();
+    node.set_prop(
+        "tabs",
+        PropValue::List(vec![
+            PropValue::String("Today".into()),
+            PropValue::Bool(true),
+        ]),
+    );
+    node.set_prop("on_select", PropValue::String("select_tab".into()));
+    node.set_prop("sticky", PropValue::Bool(true));
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Tabs: 'tabs[1]' must be a string, got bool",
+            "Tabs: 'on_select' must be an action or lambda, got string",
+            "Tabs: unknown prop 'sticky'",
+        ]
+    );
+}
+
+#[test]
+fn test_tabs_empty() {
+    let node = TabsBuilder::new(PropValue::action("select_tab")).build();
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Tabs: 'tabs' must not be empty"]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// CarouselBuilder tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn onboarding(index: usize) -> SurfaceNode {
+    CarouselBuilder::new(index, PropValue::lambda(1))
+        .page(text_node("Welcome"))
+        .page(text_node("Track habits"))
+        .page(text_node("Get started"))
+        .build()
+}
+
+#[test]
+fn test_carousel_pages() {
+    let node = onboarding(1);
+    assert_eq!(node.component_type, "Carousel");
+    assert_eq!(node.children.len(), 3);
+    assert_eq!(node.props["index"], PropValue::Number(1.0));
+    assert_eq!(node.props["on_change"], PropValue::lambda(1));
+    assert!(!node.props.contains_key("show_indicators"));
+    assert!(validate_layout_node(&node).is_empty());
+
+    let with_dots = CarouselBuilder::new(0, PropValue::lambda(1))
+        .show_indicators(true)
+        .build();
+    assert_eq!(with_dots.props["show_indicators"], PropValue::Bool(true));
+    assert!(validate_layout_node(&with_dots).is_empty());
+}
+
+#[test]
+fn test_carousel_accessible_page_value() {
+    let node = onboarding(1);
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Carousel".into()));
+    assert_eq!(info["role"], PropValue::String("group".into()));
+    assert_eq!(info["value"], PropValue::String("page 2 of 3".into()));
+}
+
+#[test]
+fn test_carousel_index_validation() {
+    let node = onboarding(3);
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Carousel: 'index' 3 out of bounds for 3 pages"]
+    );
+
+    let node = onboarding(0).with_prop("index", PropValue::Number(-1.0));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Carousel: 'index' must be a non-negative integer, got -1"]
+    );
+}
+
+#[test]
+fn test_carousel_required_and_types() {
+    assert_eq!(
+        validate_layout_node(&SurfaceNode::new("Carousel")),
+        vec![
+            "Carousel: missing required prop 'index'",
+            "Carousel: missing required prop 'on_change'",
+        ]
+    );
+
+    let node = onboarding(0)
+        .with_prop("on_change", PropValue::action("next"))
+        .with_prop("show_indicators", PropValue::String("yes".into()))
+        .with_prop("paging", PropValue::Bool(true));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Carousel: 'on_change' must be a lambda, got action",
+            "Carousel: 'show_indicators' must be a bool, got string",
+            "Carousel: unknown prop 'paging'",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// DisclosureBuilder tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_disclosure_builder() {
+    let node = DisclosureBuilder::new("Shipping", true, PropValue::action("toggle_shipping"))
+        .child(text_node("Ships in 2 days"))
+        .build();
+    assert_eq!(node.component_type, "Disclosure");
+    assert_eq!(node.props["title"], PropValue::String("Shipping".into()));
+    assert_eq!(node.props["expanded"], PropValue::Bool(true));
+    assert_eq!(
+        node.props["on_toggle"],
+        PropValue::action("toggle_shipping")
+    );
+    assert_eq!(node.children.len(), 1);
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_disclosure_accessible_state() {
+    for (expanded, state) in [(true, "expanded"), (false, "collapsed")] {
+        let node = DisclosureBuilder::new("Shipping", expanded, PropValue::lambda(1)).build();
+        let PropValue::Record(info) = &node.props["accessible"] else {
+            panic!("expected accessible record");
+        };
+        assert_eq!(info["label"], PropValue::String("Shipping".into()));
+        assert_eq!(info["value"], PropValue::String(state.into()));
+    }
+}
+
+#[test]
+fn test_disclosure_accordion_of_sections() {
+    let accordion = ColumnBuilder::new()
+        .children(
+            ["Billing", "Shipping", "Returns"]
+                .iter()
+                .enumerate()
+                .map(|(i, title)| {
+                    DisclosureBuilder::new(*title, i == 1, PropValue::action("open_section"))
+                        .child(text_node(title))
+                        .build()
+                })
+                .collect(),
+        )
+        .build();
+    for section in &accordion.children {
+        assert!(validate_layout_node(section).is_empty());
+    }
+}
+
+#[test]
+fn test_disclosure_validation_errors() {
+    let errors = validate_layout_node(&SurfaceNode::new("Disclosure"));
+    assert_eq!(
+        errors,
+        vec![
+            "Disclosure: missing required prop 'title'",
+            "Disclosure: missing required prop 'expanded'",
+            "Disclosure: missing required prop 'on_toggle'",
+        ]
+    );
+
+    let mut node = DisclosureBuilder::new("Shipping", false, PropValue::lambda(1)).build();
+    node.set_prop("title", PropValue::Bool(true));
+    node.set_prop("expanded", PropValue::String("yes".into()));
+    node.set_prop("on_toggle", PropValue::Nil);
+    node.set_prop("icon", PropValue::String("chevron".into()));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Disclosure: 'title' must be a string, got bool",
+            "Disclosure: 'expanded' must be a bool, got string",
+            "Disclosure: 'on_toggle' must be an action or lambda, got nil",
+            "Disclosure: unknown prop 'icon'",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// OverlayBuilder tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn badge_overlay() -> SurfaceNode {
+    OverlayBuilder::new()
+        .child(text_node("avatar"))
+        .layer(
+            OverlayLayer::new(text_node("3"))
+                .align(OverlayAlignment::TopEnd)
+                .offset(4.0, -4.0),
+        )
+        .build()
+}
+
+#[test]
+fn test_overlay_layers() {
+    let node = badge_overlay();
+    assert_eq!(node.component_type, "Overlay");
+    assert_eq!(node.children.len(), 2);
+    let json = serde_json::to_value(&node.props["layers"]).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "align": "center" },
+            { "align": "top_end", "offset": { "x": 4.0, "y": -4.0 } },
+        ])
+    );
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_overlay_centered_layers_omit_prop() {
+    let node = OverlayBuilder::new()
+        .child(text_node("back"))
+        .child(text_node("front"))
+        .build();
+    assert!(!node.props.contains_key("layers"));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_overlay_roundtrip() {
+    let node = badge_overlay();
+    let json = Surface::new(node.clone()).to_json();
+    let parsed: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.root, node);
+}
+
+#[test]
+fn test_overlay_layer_count_must_match_children() {
+    let mut node = badge_overlay();
+    node.children.pop();
+    assert_eq!(
+        validate_layout_node(&node),
+        vec!["Overlay: expected 1 layers (one per child), got 2"]
+    );
+}
+
+#[test]
+fn test_overlay_invalid_layers() {
+    let mut node = badge_overlay();
+    let mut bad_offset = BTreeMap::new();
+    bad_offset.insert("x".to_string(), PropValue::Number(1.0));
+    let mut layer = BTreeMap::new();
+    layer.insert("align".to_string(), PropValue::String("middle".into()));
+    layer.insert("offset".to_string(), PropValue::Record(bad_offset));
+    layer.insert("z".to_string(), PropValue::Number(1.0));
+    node.set_prop(
+        "layers",
+        PropValue::List(vec![
+            PropValue::Record(layer),
+            PropValue::Record(BTreeMap::new()),
+        ]),
+    );
+    let errors = validate_layout_node(&node);
+    assert_eq!(errors.len(), 4, "{errors:?}");
+    assert!(errors[0].starts_with("Overlay: 'layers[0].align' must be one of [top_start,"));
+    assert!(errors[1]
+        .starts_with("Overlay: 'layers[0].offset' must be a record { x: number, y: number }"));
+    assert_eq!(errors[2], "Overlay: unknown field 'layers[0].z'");
+    assert_eq!(
+        errors[3],
+        "Overlay: 'layers[1]' missing required field 'align'"
+    );
+}
+
+#[test]
+fn test_overlay_layers_must_be_list() {
+    let mut node = badge_overlay();
+    node.set_prop("layers", PropValue::String("stack".into()));
+    node.set_prop("clip", PropValue::Bool(true));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "Overlay: unknown prop 'clip'",
+            "Overlay: 'layers' must be a list, got string",
+        ]
+    );
+}
+
+#[test]
+fn test_overlay_child_position() {
+    let card = OverlayBuilder::new()
+        .child(ColumnBuilder::new().child(text_node("Card")).build())
+        .child(
+            ButtonBuilder::new("Close", PropValue::action("close"))
+                .position(Position::default().top(8.0).end(8.0))
+                .build(),
+        )
+        .build();
+    let mut expected = BTreeMap::new();
+    expected.insert("top".to_string(), PropValue::Number(8.0));
+    expected.insert("end".to_string(), PropValue::Number(8.0));
+    assert_eq!(
+        card.children[1].props.get("position"),
+        Some(&PropValue::Record(expected))
+    );
+    let errors = Surface::new(card).validate();
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn test_validate_position_prop() {
+    let mut fields = BTreeMap::new();
+    fields.insert("top".to_string(), PropValue::String("8".into()));
+    fields.insert("left".to_string(), PropValue::Number(8.0));
+    let root = OverlayBuilder::new()
+        .child(text_node("a").with_prop("position", PropValue::Record(fields)))
+        .child(text_node("b").with_prop("position", PropValue::Record(BTreeMap::new())))
+        .child(text_node("c").with_prop("position", PropValue::Number(8.0)))
+        .build();
+    assert_eq!(
+        Surface::new(root).validate(),
+        vec![
+            "root/0: Text.position: unknown field 'left'",
+            "root/0: Text.position.top: expected number, got string",
+            "root/1: Text.position: must set at least one of [top, bottom, start, end]",
+            "root/2: Text.position: expected record, got number",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_position_outside_overlay() {
+    let root = ColumnBuilder::new()
+        .child(
+            TextBuilder::new("Badge")
+                .position(Position::default().top(0.0))
+                .build(),
+        )
+        .build();
+    assert_eq!(
+        Surface::new(root).validate(),
+        vec!["root/0: Text.position: only valid on a direct child of Overlay or Modal"]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// AppBarBuilder tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_app_bar_title_only() {
+    let node = AppBarBuilder::new("Inbox").build();
+    assert_eq!(node.component_type, "AppBar");
+    assert_eq!(node.props["title"], PropValue::String("Inbox".into()));
+    assert!(!node.props.contains_key("slots"));
+    assert!(!node.props.contains_key("elevated"));
+    assert!(node.children.is_empty());
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_app_bar_slots() {
+    let node = AppBarBuilder::new("Inbox")
+        .trailing(button_node("Search"))
+        .leading(button_node("Back"))
+        .trailing(button_node("More"))
+        .elevated(true)
+        .build();
+    // The leading child always comes first.
+    let labels: Vec<_> = node
+        .children
+        .iter()
+        .map(|child| child.props["label"].clone())
+        .collect();
+    assert_eq!(
+        labels,
+        vec![
+            PropValue::String("Back".into()),
+            PropValue::String("Search".into()),
+            PropValue::String("More".into()),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_value(&node.props["slots"]).unwrap(),
+        serde_json::json!(["leading", "trailing", "trailing"])
+    );
+    assert_eq!(node.props["elevated"], PropValue::Bool(true));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_app_bar_accessible_label() {
+    let node = AppBarBuilder::new("Settings").build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("group".into()));
+    assert_eq!(info["label"], PropValue::String("Settings".into()));
+}
+
+#[test]
+fn test_app_bar_validation_errors() {
+    let errors = validate_layout_node(&SurfaceNode::new("AppBar"));
+    assert_eq!(errors, vec!["AppBar: missing required prop 'title'"]);
+
+    let mut node = AppBarBuilder::new("Inbox")
+        .leading(button_node("Back"))
+        .build();
+    node.children.push(button_node("Extra"));
+    node.set_prop("elevated", PropValue::String("yes".into()));
+    assert_eq!(
+        validate_layout_node(&node),
+        vec![
+            "AppBar: 'elevated' must be a bool, got string",
+            "AppBar: expected 2 slots (one per child), got 1",
+        ]
+    );
+}
+
+#[test]
+fn test_app_bar_invalid_slots() {
+    let node = AppBarBuilder::new("Inbox")
+        .trailing(button_node("Search"))
+        .build()
+        .with_prop(
+            "slots",
+            PropValue::List(vec![
+                PropValue::String("trailing".into()),
+                PropValue::String("leading".into()),
+                PropValue::String("center".into()),
+            ]),
+        );
+    let errors = validate_layout_node(&node);
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0], "AppBar: expected 1 slots (one per child), got 3");
+    assert_eq!(
+        errors[1],
+        "AppBar: 'slots[1]' is leading, but the leading child must come first"
+    );
+    assert!(errors[2].starts_with("AppBar: 'slots[2]' must be one of [leading, trailing]"));
+
+    let missing = AppBarBuilder::new("Inbox")
+        .trailing(button_node("Search"))
+        .build()
+        .with_prop("slots", PropValue::Nil);
+    assert_eq!(
+        validate_layout_node(&missing),
+        vec!["AppBar: 'slots' must be a list, got nil"]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// DividerBuilder / SpacerBuilder tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_divider_defaults() {
+    let node = DividerBuilder::new().build();
+    assert_eq!(node.component_type, "Divider");
+    assert_eq!(node.props.keys().collect::<Vec<_>>(), vec!["accessible"]);
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_divider_all_props() {
+    let node = DividerBuilder::new()
+        .thickness(2.0)
+        .color(ColorValue::rgb(0.8, 0.8, 0.8))
+        .inset(16.0)
+        .build();
+    assert_eq!(node.props["thickness"], PropValue::Number(2.0));
+    assert_eq!(node.props["color"], PropValue::color(0.8, 0.8, 0.8, 1.0));
+    assert_eq!(node.props["inset"], PropValue::Number(16.0));
+    assert!(validate_layout_node(&node).is_empty());
+
+    let token = DividerBuilder::new().color_token("outline").build();
+    assert!(validate_layout_node(&token).is_empty());
+}
+
+#[test]
+fn test_divider_is_decorative() {
+    let node = DividerBuilder::new().build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("none".into()));
+}
+
+#[test]
+fn test_divider_invalid_props() {
+    let mut node = DividerBuilder::new().thickness(-1.0).build();
+    node.set_prop("color", PropValue::String("grey".into()));
+    node.set_prop("size", PropValue::Number(4.0));
+    node.add_child(text_node("x"));
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Divider: 'color' must be a color, got string",
+            "Divider: unknown prop 'size'",
+            "Divider: 'thickness' must be a non-negative number, got Number(-1.0)",
+            "Divider: does not accept children, but got 1",
+        ]
+    );
+}
+
+#[test]
+fn test_spacer_flexible_and_fixed() {
+    let flexible = SpacerBuilder::new().build();
+    assert_eq!(flexible.component_type, "Spacer");
+    assert!(!flexible.props.contains_key("size"));
+    assert!(validate_layout_node(&flexible).is_empty());
+
+    let fixed = SpacerBuilder::new().size(24.0).build();
+    assert_eq!(fixed.props["size"], PropValue::Number(24.0));
+    assert!(validate_layout_node(&fixed).is_empty());
+}
+
+#[test]
+fn test_spacer_invalid_props() {
+    let mut node = SpacerBuilder::new().build();
+    node.set_prop("size", PropValue::String("big".into()));
+    node.set_prop("thickness", PropValue::Number(1.0));
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec![
+            "Spacer: 'size' must be a non-negative number, got String(\"big\")",
+            "Spacer: unknown prop 'thickness'",
+        ]
+    );
+}
+
+#[test]
+fn test_spacer_in_row() {
+    let row = RowBuilder::new()
+        .child(text_node("Inbox"))
+        .child(SpacerBuilder::new().build())
+        .child(button_node("Edit"))
+        .build();
+    let json = Surface::new(row).to_json();
+    let parsed: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.root.children[1].component_type, "Spacer");
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Scroll position tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_scroll_to_anchor() {
+    let section = ColumnBuilder::new()
+        .child(text_node("Section 2"))
+        .build()
+        .with_prop("anchor", PropValue::String("section-2".into()));
+    let node = ScrollBuilder::new()
+        .scroll_to(ScrollTarget::anchor("section-2"))
+        .child(ColumnBuilder::new().child(section).build())
+        .build();
+    assert_eq!(
+        node.props["scroll_to"],
+        PropValue::String("section-2".into())
+    );
+    let errors = validate_layout_node(&node);
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn test_scroll_to_offset_and_on_scroll() {
+    let node = ScrollBuilder::new()
+        .scroll_to(ScrollTarget::Offset(120.0))
+        .on_scroll(PropValue::lambda(4))
+        .scroll_event_interval(50.0)
+        .build();
+    assert_eq!(node.props["scroll_to"], PropValue::Number(120.0));
+    assert_eq!(node.props["on_scroll"], PropValue::lambda(4));
+    assert_eq!(node.props["scroll_event_interval"], PropValue::Number(50.0));
+    assert!(validate_layout_node(&node).is_empty());
+}
+
+#[test]
+fn test_scroll_to_missing_anchor() {
+    let node = ScrollBuilder::new()
+        .scroll_to(ScrollTarget::anchor("nowhere"))
+        .child(text_node("Body"))
+        .build();
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Scroll: 'scroll_to' anchor 'nowhere' not found among descendants".to_string()]
+    );
+}
+
+#[test]
+fn test_scroll_to_invalid_values() {
+    let negative = ScrollBuilder::new()
+        .scroll_to(ScrollTarget::Offset(-10.0))
+        .build();
+    let errors = validate_layout_node(&negative);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("offset must be non-negative"));
+
+    let wrong = ScrollBuilder::new()
+        .build()
+        .with_prop("scroll_to", PropValue::Bool(true));
+    let errors = validate_layout_node(&wrong);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("must be a string (anchor) or number (offset)"));
+}
+
+#[test]
+fn test_scroll_on_scroll_must_be_lambda() {
+    let node = ScrollBuilder::new()
+        .on_scroll(PropValue::action("scrolled"))
+        .build();
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Scroll: 'on_scroll' must be a lambda, got action".to_string()]
+    );
+}
+
+#[test]
+fn test_scroll_event_interval_requires_on_scroll() {
+    let node = ScrollBuilder::new().scroll_event_interval(16.0).build();
+    let errors = validate_layout_node(&node);
+    assert_eq!(
+        errors,
+        vec!["Scroll: 'scroll_event_interval' requires 'on_scroll'".to_string()]
+    );
+}
+
+#[test]
+fn test_anchor_prop_validation() {
+    let empty = ColumnBuilder::new()
+        .build()
+        .with_prop("anchor", PropValue::String(String::new()));
+    assert_eq!(
+        validate_layout_node(&empty),
+        vec!["Column.anchor: must not be empty".to_string()]
+    );
+
+    let wrong = RowBuilder::new()
+        .build()
+        .with_prop("anchor", PropValue::Number(1.0));
+    assert_eq!(
+        validate_layout_node(&wrong),
+        vec!["Row.anchor: expected string, got number".to_string()]
+    );
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // JSON serialization tests for layout trees
 // ══════════════════════════════════════════════════════════════════════════════
@@ -1,9 +1,13 @@
-//! Tests for ScrollList component (U5).
+//! Tests for ScrollList (U5) and Table components.
 //!
 //! Covers construction, JSON serialization, validation (happy + error),
 //! and 100-iteration determinism.
 
-use pepl_ui::{validate_list_node, PropValue, ScrollListBuilder, Surface, SurfaceNode};
+use pepl_ui::{
+    validate_list_node, PropValue, ScrollListBuilder, ScrollTarget, Surface, SurfaceNode,
+    TableBuilder, TableColumn, TextAlign,
+};
+use std::collections::BTreeMap;
 
 // ══════════════════════════════════════════════════════════════════════════════
 // Construction
@@ -120,10 +124,111 @@ fn scroll_list_valid_with_all_optional() {
     assert!(errors.is_empty(), "unexpected errors: {errors:?}");
 }
 
+#[test]
+fn scroll_list_valid_with_scroll_control() {
+    let node = ScrollListBuilder::new(
+        PropValue::List(vec![PropValue::String("a".into())]),
+        PropValue::lambda(1),
+        PropValue::lambda(2),
+    )
+    .scroll_to(ScrollTarget::anchor("a"))
+    .on_scroll(PropValue::lambda(3))
+    .scroll_event_interval(100.0)
+    .build();
+
+    assert_eq!(node.props["scroll_to"], PropValue::String("a".into()));
+    assert_eq!(
+        node.props["scroll_event_interval"],
+        PropValue::Number(100.0)
+    );
+    let errors = validate_list_node(&node);
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+}
+
+#[test]
+fn scroll_list_scroll_to_offset() {
+    let node = ScrollListBuilder::new(
+        PropValue::List(vec![]),
+        PropValue::lambda(1),
+        PropValue::lambda(2),
+    )
+    .scroll_to(ScrollTarget::Offset(240.0))
+    .build();
+
+    assert_eq!(node.props["scroll_to"], PropValue::Number(240.0));
+    assert!(validate_list_node(&node).is_empty());
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Validation (error cases)
 // ══════════════════════════════════════════════════════════════════════════════
 
+#[test]
+fn scroll_list_scroll_to_wrong_type() {
+    let node = ScrollListBuilder::new(
+        PropValue::List(vec![]),
+        PropValue::lambda(1),
+        PropValue::lambda(2),
+    )
+    .build()
+    .with_prop("scroll_to", PropValue::Bool(true));
+
+    let errors = validate_list_node(&node);
+    assert_eq!(
+        errors,
+        vec!["ScrollList.scroll_to: expected string or number, got bool".to_string()]
+    );
+}
+
+#[test]
+fn scroll_list_scroll_to_negative_offset() {
+    let node = ScrollListBuilder::new(
+        PropValue::List(vec![]),
+        PropValue::lambda(1),
+        PropValue::lambda(2),
+    )
+    .scroll_to(ScrollTarget::Offset(-1.0))
+    .build();
+
+    let errors = validate_list_node(&node);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("offset must be non-negative"));
+}
+
+#[test]
+fn scroll_list_on_scroll_not_lambda() {
+    let node = ScrollListBuilder::new(
+        PropValue::List(vec![]),
+        PropValue::lambda(1),
+        PropValue::lambda(2),
+    )
+    .on_scroll(PropValue::action("scrolled"))
+    .build();
+
+    let errors = validate_list_node(&node);
+    assert_eq!(
+        errors,
+        vec!["ScrollList.on_scroll: expected lambda, got action".to_string()]
+    );
+}
+
+#[test]
+fn scroll_list_interval_requires_on_scroll() {
+    let node = ScrollListBuilder::new(
+        PropValue::List(vec![]),
+        PropValue::lambda(1),
+        PropValue::lambda(2),
+    )
+    .scroll_event_interval(16.0)
+    .build();
+
+    let errors = validate_list_node(&node);
+    assert_eq!(
+        errors,
+        vec!["ScrollList.scroll_event_interval: requires on_scroll".to_string()]
+    );
+}
+
 #[test]
 fn scroll_list_missing_items() {
     let mut node = SurfaceNode::new("ScrollList");
@@ -254,3 +359,181 @@ fn scroll_list_determinism_100() {
         assert_eq!(Surface::new(build()).to_json(), reference);
     }
 }
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Table
+// ══════════════════════════════════════════════════════════════════════════════
+
+fn row(name: &str, price: f64) -> PropValue {
+    PropValue::Record(BTreeMap::from([
+        ("name".to_string(), PropValue::String(name.into())),
+        ("price".to_string(), PropValue::Number(price)),
+    ]))
+}
+
+fn menu_table() -> TableBuilder {
+    TableBuilder::new(
+        vec![
+            TableColumn::new("name", "Item"),
+            TableColumn::new("price", "Price")
+                .width(80.0)
+                .align(TextAlign::End),
+        ],
+        PropValue::List(vec![row("Tea", 3.5), row("Cake", 4.0)]),
+        PropValue::lambda(1),
+    )
+}
+
+#[test]
+fn table_basic_construction() {
+    let node = menu_table().build();
+    assert_eq!(node.component_type, "Table");
+    let json = serde_json::to_value(&node.props["columns"]).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "key": "name", "title": "Item" },
+            { "key": "price", "title": "Price", "width": 80.0, "align": "end" },
+        ])
+    );
+    assert!(node.children.is_empty());
+    assert!(validate_list_node(&node).is_empty());
+}
+
+#[test]
+fn table_accessible_announces_shape() {
+    let node = menu_table().build();
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["role"], PropValue::String("table".into()));
+    assert_eq!(info["value"], PropValue::String("2 rows, 2 columns".into()));
+}
+
+#[test]
+fn table_sortable() {
+    let node = menu_table()
+        .sortable(true)
+        .on_sort(PropValue::lambda(2))
+        .build();
+    assert_eq!(node.props["sortable"], PropValue::Bool(true));
+    assert!(validate_list_node(&node).is_empty());
+
+    let node = menu_table().sortable(true).build();
+    assert_eq!(
+        validate_list_node(&node),
+        vec!["Table.sortable: requires on_sort"]
+    );
+}
+
+#[test]
+fn table_json_roundtrip() {
+    let node = menu_table()
+        .sortable(true)
+        .on_sort(PropValue::lambda(2))
+        .build();
+    let json = Surface::new(node.clone()).to_json();
+    let parsed: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.root, node);
+}
+
+#[test]
+fn table_missing_required_props() {
+    let errors = validate_list_node(&SurfaceNode::new("Table"));
+    assert_eq!(
+        errors,
+        vec![
+            "Table.columns: required prop missing",
+            "Table.rows: required prop missing",
+            "Table.render_cell: required prop missing",
+        ]
+    );
+}
+
+#[test]
+fn table_row_shape_errors() {
+    let mut node = menu_table().build();
+    let partial = PropValue::Record(BTreeMap::from([(
+        "name".to_string(),
+        PropValue::String("Scone".into()),
+    )]));
+    node.set_prop(
+        "rows",
+        PropValue::List(vec![
+            row("Tea", 3.5),
+            partial,
+            PropValue::String("Pie".into()),
+        ]),
+    );
+    assert_eq!(
+        validate_list_node(&node),
+        vec![
+            "Table.rows[1]: missing column 'price'",
+            "Table.rows[2]: expected record, got string",
+        ]
+    );
+}
+
+#[test]
+fn table_rows_may_have_extra_fields() {
+    let mut node = menu_table().build();
+    let PropValue::Record(mut fields) = row("Tea", 3.5) else {
+        unreachable!()
+    };
+    fields.insert("id".to_string(), PropValue::Number(7.0));
+    node.set_prop("rows", PropValue::List(vec![PropValue::Record(fields)]));
+    assert!(validate_list_node(&node).is_empty());
+}
+
+#[test]
+fn table_column_shape_errors() {
+    let mut node = menu_table().build();
+    let bad = PropValue::Record(BTreeMap::from([
+        ("key".to_string(), PropValue::String("name".into())),
+        ("width".to_string(), PropValue::Number(-1.0)),
+        ("align".to_string(), PropValue::String("left".into())),
+        ("sticky".to_string(), PropValue::Bool(true)),
+    ]));
+    node.set_prop(
+        "columns",
+        PropValue::List(vec![
+            TableColumn::new("name", "Item").to_prop_value(),
+            bad,
+            TableColumn::new("", "Blank").to_prop_value(),
+            PropValue::Number(3.0),
+        ]),
+    );
+    node.set_prop("rows", PropValue::List(vec![]));
+    assert_eq!(
+        validate_list_node(&node),
+        vec![
+            "Table.columns[1].key: duplicate key 'name'",
+            "Table.columns[1].title: required field missing",
+            "Table.columns[1].width: must be non-negative, got -1",
+            "Table.columns[1].align: expected one of [start, center, end], got String(\"left\")",
+            "Table.columns[1]: unknown field 'sticky'",
+            "Table.columns[2].key: must not be empty",
+            "Table.columns[3]: expected record, got number",
+        ]
+    );
+}
+
+#[test]
+fn table_invalid_types_and_unknown_props() {
+    let mut node = menu_table().build();
+    node.set_prop("columns", PropValue::List(vec![]));
+    node.set_prop("render_cell", PropValue::action("cell"));
+    node.set_prop("on_sort", PropValue::action("sort"));
+    node.set_prop("striped", PropValue::Bool(true));
+    node.add_child(SurfaceNode::new("Text"));
+    assert_eq!(
+        validate_list_node(&node),
+        vec![
+            "Table.columns: must not be empty",
+            "Table.render_cell: expected lambda, got action",
+            "Table.on_sort: expected lambda, got action",
+            "Table: does not accept children, but got 1",
+            "Table: unknown prop 'striped'",
+        ]
+    );
+}
@@ -0,0 +1,188 @@
+//! Tests for the `Video` and `AudioPlayer` media components.
+
+use pepl_ui::{
+    validate_media_node, AudioPlayerBuilder, PropValue, Surface, SurfaceNode, VideoBuilder,
+};
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Video
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_video_minimal() {
+    let node = VideoBuilder::new("https://example.com/intro.mp4", "Product tour").build();
+    assert_eq!(node.component_type, "Video");
+    assert_eq!(
+        node.props["source"],
+        PropValue::String("https://example.com/intro.mp4".into())
+    );
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Product tour".into()));
+    assert_eq!(info["role"], PropValue::String("group".into()));
+    assert!(validate_media_node(&node).is_empty());
+}
+
+#[test]
+fn test_video_all_props() {
+    let node = VideoBuilder::new("asset:intro", "Intro")
+        .autoplay(true)
+        .muted(true)
+        .looping(true)
+        .controls(false)
+        .poster("asset:intro_poster")
+        .build();
+    assert_eq!(node.props["autoplay"], PropValue::Bool(true));
+    assert_eq!(node.props["muted"], PropValue::Bool(true));
+    assert_eq!(node.props["loop"], PropValue::Bool(true));
+    assert_eq!(node.props["controls"], PropValue::Bool(false));
+    assert_eq!(
+        node.props["poster"],
+        PropValue::String("asset:intro_poster".into())
+    );
+    assert!(validate_media_node(&node).is_empty());
+}
+
+#[test]
+fn test_video_json_roundtrip() {
+    let surface = Surface::new(
+        VideoBuilder::new("asset:intro", "Intro")
+            .looping(true)
+            .build(),
+    );
+    let back: Surface = serde_json::from_str(&surface.to_json()).unwrap();
+    assert_eq!(back, surface);
+}
+
+#[test]
+fn test_video_requires_source_and_label() {
+    assert_eq!(
+        validate_media_node(&SurfaceNode::new("Video")),
+        vec![
+            "Video.source: required prop missing",
+            "Video.label: required prop missing",
+        ]
+    );
+
+    let node = VideoBuilder::new("ftp://example.com/a.mp4", " ").build();
+    assert_eq!(
+        validate_media_node(&node),
+        vec![
+            "Video.source: expected url (https://, http://) or asset:<name>, got \"ftp://example.com/a.mp4\"",
+            "Video.label: must not be empty",
+        ]
+    );
+}
+
+#[test]
+fn test_video_autoplay_requires_muted() {
+    let node = VideoBuilder::new("asset:intro", "Intro")
+        .autoplay(true)
+        .build();
+    assert_eq!(
+        validate_media_node(&node),
+        vec!["Video.autoplay: requires muted: true"]
+    );
+}
+
+#[test]
+fn test_video_invalid_props() {
+    let mut node = VideoBuilder::new("asset:intro", "Intro")
+        .poster("poster.png")
+        .build();
+    node.set_prop("loop", PropValue::String("yes".into()));
+    node.set_prop("fit", PropValue::String("cover".into()));
+    node.children.push(SurfaceNode::new("Text"));
+    assert_eq!(
+        validate_media_node(&node),
+        vec![
+            "Video.poster: expected url (https://, http://) or asset:<name>, got \"poster.png\"",
+            "Video.loop: expected bool, got string",
+            "Video: does not accept children, but got 1",
+            "Video: unknown prop 'fit'",
+        ]
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// AudioPlayer
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_audio_player_minimal() {
+    let node = AudioPlayerBuilder::new("asset:song", false, PropValue::action("toggle")).build();
+    assert_eq!(node.component_type, "AudioPlayer");
+    assert_eq!(node.props["playing"], PropValue::Bool(false));
+    assert_eq!(node.props["on_toggle"], PropValue::action("toggle"));
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Audio player".into()));
+    assert_eq!(info["value"], PropValue::String("paused".into()));
+    assert!(validate_media_node(&node).is_empty());
+}
+
+#[test]
+fn test_audio_player_all_props() {
+    let node = AudioPlayerBuilder::new(
+        "https://example.com/episode-12.mp3",
+        true,
+        PropValue::action("toggle"),
+    )
+    .title("Episode 12")
+    .artwork("https://example.com/cover.png")
+    .build();
+    assert_eq!(node.props["title"], PropValue::String("Episode 12".into()));
+    assert_eq!(
+        node.props["artwork"],
+        PropValue::String("https://example.com/cover.png".into())
+    );
+    let PropValue::Record(info) = &node.props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(info["label"], PropValue::String("Episode 12".into()));
+    assert_eq!(info["value"], PropValue::String("playing".into()));
+    assert!(validate_media_node(&node).is_empty());
+}
+
+#[test]
+fn test_audio_player_required_props() {
+    assert_eq!(
+        validate_media_node(&SurfaceNode::new("AudioPlayer")),
+        vec![
+            "AudioPlayer.source: required prop missing",
+            "AudioPlayer.playing: required prop missing",
+            "AudioPlayer.on_toggle: required prop missing",
+        ]
+    );
+}
+
+#[test]
+fn test_audio_player_invalid_props() {
+    let mut node = AudioPlayerBuilder::new("song.mp3", false, PropValue::lambda(1))
+        .artwork("cover.png")
+        .build();
+    node.set_prop("playing", PropValue::Number(1.0));
+    node.set_prop("title", PropValue::Bool(true));
+    node.set_prop("volume", PropValue::Number(0.5));
+    assert_eq!(
+        validate_media_node(&node),
+        vec![
+            "AudioPlayer.source: expected url (https://, http://) or asset:<name>, got \"song.mp3\"",
+            "AudioPlayer.artwork: expected url (https://, http://) or asset:<name>, got \"cover.png\"",
+            "AudioPlayer.playing: expected bool, got number",
+            "AudioPlayer.on_toggle: expected action, got lambda",
+            "AudioPlayer.title: expected string, got bool",
+            "AudioPlayer: unknown prop 'volume'",
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_media_component() {
+    assert_eq!(
+        validate_media_node(&SurfaceNode::new("Audio")),
+        vec!["Unknown media component: Audio"]
+    );
+}
@@ -0,0 +1,183 @@
+//! Tests for the screen-stack navigation model.
+
+use pepl_ui::{
+    BackOutcome, ColumnBuilder, PropValue, Screen, ScreenStack, Surface, SurfaceNode, TextBuilder,
+};
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+fn screen(name: &str) -> Screen {
+    Screen::new(
+        name,
+        Surface::new(
+            ColumnBuilder::new()
+                .child(TextBuilder::new(name).build())
+                .build(),
+        ),
+    )
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Stack operations
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_new_stack_has_root() {
+    let stack = ScreenStack::new(screen("inbox"));
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack.current().unwrap().name, "inbox");
+    assert!(stack.validate().is_empty());
+}
+
+#[test]
+fn test_push_and_pop() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    stack.push(screen("message"));
+    stack.push(screen("attachment"));
+    assert_eq!(stack.names(), vec!["inbox", "message", "attachment"]);
+
+    assert_eq!(stack.pop().unwrap().name, "attachment");
+    assert_eq!(stack.current().unwrap().name, "message");
+}
+
+#[test]
+fn test_pop_never_removes_root() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    assert_eq!(stack.pop(), None);
+    assert_eq!(stack.len(), 1);
+}
+
+#[test]
+fn test_replace_top() {
+    let mut stack = ScreenStack::new(screen("login"));
+    let previous = stack.replace(screen("home"));
+    assert_eq!(previous.unwrap().name, "login");
+    assert_eq!(stack.names(), vec!["home"]);
+}
+
+#[test]
+fn test_replace_on_empty_stack() {
+    let mut stack = ScreenStack::default();
+    assert!(stack.is_empty());
+    assert_eq!(stack.replace(screen("home")), None);
+    assert_eq!(stack.names(), vec!["home"]);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Back handling
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_back_pops() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    stack.push(screen("message"));
+    match stack.back() {
+        BackOutcome::Popped(s) => assert_eq!(s.name, "message"),
+        other => panic!("expected Popped, got {other:?}"),
+    }
+    assert_eq!(stack.names(), vec!["inbox"]);
+}
+
+#[test]
+fn test_back_on_root_exits() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    assert_eq!(stack.back(), BackOutcome::Exit);
+    assert_eq!(ScreenStack::default().back(), BackOutcome::Exit);
+}
+
+#[test]
+fn test_back_dispatches_on_back() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    stack.push(screen("compose").on_back(PropValue::action("confirm_discard")));
+    assert_eq!(
+        stack.back(),
+        BackOutcome::Action(PropValue::action("confirm_discard"))
+    );
+    // The stack is unchanged — the program decides whether to pop.
+    assert_eq!(stack.len(), 2);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Validation
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_validate_empty_stack() {
+    assert_eq!(
+        ScreenStack::default().validate(),
+        vec!["ScreenStack: must contain at least one screen".to_string()]
+    );
+}
+
+#[test]
+fn test_validate_screen_errors() {
+    let mut stack = ScreenStack::new(screen(""));
+    stack.push(screen("detail").on_back(PropValue::lambda(1)));
+    stack.push(Screen::new(
+        "edit",
+        Surface::new(ColumnBuilder::new().build()).with_shortcut("s", PropValue::action("save")),
+    ));
+    assert_eq!(
+        stack.validate(),
+        vec![
+            "ScreenStack.screens[0].name: must not be empty".to_string(),
+            "ScreenStack.screens[1].on_back: expected action, got lambda".to_string(),
+            "ScreenStack.screens[2]: Surface.shortcuts: invalid key combo 's': key 's' requires a modifier".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_screen_tree() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    stack.push(Screen::new(
+        "detail",
+        Surface::new(
+            ColumnBuilder::new()
+                .child(SurfaceNode::new("Blink"))
+                .build(),
+        ),
+    ));
+    assert_eq!(
+        stack.validate(),
+        vec!["ScreenStack.screens[1]: root/0: Unknown component: Blink".to_string()]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// JSON serialization
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_stack_json_shape() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    stack.push(screen("compose").on_back(PropValue::action("confirm_discard")));
+    let parsed: serde_json::Value = serde_json::from_str(&stack.to_json()).unwrap();
+    let screens = parsed["screens"].as_array().unwrap();
+    assert_eq!(screens.len(), 2);
+    assert_eq!(screens[0]["name"], "inbox");
+    assert!(screens[0].get("on_back").is_none());
+    assert_eq!(screens[0]["surface"]["root"]["type"], "Column");
+    assert_eq!(screens[1]["on_back"]["__action"], "confirm_discard");
+}
+
+#[test]
+fn test_stack_json_roundtrip() {
+    let mut stack = ScreenStack::new(screen("inbox"));
+    stack.push(screen("message"));
+    let back: ScreenStack = serde_json::from_str(&stack.to_json()).unwrap();
+    assert_eq!(back, stack);
+}
+
+#[test]
+fn test_stack_determinism() {
+    let build = || {
+        let mut stack = ScreenStack::new(screen("inbox"));
+        stack.push(screen("message").on_back(PropValue::action("back")));
+        stack.to_json()
+    };
+    let first = build();
+    for _ in 0..100 {
+        assert_eq!(build(), first);
+    }
+}
@@ -0,0 +1,102 @@
+//! Tests for `Surface::assign_ids` and the debug serialization.
+
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, NodePath, PropValue, Surface, SurfaceNode, TextBuilder,
+};
+
+fn path(s: &str) -> NodePath {
+    s.parse().unwrap()
+}
+
+fn button(label: &str) -> SurfaceNode {
+    ButtonBuilder::new(label, PropValue::action("tap")).build()
+}
+
+fn screen(children: Vec<SurfaceNode>) -> Surface {
+    Surface::new(ColumnBuilder::new().build().with_children(children))
+}
+
+#[test]
+fn test_every_node_gets_a_unique_id() {
+    let surface = screen(vec![
+        TextBuilder::new("a").build(),
+        ColumnBuilder::new()
+            .child(button("b"))
+            .child(button("c"))
+            .build(),
+    ]);
+    let ids = surface.assign_ids();
+    assert_eq!(ids.len(), 5);
+    let mut unique: Vec<&str> = ids.iter().map(|(_, id)| id).collect();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), 5);
+
+    let id = ids.get(&path("root/1/0")).unwrap();
+    assert!(id.starts_with('n') && id.len() == 17, "{id}");
+    assert_eq!(ids.path_of(id), Some(&path("root/1/0")));
+    assert_eq!(ids.get(&path("root/7")), None);
+}
+
+#[test]
+fn test_ids_are_deterministic() {
+    let build = || screen(vec![TextBuilder::new("a").build(), button("b")]);
+    assert_eq!(build().assign_ids(), build().assign_ids());
+    // Prop values do not participate.
+    let relabelled = screen(vec![TextBuilder::new("z").build(), button("y")]);
+    assert_eq!(build().assign_ids(), relabelled.assign_ids());
+}
+
+#[test]
+fn test_keyed_nodes_keep_ids_across_reorder() {
+    let save = button("Save").with_test_id("save");
+    let cancel = button("Cancel").with_test_id("cancel");
+    let before = screen(vec![save.clone(), cancel.clone()]).assign_ids();
+    let after = screen(vec![TextBuilder::new("new").build(), cancel, save]).assign_ids();
+
+    assert_eq!(before.get(&path("root/0")), after.get(&path("root/2")));
+    assert_eq!(before.get(&path("root/1")), after.get(&path("root/1")));
+}
+
+#[test]
+fn test_key_takes_precedence_over_test_id() {
+    let before = screen(vec![button("a").with_key("k").with_test_id("old")]).assign_ids();
+    let after = screen(vec![
+        TextBuilder::new("new").build(),
+        button("a").with_key("k").with_test_id("renamed"),
+    ])
+    .assign_ids();
+    assert_eq!(before.get(&path("root/0")), after.get(&path("root/1")));
+}
+
+#[test]
+fn test_unkeyed_ids_follow_index_and_type() {
+    let before = screen(vec![button("a"), button("b")]).assign_ids();
+    let after = screen(vec![TextBuilder::new("x").build(), button("b")]).assign_ids();
+    assert_ne!(before.get(&path("root/0")), after.get(&path("root/0")));
+    assert_eq!(before.get(&path("root/1")), after.get(&path("root/1")));
+}
+
+#[test]
+fn test_duplicate_sibling_keys_are_disambiguated() {
+    let ids = screen(vec![
+        button("a").with_test_id("dup"),
+        button("b").with_test_id("dup"),
+    ])
+    .assign_ids();
+    assert_ne!(ids.get(&path("root/0")), ids.get(&path("root/1")));
+}
+
+#[test]
+fn test_debug_json_includes_ids() {
+    let surface = screen(vec![button("a")]);
+    let ids = surface.assign_ids();
+    let debug: serde_json::Value = serde_json::from_str(&surface.to_json_debug()).unwrap();
+    assert_eq!(debug["root"]["id"], ids.get(&NodePath::root()).unwrap());
+    assert_eq!(
+        debug["root"]["children"][0]["id"],
+        ids.get(&path("root/0")).unwrap()
+    );
+    // The regular wire format stays ID-free.
+    assert!(!surface.to_json().contains("\"id\""));
+}
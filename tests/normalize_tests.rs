@@ -0,0 +1,158 @@
+//! Tests for `Surface::normalize`.
+
+use pepl_ui::{
+    ColumnBuilder, CommonProps, Edges, ModalBuilder, PropValue, RowBuilder, ScrollBuilder,
+    ScrollDirection, Surface, SurfaceNode, TextBuilder,
+};
+
+fn text(value: &str) -> SurfaceNode {
+    TextBuilder::new(value).build()
+}
+
+fn normalized(root: SurfaceNode) -> SurfaceNode {
+    let mut surface = Surface::new(root);
+    surface.normalize();
+    let once = surface.clone();
+    surface.normalize();
+    assert_eq!(surface, once, "normalize must be idempotent");
+    surface.root
+}
+
+#[test]
+fn test_removes_default_props() {
+    let root = normalized(
+        RowBuilder::new()
+            .spacing(0.0)
+            .wrap(false)
+            .child(text("a"))
+            .child(TextBuilder::new("b").opacity(1.0).visible(true).build())
+            .build(),
+    );
+    assert!(!root.props.contains_key("spacing"));
+    assert!(!root.props.contains_key("wrap"));
+    assert_eq!(root.children[1], text("b"));
+
+    let kept = normalized(
+        ColumnBuilder::new()
+            .spacing(8.0)
+            .child(text("a"))
+            .child(text("b"))
+            .build(),
+    );
+    assert_eq!(kept.props["spacing"], PropValue::Number(8.0));
+}
+
+#[test]
+fn test_unwraps_single_child_wrappers() {
+    let root = normalized(
+        ColumnBuilder::new()
+            .spacing(8.0)
+            .child(
+                ColumnBuilder::new()
+                    .child(RowBuilder::new().child(text("a")).build())
+                    .build(),
+            )
+            .child(text("b"))
+            .build(),
+    );
+    assert_eq!(root.children, vec![text("a"), text("b")]);
+
+    // The root is unwrapped too
+    assert_eq!(
+        normalized(ColumnBuilder::new().child(text("only")).build()),
+        text("only")
+    );
+}
+
+#[test]
+fn test_keeps_meaningful_wrappers() {
+    let styled = ColumnBuilder::new()
+        .padding(Edges::from_number(16.0))
+        .child(text("a"))
+        .build();
+    assert_eq!(normalized(styled.clone()), styled);
+
+    let keyed = ColumnBuilder::new().key("card").child(text("a")).build();
+    assert_eq!(normalized(keyed.clone()), keyed);
+
+    // A flex child depends on its Row parent
+    let flexed = SurfaceNode::new("Column").with_child(
+        RowBuilder::new()
+            .child(text("a").with_prop("flex", PropValue::Number(1.0)))
+            .build(),
+    );
+    let root = normalized(flexed);
+    assert_eq!(root.component_type, "Row");
+    assert_eq!(root.children[0].props["flex"], PropValue::Number(1.0));
+}
+
+#[test]
+fn test_merges_nested_scrolls() {
+    let root = normalized(
+        ScrollBuilder::new()
+            .child(
+                ScrollBuilder::new()
+                    .child(text("a"))
+                    .child(text("b"))
+                    .build(),
+            )
+            .build(),
+    );
+    assert_eq!(root.component_type, "Scroll");
+    assert_eq!(root.children, vec![text("a"), text("b")]);
+    assert!(!root.props.contains_key("direction"));
+
+    let crossed = ScrollBuilder::new()
+        .child(
+            ScrollBuilder::new()
+                .direction(ScrollDirection::Horizontal)
+                .child(text("a"))
+                .build(),
+        )
+        .build();
+    assert_eq!(normalized(crossed).children[0].component_type, "Scroll");
+}
+
+#[test]
+fn test_normalized_surface_stays_valid() {
+    let mut surface = Surface::new(
+        ColumnBuilder::new()
+            .child(
+                ScrollBuilder::new()
+                    .child(ColumnBuilder::new().child(text("a")).build())
+                    .build(),
+            )
+            .build(),
+    );
+    surface.normalize();
+    assert!(surface.validate().is_empty(), "{:?}", surface.validate());
+    assert_eq!(surface.root.component_type, "Scroll");
+}
+
+#[test]
+fn test_converts_hex_colors() {
+    let root = normalized(text("Hi").with_prop("color", PropValue::String("#FF0000".into())));
+    assert_eq!(root.props["color"], PropValue::color(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(root.props["value"], PropValue::String("Hi".into()));
+}
+
+#[test]
+fn test_keeps_required_visible() {
+    let mut surface = Surface::new(
+        ColumnBuilder::new()
+            .child(text("a"))
+            .child(
+                ModalBuilder::new(true, PropValue::action("close"))
+                    .child(text("b"))
+                    .build(),
+            )
+            .build(),
+    );
+    assert!(surface.validate().is_empty());
+    surface.normalize();
+    assert_eq!(
+        surface.root.children[1].props["visible"],
+        PropValue::Bool(true)
+    );
+    assert!(surface.validate().is_empty(), "{:?}", surface.validate());
+}
@@ -0,0 +1,279 @@
+//! Tests for `Surface::diff`, `SurfacePatch`, and `Surface::apply_patch`.
+
+use pepl_ui::patch::PATCH_FORMAT_VERSION;
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, NodePath, PatchOp, PropValue, Route, Surface, SurfaceNode,
+    SurfacePatch, TextBuilder,
+};
+
+fn path(s: &str) -> NodePath {
+    s.parse().unwrap()
+}
+
+fn text(value: &str) -> SurfaceNode {
+    SurfaceNode::new("Text").with_prop("value", PropValue::String(value.into()))
+}
+
+fn item(key: &str) -> SurfaceNode {
+    text(key).with_key(key)
+}
+
+fn list(children: Vec<SurfaceNode>) -> Surface {
+    Surface::new(ColumnBuilder::new().build().with_children(children))
+}
+
+/// Diff `before` into `after`, check the ops reproduce `after`, and return them.
+fn diff(before: &Surface, after: &Surface) -> Vec<PatchOp> {
+    let patch = before.diff_patch(after);
+    let mut patched = before.clone();
+    patched.apply_patch(&patch).unwrap();
+    assert_eq!(&patched, after, "{patch:#?}");
+    patch.ops
+}
+
+#[test]
+fn test_diff_equal_is_empty() {
+    let surface = list(vec![item("a"), item("b")]);
+    assert!(diff(&surface, &surface.clone()).is_empty());
+}
+
+#[test]
+fn test_diff_props() {
+    let before =
+        Surface::new(SurfaceNode::new("Column").with_prop("spacing", PropValue::Number(8.0)));
+    let after =
+        Surface::new(SurfaceNode::new("Column").with_prop("padding", PropValue::Number(16.0)));
+    assert_eq!(
+        diff(&before, &after),
+        vec![
+            PatchOp::RemoveProp {
+                path: NodePath::root(),
+                key: "spacing".into(),
+            },
+            PatchOp::SetProp {
+                path: NodePath::root(),
+                key: "padding".into(),
+                value: PropValue::Number(16.0),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_diff_keyed_reorder_moves() {
+    let before = list(vec![item("a"), item("b"), item("c")]);
+    let after = list(vec![item("c"), item("a"), item("b")]);
+    assert_eq!(
+        diff(&before, &after),
+        vec![PatchOp::MoveChild {
+            path: NodePath::root(),
+            from: 2,
+            to: 0,
+        }]
+    );
+}
+
+#[test]
+fn test_diff_keyed_insert_and_remove() {
+    let before = list(vec![item("a"), item("b"), item("c")]);
+    let after = list(vec![item("a"), item("new"), item("c")]);
+    assert_eq!(
+        diff(&before, &after),
+        vec![
+            PatchOp::RemoveChild {
+                path: NodePath::root(),
+                index: 1,
+            },
+            PatchOp::InsertChild {
+                path: NodePath::root(),
+                index: 1,
+                node: item("new"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_diff_moved_child_keeps_nested_changes() {
+    let before = list(vec![item("a"), item("b")]);
+    let after = list(vec![text("B!").with_key("b"), item("a")]);
+    assert_eq!(
+        diff(&before, &after),
+        vec![
+            PatchOp::MoveChild {
+                path: NodePath::root(),
+                from: 1,
+                to: 0,
+            },
+            PatchOp::SetProp {
+                path: path("root/0"),
+                key: "value".into(),
+                value: PropValue::String("B!".into()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_diff_unkeyed_children_match_in_order() {
+    let before = list(vec![text("a"), text("b"), text("c")]);
+    let after = list(vec![text("a"), text("x")]);
+    assert_eq!(
+        diff(&before, &after),
+        vec![
+            PatchOp::RemoveChild {
+                path: NodePath::root(),
+                index: 2,
+            },
+            PatchOp::SetProp {
+                path: path("root/1"),
+                key: "value".into(),
+                value: PropValue::String("x".into()),
+            },
+        ]
+    );
+    assert_eq!(
+        diff(&after, &before)[0],
+        PatchOp::InsertChild {
+            path: NodePath::root(),
+            index: 2,
+            node: text("c"),
+        }
+    );
+}
+
+#[test]
+fn test_diff_replaces_changed_type_or_key() {
+    let button = ButtonBuilder::new("Go", PropValue::action("go")).build();
+    let before = list(vec![TextBuilder::new("Go").build()]);
+    let after = list(vec![button.clone()]);
+    assert_eq!(
+        diff(&before, &after),
+        vec![PatchOp::ReplaceNode {
+            path: path("root/0"),
+            node: button,
+        }]
+    );
+
+    let rekeyed = Surface::new(SurfaceNode::new("Column").with_key("v2"));
+    let ops = diff(
+        &Surface::new(SurfaceNode::new("Column").with_key("v1")),
+        &rekeyed,
+    );
+    assert!(matches!(ops.as_slice(), [PatchOp::ReplaceNode { .. }]));
+}
+
+#[test]
+fn test_diff_mixed_keyed_and_unkeyed() {
+    let before = list(vec![text("header"), item("a"), item("b"), text("footer")]);
+    let after = list(vec![item("b"), text("header"), item("c"), item("a")]);
+    diff(&before, &after);
+}
+
+#[test]
+fn test_patch_op_wire_format() {
+    let op = PatchOp::MoveChild {
+        path: path("root/1"),
+        from: 3,
+        to: 0,
+    };
+    let json = serde_json::to_string(&op).unwrap();
+    assert_eq!(
+        json,
+        r#"{"op":"move_child","path":"root/1","from":3,"to":0}"#
+    );
+    assert_eq!(serde_json::from_str::<PatchOp>(&json).unwrap(), op);
+}
+
+#[test]
+fn test_diff_surface_fields() {
+    let before = list(vec![item("a")]).with_shortcut("cmd+s", PropValue::action("save"));
+    let after = list(vec![item("a")])
+        .with_route(Route::new("/inbox"))
+        .with_respect_safe_area(true);
+    assert_eq!(
+        diff(&before, &after),
+        vec![
+            PatchOp::SetShortcuts {
+                shortcuts: Default::default(),
+            },
+            PatchOp::SetRoute {
+                route: Some(Route::new("/inbox")),
+            },
+            PatchOp::SetRespectSafeArea { respect: true },
+        ]
+    );
+    assert_eq!(diff(&after, &before)[1], PatchOp::SetRoute { route: None });
+}
+
+#[test]
+fn test_surface_patch_json_roundtrip() {
+    let before = list(vec![item("a"), item("b"), item("c")]);
+    let after =
+        list(vec![text("B").with_key("b"), item("a"), item("d")]).with_route(Route::new("/list"));
+    let patch = before.diff_patch(&after);
+    assert_eq!(patch.version, PATCH_FORMAT_VERSION);
+
+    let json = patch.to_json();
+    assert_eq!(json, before.diff_patch(&after).to_json());
+    let back: SurfacePatch = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, patch);
+
+    let mut patched = before.clone();
+    patched.apply_patch(&back).unwrap();
+    assert_eq!(patched, after);
+}
+
+#[test]
+fn test_surface_patch_wire_format() {
+    let before = list(vec![item("a"), item("b")]);
+    let after = list(vec![item("b"), text("A").with_key("a")]);
+    assert_eq!(
+        before.diff_patch(&after).to_json(),
+        concat!(
+            r#"{"version":1,"ops":["#,
+            r#"{"op":"move_child","path":"root","from":1,"to":0},"#,
+            r#"{"op":"set_prop","path":"root/1","key":"value","value":"A"}"#,
+            r#"]}"#
+        )
+    );
+    assert!(before.diff_patch(&before).is_empty());
+}
+
+#[test]
+fn test_apply_patch_errors() {
+    let original = list(vec![item("a")]);
+    let mut surface = original.clone();
+    assert_eq!(
+        surface.apply_patch(&SurfacePatch::new(vec![PatchOp::RemoveChild {
+            path: path("root/4"),
+            index: 0,
+        }])),
+        Err("root/4: no such node".to_string())
+    );
+    assert_eq!(
+        surface.apply_patch(&SurfacePatch::new(vec![
+            PatchOp::RemoveProp {
+                path: path("root/0"),
+                key: "value".into(),
+            },
+            PatchOp::MoveChild {
+                path: NodePath::root(),
+                from: 0,
+                to: 1,
+            },
+        ])),
+        Err("root: child index 1 out of range".to_string())
+    );
+    // A failed patch leaves the surface untouched
+    assert_eq!(surface, original);
+
+    let future = SurfacePatch {
+        version: 2,
+        ops: Vec::new(),
+    };
+    assert_eq!(
+        surface.apply_patch(&future),
+        Err("unsupported patch version 2 (expected 1)".to_string())
+    );
+}
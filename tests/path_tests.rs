@@ -0,0 +1,161 @@
+//! Tests for `NodePath` and path-based access to `Surface` nodes.
+
+use pepl_ui::patch::PatchOp;
+use pepl_ui::{
+    ColumnBuilder, NodePath, PathSegment, PropValue, Surface, SurfaceNode, SurfacePatch,
+    TextBuilder,
+};
+
+fn path(s: &str) -> NodePath {
+    s.parse().unwrap()
+}
+
+fn text(value: &str) -> SurfaceNode {
+    TextBuilder::new(value).build()
+}
+
+fn surface() -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .child(text("a"))
+            .child(ColumnBuilder::new().child(text("b")).build())
+            .child(text("c"))
+            .build(),
+    )
+}
+
+#[test]
+fn test_path_parent_and_index() {
+    assert_eq!(path("root/1/0").parent(), Some(path("root/1")));
+    assert_eq!(path("root/1/0").index(), Some(0));
+    assert_eq!(path("root/3").parent(), Some(NodePath::root()));
+    assert_eq!(NodePath::root().parent(), None);
+    assert_eq!(NodePath::root().index(), None);
+}
+
+#[test]
+fn test_get_and_get_mut() {
+    let mut surface = surface();
+    assert_eq!(surface.get(&path("root/1/0")), Some(&text("b")));
+    assert_eq!(surface.get(&path("root/1/1")), None);
+
+    surface
+        .get_mut(&path("root/1/0"))
+        .unwrap()
+        .set_prop("value", PropValue::String("B".into()));
+    assert_eq!(
+        surface.get(&path("root/1/0")).unwrap().props["value"],
+        PropValue::String("B".into())
+    );
+    assert!(surface.get_mut(&path("root/5")).is_none());
+}
+
+#[test]
+fn test_replace() {
+    let mut surface = surface();
+    assert_eq!(surface.replace(&path("root/2"), text("z")), Some(text("c")));
+    assert_eq!(surface.get(&path("root/2")), Some(&text("z")));
+
+    let before = surface.clone();
+    assert_eq!(surface.replace(&path("root/9"), text("x")), None);
+    assert_eq!(surface, before);
+
+    let old_root = surface.replace(&NodePath::root(), text("only")).unwrap();
+    assert_eq!(old_root.children.len(), 3);
+    assert_eq!(surface.root, text("only"));
+}
+
+#[test]
+fn test_remove() {
+    let mut surface = surface();
+    assert_eq!(surface.remove(&path("root/0")), Some(text("a")));
+    // Later siblings shift left
+    assert_eq!(surface.get(&path("root/1")), Some(&text("c")));
+
+    assert_eq!(surface.remove(&path("root/4")), None);
+    assert_eq!(surface.remove(&path("root/7/0")), None);
+    assert_eq!(surface.remove(&NodePath::root()), None);
+    assert_eq!(surface.root.children.len(), 2);
+}
+
+fn keyed_surface() -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .child(text("header").with_key("header"))
+            .child(
+                ColumnBuilder::new()
+                    .child(text("a").with_key("item-a"))
+                    .child(text("b").with_key("item-b"))
+                    .build()
+                    .with_key("list"),
+            )
+            .build(),
+    )
+}
+
+#[test]
+fn test_keyed_path_display_and_parse() {
+    let keyed = NodePath::root().keyed("list").keyed("item-b").child(0);
+    assert_eq!(keyed.to_string(), "root/#list/#item-b/0");
+    assert_eq!(path("root/#list/#item-b/0"), keyed);
+    assert_eq!(keyed.segments()[0], PathSegment::Key("list".to_string()));
+    assert_eq!(keyed.parent().unwrap().index(), None);
+    let json = serde_json::to_string(&keyed).unwrap();
+    assert_eq!(serde_json::from_str::<NodePath>(&json).unwrap(), keyed);
+}
+
+#[test]
+fn test_get_by_key() {
+    let mut surface = keyed_surface();
+    assert_eq!(
+        surface.get(&path("root/#list/#item-b")),
+        surface.get(&path("root/1/1"))
+    );
+    assert_eq!(
+        surface.get(&path("root/#list/1")),
+        Some(&text("b").with_key("item-b"))
+    );
+    assert_eq!(surface.get(&path("root/#missing")), None);
+
+    surface
+        .get_mut(&path("root/#list/#item-a"))
+        .unwrap()
+        .set_prop("value", PropValue::String("A".into()));
+    assert_eq!(
+        surface.get(&path("root/1/0")).unwrap().props["value"],
+        PropValue::String("A".into())
+    );
+}
+
+#[test]
+fn test_replace_and_remove_by_key() {
+    let mut surface = keyed_surface();
+    assert_eq!(
+        surface.replace(&path("root/#header"), text("title")),
+        Some(text("header").with_key("header"))
+    );
+    assert_eq!(
+        surface.remove(&path("root/#list/#item-a")),
+        Some(text("a").with_key("item-a"))
+    );
+    assert_eq!(
+        surface.get(&path("root/1/0")),
+        Some(&text("b").with_key("item-b"))
+    );
+    assert_eq!(surface.remove(&path("root/#list/#item-a")), None);
+}
+
+#[test]
+fn test_patch_by_key() {
+    let mut surface = keyed_surface();
+    let patch = SurfacePatch::new(vec![PatchOp::SetProp {
+        path: path("root/#list/#item-b"),
+        key: "value".into(),
+        value: PropValue::String("B".into()),
+    }]);
+    surface.apply_patch(&patch).unwrap();
+    assert_eq!(
+        surface.get(&path("root/1/1")).unwrap().props["value"],
+        PropValue::String("B".into())
+    );
+}
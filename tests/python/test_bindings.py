@@ -0,0 +1,50 @@
+"""Smoke tests for the `python` feature.
+
+Run after `maturin develop`:  python -m pytest tests/python
+"""
+
+import pytest
+
+import pepl_ui
+
+OK = '{"root":{"type":"Column","props":{},"children":[{"type":"Text","props":{"value":"OK"},"children":[]}]}}'
+
+
+def test_parse_and_validate():
+    surface = pepl_ui.Surface.from_json(OK)
+    assert surface.validate() == []
+    assert pepl_ui.validate(OK) == []
+
+
+def test_validate_reports_paths():
+    bad = OK.replace('"Text"', '"Blink"')
+    assert pepl_ui.validate(bad) == ["root/0: Unknown component: Blink"]
+
+
+def test_diff():
+    assert pepl_ui.diff(OK, OK.replace("OK", "Ok")) == ['root/0 props.value: "OK" != "Ok"']
+    a = pepl_ui.Surface.from_json(OK)
+    assert a.diff(a) == []
+    assert a == pepl_ui.Surface.from_json(a.to_json())
+
+
+def test_ascii_tree():
+    assert pepl_ui.ascii_tree(OK) == 'Column\n└── Text value="OK"\n'
+
+
+def test_audit():
+    assert pepl_ui.Surface.from_json(OK).audit() == [
+        "root: Column has no accessible prop",
+        "root/0: Text has no accessible prop",
+    ]
+
+
+def test_invalid_json_raises_value_error():
+    with pytest.raises(ValueError, match="invalid surface JSON"):
+        pepl_ui.validate("{")
+
+
+def test_oversized_payload_raises_value_error():
+    payload = OK.replace('"OK"', '"' + "x" * 70_000 + '"')
+    with pytest.raises(ValueError, match="string length 70000 exceeds limit 65536"):
+        pepl_ui.validate(payload)
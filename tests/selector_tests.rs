@@ -0,0 +1,81 @@
+//! Tests for `Selector` and `Surface::find_all`.
+
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, CommonProps, NodePath, PropValue, RowBuilder, Selector, Surface,
+    TextBuilder,
+};
+
+fn path(s: &str) -> NodePath {
+    s.parse().unwrap()
+}
+
+fn surface() -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("Cart").test_id("title").build())
+            .child(
+                RowBuilder::new()
+                    .key("actions")
+                    .child(ButtonBuilder::new("Cancel", PropValue::action("cancel")).build())
+                    .child(
+                        ButtonBuilder::new("OK", PropValue::action("checkout"))
+                            .key("ok")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .child(ButtonBuilder::new("OK", PropValue::action("dismiss")).build())
+            .build(),
+    )
+}
+
+#[test]
+fn test_find_all_by_component() {
+    assert_eq!(
+        surface().find_all(&Selector::component("Button")),
+        vec![path("root/1/0"), path("root/1/1"), path("root/2")]
+    );
+    assert!(surface().find_all(&Selector::component("Modal")).is_empty());
+}
+
+#[test]
+fn test_find_all_by_prop() {
+    let ok = Selector::component("Button").prop("label", "OK");
+    assert_eq!(
+        surface().find_all(&ok),
+        vec![path("root/1/1"), path("root/2")]
+    );
+    let checkout = ok.prop("on_tap", PropValue::action("checkout"));
+    assert_eq!(surface().find_all(&checkout), vec![path("root/1/1")]);
+    // Missing props never match
+    assert!(surface()
+        .find_all(&Selector::any().prop("label", PropValue::Nil))
+        .is_empty());
+}
+
+#[test]
+fn test_find_by_key_and_test_id() {
+    let surface = surface();
+    assert_eq!(
+        surface.find(&Selector::any().key("ok")),
+        Some(path("root/1/1"))
+    );
+    assert_eq!(
+        surface.find(&Selector::any().key("actions")),
+        Some(path("root/1"))
+    );
+    assert_eq!(
+        surface.find(&Selector::component("Text").test_id("title")),
+        Some(path("root/0"))
+    );
+    assert_eq!(
+        surface.find(&Selector::component("Row").test_id("title")),
+        None
+    );
+}
+
+#[test]
+fn test_any_matches_every_node() {
+    assert_eq!(surface().find_all(&Selector::any()).len(), 6);
+    assert_eq!(surface().find(&Selector::any()), Some(NodePath::root()));
+}
@@ -0,0 +1,135 @@
+//! Tests for `Slot` placeholders and `Surface::compose`.
+
+use std::collections::BTreeMap;
+
+use pepl_ui::{validate_node, ColumnBuilder, PropValue, Surface, SurfaceNode, TextBuilder};
+
+fn text(value: &str) -> SurfaceNode {
+    TextBuilder::new(value).build()
+}
+
+fn shell() -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .child(SurfaceNode::slot("header"))
+            .child(
+                ColumnBuilder::new()
+                    .child(SurfaceNode::slot("body"))
+                    .build(),
+            )
+            .child(SurfaceNode::slot_with_fallback("footer", text("v1.0")))
+            .build(),
+    )
+}
+
+fn slots(entries: &[(&str, SurfaceNode)]) -> BTreeMap<String, SurfaceNode> {
+    entries
+        .iter()
+        .map(|(name, node)| (name.to_string(), node.clone()))
+        .collect()
+}
+
+#[test]
+fn test_slot_node_shape() {
+    let slot = SurfaceNode::slot("body");
+    assert_eq!(slot.component_type, "Slot");
+    assert_eq!(slot.props["name"], PropValue::String("body".into()));
+    assert_eq!(slot.slot_name(), Some("body"));
+    assert_eq!(text("hi").slot_name(), None);
+    assert_eq!(
+        serde_json::to_string(&slot).unwrap(),
+        r#"{"type":"Slot","props":{"name":"body"},"children":[]}"#
+    );
+}
+
+#[test]
+fn test_slot_names() {
+    assert_eq!(
+        shell().slot_names().into_iter().collect::<Vec<_>>(),
+        vec!["body", "footer", "header"]
+    );
+}
+
+#[test]
+fn test_compose_fills_slots() {
+    let screen = shell().compose(slots(&[("header", text("Inbox")), ("body", text("Empty"))]));
+    let root = &screen.root;
+    assert_eq!(root.children[0], text("Inbox"));
+    assert_eq!(root.children[1].children[0], text("Empty"));
+    // Unfilled footer falls back to its child
+    assert_eq!(root.children[2], text("v1.0"));
+    assert!(screen.slot_names().is_empty());
+    assert!(screen.validate().is_empty(), "{:?}", screen.validate());
+}
+
+#[test]
+fn test_compose_leaves_shell_unchanged() {
+    let shell = shell();
+    let _ = shell.compose(slots(&[("header", text("A"))]));
+    let _ = shell.compose(slots(&[("header", text("B"))]));
+    assert_eq!(shell, self::shell());
+}
+
+#[test]
+fn test_compose_fills_repeated_slots() {
+    let shell = Surface::new(
+        ColumnBuilder::new()
+            .child(SurfaceNode::slot("divider"))
+            .child(text("middle"))
+            .child(SurfaceNode::slot("divider"))
+            .build(),
+    );
+    let screen = shell.compose(slots(&[("divider", text("---"))]));
+    assert_eq!(screen.root.children[0], text("---"));
+    assert_eq!(screen.root.children[2], text("---"));
+}
+
+#[test]
+fn test_compose_root_slot() {
+    let screen = Surface::new(SurfaceNode::slot("page")).compose(slots(&[("page", text("hi"))]));
+    assert_eq!(screen.root, text("hi"));
+}
+
+#[test]
+fn test_compose_does_not_fill_slots_in_content() {
+    let card = ColumnBuilder::new()
+        .child(SurfaceNode::slot("title"))
+        .build();
+    let screen = Surface::new(SurfaceNode::slot("body"))
+        .compose(slots(&[("body", card.clone()), ("title", text("T"))]));
+    assert_eq!(screen.root, card);
+    // A second pass fills them
+    let screen = screen.compose(slots(&[("title", text("T"))]));
+    assert_eq!(screen.root.children[0], text("T"));
+}
+
+#[test]
+fn test_validate_unfilled_slot() {
+    let screen = shell().compose(slots(&[("header", text("Inbox"))]));
+    assert_eq!(
+        screen.validate(),
+        vec!["root/1/0: Slot: unfilled slot 'body' (fill it with Surface::compose)"]
+    );
+}
+
+#[test]
+fn test_validate_malformed_slot() {
+    assert_eq!(
+        validate_node(&SurfaceNode::slot(" ")),
+        vec!["Slot.name: must not be empty"]
+    );
+    assert_eq!(
+        validate_node(&SurfaceNode::new("Slot")),
+        vec!["Slot.name: required prop missing"]
+    );
+    let two = SurfaceNode::slot("x")
+        .with_child(text("a"))
+        .with_child(text("b"));
+    assert_eq!(
+        validate_node(&two),
+        vec![
+            "Slot: unfilled slot 'x' (fill it with Surface::compose)",
+            "Slot: accepts at most one fallback child, but got 2",
+        ]
+    );
+}
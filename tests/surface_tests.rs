@@ -1,8 +1,12 @@
 //! Integration tests for `pepl-ui` Phase 1: Surface tree types, component registry, shared types.
 
 use pepl_ui::{
-    Alignment, BorderStyle, ColorValue, ComponentRegistry, Dimension, Edges, PropRequirement,
-    PropValue, ShadowStyle, Surface, SurfaceNode,
+    validate_key_combo, validate_node, ColumnBuilder, Route, SafeAreaEdges, ScrollBuilder,
+};
+use pepl_ui::{
+    AdaptiveColor, Alignment, BorderStyle, ColorValue, ComponentRegistry, Dimension, Easing, Edges,
+    PropRequirement, PropValue, ShadowStyle, Surface, SurfaceNode, Timestamp, TransitionKind,
+    TransitionSpec,
 };
 use std::collections::BTreeMap;
 
@@ -124,6 +128,17 @@ fn test_prop_value_type_names() {
     assert_eq!(PropValue::color(1.0, 0.0, 0.0, 1.0).type_name(), "color");
     assert_eq!(PropValue::action("foo").type_name(), "action");
     assert_eq!(PropValue::lambda(1).type_name(), "lambda");
+    assert_eq!(PropValue::color_token("primary").type_name(), "color token");
+    assert_eq!(PropValue::i18n("greeting").type_name(), "i18n");
+    assert_eq!(
+        PropValue::timestamp("2026-10-16".parse().unwrap()).type_name(),
+        "timestamp"
+    );
+    assert_eq!(PropValue::bytes(vec![0u8]).type_name(), "bytes");
+    assert_eq!(
+        PropValue::dimension(Dimension::Fill).type_name(),
+        "dimension"
+    );
     assert_eq!(PropValue::List(vec![]).type_name(), "list");
     assert_eq!(PropValue::Record(BTreeMap::new()).type_name(), "record");
 }
@@ -291,6 +306,37 @@ fn test_shadow_style() {
     assert_eq!(parsed["color"]["a"], 0.25);
 }
 
+#[test]
+fn test_transition_spec_json() {
+    let spec = TransitionSpec::slide(250.0).easing(Easing::EaseOut);
+    let json = serde_json::to_string(&spec).unwrap();
+    assert_eq!(
+        json,
+        r#"{"type":"slide","duration":250.0,"easing":"ease_out"}"#
+    );
+    let parsed: TransitionSpec = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, spec);
+
+    let no_easing = serde_json::to_string(&TransitionSpec::fade(100.0)).unwrap();
+    assert!(!no_easing.contains("easing"));
+}
+
+#[test]
+fn test_transition_spec_reduced_motion() {
+    let spec = TransitionSpec::scale(400.0).easing(Easing::EaseInOut);
+    let reduced = spec.reduced_motion();
+    assert_eq!(reduced.kind, TransitionKind::Fade);
+    assert_eq!(
+        reduced.duration,
+        TransitionSpec::REDUCED_MOTION_MAX_DURATION
+    );
+    assert_eq!(reduced.easing, Some(Easing::EaseInOut));
+
+    // Short fades are left untouched
+    let fade = TransitionSpec::fade(80.0);
+    assert_eq!(fade.reduced_motion(), fade);
+}
+
 #[test]
 fn test_color_value_rgb() {
     let c = ColorValue::rgb(1.0, 0.0, 0.0);
@@ -298,13 +344,206 @@ fn test_color_value_rgb() {
     assert_eq!(c.r, 1.0);
 }
 
+#[test]
+fn test_color_value_from_hex() {
+    assert_eq!(ColorValue::from_hex("#FF0000"), Ok(ColorValue::RED));
+    assert_eq!(ColorValue::from_hex("#ffffff"), Ok(ColorValue::WHITE));
+    assert_eq!(
+        ColorValue::from_hex("#00000000"),
+        Ok(ColorValue::TRANSPARENT)
+    );
+    let translucent = ColorValue::from_hex("#3366FF80").unwrap();
+    assert_eq!(translucent.g, 0.4);
+    assert_eq!(translucent.a, 128.0 / 255.0);
+
+    for bad in ["FF0000", "#FFF", "#FF00000", "#GG0000", "#ＦF0000", ""] {
+        let error = ColorValue::from_hex(bad).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("invalid hex color {bad:?}: expected #RRGGBB or #RRGGBBAA")
+        );
+    }
+}
+
+#[test]
+fn test_color_value_display_hex() {
+    assert_eq!(ColorValue::BLACK.to_string(), "#000000");
+    assert_eq!(ColorValue::rgb(0.2, 0.4, 1.0).to_string(), "#3366FF");
+    assert_eq!(ColorValue::new(1.0, 1.0, 1.0, 0.5).to_string(), "#FFFFFF80");
+    assert_eq!(ColorValue::TRANSPARENT.to_string(), "#00000000");
+    let gray = ColorValue::GRAY.to_string();
+    assert_eq!(ColorValue::from_hex(&gray).unwrap().to_string(), gray);
+}
+
+#[test]
+fn test_color_value_from_hsl() {
+    assert_eq!(ColorValue::from_hsl(0.0, 1.0, 0.5), ColorValue::RED);
+    assert_eq!(ColorValue::from_hsl(120.0, 1.0, 0.5), ColorValue::GREEN);
+    assert_eq!(ColorValue::from_hsl(240.0, 1.0, 0.5), ColorValue::BLUE);
+    assert_eq!(ColorValue::from_hsl(-120.0, 1.0, 0.5), ColorValue::BLUE);
+    assert_eq!(ColorValue::from_hsl(42.0, 0.0, 1.0), ColorValue::WHITE);
+    assert_eq!(ColorValue::from_hsl(217.0, 0.9, 0.6).to_string(), "#3D84F5");
+
+    let (h, s, l) = ColorValue::from_hex("#3D84F5").unwrap().to_hsl();
+    assert_eq!(
+        (h.round(), (s * 100.0).round(), (l * 100.0).round()),
+        (217.0, 90.0, 60.0)
+    );
+    assert_eq!(ColorValue::GRAY.to_hsl(), (0.0, 0.0, 0.5));
+}
+
+#[test]
+fn test_adaptive_color_wire_format() {
+    let ink = AdaptiveColor::new(ColorValue::BLACK, ColorValue::WHITE);
+    let value = PropValue::from(ink.clone());
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"__dark":{"r":1.0,"g":1.0,"b":1.0,"a":1.0},"__light":{"r":0.0,"g":0.0,"b":0.0,"a":1.0}}"#
+    );
+    let back: AdaptiveColor =
+        serde_json::from_value(serde_json::to_value(&value).unwrap()).unwrap();
+    assert_eq!(back, ink);
+    assert_eq!(ink.resolve(true), &ColorValue::WHITE);
+    assert_eq!(ink.resolve(false), &ColorValue::BLACK);
+}
+
+#[test]
+fn test_adaptive_color_accepted_by_color_props() {
+    let pair = |light: PropValue, dark: PropValue| {
+        PropValue::Record(BTreeMap::from([
+            ("__light".to_string(), light),
+            ("__dark".to_string(), dark),
+        ]))
+    };
+    let ink = PropValue::from(AdaptiveColor::new(ColorValue::BLACK, ColorValue::WHITE));
+    assert!(ink.is_color());
+    assert!(ink.is_adaptive_color());
+    assert!(pair(
+        PropValue::String("#FFFFFF".into()),
+        PropValue::color_token("surface")
+    )
+    .is_color());
+
+    // Both halves required, each a single color; nesting is not allowed
+    assert!(!pair(ink.clone(), PropValue::String("#000000".into())).is_color());
+    assert!(!pair(
+        PropValue::String("white".into()),
+        PropValue::String("#000000".into())
+    )
+    .is_color());
+    assert!(!PropValue::Record(BTreeMap::from([("__light".to_string(), ink.clone())])).is_color());
+
+    let node = ColumnBuilder::new()
+        .build()
+        .with_prop("background", ink.clone())
+        .with_child(
+            SurfaceNode::new("Text")
+                .with_prop("value", PropValue::String("Hi".into()))
+                .with_prop("color", ink),
+        );
+    assert!(Surface::new(node).validate().is_empty());
+}
+
+#[test]
+fn test_color_value_variants() {
+    let primary = ColorValue::from_hex("#3366FFCC").unwrap();
+    let hover = primary.lighten(0.1);
+    let pressed = primary.darken(0.1);
+    assert_eq!(hover.to_string(), "#668CFFCC");
+    assert_eq!(pressed.to_string(), "#0040FFCC");
+    assert_eq!(primary.lighten(1.0).to_string(), "#FFFFFFCC");
+    assert_eq!(primary.darken(1.0).to_string(), "#000000CC");
+
+    let disabled = primary.with_alpha(0.38);
+    assert_eq!(disabled.a, 0.38);
+    assert_eq!(primary.with_alpha(2.0).a, 1.0);
+
+    assert_eq!(
+        ColorValue::BLACK.mix(&ColorValue::WHITE, 0.5),
+        ColorValue::GRAY
+    );
+    assert_eq!(ColorValue::RED.mix(&ColorValue::BLUE, 0.0), ColorValue::RED);
+    assert_eq!(
+        ColorValue::RED.mix(&ColorValue::BLUE, 3.0),
+        ColorValue::BLUE
+    );
+    assert_eq!(
+        ColorValue::WHITE
+            .mix(&ColorValue::TRANSPARENT, 0.25)
+            .to_string(),
+        "#BFBFBFBF"
+    );
+}
+
+#[test]
+fn test_color_props_accept_hex_strings() {
+    let hex = PropValue::String("#3366FF".into());
+    assert!(hex.is_color());
+    assert_eq!(hex.as_color(), Some(ColorValue::rgb(0.2, 0.4, 1.0)));
+    assert!(!PropValue::String("blue".into()).is_color());
+    assert_eq!(PropValue::color_token("primary").as_color(), None);
+    assert_eq!(
+        PropValue::from(ColorValue::BLUE),
+        PropValue::color(0.0, 0.0, 1.0, 1.0)
+    );
+
+    let text = SurfaceNode::new("Text")
+        .with_prop("value", PropValue::String("hi".into()))
+        .with_prop("color", hex);
+    assert!(validate_node(&text).is_empty());
+    let text = text.with_prop("color", PropValue::String("#33F".into()));
+    assert_eq!(
+        validate_node(&text),
+        vec!["Text.color: expected color, got string"]
+    );
+}
+
+#[test]
+fn test_coerce_colors_serializes_hex_as_color() {
+    let hex = |s: &str| PropValue::String(s.into());
+    let shadow: BTreeMap<String, PropValue> = [
+        ("color".to_string(), hex("#00000080")),
+        ("radius".to_string(), PropValue::Number(4.0)),
+    ]
+    .into();
+    let adaptive: BTreeMap<String, PropValue> = [
+        ("__light".to_string(), hex("#FFFFFF")),
+        ("__dark".to_string(), hex("#000000")),
+    ]
+    .into();
+    let mut surface = Surface::new(
+        SurfaceNode::new("Column")
+            .with_prop("background", PropValue::Record(adaptive))
+            .with_prop("shadow", PropValue::Record(shadow))
+            .with_child(
+                SurfaceNode::new("Text")
+                    .with_prop("value", hex("#FF0000"))
+                    .with_prop("color", hex("#FF0000")),
+            ),
+    );
+    surface.coerce_colors();
+
+    let json: serde_json::Value = serde_json::from_str(&surface.to_json()).unwrap();
+    let text = &json["root"]["children"][0]["props"];
+    assert_eq!(text["value"], "#FF0000");
+    assert_eq!(
+        text["color"],
+        serde_json::json!({"r": 1.0, "g": 0.0, "b": 0.0, "a": 1.0})
+    );
+    let column = &json["root"]["props"];
+    assert_eq!(column["background"]["__dark"]["r"], 0.0);
+    assert_eq!(column["background"]["__light"]["r"], 1.0);
+    assert_eq!(column["shadow"]["color"]["a"], 128.0 / 255.0);
+    assert_eq!(column["shadow"]["radius"], 4.0);
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Component registry tests
 // ══════════════════════════════════════════════════════════════════════════════
 
 #[test]
-fn test_registry_has_10_components() {
-    assert_eq!(registry().len(), 10);
+fn test_registry_has_all_components() {
+    assert_eq!(registry().len(), 35);
 }
 
 #[test]
@@ -313,16 +552,41 @@ fn test_registry_all_component_names() {
     assert_eq!(
         names,
         vec![
+            "AppBar",
+            "AudioPlayer",
+            "BottomSheet",
             "Button",
+            "Canvas",
+            "Carousel",
+            "Chart",
             "Column",
+            "Disclosure",
+            "Divider",
+            "Drawer",
+            "FilePicker",
+            "Form",
+            "Icon",
+            "Image",
             "Modal",
+            "Overlay",
             "ProgressBar",
+            "Rating",
+            "RichText",
             "Row",
             "Scroll",
             "ScrollList",
+            "Skeleton",
+            "Snackbar",
+            "Spacer",
+            "Spinner",
+            "Stepper",
+            "Table",
+            "Tabs",
             "Text",
             "TextInput",
             "Toast",
+            "Tooltip",
+            "Video",
         ]
     );
 }
@@ -357,6 +621,38 @@ fn test_registry_lookup_invalid_e402() {
     assert!(!reg.is_valid(""));
 }
 
+#[test]
+fn test_registry_to_json() {
+    let reg = registry();
+    let json: serde_json::Value = serde_json::from_str(&reg.to_json()).unwrap();
+    let components = json["components"].as_array().unwrap();
+    assert_eq!(components.len(), reg.len());
+
+    let button = components.iter().find(|c| c["name"] == "Button").unwrap();
+    assert_eq!(button["accepts_children"], false);
+    let label = button["props"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["name"] == "label")
+        .unwrap();
+    assert_eq!(label["type"], "string");
+    assert_eq!(label["required"], true);
+    let variant = button["props"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["name"] == "variant")
+        .unwrap();
+    assert_eq!(variant["type"], "enum");
+    assert_eq!(variant["values"][0], "filled");
+
+    assert_eq!(
+        json["common_props"].as_array().unwrap().len(),
+        ComponentRegistry::common_props().len()
+    );
+}
+
 #[test]
 fn test_layout_components_accept_children() {
     let reg = registry();
@@ -458,6 +754,377 @@ fn test_toast_props() {
     assert_eq!(required, vec!["message"]);
 }
 
+// ══════════════════════════════════════════════════════════════════════════════
+// Surface shortcuts tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_surface_shortcuts_default_empty() {
+    let surface = Surface::new(SurfaceNode::new("Column"));
+    assert!(surface.shortcuts.is_empty());
+    assert!(!surface.to_json().contains("shortcuts"));
+}
+
+#[test]
+fn test_surface_shortcuts_json() {
+    let surface = Surface::new(SurfaceNode::new("Column"))
+        .with_shortcut("primary+s", PropValue::action("save"))
+        .with_shortcut("escape", PropValue::action("close"));
+    assert!(surface.validate_shortcuts().is_empty());
+
+    let json = surface.to_json();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["shortcuts"]["primary+s"]["__action"], "save");
+    assert_eq!(parsed["shortcuts"]["escape"]["__action"], "close");
+
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, surface);
+}
+
+#[test]
+fn test_surface_without_shortcuts_deserializes() {
+    let json = r#"{"root":{"type":"Column","props":{},"children":[]}}"#;
+    let surface: Surface = serde_json::from_str(json).unwrap();
+    assert!(surface.shortcuts.is_empty());
+}
+
+#[test]
+fn test_key_combo_valid() {
+    for combo in [
+        "primary+s",
+        "ctrl+shift+z",
+        "primary+alt+shift+meta+k",
+        "alt+1",
+        "escape",
+        "f5",
+        "shift+f12",
+        "page_down",
+        "ctrl+enter",
+    ] {
+        assert_eq!(validate_key_combo(combo), Ok(()), "{combo}");
+    }
+}
+
+#[test]
+fn test_key_combo_invalid() {
+    let cases = [
+        ("s", "key 's' requires a modifier"),
+        ("hyper+s", "unknown modifier 'hyper'"),
+        ("ctrl+ctrl+s", "modifiers must appear once each"),
+        ("shift+ctrl+s", "modifiers must appear once each"),
+        ("ctrl+S", "unknown key 'S'"),
+        ("ctrl+f13", "unknown key 'f13'"),
+        ("", "unknown key ''"),
+    ];
+    for (combo, expected) in cases {
+        let err = validate_key_combo(combo).unwrap_err();
+        assert!(err.contains(expected), "{combo}: {err}");
+    }
+}
+
+#[test]
+fn test_surface_shortcuts_validation_errors() {
+    let surface = Surface::new(SurfaceNode::new("Column"))
+        .with_shortcut("ctrl+s", PropValue::String("save".into()))
+        .with_shortcut("q", PropValue::action("quit"));
+    let errors = surface.validate_shortcuts();
+    assert_eq!(
+        errors,
+        vec![
+            "Surface.shortcuts['ctrl+s']: expected action, got string".to_string(),
+            "Surface.shortcuts: invalid key combo 'q': key 'q' requires a modifier".to_string(),
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Surface route tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_surface_route_json() {
+    let surface = Surface::new(SurfaceNode::new("Column"))
+        .with_route(Route::new("/orders/:id").param("id", "42"));
+    assert!(surface.validate_route().is_empty());
+
+    let json = surface.to_json();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["route"]["path"], "/orders/:id");
+    assert_eq!(parsed["route"]["params"]["id"], "42");
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, surface);
+}
+
+#[test]
+fn test_surface_without_route() {
+    let surface = Surface::new(SurfaceNode::new("Column"));
+    assert_eq!(surface.route, None);
+    assert!(surface.validate_route().is_empty());
+    assert!(!surface.to_json().contains("route"));
+}
+
+#[test]
+fn test_route_valid_patterns() {
+    for path in [
+        "/",
+        "/settings",
+        "/orders/:id",
+        "/users/:user_id/posts/:post_id",
+        "/files/*rest",
+        "/v1.2/help-center",
+    ] {
+        let route = Route::new(path);
+        assert!(route.param_names().is_ok(), "{path}");
+    }
+    assert_eq!(
+        Route::new("/users/:user_id/posts/:post_id")
+            .param_names()
+            .unwrap(),
+        vec!["user_id", "post_id"]
+    );
+}
+
+#[test]
+fn test_route_invalid_patterns() {
+    let cases = [
+        ("orders", "must start with '/'"),
+        ("/orders/", "empty segment"),
+        ("/a//b", "empty segment"),
+        ("/Orders", "invalid literal segment 'Orders'"),
+        ("/orders/:", "invalid parameter name ''"),
+        ("/orders/:Id", "invalid parameter name 'Id'"),
+        ("/a/:id/b/:id", "duplicate parameter 'id'"),
+        (
+            "/files/*rest/more",
+            "catch-all '*rest' must be the last segment",
+        ),
+    ];
+    for (path, expected) in cases {
+        let errors = Route::new(path).validate();
+        assert_eq!(errors.len(), 1, "{path}: {errors:?}");
+        assert!(errors[0].starts_with("Surface.route.path: invalid pattern"));
+        assert!(errors[0].contains(expected), "{path}: {}", errors[0]);
+    }
+}
+
+#[test]
+fn test_route_params_must_match_pattern() {
+    let route = Route::new("/orders/:id").param("tab", "items");
+    assert_eq!(
+        route.validate(),
+        vec![
+            "Surface.route.params: missing value for 'id'".to_string(),
+            "Surface.route.params: 'tab' is not a parameter of '/orders/:id'".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_route_match_path() {
+    let route = Route::new("/users/:user/posts/:post");
+    let params = route.match_path("/users/ada/posts/7").unwrap();
+    assert_eq!(params["user"], "ada");
+    assert_eq!(params["post"], "7");
+
+    assert_eq!(route.match_path("/users/ada/posts"), None);
+    assert_eq!(route.match_path("/users/ada/posts/7/extra"), None);
+    assert_eq!(route.match_path("/people/ada/posts/7"), None);
+    assert_eq!(route.match_path("users/ada/posts/7"), None);
+}
+
+#[test]
+fn test_route_match_root_and_catch_all() {
+    assert_eq!(Route::new("/").match_path("/"), Some(BTreeMap::new()));
+    assert_eq!(Route::new("/").match_path("/home"), None);
+
+    let files = Route::new("/files/*rest");
+    let params = files.match_path("/files/docs/2024/report.pdf").unwrap();
+    assert_eq!(params["rest"], "docs/2024/report.pdf");
+    assert_eq!(files.match_path("/files"), None);
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Safe area tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_surface_respect_safe_area_json() {
+    let root = ColumnBuilder::new()
+        .safe_area(SafeAreaEdges::vertical())
+        .build();
+    let surface = Surface::new(root).with_respect_safe_area(true);
+    assert!(surface.validate().is_empty());
+
+    let json = surface.to_json();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed["respect_safe_area"], true);
+    assert_eq!(
+        parsed["root"]["props"]["safe_area"],
+        serde_json::json!({ "top": true, "bottom": true, "start": false, "end": false })
+    );
+    let back: Surface = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, surface);
+
+    let plain = Surface::new(SurfaceNode::new("Column"));
+    assert!(!plain.respect_safe_area);
+    assert!(!plain.to_json().contains("respect_safe_area"));
+}
+
+#[test]
+fn test_surface_validate_safe_area_placement() {
+    let root = ColumnBuilder::new()
+        .safe_area(SafeAreaEdges::all())
+        .child(ScrollBuilder::new().safe_area(SafeAreaEdges::all()).build())
+        .build();
+    assert_eq!(root.props.get("safe_area"), Some(&PropValue::Bool(true)));
+    assert_eq!(
+        Surface::new(root).validate(),
+        vec![
+            "root/0: Scroll.safe_area: only valid on the root node",
+            "root: Column.safe_area: requires Surface.respect_safe_area",
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Color token tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_color_token_serialization() {
+    let token = PropValue::color_token("primary");
+    let json = serde_json::to_string(&token).unwrap();
+    assert_eq!(json, r#"{"__color_token":"primary"}"#);
+    let back: PropValue = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, token);
+}
+
+#[test]
+fn test_color_token_is_color() {
+    assert!(PropValue::color_token("primary").is_color());
+    assert!(PropValue::color(0.0, 0.0, 0.0, 1.0).is_color());
+    assert!(!PropValue::String("primary".into()).is_color());
+}
+
+#[test]
+fn test_validate_color_tokens() {
+    let root = SurfaceNode::new("Column")
+        .with_child(SurfaceNode::new("Text").with_prop("color", PropValue::color_token("primary")))
+        .with_child(
+            SurfaceNode::new("ProgressBar")
+                .with_prop("color", PropValue::color_token("primray"))
+                .with_prop(
+                    "background",
+                    PropValue::Record(BTreeMap::from([(
+                        "light".to_string(),
+                        PropValue::color_token("nope"),
+                    )])),
+                ),
+        );
+    let errors = Surface::new(root).validate_color_tokens(&["primary", "surface"]);
+    assert_eq!(
+        errors,
+        vec![
+            "root/1: ProgressBar.background: unknown color token 'nope'".to_string(),
+            "root/1: ProgressBar.color: unknown color token 'primray'".to_string(),
+        ]
+    );
+}
+
+// ══════════════════════════════════════════════════════════════════════════════
+// Surface validate / ascii_tree tests
+// ══════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_surface_validate_prefixes_node_paths() {
+    let root = SurfaceNode::new("Column")
+        .with_child(SurfaceNode::new("Text").with_prop("value", PropValue::String("hi".into())))
+        .with_child(SurfaceNode::new("Column").with_child(SurfaceNode::new("Blink")));
+    let surface = Surface::new(root).with_shortcut("q", PropValue::action("quit"));
+    let errors = surface.validate();
+    assert_eq!(errors[0], "root/1/0: Unknown component: Blink");
+    assert_eq!(errors.len(), 2);
+    assert!(errors[1].contains("q"));
+}
+
+#[test]
+fn test_surface_validate_valid() {
+    let root = SurfaceNode::new("Column")
+        .with_child(SurfaceNode::new("Text").with_prop("value", PropValue::String("hi".into())));
+    assert!(Surface::new(root).validate().is_empty());
+}
+
+#[test]
+fn test_surface_validate_flex_outside_row_or_column() {
+    let text = |value: &str| {
+        SurfaceNode::new("Text")
+            .with_prop("value", PropValue::String(value.into()))
+            .with_prop("flex", PropValue::Number(1.0))
+    };
+    let root = SurfaceNode::new("Column")
+        .with_prop("flex", PropValue::Number(1.0))
+        .with_child(text("row child"))
+        .with_child(SurfaceNode::new("Scroll").with_child(text("scroll child")));
+    assert_eq!(
+        Surface::new(root).validate(),
+        vec![
+            "root: Column.flex: only valid on a direct child of Row or Column",
+            "root/1/0: Text.flex: only valid on a direct child of Row or Column",
+        ]
+    );
+}
+
+#[test]
+fn test_surface_validate_align_self_outside_row_or_column() {
+    let root = SurfaceNode::new("Scroll").with_child(
+        SurfaceNode::new("Text")
+            .with_prop("value", PropValue::String("body".into()))
+            .with_prop("align_self", PropValue::String("center".into())),
+    );
+    assert_eq!(
+        Surface::new(root).validate(),
+        vec!["root/0: Text.align_self: only valid on a direct child of Row or Column"]
+    );
+}
+
+#[test]
+fn test_ascii_tree() {
+    let root = SurfaceNode::new("Column")
+        .with_prop("spacing", PropValue::Number(8.0))
+        .with_child(SurfaceNode::new("Text").with_prop("value", PropValue::String("Title".into())))
+        .with_child(
+            SurfaceNode::new("Column")
+                .with_child(
+                    SurfaceNode::new("Button")
+                        .with_test_id("save")
+                        .with_prop("on_tap", PropValue::action("save")),
+                )
+                .with_child(SurfaceNode::new("Text")),
+        )
+        .with_child(SurfaceNode::new("Text"));
+    let expected = "\
+Column spacing=8.0
+├── Text value=\"Title\"
+├── Column
+│   ├── Button #save on_tap={\"__action\":\"save\"}
+│   └── Text
+└── Text
+";
+    assert_eq!(Surface::new(root).ascii_tree(), expected);
+}
+
+#[test]
+fn test_ascii_tree_omits_accessible() {
+    let node = SurfaceNode::new("Text").with_prop(
+        "accessible",
+        PropValue::Record(BTreeMap::from([(
+            "label".to_string(),
+            PropValue::String("x".into()),
+        )])),
+    );
+    assert_eq!(node.ascii_tree(), "Text\n");
+}
+
 // ══════════════════════════════════════════════════════════════════════════════
 // Surface schema freeze test
 // ══════════════════════════════════════════════════════════════════════════════
@@ -560,3 +1227,206 @@ fn test_registry_determinism_100_iterations() {
         );
     }
 }
+
+// ── Timestamp ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_timestamp_serialization_roundtrip() {
+    for text in [
+        "2026-10-16",
+        "2026-10-16T09:30:00Z",
+        "2024-02-29T23:59:59.250+05:30",
+    ] {
+        let value = PropValue::timestamp(text.parse().unwrap());
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, format!(r#"{{"__timestamp":"{text}"}}"#));
+        assert_eq!(serde_json::from_str::<PropValue>(&json).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_timestamp_rejects_invalid_text() {
+    for (text, reason) in [
+        ("2026-02-29", "day out of range"),
+        ("2026-13-01", "month out of range"),
+        ("2026-10-16T24:00:00Z", "time out of range"),
+        ("2026-10-16T09:30:00", "missing offset (use Z or ±HH:MM)"),
+        ("2026-10-16T09:30Z", "expected THH:MM:SS after the date"),
+        ("16/10/2026", "expected YYYY-MM-DD"),
+    ] {
+        assert_eq!(
+            Timestamp::parse(text).unwrap_err(),
+            format!("invalid timestamp '{text}': {reason}")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_timestamp_rejected_on_parse() {
+    let json = r#"{"type":"Text","props":{"due":{"__timestamp":"2026-02-30"}},"children":[]}"#;
+    assert!(serde_json::from_str::<SurfaceNode>(json).is_err());
+    let json = r#"{"type":"Text","props":{"due":{"__timestamp":3}},"children":[]}"#;
+    assert!(serde_json::from_str::<SurfaceNode>(json).is_err());
+}
+
+#[test]
+fn test_timestamp_ordering_uses_instant() {
+    let utc: Timestamp = "2026-10-16T10:00:00Z".parse().unwrap();
+    let offset: Timestamp = "2026-10-16T11:30:00+02:00".parse().unwrap();
+    let date: Timestamp = "2026-10-16".parse().unwrap();
+    assert_eq!(date.unix_millis(), 1_792_108_800_000);
+    assert_eq!(offset.unix_millis(), utc.unix_millis() - 30 * 60 * 1000);
+    assert!(date < offset && offset < utc);
+    assert!(!date.has_time() && utc.has_time());
+}
+
+// ── Bytes ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_bytes_serialize_as_base64() {
+    for (data, encoded) in [
+        (&b""[..], ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (&[0xff, 0xfe, 0x00, 0x89][..], "//4AiQ=="),
+    ] {
+        let value = PropValue::bytes(data);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, format!(r#"{{"__bytes":"{encoded}"}}"#));
+        assert_eq!(serde_json::from_str::<PropValue>(&json).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_invalid_base64_rejected_on_parse() {
+    for payload in [r#""Zg=""#, r#""Z!=="#, r#""Zg==Zg==""#, "3"] {
+        let json = format!(r#"{{"__bytes":{payload}}}"#);
+        assert!(
+            serde_json::from_str::<PropValue>(&json).is_err(),
+            "{json} should not parse"
+        );
+    }
+}
+
+#[test]
+fn test_validate_inline_bytes() {
+    let root = SurfaceNode::new("Column")
+        .with_child(SurfaceNode::new("Text").with_prop("icon", PropValue::bytes(vec![0u8; 8])))
+        .with_child(SurfaceNode::new("Text").with_prop(
+            "items",
+            PropValue::List(vec![PropValue::bytes(vec![0u8; 9])]),
+        ));
+    let surface = Surface::new(root);
+    assert_eq!(
+        surface.validate_inline_bytes(8),
+        vec!["root/1: Text.items: inline bytes 9 exceed limit 8"]
+    );
+    assert!(surface
+        .validate_inline_bytes(Surface::DEFAULT_MAX_INLINE_BYTES)
+        .is_empty());
+}
+
+// ── Dimension PropValue ───────────────────────────────────────────────────────
+
+#[test]
+fn test_dimension_prop_value_wire_format() {
+    for (dim, json) in [
+        (Dimension::Px(120.0), r#"{"__dim":120.0}"#),
+        (Dimension::Auto, r#"{"__dim":"auto"}"#),
+        (Dimension::Fill, r#"{"__dim":"fill"}"#),
+        (Dimension::Percent(50.0), r#"{"__dim":"50%"}"#),
+        (Dimension::Percent(12.5), r#"{"__dim":"12.5%"}"#),
+    ] {
+        let value = PropValue::dimension(dim);
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<PropValue>(json).unwrap(), value);
+    }
+    assert_eq!(
+        serde_json::from_str::<PropValue>(r#"{"__dim":120}"#).unwrap(),
+        PropValue::dimension(Dimension::Px(120.0))
+    );
+}
+
+#[test]
+fn test_invalid_dimension_rejected_on_parse() {
+    for json in [
+        r#"{"__dim":"wide"}"#,
+        r#"{"__dim":"%"}"#,
+        r#"{"__dim":true}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<PropValue>(json).is_err(),
+            "{json} should not parse"
+        );
+    }
+    assert_eq!(
+        Dimension::parse("wide").unwrap_err(),
+        r#"invalid dimension 'wide': expected "auto", "fill", or "<n>%""#
+    );
+}
+
+#[test]
+fn test_dimension_coercion() {
+    assert_eq!(
+        PropValue::Number(8.0).as_dimension(),
+        Some(Dimension::Px(8.0))
+    );
+    assert_eq!(
+        PropValue::dimension(Dimension::Fill).as_dimension(),
+        Some(Dimension::Fill)
+    );
+    assert_eq!(PropValue::String("fill".into()).as_dimension(), None);
+}
+
+#[test]
+fn test_size_props_accept_dimensions() {
+    let nodes = [
+        SurfaceNode::new("Column").with_prop("spacing", PropValue::dimension(Dimension::Fill)),
+        SurfaceNode::new("ProgressBar")
+            .with_prop("value", PropValue::Number(0.5))
+            .with_prop("height", PropValue::dimension(Dimension::Percent(10.0))),
+        SurfaceNode::new("Modal")
+            .with_prop("visible", PropValue::Bool(true))
+            .with_prop("on_dismiss", PropValue::action("close"))
+            .with_prop("width", PropValue::dimension(Dimension::Percent(80.0)))
+            .with_prop("max_height", PropValue::dimension(Dimension::Auto)),
+    ];
+    for node in nodes {
+        assert_eq!(validate_node(&node), Vec::<String>::new(), "{node:?}");
+    }
+}
+
+#[test]
+fn test_size_props_reject_out_of_range_dimensions() {
+    let node = SurfaceNode::new("Row")
+        .with_prop("spacing", PropValue::dimension(Dimension::Percent(150.0)));
+    assert_eq!(
+        validate_node(&node),
+        vec!["Row.spacing: percent must be within 0-100, got 150"]
+    );
+    let node = SurfaceNode::new("Row").with_prop("spacing", PropValue::String("8".into()));
+    assert_eq!(
+        validate_node(&node),
+        vec!["Row.spacing: expected number or dimension, got string"]
+    );
+}
+
+#[test]
+fn test_size_props_reject_out_of_range_numbers() {
+    // Plain numbers are pixels and validate like `{"__dim": n}`
+    let number = SurfaceNode::new("Column").with_prop("width", PropValue::Number(-50.0));
+    let dim =
+        SurfaceNode::new("Column").with_prop("width", PropValue::dimension(Dimension::Px(-50.0)));
+    let expected = vec!["Column.width: pixels must be non-negative, got -50"];
+    assert_eq!(validate_node(&number), expected);
+    assert_eq!(validate_node(&dim), expected);
+
+    let node = SurfaceNode::new("Row").with_prop("spacing", PropValue::Number(f64::INFINITY));
+    assert_eq!(
+        validate_node(&node),
+        vec!["Row.spacing: pixels must be non-negative, got inf"]
+    );
+    let node = SurfaceNode::new("Row").with_prop("spacing", PropValue::Number(0.0));
+    assert!(validate_node(&node).is_empty());
+}
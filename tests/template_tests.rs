@@ -0,0 +1,166 @@
+//! Tests for `Template` placeholder substitution.
+
+use pepl_ui::template::Bindings;
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, ProgressBarBuilder, PropValue, Surface, SurfaceNode, Template,
+    TextBuilder,
+};
+
+fn bindings(pairs: &[(&str, PropValue)]) -> Bindings {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect()
+}
+
+fn text(value: &str) -> Template {
+    Template::new(Surface::new(
+        SurfaceNode::new("Text").with_prop("value", PropValue::String(value.into())),
+    ))
+}
+
+fn rendered_value(template: &Template, b: &Bindings) -> PropValue {
+    template.render(b).root.props["value"].clone()
+}
+
+#[test]
+fn test_substitutes_inside_text() {
+    let t = text("Hello, {name}! You have {count} items.");
+    let b = bindings(&[
+        ("name", PropValue::String("Ada".into())),
+        ("count", PropValue::Number(3.0)),
+    ]);
+    assert_eq!(
+        rendered_value(&t, &b),
+        PropValue::String("Hello, Ada! You have 3 items.".into())
+    );
+}
+
+#[test]
+fn test_whole_string_placeholder_keeps_type() {
+    let t = text("{progress}");
+    let b = bindings(&[("progress", PropValue::Number(0.5))]);
+    assert_eq!(rendered_value(&t, &b), PropValue::Number(0.5));
+}
+
+#[test]
+fn test_number_and_bool_formatting() {
+    let t = text("{a}|{b}|{c}|{d}");
+    let b = bindings(&[
+        ("a", PropValue::Number(2.5)),
+        ("b", PropValue::Number(-4.0)),
+        ("c", PropValue::Bool(true)),
+        ("d", PropValue::Nil),
+    ]);
+    assert_eq!(
+        rendered_value(&t, &b),
+        PropValue::String("2.5|-4|true|".into())
+    );
+}
+
+#[test]
+fn test_escapes() {
+    let t = text("{{name}} costs {{{price}}}");
+    let b = bindings(&[("price", PropValue::Number(5.0))]);
+    assert_eq!(
+        rendered_value(&t, &b),
+        PropValue::String("{name} costs {5}".into())
+    );
+    assert_eq!(
+        t.placeholders().into_iter().collect::<Vec<_>>(),
+        vec!["price"]
+    );
+}
+
+#[test]
+fn test_invalid_names_are_literal() {
+    let t = text("{not a name} {1x} {} }");
+    assert!(t.placeholders().is_empty());
+    assert_eq!(
+        rendered_value(&t, &Bindings::new()),
+        PropValue::String("{not a name} {1x} {} }".into())
+    );
+}
+
+#[test]
+fn test_unbound_left_verbatim() {
+    let t = text("Hi {first} {last}");
+    let b = bindings(&[("first", PropValue::String("Ada".into()))]);
+    assert_eq!(
+        rendered_value(&t, &b),
+        PropValue::String("Hi Ada {last}".into())
+    );
+    assert_eq!(t.unbound(&b), vec!["last".to_string()]);
+}
+
+#[test]
+fn test_nested_nodes_and_action_args() {
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new("Order {id}").build())
+            .child(
+                ButtonBuilder::new(
+                    "Cancel",
+                    PropValue::action_with_args("cancel", vec![PropValue::String("{id}".into())]),
+                )
+                .build(),
+            )
+            .child(ProgressBarBuilder::new(0.0).build())
+            .build(),
+    );
+    let t = Template::new(surface);
+    let out = t.render(&bindings(&[("id", PropValue::Number(42.0))]));
+    assert_eq!(
+        out.root.children[0].props["value"],
+        PropValue::String("Order 42".into())
+    );
+    assert_eq!(
+        out.root.children[1].props["on_tap"],
+        PropValue::action_with_args("cancel", vec![PropValue::Number(42.0)])
+    );
+    // The accessible label generated from the templated text is substituted too.
+    let PropValue::Record(accessible) = &out.root.children[0].props["accessible"] else {
+        panic!("expected accessible record");
+    };
+    assert_eq!(accessible["label"], PropValue::String("Order 42".into()));
+}
+
+#[test]
+fn test_render_does_not_mutate_template() {
+    let t = text("{x}");
+    let _ = t.render(&bindings(&[("x", PropValue::Bool(true))]));
+    assert_eq!(
+        t.surface().root.props["value"],
+        PropValue::String("{x}".into())
+    );
+}
+
+#[test]
+fn test_render_is_deterministic() {
+    let t = text("{a} and {b}");
+    let b = bindings(&[
+        ("a", PropValue::String("x".into())),
+        ("b", PropValue::String("y".into())),
+    ]);
+    assert_eq!(t.render(&b), t.render(&b));
+}
+
+#[test]
+fn test_substituted_values_are_not_rescanned() {
+    let t = text("{a}");
+    let b = bindings(&[
+        ("a", PropValue::String("{b}".into())),
+        ("b", PropValue::String("nope".into())),
+    ]);
+    assert_eq!(rendered_value(&t, &b), PropValue::String("{b}".into()));
+}
+
+#[test]
+fn test_multibyte_text() {
+    let t = text("¡Hola, {name}! ✓");
+    let b = bindings(&[("name", PropValue::String("José".into()))]);
+    assert_eq!(
+        rendered_value(&t, &b),
+        PropValue::String("¡Hola, José! ✓".into())
+    );
+}
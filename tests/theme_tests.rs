@@ -0,0 +1,321 @@
+//! Tests for `PropValue::ThemeToken`, `Theme`, `resolve_theme`,
+//! `Theme::validate_surface`, and `Typography`.
+
+use pepl_ui::components::content::{TextSize, TextWeight};
+use pepl_ui::theme::{resolve_theme, TextStyle, Theme, ThemeErrorKind, Typography};
+use pepl_ui::{
+    validate_node, ButtonBuilder, ColorValue, ColumnBuilder, Edges, IconBuilder, NodePath,
+    PropValue, RowBuilder, Spacing, Surface, SurfaceNode, TextBuilder,
+};
+
+fn blue() -> ColorValue {
+    ColorValue {
+        r: 0.2,
+        g: 0.4,
+        b: 1.0,
+        a: 1.0,
+    }
+}
+
+fn theme() -> Theme {
+    Theme::new()
+        .with_color("primary", blue())
+        .with_spacing("md", 16.0)
+        .with_radius("lg", 12.0)
+        .with_typography("title", 22.0)
+}
+
+fn token(token: &str) -> PropValue {
+    PropValue::theme_token(token)
+}
+
+// ── Wire format ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_theme_token_serialization() {
+    let value = token("spacing.md");
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"__theme_token":"spacing.md"}"#);
+    let back: PropValue = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, value);
+    assert_eq!(value.type_name(), "theme token");
+}
+
+#[test]
+fn test_theme_token_groups() {
+    assert!(token("color.primary").is_color());
+    assert!(!token("spacing.md").is_color());
+    assert!(token("spacing.md").is_theme_token("spacing"));
+    assert!(!token("spacing.").is_theme_token("spacing"));
+    assert!(!token("md").is_theme_token("spacing"));
+}
+
+#[test]
+fn test_theme_json_roundtrip() {
+    let json = serde_json::to_string(&theme()).unwrap();
+    let back: Theme = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, theme());
+
+    let partial: Theme = serde_json::from_str(r#"{ "spacing": { "sm": 8 } }"#).unwrap();
+    assert_eq!(partial.resolve("spacing.sm"), Some(PropValue::Number(8.0)));
+    assert!(partial.colors.is_empty());
+}
+
+// ── Validation ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_validators_accept_tokens_by_group() {
+    let column = SurfaceNode::new("Column")
+        .with_prop("spacing", token("spacing.md"))
+        .with_prop("padding", token("spacing.md"))
+        .with_prop("corner_radius", token("radius.lg"))
+        .with_prop("background", token("color.primary"));
+    assert!(
+        validate_node(&column).is_empty(),
+        "{:?}",
+        validate_node(&column)
+    );
+
+    let text = TextBuilder::new("Title").build();
+    let text = text.with_prop("size", token("typography.title"));
+    assert!(validate_node(&text).is_empty());
+}
+
+#[test]
+fn test_validators_reject_tokens_from_other_groups() {
+    let column = SurfaceNode::new("Column")
+        .with_prop("spacing", token("color.primary"))
+        .with_prop("background", token("spacing.md"));
+    assert_eq!(
+        validate_node(&column),
+        vec![
+            "Column: 'background' must be a color, got theme token",
+            "Column.spacing: expected number or dimension, got theme token",
+        ]
+    );
+}
+
+// ── Resolution ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_resolve_theme_replaces_tokens() {
+    let mut surface = Surface::new(
+        ColumnBuilder::new()
+            .child(
+                TextBuilder::new("Welcome")
+                    .build()
+                    .with_prop("size", token("typography.title"))
+                    .with_prop("color", token("color.primary")),
+            )
+            .build()
+            .with_prop("spacing", token("spacing.md"))
+            .with_prop("corner_radius", token("radius.lg"))
+            .with_prop("background", PropValue::color_token("primary")),
+    );
+    assert!(resolve_theme(&mut surface, &theme()).is_empty());
+
+    let root = &surface.root;
+    assert_eq!(root.props["spacing"], PropValue::Number(16.0));
+    assert_eq!(root.props["corner_radius"], PropValue::Number(12.0));
+    assert_eq!(
+        root.props["background"],
+        PropValue::color(0.2, 0.4, 1.0, 1.0)
+    );
+    let text = &root.children[0];
+    assert_eq!(text.props["size"], PropValue::Number(22.0));
+    assert_eq!(text.props["color"], PropValue::color(0.2, 0.4, 1.0, 1.0));
+    assert!(surface.validate().is_empty(), "{:?}", surface.validate());
+}
+
+#[test]
+fn test_resolve_theme_nested_values() {
+    let mut surface = Surface::new(SurfaceNode::new("Canvas").with_prop(
+        "commands",
+        PropValue::List(vec![PropValue::Record(
+            [("fill".to_string(), token("color.primary"))].into(),
+        )]),
+    ));
+    assert!(resolve_theme(&mut surface, &theme()).is_empty());
+    assert_eq!(
+        surface.root.props["commands"],
+        PropValue::List(vec![PropValue::Record(
+            [("fill".to_string(), PropValue::color(0.2, 0.4, 1.0, 1.0))].into(),
+        )])
+    );
+}
+
+#[test]
+fn test_resolve_theme_reports_unknown_tokens() {
+    let mut surface = Surface::new(
+        SurfaceNode::new("Column")
+            .with_prop("spacing", token("spacing.xxl"))
+            .with_child(
+                SurfaceNode::new("Text")
+                    .with_prop("value", PropValue::String("hi".into()))
+                    .with_prop("color", PropValue::color_token("accent")),
+            ),
+    );
+    assert_eq!(
+        resolve_theme(&mut surface, &theme()),
+        vec![
+            "root: Column.spacing: unknown theme token 'spacing.xxl'",
+            "root/0: Text.color: unknown color token 'accent'",
+        ]
+    );
+    // Unresolved tokens stay in place
+    assert_eq!(surface.root.props["spacing"], token("spacing.xxl"));
+}
+
+#[test]
+fn test_theme_resolve_unknown_group() {
+    assert_eq!(theme().resolve("shadow.md"), None);
+    assert_eq!(theme().resolve("primary"), None);
+    assert_eq!(
+        theme().resolve("color.primary"),
+        Some(PropValue::color(0.2, 0.4, 1.0, 1.0))
+    );
+}
+
+// ── Typography ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_typography_standard_covers_presets() {
+    let typography = Typography::standard();
+    for size in TextSize::ALL {
+        assert!(typography.preset(size).is_some(), "{size:?}");
+    }
+    assert_eq!(
+        typography.preset(TextSize::Title),
+        Some(&TextStyle::new(20.0, 28.0, 600))
+    );
+    assert_eq!(typography.get("caption"), None);
+}
+
+#[test]
+fn test_typography_json() {
+    let typography = Typography::new().with_style("caption", TextStyle::new(12.0, 16.0, 500));
+    assert_eq!(
+        typography.to_json(),
+        r#"{"caption":{"size":12.0,"line_height":16.0,"weight":500}}"#
+    );
+    let back: Typography = serde_json::from_str(&Typography::standard().to_json()).unwrap();
+    assert_eq!(back, Typography::standard());
+}
+
+#[test]
+fn test_text_style_prop() {
+    let node = TextBuilder::new("Settings")
+        .style("title")
+        .weight(TextWeight::Bold)
+        .build();
+    assert_eq!(node.props["style"], PropValue::String("title".into()));
+    assert!(validate_node(&node).is_empty());
+
+    let blank = node
+        .clone()
+        .with_prop("style", PropValue::String(" ".into()));
+    assert_eq!(validate_node(&blank), vec!["Text.style: must not be empty"]);
+    let number = node.with_prop("style", PropValue::Number(20.0));
+    assert_eq!(
+        validate_node(&number),
+        vec!["Text.style: expected string, got number"]
+    );
+}
+
+// ── Spacing scale ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_default_spacing_scale() {
+    let theme = Theme::new().with_spacing("md", 20.0).with_default_spacing();
+    assert_eq!(theme.resolve("spacing.xs"), Some(PropValue::Number(4.0)));
+    assert_eq!(theme.resolve("spacing.xl"), Some(PropValue::Number(32.0)));
+    // Existing steps are kept
+    assert_eq!(theme.resolve("spacing.md"), Some(PropValue::Number(20.0)));
+    for step in Spacing::ALL {
+        assert_eq!(step.token(), format!("spacing.{}", step.as_str()));
+    }
+}
+
+#[test]
+fn test_resolve_spacing_tokens() {
+    let mut surface = Surface::new(
+        ColumnBuilder::new()
+            .spacing_token(Spacing::Sm)
+            .padding_token(Spacing::Lg)
+            .build(),
+    );
+    let theme = Theme::new().with_default_spacing();
+    assert!(resolve_theme(&mut surface, &theme).is_empty());
+    assert_eq!(surface.root.props["spacing"], PropValue::Number(8.0));
+    assert_eq!(surface.root.props["padding"], PropValue::Number(24.0));
+    assert!(surface.validate().is_empty());
+}
+
+// ── Surface validation ────────────────────────────────────────────────────────
+
+#[test]
+fn test_validate_surface_reports_drift() {
+    let theme = theme().with_default_spacing().with_icon("brand_logo");
+    let surface = Surface::new(
+        ColumnBuilder::new()
+            .spacing(13.0)
+            .padding(Edges::sides(0.0, 16.0, 10.0, 16.0))
+            .child(
+                TextBuilder::new("Hi")
+                    .build()
+                    .with_prop("color", PropValue::color_token("accent")),
+            )
+            .child(IconBuilder::new("asset:brand_logo").build())
+            .child(
+                ButtonBuilder::new("Find", PropValue::action("find"))
+                    .icon("serch")
+                    .build(),
+            )
+            .child(
+                RowBuilder::new()
+                    .spacing_token(Spacing::Xl)
+                    .build()
+                    .with_prop("run_spacing", token("spacing.huge")),
+            )
+            .build(),
+    );
+
+    let errors = theme.validate_surface(&surface);
+    assert_eq!(
+        errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec![
+            "root: Column.padding: 10 is not on the spacing scale",
+            "root: Column.spacing: 13 is not on the spacing scale",
+            "root/0: Text.color: unknown color token 'accent'",
+            "root/2: Button.icon: unknown icon 'serch' (did you mean 'search'?)",
+            "root/3: Row.run_spacing: unknown theme token 'spacing.huge'",
+        ]
+    );
+    assert_eq!(errors[1].path, NodePath::root());
+    assert_eq!(errors[1].prop, "spacing");
+    assert_eq!(errors[1].kind, ThemeErrorKind::OffScaleSpacing(13.0));
+    // Validation does not resolve anything
+    assert_eq!(
+        surface.root.children[3].props["spacing"],
+        token("spacing.xl")
+    );
+}
+
+#[test]
+fn test_validate_surface_without_spacing_scale() {
+    let surface = Surface::new(ColumnBuilder::new().spacing(13.0).build());
+    assert!(Theme::new().validate_surface(&surface).is_empty());
+    assert!(theme()
+        .with_default_spacing()
+        .validate_surface(&Surface::new(ColumnBuilder::new().spacing(8.0).build()))
+        .is_empty());
+}
+
+#[test]
+fn test_theme_icons_serialization() {
+    assert!(!serde_json::to_string(&theme()).unwrap().contains("icons"));
+    let with_icons = theme().with_icon("brand_logo");
+    let json = serde_json::to_string(&with_icons).unwrap();
+    assert!(json.contains(r#""icons":["brand_logo"]"#));
+    assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), with_icons);
+}
@@ -0,0 +1,167 @@
+//! Tests for `EventTrace` record/replay and `Surface::resolve_event`.
+
+use pepl_ui::trace::{EventTrace, TraceEvent};
+use pepl_ui::{
+    ButtonBuilder, ColumnBuilder, NodePath, PropValue, Surface, SurfaceNode, TextBuilder,
+    TextInputBuilder,
+};
+
+fn path(s: &str) -> NodePath {
+    s.parse().unwrap()
+}
+
+/// A counter screen: a label, an increment button, and a name input.
+fn counter(count: u32) -> Surface {
+    Surface::new(
+        ColumnBuilder::new()
+            .child(TextBuilder::new(format!("Count: {count}")).build())
+            .child(ButtonBuilder::new("+1", PropValue::action("increment")).build())
+            .child(TextInputBuilder::new("", PropValue::lambda(7)).build())
+            .build(),
+    )
+}
+
+// ── resolve_event ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_resolve_event() {
+    let surface = counter(0);
+    assert_eq!(
+        surface.resolve_event(&path("root/1"), "on_tap"),
+        Some(&PropValue::action("increment"))
+    );
+    assert_eq!(
+        surface.resolve_event(&path("root/2"), "on_change"),
+        Some(&PropValue::lambda(7))
+    );
+    // Not a handler, missing prop, missing node.
+    assert_eq!(surface.resolve_event(&path("root/1"), "label"), None);
+    assert_eq!(surface.resolve_event(&path("root/0"), "on_tap"), None);
+    assert_eq!(surface.resolve_event(&path("root/9"), "on_tap"), None);
+}
+
+#[test]
+fn test_get_resolves_event_paths() {
+    let surface = counter(0);
+    assert_eq!(
+        surface.get(&NodePath::root()).unwrap().component_type,
+        "Column"
+    );
+    assert_eq!(
+        surface.get(&path("root/2")).unwrap().component_type,
+        "TextInput"
+    );
+    assert!(surface.get(&path("root/0/0")).is_none());
+}
+
+// ── Recording ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_record_resolves_handler() {
+    let surface = counter(0);
+    let mut trace = EventTrace::new();
+    trace
+        .record(&surface, path("root/1"), "on_tap", vec![])
+        .unwrap();
+    trace
+        .record(&surface, path("root/2"), "on_change", vec!["Ada".into()])
+        .unwrap();
+
+    assert_eq!(trace.len(), 2);
+    assert_eq!(
+        trace.events()[1],
+        TraceEvent::new(path("root/2"), "on_change", PropValue::lambda(7))
+            .with_args(vec!["Ada".into()])
+    );
+}
+
+#[test]
+fn test_record_rejects_missing_handler() {
+    let surface = counter(0);
+    let mut trace = EventTrace::new();
+    assert_eq!(
+        trace.record(&surface, path("root/0"), "on_tap", vec![]),
+        Err("root/0: Text has no handler 'on_tap'".to_string())
+    );
+    assert_eq!(
+        trace.record(&surface, path("root/5"), "on_tap", vec![]),
+        Err("root/5: no node at path".to_string())
+    );
+    assert!(trace.is_empty());
+}
+
+#[test]
+fn test_trace_json_roundtrip() {
+    let mut trace = EventTrace::new();
+    trace.push(TraceEvent::new(
+        path("root/1"),
+        "on_tap",
+        PropValue::action("increment"),
+    ));
+    trace.push(
+        TraceEvent::new(path("root/2"), "on_change", PropValue::lambda(7))
+            .with_args(vec!["Ada".into()]),
+    );
+    let json = trace.to_json();
+    assert_eq!(
+        json,
+        r#"{"events":[{"path":"root/1","prop":"on_tap","handler":{"__action":"increment"}},{"path":"root/2","prop":"on_change","handler":{"__lambda":7},"args":["Ada"]}]}"#
+    );
+    assert_eq!(EventTrace::from_json(&json).unwrap(), trace);
+    assert!(EventTrace::from_json(r#"{"events":[{"path":"x"}]}"#).is_err());
+}
+
+// ── Replay ────────────────────────────────────────────────────────────────────
+
+/// Minimal "evaluator": `increment` bumps the count read from the label.
+fn dispatch(surface: &Surface, event: &TraceEvent) -> Surface {
+    let PropValue::String(label) = &surface.root.children[0].props["value"] else {
+        unreachable!()
+    };
+    let count: u32 = label.trim_start_matches("Count: ").parse().unwrap();
+    match &event.handler {
+        PropValue::ActionRef { action, .. } if action == "increment" => counter(count + 1),
+        _ => surface.clone(),
+    }
+}
+
+#[test]
+fn test_replay_is_deterministic() {
+    let mut trace = EventTrace::new();
+    let mut surface = counter(0);
+    for _ in 0..3 {
+        trace
+            .record(&surface, path("root/1"), "on_tap", vec![])
+            .unwrap();
+        surface = dispatch(&surface, trace.events().last().unwrap());
+    }
+
+    let replayed = trace.replay(counter(0), dispatch).unwrap();
+    assert_eq!(replayed, surface);
+    assert_eq!(replayed, trace.replay(counter(0), dispatch).unwrap());
+}
+
+#[test]
+fn test_replay_reports_divergence() {
+    let mut trace = EventTrace::new();
+    trace.push(TraceEvent::new(
+        path("root/1"),
+        "on_tap",
+        PropValue::action("increment"),
+    ));
+    trace.push(TraceEvent::new(
+        path("root/1"),
+        "on_tap",
+        PropValue::action("save"),
+    ));
+
+    let error = trace.replay(counter(0), dispatch).unwrap_err();
+    assert_eq!(
+        error,
+        r#"event 1: root/1: Button.on_tap: recorded {"__action":"save"}, found {"__action":"increment"}"#
+    );
+
+    let removed = |_: &Surface, _: &TraceEvent| Surface::new(SurfaceNode::new("Column"));
+    let error = trace.replay(counter(0), removed).unwrap_err();
+    assert_eq!(error, "event 1: root/1: no node at path");
+}
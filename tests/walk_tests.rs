@@ -0,0 +1,117 @@
+//! Tests for `SurfaceNode::walk`, its variants, and the `map_*` transforms.
+
+use pepl_ui::{CommonProps, NodePath, PropValue, Route, Surface, SurfaceNode, TextBuilder};
+
+fn node(name: &str, children: Vec<SurfaceNode>) -> SurfaceNode {
+    SurfaceNode::new("Column")
+        .with_prop("name", PropValue::String(name.into()))
+        .with_children(children)
+}
+
+/// a ─┬─ b ── c
+///    └─ d
+fn tree() -> SurfaceNode {
+    node(
+        "a",
+        vec![node("b", vec![node("c", vec![])]), node("d", vec![])],
+    )
+}
+
+fn name(node: &SurfaceNode) -> String {
+    match &node.props["name"] {
+        PropValue::String(name) => name.clone(),
+        other => panic!("unexpected name {other:?}"),
+    }
+}
+
+#[test]
+fn test_walk_pre_order() {
+    let mut visited = Vec::new();
+    tree().walk(|node, path| visited.push(format!("{} {path}", name(node))));
+    assert_eq!(visited, ["a root", "b root/0", "c root/0/0", "d root/1"]);
+}
+
+#[test]
+fn test_walk_post_order() {
+    let mut visited = Vec::new();
+    tree().walk_post(|node, _| visited.push(name(node)));
+    assert_eq!(visited, ["c", "b", "d", "a"]);
+}
+
+#[test]
+fn test_walk_paths_are_relative_to_start() {
+    let tree = tree();
+    let mut paths = Vec::new();
+    tree.children[0].walk(|_, path| paths.push(path.clone()));
+    assert_eq!(paths, [NodePath::root(), NodePath::root().child(0)]);
+}
+
+#[test]
+fn test_walk_mut_sees_callback_changes() {
+    let mut surface = Surface::new(tree());
+    surface.walk_mut(|node, path| {
+        // Prune b's subtree before the walk descends into it
+        if path.depth() == 1 {
+            node.children.clear();
+        }
+        node.set_prop("depth", PropValue::Number(path.depth() as f64));
+    });
+    let mut visited = Vec::new();
+    surface.walk(|node, _| visited.push((name(node), node.props["depth"].clone())));
+    assert_eq!(
+        visited,
+        [
+            ("a".to_string(), PropValue::Number(0.0)),
+            ("b".to_string(), PropValue::Number(1.0)),
+            ("d".to_string(), PropValue::Number(1.0)),
+        ]
+    );
+}
+
+#[test]
+fn test_walk_post_mut_aggregates_bottom_up() {
+    let mut tree = tree();
+    tree.walk_post_mut(|node, _| {
+        let size: f64 = node
+            .children
+            .iter()
+            .map(|child| match child.props["size"] {
+                PropValue::Number(size) => size,
+                _ => unreachable!("children are visited first"),
+            })
+            .sum();
+        node.set_prop("size", PropValue::Number(size + 1.0));
+    });
+    assert_eq!(tree.props["size"], PropValue::Number(4.0));
+    assert_eq!(tree.children[0].props["size"], PropValue::Number(2.0));
+}
+
+#[test]
+fn test_map_nodes_bottom_up() {
+    let surface = Surface::new(tree()).with_route(Route::new("/tree"));
+    let mapped = surface.clone().map_nodes(|node| {
+        // Children are already mapped: prefix each name with its children's
+        let inner: String = node.children.iter().map(name).collect();
+        let name = format!("{}{inner}", name(&node));
+        node.with_prop("name", PropValue::String(name))
+    });
+    assert_eq!(name(&mapped.root), "abcd");
+    assert_eq!(name(&mapped.root.children[0]), "bc");
+    // Everything but the tree is kept
+    assert_eq!(mapped.route, surface.route);
+}
+
+#[test]
+fn test_map_props() {
+    let node = TextBuilder::new("hello").test_id("greeting").build();
+    let mapped = Surface::new(node).map_props(|component, key, value| match (key, value) {
+        ("accessible", _) => None,
+        ("value", PropValue::String(s)) => Some(PropValue::String(format!("{component}: {s}"))),
+        (_, value) => Some(value),
+    });
+    assert_eq!(
+        mapped.root.props.into_iter().collect::<Vec<_>>(),
+        vec![("value".to_string(), PropValue::String("Text: hello".into()))]
+    );
+    assert_eq!(mapped.root.test_id.as_deref(), Some("greeting"));
+}
@@ -0,0 +1,72 @@
+//! Native tests for the wasm exports. Run with `--features wasm`.
+
+#![cfg(feature = "wasm")]
+
+use pepl_ui::wasm::{build_node, registry_json, validate_surface_json};
+
+#[test]
+fn test_validate_surface_json_valid() {
+    let json = r#"{"root":{"type":"Text","props":{"value":"Hi"},"children":[]}}"#;
+    assert_eq!(validate_surface_json(json), "[]");
+}
+
+#[test]
+fn test_validate_surface_json_errors() {
+    let json = r#"{"root":{"type":"Text","props":{"value":1},"children":[]}}"#;
+    let errors: Vec<String> = serde_json::from_str(&validate_surface_json(json)).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("root: Text.value"));
+}
+
+#[test]
+fn test_validate_surface_json_parse_error() {
+    let errors: Vec<String> = serde_json::from_str(&validate_surface_json("{")).unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("invalid surface JSON"));
+}
+
+#[test]
+fn test_validate_surface_json_enforces_limits() {
+    let json = format!(
+        r#"{{"root":{{"type":"Text","props":{{"value":"{}"}},"children":[]}}}}"#,
+        "x".repeat(70_000)
+    );
+    let errors: Vec<String> = serde_json::from_str(&validate_surface_json(&json)).unwrap();
+    assert_eq!(
+        errors,
+        vec!["root: value: string length 70000 exceeds limit 65536"]
+    );
+}
+
+#[test]
+fn test_registry_json() {
+    let json: serde_json::Value = serde_json::from_str(&registry_json()).unwrap();
+    assert!(json["components"].as_array().unwrap().len() >= 11);
+}
+
+#[test]
+fn test_build_node_fills_accessible() {
+    let node = build_node(
+        "Button",
+        r#"{"label":"OK","on_tap":{"__action":"ok"}}"#,
+        "[]",
+    )
+    .unwrap();
+    let surface = format!("{{\"root\":{node}}}");
+    assert_eq!(validate_surface_json(&surface), "[]");
+    assert!(node.contains("\"accessible\""));
+}
+
+#[test]
+fn test_build_node_errors() {
+    assert_eq!(
+        build_node("Blink", "{}", "[]").unwrap_err(),
+        "Unknown component: Blink"
+    );
+    assert!(build_node("Text", "[", "[]")
+        .unwrap_err()
+        .starts_with("invalid props JSON"));
+    assert!(build_node("Column", "{}", "{}")
+        .unwrap_err()
+        .starts_with("invalid children JSON"));
+}